@@ -7,12 +7,14 @@
 // - Pascal syntax highlighting (from bruto-pascal-lang)
 // - Menu bar and status line
 // - Sample Pascal program loaded on startup
+// - Open windows saved on exit and restored on the next start
 
 use turbo_vision::app::Application;
 use turbo_vision::core::command::{CM_CLOSE, CM_HELP_INDEX, CM_QUIT};
 use turbo_vision::core::event::KB_F10;
 use turbo_vision::core::geometry::Rect;
 use turbo_vision::core::menu_data::{Menu, MenuItem};
+use turbo_vision::views::desktop_state::WindowFactories;
 use turbo_vision::views::edit_window::EditWindow;
 use turbo_vision::views::help_file::HelpFile;
 use turbo_vision::views::menu_bar::{MenuBar, SubMenu};
@@ -20,6 +22,9 @@ use turbo_vision::views::msgbox::message_box_ok;
 use turbo_vision::views::status_line::{StatusItem, StatusLine};
 use turbo_vision::views::syntax::{SyntaxHighlighter, Token, TokenType};
 
+/// Window layout saved on exit and restored on startup
+const DESKTOP_FILE: &str = "pascal_ide.desktop";
+
 // Help context IDs
 const HC_FILE_MENU: u16 = 1;
 const HC_EDITOR: u16 = 2;
@@ -514,17 +519,22 @@ fn main() -> turbo_vision::core::error::Result<()> {
         ],
     ));
 
-    // Editor window with Pascal syntax highlighting and sample program
-    let edit_window = EditWindow::new(Rect::new(0, 0, w, h - 2), "Untitled.pas");
-    edit_window
-        .editor_rc()
-        .borrow_mut()
-        .set_highlighter(Box::new(PascalHighlighter::new()));
-    edit_window
-        .editor_rc()
-        .borrow_mut()
-        .set_text(SAMPLE_PROGRAM);
-    app.desktop.add(Box::new(edit_window));
+    // Reopen the windows of the last session, or start with the sample program
+    let mut factories = WindowFactories::new();
+    factories.register("edit_window", |state| {
+        let edit_window = EditWindow::from_state(state)?;
+        setup_editor(&edit_window, state.get("file").is_none());
+        Some(Box::new(edit_window))
+    });
+    let restored = app
+        .desktop
+        .restore_from_file(DESKTOP_FILE, &factories)
+        .unwrap_or(0);
+    if restored == 0 {
+        let edit_window = EditWindow::new(Rect::new(0, 0, w, h - 2), "Untitled.pas");
+        setup_editor(&edit_window, true);
+        app.desktop.add(Box::new(edit_window));
+    }
 
     // Show about dialog at startup
     message_box_ok(
@@ -538,5 +548,18 @@ fn main() -> turbo_vision::core::error::Result<()> {
 
     app.run();
 
+    // Remember the open windows for the next session
+    app.desktop.save_to_file(DESKTOP_FILE)?;
+
     Ok(())
 }
+
+/// Pascal syntax highlighting, plus the sample program for untitled editors
+fn setup_editor(edit_window: &EditWindow, sample: bool) {
+    let editor = edit_window.editor_rc();
+    let mut editor = editor.borrow_mut();
+    editor.set_highlighter(Box::new(PascalHighlighter::new()));
+    if sample {
+        editor.set_text(SAMPLE_PROGRAM);
+    }
+}
@@ -5,22 +5,35 @@
 //! Provides the central event loop and command dispatching system.
 
 use crate::core::command::{
-    CM_CANCEL, CM_CASCADE, CM_COMMAND_SET_CHANGED, CM_HELP_INDEX, CM_QUIT, CM_REDRAW,
-    CM_SCREENSHOT, CM_TILE, CommandId,
+    CM_CANCEL, CM_CASCADE, CM_CLOSE, CM_COMMAND_PALETTE, CM_COMMAND_SET_CHANGED, CM_CONTEXT_MENU,
+    CM_HELP_INDEX, CM_IDLE, CM_KEYBOARD_REF, CM_QUIT, CM_REDRAW, CM_SCREENSHOT, CM_TILE,
+    CM_WINDOW_LIST, CommandId,
 };
 use crate::core::command_set;
 use crate::core::error::Result;
-use crate::core::event::{Event, EventType, KB_ALT_X, KB_CTRL_F12, KB_F1, KB_F12};
-use crate::core::geometry::Rect;
+use crate::core::event::{Event, EventType, KB_F12, MB_RIGHT_BUTTON, alt_code_to_char};
+use crate::core::geometry::{Point, Rect};
+use crate::core::i18n::tr;
+use crate::core::keymap::{KeyBinding, KeyContext, KeyResolution, Keymap};
 use crate::terminal::Terminal;
+use crate::views::accessibility::{AnnouncementSink, LineSink, accessible_lines, describe_focused};
+use crate::views::command_palette::{CommandPalette, PaletteEntry, menu_entries};
 use crate::views::help_context::HelpContext;
 use crate::views::help_file::HelpFile;
 use crate::views::help_window::HelpWindow;
-use crate::views::{IdleView, View, desktop::Desktop, menu_bar::MenuBar, status_line::StatusLine};
+use crate::views::window_list::{WindowListAction, WindowListDialog, window_entries};
+use crate::views::{
+    IdleView, View, ViewId, desktop::Desktop, find_context_menu, focus_path, key_contexts,
+    menu_bar::MenuBar, menu_box::popup_menu, send_context_command, short_type_name,
+    status_line::StatusLine,
+};
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::time::Duration;
 
+/// Help topic id of the generated keyboard reference
+const KEYBOARD_REFERENCE_TOPIC: &str = "keyboard-reference";
+
 pub struct Application {
     pub terminal: Terminal,
     pub menu_bar: Option<MenuBar>,
@@ -39,6 +52,17 @@ pub struct Application {
     help_file: Option<Rc<RefCell<HelpFile>>>,
     /// Help context mappings (context ID to topic ID)
     help_context: HelpContext,
+    /// Receiver of accessibility announcements (focus changes, message boxes)
+    announcer: Option<Box<dyn AnnouncementSink>>,
+    /// Announce whole windows linearly when they get the focus
+    accessible_mode: bool,
+    /// Last announced window (its spoken description) and focused control
+    announced_window: Option<String>,
+    announced_focus: Option<ViewId>,
+    /// Key bindings translated into commands before the views see the keys
+    keymap: Keymap,
+    /// Commands offered by the command palette besides the menu commands
+    palette_commands: Vec<PaletteEntry>,
 }
 
 impl Application {
@@ -72,7 +96,68 @@ impl Application {
     /// }
     /// ```
     pub fn new() -> Result<Self> {
-        let terminal = Terminal::init()?;
+        let mut app = Self::with_terminal(Terminal::init()?);
+
+        // Opt-in remote key injection for testing/automation. Off unless the
+        // TV_REMOTE_KEYS environment variable holds a port number.
+        if let Ok(port_str) = std::env::var("TV_REMOTE_KEYS") {
+            if let Ok(port) = port_str.trim().parse::<u16>() {
+                if let Err(e) = app.enable_remote_input(port) {
+                    log::warn!("TV_REMOTE_KEYS: failed to listen on port {port}: {e}");
+                }
+            }
+        }
+
+        // Opt-in screen-reader announcements: TV_ANNOUNCE=stderr, or a file
+        // (or named pipe) to append spoken lines to. Turns on accessible mode.
+        if let Some(target) = std::env::var_os("TV_ANNOUNCE") {
+            let sink: std::io::Result<Box<dyn AnnouncementSink>> = if target == "stderr" {
+                Ok(Box::new(LineSink::stderr()))
+            } else {
+                LineSink::append(&target).map(|sink| Box::new(sink) as Box<dyn AnnouncementSink>)
+            };
+            match sink {
+                Ok(sink) => {
+                    app.set_announcement_sink(Some(sink));
+                    app.set_accessible_mode(true);
+                }
+                Err(e) => log::warn!("TV_ANNOUNCE: cannot open {}: {e}", target.display()),
+            }
+        }
+
+        // Opt-in session recording, e.g. to capture a bug report. Off unless
+        // the TV_RECORD_EVENTS environment variable names a file.
+        if let Some(path) = std::env::var_os("TV_RECORD_EVENTS") {
+            if let Err(e) = app.start_recording(&path) {
+                log::warn!(
+                    "TV_RECORD_EVENTS: failed to record to {}: {e}",
+                    path.display()
+                );
+            }
+        }
+
+        Ok(app)
+    }
+
+    /// Creates an application around an already initialized terminal.
+    ///
+    /// This is the constructor to use with a non-default backend, for example
+    /// a [`HeadlessBackend`](crate::terminal::HeadlessBackend) when rendering
+    /// the whole application off-screen in tests. Unlike [`new()`](Self::new)
+    /// it does not look at the `TV_REMOTE_KEYS`, `TV_ANNOUNCE` and
+    /// `TV_RECORD_EVENTS` environment variables.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use turbo_vision::app::Application;
+    /// use turbo_vision::terminal::{HeadlessBackend, Terminal};
+    ///
+    /// let terminal = Terminal::with_backend(Box::new(HeadlessBackend::new(80, 25))).unwrap();
+    /// let mut app = Application::with_terminal(terminal);
+    /// app.draw();
+    /// ```
+    pub fn with_terminal(terminal: Terminal) -> Self {
         let (width, height) = terminal.size();
 
         // Create Desktop with full screen bounds initially
@@ -93,18 +178,14 @@ impl Application {
             overlay_widgets: Vec::new(),
             help_file: None,
             help_context: HelpContext::new(),
+            announcer: None,
+            accessible_mode: false,
+            announced_window: None,
+            announced_focus: None,
+            keymap: Keymap::standard(),
+            palette_commands: Vec::new(),
         };
 
-        // Opt-in remote key injection for testing/automation. Off unless the
-        // TV_REMOTE_KEYS environment variable holds a port number.
-        if let Ok(port_str) = std::env::var("TV_REMOTE_KEYS") {
-            if let Ok(port) = port_str.trim().parse::<u16>() {
-                if let Err(e) = app.enable_remote_input(port) {
-                    log::warn!("TV_REMOTE_KEYS: failed to listen on port {port}: {e}");
-                }
-            }
-        }
-
         // Set initial Desktop bounds (adjusts for missing menu/status)
         // Matches Borland: TProgram::initDeskTop() with no menuBar/statusLine
         app.update_desktop_bounds();
@@ -113,7 +194,7 @@ impl Application {
         // This sets up the owner chain so views can resolve colors through Desktop's CP_APP_COLOR palette
         app.desktop.init_palette_chain();
 
-        Ok(app)
+        app
     }
 
     pub fn set_menu_bar(&mut self, menu_bar: MenuBar) {
@@ -194,7 +275,7 @@ impl Application {
     /// Queries the actual terminal size, resizes internal buffers, and
     /// re-lays out the menu bar, status line, and desktop to match.
     pub fn handle_redraw(&mut self) {
-        if let Ok((w, h)) = Terminal::query_size() {
+        if let Ok((w, h)) = self.terminal.screen_size() {
             let (cur_w, cur_h) = self.terminal.size();
             if w != cur_w || h != cur_h {
                 self.terminal.resize(w as u16, h as u16);
@@ -459,6 +540,95 @@ impl Application {
         // Update cursor after drawing all views
         // Desktop contains windows/dialogs with focused controls
         self.desktop.update_cursor(&mut self.terminal);
+
+        self.publish_focus(None);
+    }
+
+    /// Report the focused view after a frame has been drawn: to the remote-input
+    /// `FOCUS` query, and as an announcement when the focus has moved.
+    ///
+    /// `modal` is a modal view running its own loop outside the desktop (see
+    /// [`Dialog::execute`](crate::views::dialog::Dialog::execute)); the focus is
+    /// then looked up inside it instead of on the desktop.
+    pub(crate) fn publish_focus(&mut self, modal: Option<&dyn View>) {
+        let root: &dyn View = modal.unwrap_or(&self.desktop);
+        if self.terminal.has_remote_input() {
+            let focus = describe_focus(root);
+            self.terminal.set_remote_focus(focus);
+        }
+        if self.announcer.is_none() {
+            return;
+        }
+
+        // The focused window: the modal view, or the desktop's top window.
+        let window = match modal {
+            Some(view) => Some(view),
+            None => self.desktop.current().map(|(_, view)| view),
+        };
+        let window_name = window
+            .and_then(View::accessible)
+            .map(|info| info.to_string());
+        let focused = focus_path(root).last().map(|&(id, _)| id);
+
+        let mut lines = Vec::new();
+        let window_changed = window_name != self.announced_window;
+        if window_changed {
+            if let Some(window) = window.filter(|_| self.accessible_mode) {
+                lines = accessible_lines(window);
+            } else if let Some(name) = &window_name {
+                lines.push(name.clone());
+            }
+        }
+        // In accessible mode the window rendering already marks the focus.
+        if focused != self.announced_focus && !(window_changed && self.accessible_mode) {
+            lines.extend(describe_focused(root));
+        }
+
+        self.announced_window = window_name;
+        self.announced_focus = focused;
+        for line in lines {
+            self.announce(&line);
+        }
+    }
+
+    /// Send one line of text to the announcement sink, if any
+    ///
+    /// Applications can call this to announce status changes themselves
+    /// (e.g. "File saved").
+    pub fn announce(&mut self, text: &str) {
+        if let Some(sink) = &mut self.announcer {
+            sink.announce(text);
+        }
+    }
+
+    /// Set (or remove, with `None`) the sink that receives accessibility announcements
+    ///
+    /// Once set, focus changes, newly focused windows and `message_box` texts are
+    /// announced to it. See [`accessibility`](crate::views::accessibility).
+    pub fn set_announcement_sink(&mut self, sink: Option<Box<dyn AnnouncementSink>>) {
+        self.announcer = sink;
+        self.announced_window = None;
+        self.announced_focus = None;
+    }
+
+    /// Enable or disable accessible mode
+    ///
+    /// In accessible mode a window or dialog that gets the focus is announced
+    /// in full, one line per control (see [`accessible_text`](Self::accessible_text)),
+    /// instead of by its title only.
+    pub fn set_accessible_mode(&mut self, enabled: bool) {
+        self.accessible_mode = enabled;
+    }
+
+    /// Linear, screen-reader friendly rendering of the focused window
+    ///
+    /// One line per control, indented by nesting depth, with the focused
+    /// control marked by `> `. Empty if no window is open.
+    pub fn accessible_text(&self) -> Vec<String> {
+        self.desktop
+            .current()
+            .map(|(_, window)| accessible_lines(window))
+            .unwrap_or_default()
     }
 
     pub fn handle_event(&mut self, event: &mut Event) {
@@ -470,33 +640,40 @@ impl Application {
             return;
         }
 
-        // Pre-dispatch global shortcuts — these must be handled before any
-        // view sees the event, because focused views (e.g. the editor) would
-        // otherwise consume the key code.
-        if event.what == EventType::Keyboard {
-            match event.key_code {
-                KB_F1 => {
-                    self.show_help();
-                    event.clear();
-                    return;
-                }
-                KB_ALT_X => {
-                    *event = Event::command(CM_QUIT);
-                    self.running = false;
-                    return;
-                }
-                KB_F12 => {
-                    self.dump_screen_ansi();
-                    event.clear();
-                    return;
-                }
-                KB_CTRL_F12 => {
-                    self.take_screenshot();
+        // Translate key bindings before any view sees the event, because
+        // focused views (e.g. the editor) would otherwise consume the key code.
+        match self.keymap.resolve(&key_contexts(&self.desktop), event) {
+            KeyResolution::Command(CM_QUIT) => {
+                // Quit even if a view would swallow the command
+                *event = Event::command(CM_QUIT);
+                self.running = false;
+                return;
+            }
+            KeyResolution::Command(command) => {
+                self.activate_menu_item(command);
+                // Application commands are carried out here, so that a modal
+                // dialog, which ends on any command below 1000, never sees them
+                if self.handle_app_command(command) {
                     event.clear();
                     return;
                 }
-                _ => {}
+                *event = Event::command(command);
+            }
+            KeyResolution::Pending | KeyResolution::Discarded => {
+                event.clear();
+                return;
             }
+            KeyResolution::Unbound => {}
+        }
+        if event.what == EventType::Keyboard && event.key_code == KB_F12 {
+            self.dump_screen_ansi();
+            event.clear();
+            return;
+        }
+
+        if self.select_numbered_window(event) {
+            event.clear();
+            return;
         }
 
         // Menu bar gets first shot
@@ -507,6 +684,21 @@ impl Application {
             }
         }
 
+        // A right-click focuses the view under the mouse, then opens its context
+        // menu, unless the view handled the click itself
+        if event.what == EventType::MouseDown
+            && event.mouse.buttons & MB_RIGHT_BUTTON != 0
+            && self.desktop.bounds().contains(event.mouse.pos)
+        {
+            let pos = event.mouse.pos;
+            self.desktop.handle_event(event);
+            if event.what == EventType::MouseDown {
+                self.show_context_menu(Some(pos));
+                event.clear();
+            }
+            return;
+        }
+
         // Desktop/windows
         self.desktop.handle_event(event);
         if event.what == EventType::Nothing {
@@ -523,30 +715,42 @@ impl Application {
 
         // Application-level command handling
         if event.what == EventType::Command {
-            match event.command {
-                CM_QUIT => {
-                    self.running = false;
-                    event.clear();
-                }
-                CM_TILE => {
-                    self.tile();
-                    event.clear();
-                }
-                CM_CASCADE => {
-                    self.cascade();
-                    event.clear();
-                }
-                CM_HELP_INDEX => {
-                    self.show_help();
-                    event.clear();
-                }
-                CM_SCREENSHOT => {
-                    self.take_screenshot();
-                    event.clear();
-                }
-                _ => {}
+            if event.command == CM_QUIT {
+                self.running = false;
+                event.clear();
+            } else if self.handle_app_command(event.command) {
+                event.clear();
+            }
+        }
+    }
+
+    /// Carry out a command meant for the application itself (tiling, help,
+    /// palette, window list, context menu, screenshot); false for any other
+    fn handle_app_command(&mut self, command: CommandId) -> bool {
+        match command {
+            CM_TILE => self.tile(),
+            CM_CASCADE => self.cascade(),
+            CM_HELP_INDEX => self.show_help(),
+            CM_KEYBOARD_REF => self.show_keyboard_reference(),
+            CM_COMMAND_PALETTE => self.show_command_palette(),
+            CM_CONTEXT_MENU => {
+                self.show_context_menu(None);
             }
+            CM_WINDOW_LIST => self.show_window_list(),
+            CM_SCREENSHOT => self.take_screenshot(),
+            _ => return false,
         }
+        true
+    }
+
+    /// Alt+1..9: bring the window with that number to the front
+    /// Matches Borland: `TProgram::handleEvent()` broadcasts `cmSelectWindowNum`
+    fn select_numbered_window(&mut self, event: &Event) -> bool {
+        event.what == EventType::Keyboard
+            && alt_code_to_char(event.key_code)
+                .and_then(|ch| ch.to_digit(10))
+                .and_then(|number| u8::try_from(number).ok())
+                .is_some_and(|number| self.desktop.select_window_number(number))
     }
 
     /// Enable the remote keyboard-input listener on the given TCP port.
@@ -568,6 +772,29 @@ impl Application {
         Ok(())
     }
 
+    /// Record every incoming event, with timestamps, to `path`.
+    ///
+    /// A thin wrapper around
+    /// [`Terminal::start_recording`](crate::terminal::Terminal::start_recording).
+    /// The resulting file can be replayed deterministically with a
+    /// [`ReplayBackend`](crate::terminal::recording::ReplayBackend).
+    ///
+    /// It can also be enabled without code changes by setting the
+    /// `TV_RECORD_EVENTS` environment variable to the desired file name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created.
+    pub fn start_recording(&mut self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        self.terminal.start_recording(path)?;
+        Ok(())
+    }
+
+    /// Stop recording incoming events.
+    pub fn stop_recording(&mut self) {
+        self.terminal.stop_recording();
+    }
+
     /// Save a PNG screenshot of the current screen (bound to Ctrl+F12).
     ///
     /// The file is written to the current working directory with a
@@ -641,27 +868,168 @@ impl Application {
     /// Show help for a specific topic
     /// Opens the help window and displays the given topic
     pub fn show_help_topic(&mut self, topic_id: &str) {
+        if let Some(ref help_file) = self.help_file {
+            let help_file = Rc::clone(help_file);
+            self.exec_help_window(help_file, topic_id);
+        }
+    }
+
+    fn exec_help_window(&mut self, help_file: Rc<RefCell<HelpFile>>, topic_id: &str) {
         use crate::core::state::SF_MODAL;
 
-        if let Some(ref help_file) = self.help_file {
-            let (width, height) = self.terminal.size();
-            let help_width = (width * 3 / 4).max(40).min(width - 4);
-            let help_height = (height * 3 / 4).max(10).min(height - 4);
-            let x = (width - help_width) / 2;
-            let y = (height - help_height) / 2;
+        let (width, height) = self.terminal.size();
+        let help_width = (width * 3 / 4).max(40).min(width - 4);
+        let help_height = (height * 3 / 4).max(10).min(height - 4);
+        let x = (width - help_width) / 2;
+        let y = (height - help_height) / 2;
+
+        let bounds = Rect::new(x, y, x + help_width, y + help_height);
+        let mut help_window = HelpWindow::new(bounds, "Help", help_file);
+        help_window.show_topic(topic_id);
+
+        // Set SF_MODAL flag so exec_view runs the modal loop
+        // Matches Borland: THelpWindow is displayed modally
+        let current_state = help_window.state();
+        help_window.set_state(current_state | SF_MODAL);
+
+        // Execute the help window as modal
+        self.exec_view(Box::new(help_window));
+    }
 
-            let bounds = Rect::new(x, y, x + help_width, y + help_height);
-            let mut help_window = HelpWindow::new(bounds, "Help", Rc::clone(help_file));
-            help_window.show_topic(topic_id);
+    /// Show the key bindings of the keymap as a help topic (`CM_KEYBOARD_REF`)
+    pub fn show_keyboard_reference(&mut self) {
+        let topic = self
+            .keymap
+            .to_markdown(&tr("Keyboard Reference"), KEYBOARD_REFERENCE_TOPIC);
+        let help_file = Rc::new(RefCell::new(HelpFile::from_content(&topic)));
+        self.exec_help_window(help_file, KEYBOARD_REFERENCE_TOPIC);
+    }
 
-            // Set SF_MODAL flag so exec_view runs the modal loop
-            // Matches Borland: THelpWindow is displayed modally
-            let current_state = help_window.state();
-            help_window.set_state(current_state | SF_MODAL);
+    /// Offer a command that is not in the menu bar in the command palette
+    pub fn add_palette_command(&mut self, text: &str, command: CommandId) {
+        self.palette_commands.push(PaletteEntry::new(text, command));
+    }
+
+    /// Let the user pick a menu or registered command by name and post it
+    /// (`CM_COMMAND_PALETTE`)
+    ///
+    /// Entries without a shortcut of their own show their global key binding.
+    pub fn show_command_palette(&mut self) {
+        let mut entries = self.menu_bar.as_ref().map(menu_entries).unwrap_or_default();
+        entries.extend(self.palette_commands.iter().cloned());
+        for entry in &mut entries {
+            if entry.shortcut.is_none() {
+                entry.shortcut = self
+                    .keymap
+                    .binding_for(KeyContext::Global, entry.command)
+                    .map(KeyBinding::key_text);
+            }
+        }
+
+        let (width, height) = self.terminal.size();
+        let rows = i16::try_from(entries.len()).unwrap_or(i16::MAX);
+        let palette_width = 60.min(width - 4);
+        let palette_height = rows.saturating_add(3).clamp(6, 20).min(height - 4);
+        let x = (width - palette_width) / 2;
+        let y = (height - palette_height) / 4;
+        let bounds = Rect::new(x, y, x + palette_width, y + palette_height);
+
+        let mut palette = CommandPalette::new(bounds, &tr("Command Palette"), entries);
+        if let Some(command) = palette.execute(self) {
+            self.activate_menu_item(command);
+            self.terminal.put_event(Event::command(command));
+        }
+    }
+
+    /// Update the check mark of the menu item sending `command`, for a command
+    /// given without going through the menu (key binding, command palette)
+    fn activate_menu_item(&self, command: CommandId) {
+        if let Some(ref menu_bar) = self.menu_bar {
+            menu_bar.activate(command);
+        }
+    }
+
+    /// Open the context menu of the view under `pos`, or of the focused view
+    /// without a position (`CM_CONTEXT_MENU`, Shift+F10 or the Menu key)
+    /// Matches magiblot: `popupMenu()` with the view as receiver
+    ///
+    /// The menu comes from the innermost view that has one
+    /// ([`View::context_menu`]) and the picked command is sent straight to
+    /// that view; if the view leaves it unhandled, it is posted like any other
+    /// command. Returns false if no view has a menu.
+    pub fn show_context_menu(&mut self, pos: Option<Point>) -> bool {
+        let Some((menu, at, owner)) = find_context_menu(&self.desktop, pos) else {
+            return false;
+        };
+        self.draw();
+        let (width, height) = self.terminal.size();
+        let command = popup_menu(&mut self.terminal, at, menu, Rect::new(0, 0, width, height));
+        if command != 0 {
+            let event = send_context_command(&mut self.desktop, owner, command);
+            if event.what != EventType::Nothing {
+                self.terminal.put_event(event);
+            }
+        }
+        true
+    }
 
-            // Execute the help window as modal
-            self.exec_view(Box::new(help_window));
+    /// Let the user activate or close desktop windows from a list
+    /// (`CM_WINDOW_LIST`, Alt+0)
+    ///
+    /// Closing goes through [`Desktop::close_window`], so `valid()` can keep a
+    /// window open. A window that leaves closing to its owner (auto-close off,
+    /// e.g. to ask about unsaved changes) is brought to the front and
+    /// `CM_CLOSE` is posted for it; with several such windows, only the first.
+    pub fn show_window_list(&mut self) {
+        let entries = window_entries(&self.desktop);
+        if entries.is_empty() {
+            return;
         }
+        let (width, height) = self.terminal.size();
+        let rows = i16::try_from(entries.len()).unwrap_or(i16::MAX);
+        let list_width = 56.min(width - 4);
+        let list_height = rows.saturating_add(4).clamp(12, 20).min(height - 4);
+        let x = (width - list_width) / 2;
+        let y = (height - list_height) / 3;
+        let bounds = Rect::new(x, y, x + list_width, y + list_height);
+
+        let mut dialog = WindowListDialog::new(bounds, &tr("Windows"), entries);
+        match dialog.execute(self) {
+            Some(WindowListAction::Activate(view_id)) => {
+                self.desktop.bring_to_front(view_id);
+            }
+            Some(WindowListAction::Close(view_ids)) => {
+                let asked: Vec<ViewId> = view_ids
+                    .into_iter()
+                    .filter(|&view_id| self.desktop.close_window(view_id))
+                    .collect();
+                self.desktop.remove_closed_windows();
+                let left_to_owner = asked
+                    .into_iter()
+                    .find(|&view_id| self.desktop.contains_id(view_id));
+                if let Some(view_id) = left_to_owner {
+                    self.desktop.bring_to_front(view_id);
+                    self.terminal.put_event(Event::command(CM_CLOSE));
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Key bindings used to turn key presses into commands
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    /// Mutable access to the key bindings, e.g. to bind application commands
+    /// or [`load`](Keymap::load) a user keymap file
+    pub fn keymap_mut(&mut self) -> &mut Keymap {
+        &mut self.keymap
+    }
+
+    /// Replace the key bindings (default: [`Keymap::standard`])
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.keymap = keymap;
     }
 
     /// Show context-sensitive help
@@ -792,6 +1160,13 @@ impl Application {
             command_set::disable_command(CM_CASCADE);
         }
 
+        // Let views pick up data loaded in the background (lazy list and tree
+        // sources) and animate; a view that changed asks for a redraw
+        self.desktop.handle_event(&mut Event::broadcast(CM_IDLE));
+        if crate::views::take_redraw_request() {
+            self.needs_redraw = true;
+        }
+
         // Check if command set changed and broadcast to all views
         if command_set::command_set_changed() {
             let mut event = Event::broadcast(CM_COMMAND_SET_CHANGED);
@@ -844,8 +1219,56 @@ impl Application {
     }
 }
 
+/// Describe the focused view below `root` for the remote-input `FOCUS` query:
+/// `<Type> id=<n> bounds=<ax>,<ay>,<bx>,<by> path=<Root>/<Type>/...`
+fn describe_focus(root: &dyn View) -> Option<String> {
+    let path = focus_path(root);
+    let &(id, view) = path.last()?;
+    let names: Vec<&str> = std::iter::once(short_type_name(root.type_name()))
+        .chain(path.iter().map(|(_, v)| short_type_name(v.type_name())))
+        .collect();
+    let b = view.bounds();
+    Some(format!(
+        "{} id={} bounds={},{},{},{} path={}",
+        short_type_name(view.type_name()),
+        id.as_u16(),
+        b.a.x,
+        b.a.y,
+        b.b.x,
+        b.b.y,
+        names.join("/")
+    ))
+}
+
 impl Drop for Application {
     fn drop(&mut self) {
         let _ = self.terminal.shutdown();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::CM_OK;
+    use crate::core::event::KB_F1;
+    use crate::terminal::HeadlessBackend;
+    use crate::terminal::recording::{RecordedEvent, ReplayBackend};
+    use crate::views::dialog::Dialog;
+
+    #[test]
+    fn global_keys_do_not_end_a_modal_dialog() {
+        let events = [Event::keyboard(KB_F1), Event::command(CM_OK)]
+            .into_iter()
+            .map(|event| RecordedEvent {
+                at: Duration::ZERO,
+                event,
+            })
+            .collect();
+        let backend = ReplayBackend::new(Box::new(HeadlessBackend::new(80, 25)), events);
+        let mut app =
+            Application::with_terminal(Terminal::with_backend(Box::new(backend)).unwrap());
+
+        let dialog = Dialog::new_modal(Rect::new(10, 5, 50, 15), "Busy");
+        assert_eq!(app.exec_view(dialog), CM_OK);
+    }
+}
@@ -17,6 +17,9 @@ pub const CM_PREV: CommandId = 28; // Cycle to previous window (Borland: cmPrev)
 pub const CM_TILE: CommandId = 29; // Tile windows (Borland: cmTile)
 pub const CM_CASCADE: CommandId = 30; // Cascade windows (Borland: cmCascade)
 pub const CM_SCREENSHOT: CommandId = 31; // Save a PNG screenshot (also bound to Ctrl+F12)
+pub const CM_COMMAND_PALETTE: CommandId = 32; // Open the command palette (Ctrl+Shift+P)
+pub const CM_CONTEXT_MENU: CommandId = 33; // Open the focused view's context menu (Shift+F10, Menu key)
+pub const CM_WINDOW_LIST: CommandId = 34; // Show the list of desktop windows (Alt+0)
 pub const CM_OK: CommandId = 10;
 pub const CM_CANCEL: CommandId = 11;
 pub const CM_YES: CommandId = 12;
@@ -25,6 +28,7 @@ pub const CM_DEFAULT: CommandId = 14;
 
 // Broadcast commands
 pub const CM_REDRAW: CommandId = 53; // Full screen redraw needed (terminal resize, palette change, etc.)
+pub const CM_IDLE: CommandId = 54; // Sent while idle; views that pulled in background data clear it to get redrawn
 pub const CM_COMMAND_SET_CHANGED: CommandId = 52; // Borland: cmCommandSetChanged
 pub const CM_RECEIVED_FOCUS: CommandId = 50; // Borland: cmReceivedFocus
 pub const CM_RELEASED_FOCUS: CommandId = 51; // Borland: cmReleasedFocus
@@ -32,6 +36,7 @@ pub const CM_GRAB_DEFAULT: CommandId = 62; // Borland: cmGrabDefault
 pub const CM_RELEASE_DEFAULT: CommandId = 63; // Borland: cmReleaseDefault
 pub const CM_FILE_FOCUSED: CommandId = 64; // Borland: cmFileFocused - file dialog selection changed
 pub const CM_FILE_DOUBLE_CLICKED: CommandId = 65; // Borland: cmFileDoubleClicked - file double-clicked in list
+pub const CM_OUTLINE_CHANGED: CommandId = 67; // Outline edited by the user (OutlineChangeKind stored in key_code)
 
 // Custom commands (user defined)
 pub const CM_ABOUT: CommandId = 100;
@@ -79,3 +84,63 @@ pub const CM_FOCUS_LINK: CommandId = 66; // Label hotkey: focus the linked contr
 pub const CM_LISTBOX_DEMO: CommandId = 150;
 pub const CM_LISTBOX_SELECT: CommandId = 151;
 pub const CM_MEMO_DEMO: CommandId = 152;
+
+/// Names of the standard commands, as written in resource and configuration files
+pub const COMMAND_NAMES: &[(&str, CommandId)] = &[
+    ("CM_QUIT", CM_QUIT),
+    ("CM_CLOSE", CM_CLOSE),
+    ("CM_ZOOM", CM_ZOOM),
+    ("CM_NEXT", CM_NEXT),
+    ("CM_PREV", CM_PREV),
+    ("CM_TILE", CM_TILE),
+    ("CM_CASCADE", CM_CASCADE),
+    ("CM_SCREENSHOT", CM_SCREENSHOT),
+    ("CM_COMMAND_PALETTE", CM_COMMAND_PALETTE),
+    ("CM_CONTEXT_MENU", CM_CONTEXT_MENU),
+    ("CM_WINDOW_LIST", CM_WINDOW_LIST),
+    ("CM_OK", CM_OK),
+    ("CM_CANCEL", CM_CANCEL),
+    ("CM_YES", CM_YES),
+    ("CM_NO", CM_NO),
+    ("CM_DEFAULT", CM_DEFAULT),
+    ("CM_ABOUT", CM_ABOUT),
+    ("CM_NEW", CM_NEW),
+    ("CM_OPEN", CM_OPEN),
+    ("CM_SAVE", CM_SAVE),
+    ("CM_SAVE_AS", CM_SAVE_AS),
+    ("CM_SAVE_ALL", CM_SAVE_ALL),
+    ("CM_CLOSE_FILE", CM_CLOSE_FILE),
+    ("CM_UNDO", CM_UNDO),
+    ("CM_REDO", CM_REDO),
+    ("CM_CUT", CM_CUT),
+    ("CM_COPY", CM_COPY),
+    ("CM_PASTE", CM_PASTE),
+    ("CM_SELECT_ALL", CM_SELECT_ALL),
+    ("CM_FIND", CM_FIND),
+    ("CM_REPLACE", CM_REPLACE),
+    ("CM_SEARCH_AGAIN", CM_SEARCH_AGAIN),
+    ("CM_FIND_IN_FILES", CM_FIND_IN_FILES),
+    ("CM_GOTO_LINE", CM_GOTO_LINE),
+    ("CM_ZOOM_IN", CM_ZOOM_IN),
+    ("CM_ZOOM_OUT", CM_ZOOM_OUT),
+    ("CM_TOGGLE_SIDEBAR", CM_TOGGLE_SIDEBAR),
+    ("CM_TOGGLE_STATUSBAR", CM_TOGGLE_STATUSBAR),
+    ("CM_HELP_INDEX", CM_HELP_INDEX),
+    ("CM_KEYBOARD_REF", CM_KEYBOARD_REF),
+];
+
+/// Look up a standard command by its constant name (e.g. `CM_OPEN`)
+pub fn command_by_name(name: &str) -> Option<CommandId> {
+    COMMAND_NAMES
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(name))
+        .map(|&(_, command)| command)
+}
+
+/// Constant name of a standard command (the inverse of [`command_by_name`])
+pub fn command_name(command: CommandId) -> Option<&'static str> {
+    COMMAND_NAMES
+        .iter()
+        .find(|&&(_, known)| known == command)
+        .map(|&(name, _)| name)
+}
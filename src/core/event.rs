@@ -45,6 +45,7 @@ pub const KB_PGUP: KeyCode = 0x4900;
 pub const KB_PGDN: KeyCode = 0x5100;
 pub const KB_INS: KeyCode = 0x5200;
 pub const KB_DEL: KeyCode = 0x5300;
+pub const KB_MENU: KeyCode = 0xDD00; // Menu (context menu) key
 
 // Alt + letter (scan codes from PC keyboard)
 pub const KB_ALT_A: KeyCode = 0x1E00;
@@ -75,6 +76,16 @@ pub const KB_ALT_Y: KeyCode = 0x1500;
 pub const KB_ALT_Z: KeyCode = 0x2C00;
 pub const KB_ALT_F1: KeyCode = 0x6800; // Alt+F1 for help history back
 pub const KB_ALT_F3: KeyCode = 0x6A00;
+pub const KB_ALT_1: KeyCode = 0x7800;
+pub const KB_ALT_2: KeyCode = 0x7900;
+pub const KB_ALT_3: KeyCode = 0x7A00;
+pub const KB_ALT_4: KeyCode = 0x7B00;
+pub const KB_ALT_5: KeyCode = 0x7C00;
+pub const KB_ALT_6: KeyCode = 0x7D00;
+pub const KB_ALT_7: KeyCode = 0x7E00;
+pub const KB_ALT_8: KeyCode = 0x7F00;
+pub const KB_ALT_9: KeyCode = 0x8000;
+pub const KB_ALT_0: KeyCode = 0x8100;
 
 // ESC + letter (for macOS Alt emulation)
 pub const KB_ESC_F: KeyCode = 0x2101; // ESC+F
@@ -307,9 +318,9 @@ impl fmt::Display for Event {
     }
 }
 
-/// Convert a lowercase letter to its Alt+letter key code
-/// Returns None if the character is not a letter
-fn char_to_alt_code(c: char) -> Option<KeyCode> {
+/// Convert a lowercase letter or a digit to its Alt+key code
+/// Returns None if the character is not a letter or digit
+pub(crate) fn char_to_alt_code(c: char) -> Option<KeyCode> {
     match c {
         'a' => Some(KB_ALT_A),
         'b' => Some(KB_ALT_B),
@@ -337,10 +348,19 @@ fn char_to_alt_code(c: char) -> Option<KeyCode> {
         'x' => Some(KB_ALT_X),
         'y' => Some(KB_ALT_Y),
         'z' => Some(KB_ALT_Z),
+        '0' => Some(KB_ALT_0),
+        '1'..='9' => Some(KB_ALT_1 + (c as KeyCode - '1' as KeyCode) * 0x100),
         _ => None,
     }
 }
 
+/// Letter or digit of an Alt+key code (the inverse of `char_to_alt_code`)
+pub(crate) fn alt_code_to_char(key_code: KeyCode) -> Option<char> {
+    ('a'..='z')
+        .chain('0'..='9')
+        .find(|&c| char_to_alt_code(c) == Some(key_code))
+}
+
 /// ESC sequence tracker for macOS Alt emulation
 pub struct EscSequenceTracker {
     last_esc_time: Option<Instant>,
@@ -421,7 +441,7 @@ impl EscSequenceTracker {
                 if Instant::now().duration_since(last_time)
                     <= Duration::from_millis(self.timeout_ms)
                 {
-                    // Map ESC+letter to ALT codes (for macOS Alt emulation)
+                    // Map ESC+letter/digit to ALT codes (for macOS Alt emulation)
                     // This makes ESC+F identical to Alt+F from the application's perspective
                     if let CKC::Char(c) = key.code {
                         if let Some(alt_code) = char_to_alt_code(c.to_ascii_lowercase()) {
@@ -472,6 +492,7 @@ fn parse_base_key(upper: &str, original: &str) -> Option<CKC> {
         "DOWN" => CKC::Down,
         "LEFT" => CKC::Left,
         "RIGHT" => CKC::Right,
+        "MENU" => CKC::Menu,
         _ => {
             // Any single character is taken literally (preserving its case).
             let mut chars = original.chars();
@@ -495,7 +516,7 @@ fn parse_base_key(upper: &str, original: &str) -> Option<CKC> {
 /// Recognized keys: `F1`..`F12`, `ENTER`/`RETURN`, `ESC`/`ESCAPE`, `TAB`,
 /// `BACKTAB`, `SPACE`, `BACKSPACE`, `DEL`/`DELETE`, `INS`/`INSERT`, `HOME`,
 /// `END`, `PGUP`/`PAGEUP`, `PGDN`/`PAGEDOWN`, `UP`, `DOWN`, `LEFT`, `RIGHT`,
-/// and any single character.
+/// `MENU`, and any single character.
 ///
 /// Returns `None` if the chord cannot be parsed into a known key code. The
 /// resulting event is identical to what the corresponding physical key press
@@ -537,6 +558,57 @@ pub fn parse_key_chord(chord: &str) -> Option<Event> {
     })
 }
 
+/// Human-readable name of a key code, such as `"Ctrl+K"`, `"Alt+X"` or `"F10"`
+///
+/// The inverse of [`parse_key_chord`]: the name parses back to the same key
+/// code. Returns `None` for codes no key press produces.
+pub fn key_name(key_code: KeyCode) -> Option<String> {
+    let name = match key_code {
+        KB_ESC => "Esc",
+        KB_ENTER => "Enter",
+        KB_BACKSPACE => "Backspace",
+        KB_TAB => "Tab",
+        KB_SHIFT_TAB => "Shift+Tab",
+        KB_UP => "Up",
+        KB_DOWN => "Down",
+        KB_LEFT => "Left",
+        KB_RIGHT => "Right",
+        KB_HOME => "Home",
+        KB_END => "End",
+        KB_PGUP => "PgUp",
+        KB_PGDN => "PgDn",
+        KB_INS => "Ins",
+        KB_DEL => "Del",
+        KB_MENU => "Menu",
+        KB_ALT_F1 => "Alt+F1",
+        KB_ALT_F3 => "Alt+F3",
+        KB_CTRL_F12 => "Ctrl+F12",
+        0x20 => "Space",
+        _ => {
+            let function_keys = [
+                KB_F1, KB_F2, KB_F3, KB_F4, KB_F5, KB_F6, KB_F7, KB_F8, KB_F9, KB_F10, KB_F11,
+                KB_F12,
+            ];
+            if let Some(index) = function_keys.iter().position(|&key| key == key_code) {
+                return Some(format!("F{}", index + 1));
+            }
+            if let Some(letter) = alt_code_to_char(key_code) {
+                return Some(format!("Alt+{}", letter.to_ascii_uppercase()));
+            }
+            return match key_code {
+                KB_CTRL_A..=KB_CTRL_Z => {
+                    char::from_u32(u32::from('A') + u32::from(key_code - KB_CTRL_A))
+                        .map(|letter| format!("Ctrl+{letter}"))
+                }
+                _ => char::from_u32(u32::from(key_code))
+                    .filter(|ch| !ch.is_control())
+                    .map(String::from),
+            };
+        }
+    };
+    Some(name.to_string())
+}
+
 /// Convert crossterm KeyEvent to our KeyCode
 fn crossterm_to_keycode(key: KeyEvent) -> KeyCode {
     match key.code {
@@ -552,7 +624,7 @@ fn crossterm_to_keycode(key: KeyEvent) -> KeyCode {
 
             // Check for Alt modifier
             if key.modifiers.contains(KeyModifiers::ALT) {
-                // Alt + letter
+                // Alt + letter or digit
                 if let Some(alt_code) = char_to_alt_code(c.to_ascii_lowercase()) {
                     return alt_code;
                 }
@@ -581,6 +653,7 @@ fn crossterm_to_keycode(key: KeyEvent) -> KeyCode {
         CKC::PageDown => KB_PGDN,
         CKC::Insert => KB_INS,
         CKC::Delete => KB_DEL,
+        CKC::Menu => KB_MENU,
         CKC::F(1) => {
             if key.modifiers.contains(KeyModifiers::ALT) {
                 KB_ALT_F1
@@ -645,6 +718,32 @@ mod chord_tests {
         assert_eq!(parse_key_chord("Tab").unwrap().key_code, KB_TAB);
     }
 
+    #[test]
+    fn key_names_parse_back() {
+        for key_code in [
+            KB_CTRL_K,
+            KB_ALT_X,
+            KB_F10,
+            KB_CTRL_F12,
+            KB_SHIFT_TAB,
+            KB_PGDN,
+            KB_ENTER,
+            KB_MENU,
+            KB_ALT_1,
+            KB_ALT_0,
+            0x20,
+            0x61,
+        ] {
+            let name = key_name(key_code).unwrap();
+            assert_eq!(parse_key_chord(&name).unwrap().key_code, key_code, "{name}");
+        }
+        assert_eq!(key_name(KB_CTRL_K).as_deref(), Some("Ctrl+K"));
+        assert_eq!(key_name(KB_ALT_X).as_deref(), Some("Alt+X"));
+        assert_eq!(alt_code_to_char(KB_ALT_W), Some('w'));
+        assert_eq!(key_name(KB_ALT_9).as_deref(), Some("Alt+9"));
+        assert_eq!(alt_code_to_char(KB_ALT_5), Some('5'));
+    }
+
     #[test]
     fn rejects_garbage() {
         assert!(parse_key_chord("NOPEKEY").is_none());
@@ -19,8 +19,22 @@
 //
 //   // Get history list
 //   let items = HistoryManager::get_list(history_id);
-
+//
+//   // Keep the lists across runs
+//   HistoryManager::load(&path)?;
+//   HistoryManager::save(&path)?;
+//
+// History file format (most recent first, lines of one list together):
+//   # Turbo Vision history
+//   1 "another search"
+//   1 "search term"
+//   2 "*.rs"
+
+use crate::core::error::{Result, TurboVisionError};
+use crate::core::quoted::{quote, unquote};
 use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
 
 /// Maximum number of items to store in each history list
@@ -31,6 +45,7 @@ const MAX_HISTORY_ITEMS: usize = 20;
 pub struct HistoryList {
     items: Vec<String>,
     max_items: usize,
+    persistent: bool,
 }
 
 impl HistoryList {
@@ -39,6 +54,7 @@ impl HistoryList {
         Self {
             items: Vec::new(),
             max_items: MAX_HISTORY_ITEMS,
+            persistent: true,
         }
     }
 
@@ -47,6 +63,7 @@ impl HistoryList {
         Self {
             items: Vec::new(),
             max_items,
+            persistent: true,
         }
     }
 
@@ -96,6 +113,36 @@ impl HistoryList {
     pub fn get(&self, index: usize) -> Option<&String> {
         self.items.get(index)
     }
+
+    /// Maximum number of items kept
+    pub fn max_items(&self) -> usize {
+        self.max_items
+    }
+
+    /// Whether the list is written to the history file (default: true)
+    pub fn is_persistent(&self) -> bool {
+        self.persistent
+    }
+
+    /// Include or exclude the list from the history file
+    pub fn set_persistent(&mut self, persistent: bool) {
+        self.persistent = persistent;
+    }
+
+    /// Append older items read from a file after the current ones
+    ///
+    /// Items already in the list keep their place, and the list stays within
+    /// its maximum size.
+    pub fn merge_older(&mut self, older: impl IntoIterator<Item = String>) {
+        for item in older {
+            if self.items.len() >= self.max_items {
+                break;
+            }
+            if !item.is_empty() && !self.items.contains(&item) {
+                self.items.push(item);
+            }
+        }
+    }
 }
 
 impl Default for HistoryList {
@@ -224,6 +271,169 @@ impl HistoryManager {
             list.items.truncate(max_items);
         }
     }
+
+    /// Include or exclude a history list from [`save`](Self::save) and
+    /// [`load`](Self::load)
+    ///
+    /// Lists are persistent by default; opt out for ids that may hold
+    /// passwords or other data that should not outlive the session.
+    pub fn set_persistent(history_id: u16, persistent: bool) {
+        let mut manager = history_manager().lock().unwrap();
+        manager
+            .entry(history_id)
+            .or_default()
+            .set_persistent(persistent);
+    }
+
+    /// Check if a history list is saved to the history file
+    pub fn is_persistent(history_id: u16) -> bool {
+        let manager = history_manager().lock().unwrap();
+        manager
+            .get(&history_id)
+            .is_none_or(HistoryList::is_persistent)
+    }
+
+    /// Write all persistent history lists in the history file format
+    pub fn to_text() -> String {
+        let manager = history_manager().lock().unwrap();
+        write_histories(&manager)
+    }
+
+    /// Merge history lists read from the history file format
+    ///
+    /// Items from the text go after the ones already entered in this session,
+    /// each list keeps its maximum size, and lists that opted out of
+    /// persistence are left alone.
+    pub fn merge_text(text: &str) -> Result<()> {
+        let histories = read_histories(text)?;
+        let mut manager = history_manager().lock().unwrap();
+        merge_histories(&mut manager, histories);
+        Ok(())
+    }
+
+    /// Save all persistent history lists to a file
+    ///
+    /// The file is written next to its final name and renamed into place, so
+    /// a crash never leaves a truncated history behind. Missing parent
+    /// directories are created.
+    pub fn save(path: impl AsRef<Path>) -> Result<()> {
+        write_atomic(path.as_ref(), &Self::to_text())
+    }
+
+    /// Load history lists saved by [`save`](Self::save)
+    ///
+    /// A missing file is not an error: there is simply no history yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::merge_text(&text),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(TurboVisionError::file_operation(path, error)),
+        }
+    }
+
+    /// Default history file for an application
+    ///
+    /// `$XDG_CONFIG_HOME/<app>/history`, falling back to
+    /// `~/.config/<app>/history`, or `%APPDATA%\<app>\history` on Windows.
+    /// Returns `None` if none of these variables is set.
+    pub fn default_path(app_name: &str) -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join(app_name).join("history"))
+    }
+}
+
+fn config_dir() -> Option<PathBuf> {
+    let var = |name: &str| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    if cfg!(windows) {
+        return var("APPDATA");
+    }
+    var("XDG_CONFIG_HOME").or_else(|| var("HOME").map(|home| home.join(".config")))
+}
+
+const HISTORY_HEADER: &str = "# Turbo Vision history";
+
+fn write_histories(lists: &HashMap<u16, HistoryList>) -> String {
+    let mut ids: Vec<u16> = lists
+        .iter()
+        .filter(|(_, list)| list.is_persistent())
+        .map(|(&id, _)| id)
+        .collect();
+    ids.sort_unstable();
+
+    let mut text = format!("{HISTORY_HEADER}\n");
+    for id in ids {
+        for item in lists[&id].items() {
+            text.push_str(&id.to_string());
+            text.push(' ');
+            text.push_str(&quote(item));
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Items by history id, most recent first, in the order of the file
+fn read_histories(text: &str) -> Result<Vec<(u16, Vec<String>)>> {
+    let mut histories: Vec<(u16, Vec<String>)> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error =
+            |message: &str| TurboVisionError::parse(format!("line {}: {message}", index + 1));
+        let (id, item) = line
+            .split_once(' ')
+            .ok_or_else(|| error("expected `ID \"text\"`"))?;
+        let id: u16 = id
+            .parse()
+            .map_err(|err| error(&format!("history id: {err}")))?;
+        let item = unquote(item.trim_start()).ok_or_else(|| error("malformed quoted text"))?;
+        match histories.iter_mut().find(|(existing, _)| *existing == id) {
+            Some((_, items)) => items.push(item),
+            None => histories.push((id, vec![item])),
+        }
+    }
+    Ok(histories)
+}
+
+fn merge_histories(lists: &mut HashMap<u16, HistoryList>, histories: Vec<(u16, Vec<String>)>) {
+    for (id, items) in histories {
+        let list = lists.entry(id).or_default();
+        if list.is_persistent() {
+            list.merge_older(items);
+        }
+    }
+}
+
+/// Replace `path` with `text` through a temporary file and a rename
+fn write_atomic(path: &Path, text: &str) -> Result<()> {
+    let error = |error| TurboVisionError::file_operation(path, error);
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent).map_err(error)?;
+    }
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp = path.with_file_name(temp_name);
+
+    let written = std::fs::File::create(&temp).and_then(|mut file| {
+        file.write_all(text.as_bytes())?;
+        file.sync_all()
+    });
+    match written.and_then(|()| std::fs::rename(&temp, path)) {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            let _ = std::fs::remove_file(&temp);
+            Err(error(err))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -325,4 +535,67 @@ mod tests {
         assert_eq!(list1[1], "list1_item1");
         assert_eq!(list2[0], "list2_item1");
     }
+
+    #[test]
+    fn test_history_text_round_trip() {
+        let mut lists = HashMap::new();
+        let mut search = HistoryList::new();
+        search.add("search term".to_string());
+        search.add("say \"hi\"\\now".to_string());
+        lists.insert(1, search);
+        let mut secret = HistoryList::new();
+        secret.add("hunter2".to_string());
+        secret.set_persistent(false);
+        lists.insert(2, secret);
+
+        let text = write_histories(&lists);
+        assert!(text.starts_with(HISTORY_HEADER));
+        assert!(!text.contains("hunter2"));
+
+        let histories = read_histories(&text).unwrap();
+        assert_eq!(
+            histories,
+            vec![(
+                1,
+                vec!["say \"hi\"\\now".to_string(), "search term".to_string()]
+            )]
+        );
+        read_histories("1 unquoted").unwrap_err();
+        read_histories("x \"text\"").unwrap_err();
+    }
+
+    #[test]
+    fn test_history_merge_keeps_session_items_first() {
+        let mut lists = HashMap::new();
+        let mut list = HistoryList::with_max_items(3);
+        list.add("typed now".to_string());
+        lists.insert(1, list);
+        let mut secret = HistoryList::new();
+        secret.set_persistent(false);
+        lists.insert(2, secret);
+
+        let saved = vec!["a", "typed now", "b", "c"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        merge_histories(
+            &mut lists,
+            vec![(1, saved), (2, vec!["leaked".to_string()])],
+        );
+
+        assert_eq!(lists[&1].items(), ["typed now", "a", "b"]);
+        assert!(lists[&2].is_empty());
+    }
+
+    #[test]
+    fn test_history_write_atomic() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app").join("history");
+
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second");
+        assert!(!dir.path().join("app").join("history.tmp").exists());
+    }
 }
@@ -0,0 +1,479 @@
+// (C) 2026 - Enzo Lombardi
+
+//! Internationalization - message catalog for built-in and application strings.
+//!
+//! Strings are looked up by their English source text (gettext style), so an
+//! untranslated string simply shows up in English. Every built-in view (message
+//! boxes, [`FileDialog`](crate::views::file_dialog::FileDialog),
+//! [`ChDirDialog`](crate::views::chdir_dialog::ChDirDialog),
+//! [`ColorDialog`](crate::views::color_dialog::ColorDialog)) and the status line
+//! pass their texts through [`tr`]. German (`de`) and Italian (`it`)
+//! translations of the built-in strings are included.
+//!
+//! ## Fallback chain
+//!
+//! A lookup tries, in order: the current locale (`de-CH`), its language
+//! (`de`), the locales set with [`set_fallback_chain`], and finally the source
+//! text itself.
+//!
+//! ## Hotkey markers
+//!
+//! Texts may contain `~x~` markers that select the hotkey letter. [`register`]
+//! rejects translations whose markers are unbalanced or that do not mark the
+//! same number of hotkeys as the source, so a translated button is always
+//! reachable from the keyboard.
+//!
+//! The catalog is per thread, like the command set: set it up on the UI thread.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use turbo_vision::core::i18n;
+//!
+//! i18n::register("fr", "~C~ancel", "~A~nnuler").unwrap();
+//! i18n::set_locale("fr-CA");
+//! assert_eq!(i18n::tr("~C~ancel"), "~A~nnuler");
+//! assert_eq!(i18n::tr("Not translated"), "Not translated");
+//!
+//! // Shortcut markers must stay valid.
+//! assert!(i18n::register("fr", "~O~pen", "Ouvrir").is_err());
+//! # i18n::set_locale("en");
+//! ```
+
+use crate::core::error::{Result, TurboVisionError};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Locale of the source strings (no lookup needed)
+pub const SOURCE_LOCALE: &str = "en";
+
+/// Message catalog: translations per locale plus the lookup chain
+#[derive(Debug, Clone)]
+pub struct Catalog {
+    locale: String,
+    fallbacks: Vec<String>,
+    tables: HashMap<String, HashMap<String, String>>,
+}
+
+impl Catalog {
+    /// Create a catalog with the built-in translations and the source locale selected
+    pub fn new() -> Self {
+        let mut catalog = Self {
+            locale: SOURCE_LOCALE.to_string(),
+            fallbacks: Vec::new(),
+            tables: HashMap::new(),
+        };
+        for (locale, table) in [("de", BUILTIN_DE), ("it", BUILTIN_IT)] {
+            for (source, translation) in table {
+                catalog
+                    .register(locale, source, translation)
+                    .expect("built-in translations keep hotkey markers valid");
+            }
+        }
+        catalog
+    }
+
+    /// Select the locale used by [`tr`](Self::tr), e.g. `de`, `it-CH` or `de_DE.UTF-8`
+    pub fn set_locale(&mut self, locale: &str) {
+        self.locale = normalize_locale(locale);
+    }
+
+    /// The current locale in normalized form (e.g. `de-DE`)
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Set the locales tried after the current locale and its language
+    pub fn set_fallback_chain(&mut self, locales: &[&str]) {
+        self.fallbacks = locales.iter().map(|l| normalize_locale(l)).collect();
+    }
+
+    /// The locales a lookup tries, in order (without the source locale)
+    pub fn locale_chain(&self) -> Vec<String> {
+        let mut chain = vec![self.locale.clone()];
+        if let Some((language, _)) = self.locale.split_once('-') {
+            chain.push(language.to_string());
+        }
+        for fallback in &self.fallbacks {
+            if !chain.contains(fallback) {
+                chain.push(fallback.clone());
+            }
+        }
+        chain
+    }
+
+    /// Add or replace the translation of `source` for `locale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-input error if the `~` hotkey markers of
+    /// `translation` are unbalanced or mark a different number of hotkeys
+    /// than `source`.
+    pub fn register(&mut self, locale: &str, source: &str, translation: &str) -> Result<()> {
+        check_hotkey_markers(source, translation)?;
+        self.tables
+            .entry(normalize_locale(locale))
+            .or_default()
+            .insert(source.to_string(), translation.to_string());
+        Ok(())
+    }
+
+    /// Translate `source` following the fallback chain
+    pub fn tr(&self, source: &str) -> String {
+        self.lookup(source).unwrap_or(source).to_string()
+    }
+
+    /// Translation of `source`, or `None` if no locale in the chain has one
+    pub fn lookup(&self, source: &str) -> Option<&str> {
+        self.locale_chain()
+            .iter()
+            .filter_map(|locale| self.tables.get(locale))
+            .find_map(|table| table.get(source))
+            .map(String::as_str)
+    }
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+thread_local! {
+    static CATALOG: RefCell<Catalog> = RefCell::new(Catalog::new());
+}
+
+/// Select the locale for built-in views, e.g. `de`, `it-CH` or `de_DE.UTF-8`
+pub fn set_locale(locale: &str) {
+    CATALOG.with(|c| c.borrow_mut().set_locale(locale));
+}
+
+/// Select the locale from `LC_ALL`, `LC_MESSAGES` or `LANG` (first one set)
+///
+/// Returns the selected locale, or `None` if none of the variables is set (or
+/// they name the `C`/`POSIX` locale), in which case the locale is unchanged.
+pub fn set_locale_from_env() -> Option<String> {
+    let value = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())?;
+    if value == "C" || value == "POSIX" || value.starts_with("C.") {
+        return None;
+    }
+    set_locale(&value);
+    Some(locale())
+}
+
+/// The current locale in normalized form (e.g. `de-DE`)
+pub fn locale() -> String {
+    CATALOG.with(|c| c.borrow().locale().to_string())
+}
+
+/// Set the locales tried after the current locale and its language
+pub fn set_fallback_chain(locales: &[&str]) {
+    CATALOG.with(|c| c.borrow_mut().set_fallback_chain(locales));
+}
+
+/// Add or replace the translation of `source` for `locale`
+///
+/// # Errors
+///
+/// Returns an invalid-input error if the hotkey markers of `translation`
+/// do not match those of `source`.
+pub fn register(locale: &str, source: &str, translation: &str) -> Result<()> {
+    CATALOG.with(|c| c.borrow_mut().register(locale, source, translation))
+}
+
+/// Register several `(source, translation)` pairs for `locale`
+///
+/// # Errors
+///
+/// Stops at, and returns, the first invalid translation.
+pub fn register_all(locale: &str, translations: &[(&str, &str)]) -> Result<()> {
+    translations
+        .iter()
+        .try_for_each(|(source, translation)| register(locale, source, translation))
+}
+
+/// Translate `source` into the current locale (or return it unchanged)
+pub fn tr(source: &str) -> String {
+    CATALOG.with(|c| c.borrow().tr(source))
+}
+
+/// Width of a text on screen, not counting `~` hotkey markers
+/// Matches Borland: cstrlen()
+pub fn cstrlen(text: &str) -> usize {
+    use unicode_width::UnicodeWidthChar;
+    text.chars()
+        .filter(|&c| c != '~')
+        .map(|c| c.width().unwrap_or(0))
+        .sum()
+}
+
+/// [`cstrlen`] as a screen coordinate, for sizing views around translated text
+pub(crate) fn text_width(text: &str) -> i16 {
+    i16::try_from(cstrlen(text)).unwrap_or(i16::MAX)
+}
+
+/// Turn `de_DE.UTF-8` / `de-de` / `DE` into `de-DE` / `de-DE` / `de`
+fn normalize_locale(locale: &str) -> String {
+    let base = locale.split(['.', '@']).next().unwrap_or(locale);
+    let mut parts = base.split(['-', '_']);
+    let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+    match parts.next() {
+        Some(region) if !region.is_empty() => {
+            format!("{language}-{}", region.to_ascii_uppercase())
+        }
+        _ => language,
+    }
+}
+
+/// Number of `~x~` hotkey segments in `text`, or `None` if markers are unbalanced
+fn hotkey_count(text: &str) -> Option<usize> {
+    let segments: Vec<&str> = text.split('~').collect();
+    if segments.len().is_multiple_of(2) {
+        return None; // odd number of markers
+    }
+    // Odd-indexed segments are the marked ones; an empty one marks nothing.
+    let marked = segments.iter().skip(1).step_by(2);
+    if marked.clone().any(|s| s.is_empty()) {
+        return None;
+    }
+    Some(marked.count())
+}
+
+fn check_hotkey_markers(source: &str, translation: &str) -> Result<()> {
+    let expected = hotkey_count(source).unwrap_or(0);
+    match hotkey_count(translation) {
+        None => Err(TurboVisionError::invalid_input(format!(
+            "unbalanced ~ hotkey markers in translation {translation:?}"
+        ))),
+        Some(found) if found != expected => Err(TurboVisionError::invalid_input(format!(
+            "translation {translation:?} marks {found} hotkey(s), {source:?} marks {expected}"
+        ))),
+        Some(_) => Ok(()),
+    }
+}
+
+const BUILTIN_DE: &[(&str, &str)] = &[
+    ("Warning", "Warnung"),
+    ("Error", "Fehler"),
+    ("Information", "Information"),
+    ("Confirm", "Bestätigen"),
+    ("Message", "Meldung"),
+    ("~Y~es", "~J~a"),
+    ("~N~o", "~N~ein"),
+    ("~O~K", "~O~K"),
+    ("~C~ancel", "~A~bbrechen"),
+    ("OK", "OK"),
+    ("Cancel", "Abbrechen"),
+    ("~F~ind:", "~S~uchen:"),
+    ("~R~eplace with:", "~E~rsetzen durch:"),
+    ("~L~ine number:", "~Z~eilennummer:"),
+    ("~O~pen", "Ö~f~fnen"),
+    ("~N~ame:", "~N~ame:"),
+    ("~F~iles:", "~D~ateien:"),
+    ("Change Directory", "Verzeichnis wechseln"),
+    ("Directory ~n~ame", "Verzeichnis~n~ame"),
+    ("Directory ~t~ree", "Verzeichnis~b~aum"),
+    ("~C~hdir", "~W~echseln"),
+    ("~R~evert", "~Z~urücksetzen"),
+    ("Invalid directory", "Ungültiges Verzeichnis"),
+    (
+        "Select foreground and background colors:",
+        "Vorder- und Hintergrundfarbe auswählen:",
+    ),
+    ("Foreground:", "Vordergrund:"),
+    ("Background:", "Hintergrund:"),
+    ("Preview:", "Vorschau:"),
+    (
+        "Sample text with\nselected colors",
+        "Beispieltext mit\nden gewählten Farben",
+    ),
+    ("loading…", "wird geladen…"),
+    ("New item", "Neuer Eintrag"),
+    ("Cancelling…", "Wird abgebrochen…"),
+    ("Elapsed:", "Vergangen:"),
+    ("Remaining:", "Verbleibend:"),
+    ("Invalid value", "Ungültiger Wert"),
+    (
+        "Invalid character in input",
+        "Ungültiges Zeichen in der Eingabe",
+    ),
+    ("Value not in the range", "Wert nicht im Bereich"),
+    (
+        "Input is not in the list of valid values",
+        "Eingabe ist nicht in der Liste gültiger Werte",
+    ),
+    (
+        "Input does not conform to picture",
+        "Eingabe entspricht nicht dem Muster",
+    ),
+    ("Checking…", "Wird geprüft…"),
+    ("Check failed", "Prüfung fehlgeschlagen"),
+    ("January", "Januar"),
+    ("February", "Februar"),
+    ("March", "März"),
+    ("April", "April"),
+    ("May", "Mai"),
+    ("June", "Juni"),
+    ("July", "Juli"),
+    ("August", "August"),
+    ("September", "September"),
+    ("October", "Oktober"),
+    ("November", "November"),
+    ("December", "Dezember"),
+    ("Mo", "Mo"),
+    ("Tu", "Di"),
+    ("We", "Mi"),
+    ("Th", "Do"),
+    ("Fr", "Fr"),
+    ("Sa", "Sa"),
+    ("Su", "So"),
+    ("Keyboard Reference", "Tastaturbelegung"),
+    ("Command Palette", "Befehlspalette"),
+    ("~U~ndo", "~R~ückgängig"),
+    ("Cu~t~", "Aus~s~chneiden"),
+    ("~C~opy", "~K~opieren"),
+    ("~P~aste", "~E~infügen"),
+    ("Select ~A~ll", "~A~lles markieren"),
+    ("Windows", "Fenster"),
+    ("~W~indows", "~F~enster"),
+    ("~S~witch to", "~W~echseln zu"),
+    ("~C~lose", "~S~chließen"),
+];
+
+const BUILTIN_IT: &[(&str, &str)] = &[
+    ("Warning", "Avviso"),
+    ("Error", "Errore"),
+    ("Information", "Informazione"),
+    ("Confirm", "Conferma"),
+    ("Message", "Messaggio"),
+    ("~Y~es", "~S~ì"),
+    ("~N~o", "~N~o"),
+    ("~O~K", "~O~K"),
+    ("~C~ancel", "~A~nnulla"),
+    ("OK", "OK"),
+    ("Cancel", "Annulla"),
+    ("~F~ind:", "~T~rova:"),
+    ("~R~eplace with:", "~S~ostituisci con:"),
+    ("~L~ine number:", "~N~umero di riga:"),
+    ("~O~pen", "~A~pri"),
+    ("~N~ame:", "~N~ome:"),
+    ("~F~iles:", "~F~ile:"),
+    ("Change Directory", "Cambia cartella"),
+    ("Directory ~n~ame", "~N~ome cartella"),
+    ("Directory ~t~ree", "~A~lbero delle cartelle"),
+    ("~C~hdir", "~C~ambia"),
+    ("~R~evert", "~R~ipristina"),
+    ("Invalid directory", "Cartella non valida"),
+    (
+        "Select foreground and background colors:",
+        "Seleziona i colori di primo piano e di sfondo:",
+    ),
+    ("Foreground:", "Primo piano:"),
+    ("Background:", "Sfondo:"),
+    ("Preview:", "Anteprima:"),
+    (
+        "Sample text with\nselected colors",
+        "Testo di esempio\ncon i colori scelti",
+    ),
+    ("loading…", "caricamento…"),
+    ("New item", "Nuovo elemento"),
+    ("Cancelling…", "Annullamento…"),
+    ("Elapsed:", "Trascorso:"),
+    ("Remaining:", "Rimanente:"),
+    ("Invalid value", "Valore non valido"),
+    (
+        "Invalid character in input",
+        "Carattere non valido nell'input",
+    ),
+    (
+        "Value not in the range",
+        "Valore non compreso nell'intervallo",
+    ),
+    (
+        "Input is not in the list of valid values",
+        "L'input non è nell'elenco dei valori validi",
+    ),
+    (
+        "Input does not conform to picture",
+        "L'input non è conforme al modello",
+    ),
+    ("Checking…", "Verifica in corso…"),
+    ("Check failed", "Verifica non riuscita"),
+    ("January", "Gennaio"),
+    ("February", "Febbraio"),
+    ("March", "Marzo"),
+    ("April", "Aprile"),
+    ("May", "Maggio"),
+    ("June", "Giugno"),
+    ("July", "Luglio"),
+    ("August", "Agosto"),
+    ("September", "Settembre"),
+    ("October", "Ottobre"),
+    ("November", "Novembre"),
+    ("December", "Dicembre"),
+    ("Mo", "Lu"),
+    ("Tu", "Ma"),
+    ("We", "Me"),
+    ("Th", "Gi"),
+    ("Fr", "Ve"),
+    ("Sa", "Sa"),
+    ("Su", "Do"),
+    ("Keyboard Reference", "Scorciatoie da tastiera"),
+    ("Command Palette", "Tavolozza dei comandi"),
+    ("~U~ndo", "~A~nnulla"),
+    ("Cu~t~", "~T~aglia"),
+    ("~C~opy", "~C~opia"),
+    ("~P~aste", "~I~ncolla"),
+    ("Select ~A~ll", "~S~eleziona tutto"),
+    ("Windows", "Finestre"),
+    ("~W~indows", "~F~inestre"),
+    ("~S~witch to", "~P~assa a"),
+    ("~C~lose", "~C~hiudi"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_locale_names() {
+        assert_eq!(normalize_locale("de_DE.UTF-8"), "de-DE");
+        assert_eq!(normalize_locale("it-ch"), "it-CH");
+        assert_eq!(normalize_locale("DE"), "de");
+    }
+
+    #[test]
+    fn lookup_follows_fallback_chain() {
+        let mut catalog = Catalog::new();
+        catalog.register("de-AT", "~C~ancel", "~A~bbruch").unwrap();
+        catalog.register("fr", "Preview:", "Aperçu :").unwrap();
+        catalog.set_locale("de_AT.UTF-8");
+        catalog.set_fallback_chain(&["fr"]);
+
+        assert_eq!(catalog.locale_chain(), ["de-AT", "de", "fr"]);
+        assert_eq!(catalog.tr("~C~ancel"), "~A~bbruch"); // region
+        assert_eq!(catalog.tr("~N~o"), "~N~ein"); // language
+        catalog.set_locale("es");
+        assert_eq!(catalog.tr("Preview:"), "Aperçu :"); // explicit fallback
+        assert_eq!(catalog.tr("~N~o"), "~N~o"); // source text
+    }
+
+    #[test]
+    fn rejects_broken_hotkey_markers() {
+        let mut catalog = Catalog::new();
+        catalog.register("de", "~S~ave", "~S~peichern").unwrap();
+        assert!(catalog.register("de", "~S~ave", "~Speichern").is_err());
+        assert!(catalog.register("de", "~S~ave", "Speichern").is_err());
+        assert!(catalog.register("de", "~S~ave", "~~Speichern").is_err());
+        assert!(catalog.register("de", "Save", "~S~peichern").is_err());
+    }
+
+    #[test]
+    fn cstrlen_ignores_markers() {
+        assert_eq!(cstrlen("~A~bbrechen"), 9);
+        assert_eq!(cstrlen("Ö~f~fnen"), 6);
+    }
+}
@@ -0,0 +1,557 @@
+// (C) 2026 - Enzo Lombardi
+
+//! Keymap - configurable key bindings from key chords to commands.
+// Keymap - Configurable key bindings
+//
+// Matches Borland: the accelerator keys of TMenuItem and TStatusItem and the
+// key tables of TEditor (firstKeys, quickKeys, blockKeys), gathered in one
+// place and made configurable.
+//
+// A binding maps a sequence of one or more key chords to a command within a
+// context: global, editor or dialog. The application translates key presses
+// through the keymap before any view sees them, trying the contexts of the
+// focused views (innermost first) and then the global one. A key that starts
+// a longer sequence (Ctrl+K in Ctrl+K Ctrl+B) is held back until the
+// sequence is complete or broken.
+//
+// Keymap file format:
+//   # Turbo Vision keymap
+//   [global]
+//   Alt+X = CM_QUIT
+//   [editor]
+//   Ctrl+K Ctrl+A = CM_SELECT_ALL
+//   # `none` removes a binding
+//   Ctrl+Y = none
+
+use crate::core::command::{
+    CM_COMMAND_PALETTE, CM_CONTEXT_MENU, CM_COPY, CM_CUT, CM_HELP_INDEX, CM_PASTE, CM_QUIT,
+    CM_REDO, CM_SCREENSHOT, CM_SELECT_ALL, CM_UNDO, CM_WINDOW_LIST, CommandId, command_by_name,
+    command_name,
+};
+use crate::core::error::{Result, TurboVisionError};
+use crate::core::event::{Event, EventType, KB_SHIFT_TAB, KeyCode, key_name, parse_key_chord};
+use crossterm::event::KeyModifiers;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Where a key binding applies
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyContext {
+    /// Everywhere, unless a more specific context binds the same keys
+    Global,
+    /// While an editor has the focus
+    Editor,
+    /// While a dialog is active
+    Dialog,
+}
+
+impl KeyContext {
+    /// All contexts, in the order they are listed
+    pub const ALL: [KeyContext; 3] = [KeyContext::Global, KeyContext::Editor, KeyContext::Dialog];
+
+    /// Section name in keymap files (`global`, `editor`, `dialog`)
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyContext::Global => "global",
+            KeyContext::Editor => "editor",
+            KeyContext::Dialog => "dialog",
+        }
+    }
+
+    /// Look up a context by its section name, ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|context| context.name().eq_ignore_ascii_case(name))
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            KeyContext::Global => "Global",
+            KeyContext::Editor => "Editor",
+            KeyContext::Dialog => "Dialog",
+        }
+    }
+}
+
+/// A single key press: the key code plus Shift for keys that do not fold
+/// it into the code (Ctrl and Alt always are)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub key_code: KeyCode,
+    pub shift: bool,
+}
+
+impl KeyChord {
+    pub fn new(key_code: KeyCode) -> Self {
+        Self {
+            key_code,
+            shift: false,
+        }
+    }
+
+    /// The chord of a keyboard event
+    pub fn from_event(event: &Event) -> Self {
+        // Shift is already part of printable characters and of Shift+Tab
+        let folded = matches!(event.key_code, 0x20..0x7F | 0xA0..0x100 | KB_SHIFT_TAB);
+        Self {
+            key_code: event.key_code,
+            shift: !folded && event.key_modifiers.contains(KeyModifiers::SHIFT),
+        }
+    }
+
+    /// Parse a chord such as `"Ctrl+K"` or `"Shift+F3"` (see
+    /// [`parse_key_chord`])
+    pub fn parse(text: &str) -> Option<Self> {
+        parse_key_chord(text).map(|event| Self::from_event(&event))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+        match key_name(self.key_code) {
+            Some(name) => f.write_str(&name),
+            None => write!(f, "{:#06x}", self.key_code),
+        }
+    }
+}
+
+/// Parse a space-separated sequence of chords, such as `"Ctrl+K Ctrl+B"`
+pub fn parse_key_sequence(text: &str) -> Option<Vec<KeyChord>> {
+    let keys: Option<Vec<KeyChord>> = text.split_whitespace().map(KeyChord::parse).collect();
+    keys.filter(|keys| !keys.is_empty())
+}
+
+/// One key binding
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub context: KeyContext,
+    pub keys: Vec<KeyChord>,
+    pub command: CommandId,
+}
+
+impl KeyBinding {
+    /// The keys as written in keymap files, such as `"Ctrl+K Ctrl+B"`
+    pub fn key_text(&self) -> String {
+        let names: Vec<String> = self.keys.iter().map(KeyChord::to_string).collect();
+        names.join(" ")
+    }
+}
+
+/// What a key press means to the keymap
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyResolution {
+    /// The key completed a binding
+    Command(CommandId),
+    /// The key starts or continues a longer sequence; wait for the next one
+    Pending,
+    /// The key broke a sequence that was in progress and should be dropped
+    Discarded,
+    /// The key is not bound; pass it on to the views
+    Unbound,
+}
+
+/// Key bindings by context, with the state of a sequence being typed
+#[derive(Clone, Debug, Default)]
+pub struct Keymap {
+    bindings: Vec<KeyBinding>,
+    commands: HashMap<String, CommandId>,
+    pending: Vec<KeyChord>,
+}
+
+impl Keymap {
+    /// Create a keymap without bindings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The default bindings of an application
+    ///
+    /// Global: Alt+X quits, F1 opens help, Ctrl+F12 takes a screenshot,
+    /// Ctrl+Shift+P opens the command palette, Shift+F10 and the Menu key open the focused view's context menu,
+    /// Alt+0 opens the window list.
+    /// Editor: the CUA clipboard and undo keys.
+    /// Dialog: Shift+F10 and the Menu key, as global bindings do not apply
+    /// in dialogs.
+    ///
+    /// Most terminals do not report Shift together with Ctrl and a letter;
+    /// an application for them can add Ctrl+P, which is not bound by default
+    /// as it usually means Print:
+    /// `keymap.bind(KeyContext::Global, "Ctrl+P", CM_COMMAND_PALETTE)`
+    pub fn standard() -> Self {
+        let mut keymap = Self::new();
+        let defaults = [
+            (KeyContext::Global, "Alt+X", CM_QUIT),
+            (KeyContext::Global, "F1", CM_HELP_INDEX),
+            (KeyContext::Global, "Ctrl+F12", CM_SCREENSHOT),
+            (KeyContext::Global, "Ctrl+Shift+P", CM_COMMAND_PALETTE),
+            (KeyContext::Global, "Shift+F10", CM_CONTEXT_MENU),
+            (KeyContext::Global, "Menu", CM_CONTEXT_MENU),
+            (KeyContext::Global, "Alt+0", CM_WINDOW_LIST),
+            (KeyContext::Editor, "Ctrl+A", CM_SELECT_ALL),
+            (KeyContext::Editor, "Ctrl+C", CM_COPY),
+            (KeyContext::Editor, "Ctrl+X", CM_CUT),
+            (KeyContext::Editor, "Ctrl+V", CM_PASTE),
+            (KeyContext::Editor, "Ctrl+Z", CM_UNDO),
+            (KeyContext::Editor, "Ctrl+Y", CM_REDO),
+            (KeyContext::Dialog, "Shift+F10", CM_CONTEXT_MENU),
+            (KeyContext::Dialog, "Menu", CM_CONTEXT_MENU),
+        ];
+        for (context, keys, command) in defaults {
+            keymap
+                .bind(context, keys, command)
+                .expect("standard key bindings parse");
+        }
+        keymap
+    }
+
+    /// Bind a key sequence such as `"Ctrl+K Ctrl+B"` to a command,
+    /// replacing any binding of the same keys in the context
+    pub fn bind(&mut self, context: KeyContext, keys: &str, command: CommandId) -> Result<()> {
+        let keys = Self::keys(keys)?;
+        self.bind_keys(context, keys, command);
+        Ok(())
+    }
+
+    /// Bind already parsed chords to a command
+    pub fn bind_keys(&mut self, context: KeyContext, keys: Vec<KeyChord>, command: CommandId) {
+        self.bindings
+            .retain(|binding| binding.context != context || binding.keys != keys);
+        self.bindings.push(KeyBinding {
+            context,
+            keys,
+            command,
+        });
+    }
+
+    /// Remove the binding of a key sequence; returns true if there was one
+    pub fn unbind(&mut self, context: KeyContext, keys: &str) -> Result<bool> {
+        let keys = Self::keys(keys)?;
+        let count = self.bindings.len();
+        self.bindings
+            .retain(|binding| binding.context != context || binding.keys != keys);
+        Ok(self.bindings.len() != count)
+    }
+
+    fn keys(text: &str) -> Result<Vec<KeyChord>> {
+        parse_key_sequence(text)
+            .ok_or_else(|| TurboVisionError::invalid_input(format!("unknown keys `{text}`")))
+    }
+
+    /// All bindings, in the order they were added
+    pub fn bindings(&self) -> &[KeyBinding] {
+        &self.bindings
+    }
+
+    /// The binding of a command in a context, e.g. to show it in a menu
+    pub fn binding_for(&self, context: KeyContext, command: CommandId) -> Option<&KeyBinding> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.context == context && binding.command == command)
+    }
+
+    /// Give an application command a name usable in keymap files
+    pub fn define_command(&mut self, name: &str, command: CommandId) {
+        self.commands.insert(name.to_string(), command);
+    }
+
+    /// Name of a command as written in keymap files: a name given with
+    /// [`define_command`](Self::define_command), a standard `CM_*` name, or
+    /// the number
+    pub fn command_name(&self, command: CommandId) -> String {
+        let mut defined: Vec<&str> = self
+            .commands
+            .iter()
+            .filter(|&(_, &known)| known == command)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        defined.sort_unstable();
+        defined
+            .first()
+            .copied()
+            .or_else(|| command_name(command))
+            .map_or_else(|| command.to_string(), String::from)
+    }
+
+    fn command(&self, word: &str) -> Option<CommandId> {
+        word.parse::<CommandId>()
+            .ok()
+            .or_else(|| self.commands.get(word).copied())
+            .or_else(|| command_by_name(word))
+    }
+
+    /// Feed a key press through the keymap
+    ///
+    /// `contexts` are tried in order, so list the innermost first and
+    /// [`KeyContext::Global`] last. Events other than key presses are
+    /// [`Unbound`](KeyResolution::Unbound).
+    pub fn resolve(&mut self, contexts: &[KeyContext], event: &Event) -> KeyResolution {
+        if event.what != EventType::Keyboard || event.key_code == 0 {
+            return KeyResolution::Unbound;
+        }
+        self.pending.push(KeyChord::from_event(event));
+
+        let exact = contexts.iter().find_map(|&context| {
+            self.bindings
+                .iter()
+                .find(|binding| binding.context == context && binding.keys == self.pending)
+        });
+        if let Some(binding) = exact {
+            let command = binding.command;
+            self.pending.clear();
+            return KeyResolution::Command(command);
+        }
+
+        let longer = self.bindings.iter().any(|binding| {
+            contexts.contains(&binding.context)
+                && binding.keys.len() > self.pending.len()
+                && binding.keys.starts_with(&self.pending)
+        });
+        if longer {
+            return KeyResolution::Pending;
+        }
+
+        let broken = self.pending.len() > 1;
+        self.pending.clear();
+        if broken {
+            KeyResolution::Discarded
+        } else {
+            KeyResolution::Unbound
+        }
+    }
+
+    /// Keys of the sequence typed so far (empty unless a sequence is pending)
+    pub fn pending(&self) -> &[KeyChord] {
+        &self.pending
+    }
+
+    /// Forget a partly typed sequence
+    pub fn cancel_pending(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Apply the bindings of a keymap file on top of the current ones
+    ///
+    /// Bindings before the first `[context]` section are global; a command
+    /// of `none` removes the binding.
+    pub fn merge_text(&mut self, text: &str) -> Result<()> {
+        let mut context = KeyContext::Global;
+        for (index, line) in text.lines().enumerate() {
+            let error =
+                |message: String| TurboVisionError::parse(format!("line {}: {message}", index + 1));
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line
+                .strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+            {
+                context = KeyContext::from_name(name.trim())
+                    .ok_or_else(|| error(format!("unknown context `{name}`")))?;
+                continue;
+            }
+            let (keys, command) = line
+                .rsplit_once('=')
+                .ok_or_else(|| error("expected `keys = command`".to_string()))?;
+            let keys = parse_key_sequence(keys)
+                .ok_or_else(|| error(format!("unknown keys `{}`", keys.trim())))?;
+            match command.trim() {
+                "none" => self
+                    .bindings
+                    .retain(|binding| binding.context != context || binding.keys != keys),
+                command => {
+                    let command = self
+                        .command(command)
+                        .ok_or_else(|| error(format!("unknown command `{command}`")))?;
+                    self.bind_keys(context, keys, command);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Load a keymap file on top of the current bindings
+    ///
+    /// A missing file is not an error, so an optional user keymap can be
+    /// loaded unconditionally.
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(text) => self.merge_text(&text),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(TurboVisionError::file_operation(path, error)),
+        }
+    }
+
+    /// Write all bindings in the keymap file format
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# Turbo Vision keymap\n");
+        for context in KeyContext::ALL {
+            let bindings: Vec<&KeyBinding> = self
+                .bindings
+                .iter()
+                .filter(|binding| binding.context == context)
+                .collect();
+            if bindings.is_empty() {
+                continue;
+            }
+            let _ = writeln!(text, "[{}]", context.name());
+            for binding in bindings {
+                let command = self.command_name(binding.command);
+                let _ = writeln!(text, "{} = {command}", binding.key_text());
+            }
+        }
+        text
+    }
+
+    /// A keyboard reference help topic listing the bindings by context
+    ///
+    /// The markdown can be loaded with
+    /// [`HelpFile::from_content`](crate::views::help_file::HelpFile::from_content)
+    /// or added to an existing help file with
+    /// [`HelpFile::add_content`](crate::views::help_file::HelpFile::add_content).
+    pub fn to_markdown(&self, title: &str, topic_id: &str) -> String {
+        let mut text = format!("# {title} {{#{topic_id}}}\n");
+        for context in KeyContext::ALL {
+            let rows: Vec<(String, String)> = self
+                .bindings
+                .iter()
+                .filter(|binding| binding.context == context)
+                .map(|binding| {
+                    let keys = format!("`{}`", binding.key_text());
+                    (keys, describe(&self.command_name(binding.command)))
+                })
+                .collect();
+            if rows.is_empty() {
+                continue;
+            }
+            let width = rows.iter().map(|(keys, _)| keys.len()).max().unwrap_or(0);
+            let _ = write!(text, "\n## {}\n\n", context.title());
+            for (keys, description) in rows {
+                let _ = writeln!(text, "  {keys:width$}  {description}");
+            }
+        }
+        text
+    }
+}
+
+/// `CM_SELECT_ALL` -> `Select all`
+fn describe(command_name: &str) -> String {
+    let words = command_name
+        .strip_prefix("CM_")
+        .unwrap_or(command_name)
+        .replace('_', " ")
+        .to_lowercase();
+    let mut chars = words.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::CM_FIND;
+    use crate::core::event::{KB_CTRL_B, KB_CTRL_C, KB_CTRL_K, KB_F3};
+
+    const EDITOR: &[KeyContext] = &[KeyContext::Editor, KeyContext::Global];
+
+    #[test]
+    fn sequences_wait_for_the_next_key() {
+        let mut keymap = Keymap::new();
+        keymap
+            .bind(KeyContext::Editor, "Ctrl+K Ctrl+B", CM_SELECT_ALL)
+            .unwrap();
+
+        let ctrl_k = Event::keyboard(KB_CTRL_K);
+        assert_eq!(keymap.resolve(EDITOR, &ctrl_k), KeyResolution::Pending);
+        assert_eq!(keymap.pending(), [KeyChord::new(KB_CTRL_K)]);
+        assert_eq!(
+            keymap.resolve(EDITOR, &Event::keyboard(KB_CTRL_B)),
+            KeyResolution::Command(CM_SELECT_ALL)
+        );
+
+        keymap.resolve(EDITOR, &ctrl_k);
+        assert_eq!(
+            keymap.resolve(EDITOR, &Event::keyboard(KB_CTRL_C)),
+            KeyResolution::Discarded
+        );
+        assert!(keymap.pending().is_empty());
+
+        // Not bound outside the editor
+        assert_eq!(
+            keymap.resolve(&[KeyContext::Global], &ctrl_k),
+            KeyResolution::Unbound
+        );
+    }
+
+    #[test]
+    fn inner_contexts_win() {
+        let mut keymap = Keymap::standard();
+        keymap.bind(KeyContext::Editor, "Alt+X", CM_CUT).unwrap();
+        let alt_x = parse_key_chord("Alt+X").unwrap();
+
+        assert_eq!(
+            keymap.resolve(EDITOR, &alt_x),
+            KeyResolution::Command(CM_CUT)
+        );
+        assert_eq!(
+            keymap.resolve(&[KeyContext::Dialog, KeyContext::Global], &alt_x),
+            KeyResolution::Command(CM_QUIT)
+        );
+    }
+
+    #[test]
+    fn keymap_files_override_bindings() {
+        let mut keymap = Keymap::standard();
+        keymap.define_command("cmFindNext", 500);
+        keymap
+            .merge_text(
+                "# user keys\n\
+                 F3 = CM_FIND\n\
+                 [editor]\n\
+                 Ctrl+Y = none\n\
+                 Ctrl+K Ctrl+B = cmFindNext\n",
+            )
+            .unwrap();
+
+        assert_eq!(
+            keymap
+                .binding_for(KeyContext::Global, CM_FIND)
+                .unwrap()
+                .keys,
+            [KeyChord::new(KB_F3)]
+        );
+        assert!(keymap.binding_for(KeyContext::Editor, CM_REDO).is_none());
+        let text = keymap.to_text();
+        assert!(text.contains("[editor]\nCtrl+A = CM_SELECT_ALL\n"));
+        assert!(text.contains("Ctrl+K Ctrl+B = cmFindNext\n"));
+
+        let mut reloaded = Keymap::new();
+        reloaded.define_command("cmFindNext", 500);
+        reloaded.merge_text(&text).unwrap();
+        assert_eq!(reloaded.to_text(), text);
+
+        let error = keymap.merge_text("[editor]\nCtrl+Q = CM_NOPE").unwrap_err();
+        assert!(error.to_string().contains("line 2"));
+        keymap.merge_text("[menus]").unwrap_err();
+        keymap.merge_text("Hyper+Q = CM_QUIT").unwrap_err();
+    }
+
+    #[test]
+    fn keyboard_reference_lists_bindings() {
+        let text = Keymap::standard().to_markdown("Keyboard Reference", "keys");
+        assert!(text.starts_with("# Keyboard Reference {#keys}\n"));
+        assert!(text.contains("## Editor"));
+        assert!(text.contains("`Ctrl+A`  Select all\n"));
+        assert!(text.contains("`Alt+X`         Quit\n"));
+        assert!(text.contains("`Shift+Ctrl+P`  Command palette\n"));
+    }
+}
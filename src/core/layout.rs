@@ -0,0 +1,209 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Layout helpers - grow mode resizing and stack/grid rectangle splitting.
+//!
+//! [`calc_bounds`] applies a view's grow mode when its owner is resized
+//! (used by `Group::set_bounds()`). [`stack`] and [`grid`] split an area
+//! into child rectangles, ready to pass to `Group::add()`:
+//!
+//! ```
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::core::layout::{Axis, Extent, stack};
+//!
+//! // A 1-row toolbar, a body taking the rest and a 1-row status area
+//! let rows = stack(
+//!     Rect::new(0, 0, 40, 20),
+//!     Axis::Vertical,
+//!     &[Extent::Fixed(1), Extent::Fill(1), Extent::Fixed(1)],
+//! );
+//! assert_eq!(rows[1], Rect::new(0, 1, 40, 19));
+//! ```
+
+use super::geometry::{Point, Rect};
+use super::state::{GF_GROW_HI_X, GF_GROW_HI_Y, GF_GROW_LO_X, GF_GROW_LO_Y, GF_GROW_REL, GrowMode};
+
+/// Direction along which areas are stacked or split
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Left to right
+    Horizontal,
+    /// Top to bottom
+    Vertical,
+}
+
+/// Size of one slot in a [`stack`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extent {
+    /// Exactly this many columns or rows
+    Fixed(i16),
+    /// A share of the space left after the fixed slots, by weight
+    Fill(u16),
+}
+
+/// Move the edges of `bounds` (relative to its owner) for an owner resized
+/// from `old_size` to `new_size`
+/// Matches Borland: TView::calcBounds()
+pub fn calc_bounds(bounds: Rect, mode: GrowMode, old_size: Point, new_size: Point) -> Rect {
+    let grow = |edge: i16, old: i16, new: i16| -> i16 {
+        if mode & GF_GROW_REL != 0 && old > 0 {
+            let scaled = (i32::from(edge) * i32::from(new) + i32::from(old) / 2) / i32::from(old);
+            i16::try_from(scaled).unwrap_or(edge)
+        } else {
+            edge + new - old
+        }
+    };
+
+    let mut r = bounds;
+    if mode & GF_GROW_LO_X != 0 {
+        r.a.x = grow(r.a.x, old_size.x, new_size.x);
+    }
+    if mode & GF_GROW_HI_X != 0 {
+        r.b.x = grow(r.b.x, old_size.x, new_size.x);
+    }
+    if mode & GF_GROW_LO_Y != 0 {
+        r.a.y = grow(r.a.y, old_size.y, new_size.y);
+    }
+    if mode & GF_GROW_HI_Y != 0 {
+        r.b.y = grow(r.b.y, old_size.y, new_size.y);
+    }
+    // Never turn a view inside out
+    r.b.x = r.b.x.max(r.a.x);
+    r.b.y = r.b.y.max(r.a.y);
+    r
+}
+
+/// Split `area` along `axis` into one rectangle per extent
+///
+/// Fixed slots get their size (as far as it fits); fill slots share the rest by
+/// weight, the last fill slot taking any rounding remainder.
+pub fn stack(area: Rect, axis: Axis, extents: &[Extent]) -> Vec<Rect> {
+    let total = match axis {
+        Axis::Horizontal => area.width_clamped(),
+        Axis::Vertical => area.height_clamped(),
+    };
+    let fixed: i16 = extents
+        .iter()
+        .map(|extent| match extent {
+            Extent::Fixed(size) => (*size).max(0),
+            Extent::Fill(_) => 0,
+        })
+        .sum();
+    let weights: u32 = extents
+        .iter()
+        .map(|extent| match extent {
+            Extent::Fill(weight) => u32::from(*weight),
+            Extent::Fixed(_) => 0,
+        })
+        .sum();
+    let spare = u32::from((total - fixed).max(0).cast_unsigned());
+    let last_fill = extents
+        .iter()
+        .rposition(|extent| matches!(extent, Extent::Fill(_)));
+
+    let mut rects = Vec::with_capacity(extents.len());
+    let mut pos = 0;
+    let mut shared = 0;
+    for (index, extent) in extents.iter().enumerate() {
+        let size = match *extent {
+            Extent::Fixed(size) => size.max(0),
+            Extent::Fill(_) if Some(index) == last_fill => {
+                i16::try_from(spare - shared).unwrap_or(0)
+            }
+            Extent::Fill(weight) => {
+                let share = (spare * u32::from(weight))
+                    .checked_div(weights)
+                    .unwrap_or(0);
+                shared += share;
+                i16::try_from(share).unwrap_or(0)
+            }
+        };
+        let start = pos.min(total);
+        let end = (pos + size).min(total);
+        rects.push(match axis {
+            Axis::Horizontal => Rect::new(area.a.x + start, area.a.y, area.a.x + end, area.b.y),
+            Axis::Vertical => Rect::new(area.a.x, area.a.y + start, area.b.x, area.a.y + end),
+        });
+        pos += size;
+    }
+    rects
+}
+
+/// Split `area` into `columns` x `rows` equal cells, listed row by row
+///
+/// Cells on the right and at the bottom absorb the rounding remainder.
+pub fn grid(area: Rect, columns: u16, rows: u16) -> Vec<Rect> {
+    let even = |count: u16| vec![Extent::Fill(1); usize::from(count)];
+    let mut cells = Vec::with_capacity(usize::from(columns) * usize::from(rows));
+    for row in stack(area, Axis::Vertical, &even(rows)) {
+        cells.extend(stack(row, Axis::Horizontal, &even(columns)));
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::state::GF_GROW_ALL;
+
+    fn resized(mode: GrowMode) -> Rect {
+        calc_bounds(
+            Rect::new(10, 5, 20, 8),
+            mode,
+            Point::new(40, 20),
+            Point::new(60, 30),
+        )
+    }
+
+    #[test]
+    fn calc_bounds_follows_grow_mode() {
+        assert_eq!(resized(0), Rect::new(10, 5, 20, 8));
+        assert_eq!(
+            resized(GF_GROW_HI_X | GF_GROW_HI_Y),
+            Rect::new(10, 5, 40, 18)
+        );
+        assert_eq!(resized(GF_GROW_ALL), Rect::new(30, 15, 40, 18));
+        assert_eq!(resized(GF_GROW_ALL | GF_GROW_REL), Rect::new(15, 8, 30, 12));
+    }
+
+    #[test]
+    fn calc_bounds_never_inverts() {
+        let r = calc_bounds(
+            Rect::new(2, 2, 10, 4),
+            GF_GROW_HI_X,
+            Point::new(20, 10),
+            Point::new(5, 10),
+        );
+        assert_eq!(r, Rect::new(2, 2, 2, 4));
+    }
+
+    #[test]
+    fn stack_shares_space_by_weight() {
+        let cols = stack(
+            Rect::new(0, 0, 31, 5),
+            Axis::Horizontal,
+            &[Extent::Fixed(1), Extent::Fill(1), Extent::Fill(2)],
+        );
+        assert_eq!(
+            cols,
+            vec![
+                Rect::new(0, 0, 1, 5),
+                Rect::new(1, 0, 11, 5),
+                Rect::new(11, 0, 31, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_splits_evenly() {
+        let cells = grid(Rect::new(0, 0, 10, 5), 2, 2);
+        assert_eq!(
+            cells,
+            vec![
+                Rect::new(0, 0, 5, 2),
+                Rect::new(5, 0, 10, 2),
+                Rect::new(0, 2, 5, 5),
+                Rect::new(5, 2, 10, 5),
+            ]
+        );
+    }
+}
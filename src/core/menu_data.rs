@@ -7,8 +7,64 @@
 // This module provides data structures for building menus in a declarative way,
 // matching Borland's approach while being Rust-idiomatic.
 
-use crate::core::command::CommandId;
+use crate::core::command::{CM_COPY, CM_CUT, CM_PASTE, CM_SELECT_ALL, CommandId};
 use crate::core::event::KeyCode;
+use crate::core::i18n::tr;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Check mark of a menu item, bound to application state
+///
+/// The state is shared through an `Rc`, so the application reads it back
+/// after the menu changed it and can change it while the menu is closed.
+#[derive(Clone, Debug, Default)]
+pub enum MenuCheck {
+    /// Plain item without a check mark
+    #[default]
+    None,
+    /// Shows ✓ while the flag is set; picking the item toggles it
+    Check(Rc<RefCell<bool>>),
+    /// Shows • while the group holds `value`; picking the item selects it,
+    /// which clears the other items bound to the same group
+    Radio {
+        group: Rc<RefCell<usize>>,
+        value: usize,
+    },
+}
+
+impl MenuCheck {
+    /// Check if the item is currently checked
+    pub fn is_checked(&self) -> bool {
+        match self {
+            Self::None => false,
+            Self::Check(flag) => *flag.borrow(),
+            Self::Radio { group, value } => *group.borrow() == *value,
+        }
+    }
+
+    /// Mark drawn in front of the item text, `' '` while unchecked; `None`
+    /// for plain items
+    pub fn mark(&self) -> Option<char> {
+        let checked = self.is_checked();
+        match self {
+            Self::None => None,
+            Self::Check(_) => Some(if checked { '✓' } else { ' ' }),
+            Self::Radio { .. } => Some(if checked { '•' } else { ' ' }),
+        }
+    }
+
+    /// Apply picking the item: toggle a check, select a radio value
+    pub fn activate(&self) {
+        match self {
+            Self::None => {}
+            Self::Check(flag) => {
+                let checked = !*flag.borrow();
+                *flag.borrow_mut() = checked;
+            }
+            Self::Radio { group, value } => *group.borrow_mut() = *value,
+        }
+    }
+}
 
 /// Menu item - can be a regular command, a submenu, or a separator
 ///
@@ -34,6 +90,8 @@ pub enum MenuItem {
         enabled: bool,
         /// Optional shortcut text to display (e.g., "Ctrl+O", "F3")
         shortcut: Option<String>,
+        /// Check mark bound to application state
+        check: MenuCheck,
     },
     /// Submenu item that opens a nested menu
     /// Matches Borland: TMenuItem with subMenu
@@ -69,6 +127,7 @@ impl MenuItem {
             help_ctx,
             enabled: true,
             shortcut: None,
+            check: MenuCheck::None,
         }
     }
 
@@ -92,6 +151,7 @@ impl MenuItem {
             help_ctx,
             enabled: true,
             shortcut: Some(shortcut.to_string()),
+            check: MenuCheck::None,
         }
     }
 
@@ -104,9 +164,44 @@ impl MenuItem {
             help_ctx,
             enabled: false,
             shortcut: None,
+            check: MenuCheck::None,
         }
     }
 
+    /// Create an item with a check mark bound to `flag`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let wrap = Rc::new(RefCell::new(true));
+    /// let item = MenuItem::checkbox("~W~ord wrap", CM_WORD_WRAP, 0, Rc::clone(&wrap), 0);
+    /// ```
+    pub fn checkbox(
+        text: &str,
+        command: CommandId,
+        key_code: KeyCode,
+        flag: Rc<RefCell<bool>>,
+        help_ctx: u16,
+    ) -> Self {
+        let mut item = Self::new(text, command, key_code, help_ctx);
+        item.set_check(MenuCheck::Check(flag));
+        item
+    }
+
+    /// Create an item of a radio group: it is checked while `group` holds
+    /// `value`
+    pub fn radio(
+        text: &str,
+        command: CommandId,
+        key_code: KeyCode,
+        group: Rc<RefCell<usize>>,
+        value: usize,
+        help_ctx: u16,
+    ) -> Self {
+        let mut item = Self::new(text, command, key_code, help_ctx);
+        item.set_check(MenuCheck::Radio { group, value });
+        item
+    }
+
     /// Create a submenu item
     ///
     /// Matches Borland: `TMenuItem(name, keyCode, subMenu, helpCtx)`
@@ -182,6 +277,63 @@ impl MenuItem {
             _ => None,
         }
     }
+
+    /// Replace the display text (with ~ markers)
+    pub fn set_text(&mut self, new_text: &str) {
+        if let Self::Regular { text, .. } | Self::SubMenu { text, .. } = self {
+            *text = new_text.to_string();
+        }
+    }
+
+    /// Enable or disable a regular item
+    pub fn set_enabled(&mut self, enable: bool) {
+        if let Self::Regular { enabled, .. } = self {
+            *enabled = enable;
+        }
+    }
+
+    /// Replace the shortcut display text of a regular item
+    pub fn set_shortcut(&mut self, new_shortcut: Option<&str>) {
+        if let Self::Regular { shortcut, .. } = self {
+            *shortcut = new_shortcut.map(str::to_string);
+        }
+    }
+
+    /// Get the check mark binding (`MenuCheck::None` unless a regular item)
+    pub fn check(&self) -> &MenuCheck {
+        const NONE: &MenuCheck = &MenuCheck::None;
+        match self {
+            Self::Regular { check, .. } => check,
+            _ => NONE,
+        }
+    }
+
+    /// Bind the check mark of a regular item
+    pub fn set_check(&mut self, new_check: MenuCheck) {
+        if let Self::Regular { check, .. } = self {
+            *check = new_check;
+        }
+    }
+
+    /// Check if the item has a check mark column
+    pub fn is_checkable(&self) -> bool {
+        !matches!(self.check(), MenuCheck::None)
+    }
+
+    /// Check if the item is checked
+    pub fn is_checked(&self) -> bool {
+        self.check().is_checked()
+    }
+
+    /// Set the bound state: a check flag directly, a radio group to this
+    /// item's value (unchecking a radio item does nothing)
+    pub fn set_checked(&mut self, checked: bool) {
+        match self.check() {
+            MenuCheck::Check(flag) => *flag.borrow_mut() = checked,
+            radio @ MenuCheck::Radio { .. } if checked => radio.activate(),
+            _ => {}
+        }
+    }
 }
 
 /// Menu - a collection of menu items
@@ -255,6 +407,31 @@ impl Menu {
     pub fn is_empty(&self) -> bool {
         self.items.is_empty()
     }
+
+    /// Remove all items, e.g. before rebuilding a "Recent files" list
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.default_index = None;
+    }
+
+    /// Find the regular item sending `command`, searching submenus too
+    pub fn item_by_command(&self, command: CommandId) -> Option<&MenuItem> {
+        self.items.iter().find_map(|item| match item {
+            MenuItem::Regular { command: own, .. } if *own == command => Some(item),
+            MenuItem::SubMenu { menu, .. } => menu.item_by_command(command),
+            _ => None,
+        })
+    }
+
+    /// Find the regular item sending `command` for changing it, searching
+    /// submenus too
+    pub fn item_by_command_mut(&mut self, command: CommandId) -> Option<&mut MenuItem> {
+        self.items.iter_mut().find_map(|item| match item {
+            MenuItem::Regular { command: own, .. } if *own == command => Some(item),
+            MenuItem::SubMenu { menu, .. } => menu.item_by_command_mut(command),
+            _ => None,
+        })
+    }
 }
 
 impl Default for Menu {
@@ -332,6 +509,46 @@ impl MenuBuilder {
         self
     }
 
+    /// Add an item with a check mark bound to `flag`
+    #[must_use]
+    pub fn checkbox(
+        mut self,
+        text: &str,
+        command: CommandId,
+        key_code: KeyCode,
+        flag: &Rc<RefCell<bool>>,
+    ) -> Self {
+        self.items.push(MenuItem::checkbox(
+            text,
+            command,
+            key_code,
+            Rc::clone(flag),
+            self.help_ctx,
+        ));
+        self
+    }
+
+    /// Add an item of the radio group `group`, checked while it holds `value`
+    #[must_use]
+    pub fn radio(
+        mut self,
+        text: &str,
+        command: CommandId,
+        key_code: KeyCode,
+        group: &Rc<RefCell<usize>>,
+        value: usize,
+    ) -> Self {
+        self.items.push(MenuItem::radio(
+            text,
+            command,
+            key_code,
+            Rc::clone(group),
+            value,
+            self.help_ctx,
+        ));
+        self
+    }
+
     /// Add a submenu
     pub fn submenu(mut self, text: &str, key_code: KeyCode, menu: Menu) -> Self {
         self.items
@@ -399,6 +616,7 @@ pub struct MenuItemBuilder {
     help_ctx: u16,
     enabled: bool,
     shortcut: Option<String>,
+    check: MenuCheck,
 }
 
 impl MenuItemBuilder {
@@ -411,6 +629,7 @@ impl MenuItemBuilder {
             help_ctx: 0,
             enabled: true,
             shortcut: None,
+            check: MenuCheck::None,
         }
     }
 
@@ -457,6 +676,21 @@ impl MenuItemBuilder {
         self
     }
 
+    /// Binds a check mark to `flag` (default: none).
+    #[must_use]
+    pub fn checked(mut self, flag: Rc<RefCell<bool>>) -> Self {
+        self.check = MenuCheck::Check(flag);
+        self
+    }
+
+    /// Makes the item part of the radio group `group`, checked while the
+    /// group holds `value` (default: none).
+    #[must_use]
+    pub fn radio(mut self, group: Rc<RefCell<usize>>, value: usize) -> Self {
+        self.check = MenuCheck::Radio { group, value };
+        self
+    }
+
     /// Builds the MenuItem::Regular variant.
     ///
     /// # Panics
@@ -473,6 +707,7 @@ impl MenuItemBuilder {
             help_ctx: self.help_ctx,
             enabled: self.enabled,
             shortcut: self.shortcut,
+            check: self.check,
         }
     }
 }
@@ -483,6 +718,28 @@ impl Default for MenuItemBuilder {
     }
 }
 
+/// Context menu of text views: Cut, Copy, Paste and Select All
+///
+/// Cut and Copy need a selection, Cut and Paste an editable view. Used by
+/// the default context menus of `EditorWindow` and `InputLine`.
+pub fn clipboard_menu(has_selection: bool, editable: bool) -> Menu {
+    let item = |text: &str, command: CommandId, shortcut: &str, enabled: bool| {
+        MenuItemBuilder::new()
+            .text(tr(text))
+            .command(command)
+            .shortcut(shortcut)
+            .enabled(enabled)
+            .build()
+    };
+    Menu::from_items(vec![
+        item("Cu~t~", CM_CUT, "Ctrl+X", has_selection && editable),
+        item("~C~opy", CM_COPY, "Ctrl+C", has_selection),
+        item("~P~aste", CM_PASTE, "Ctrl+V", editable),
+        MenuItem::separator(),
+        item("Select ~A~ll", CM_SELECT_ALL, "Ctrl+A", true),
+    ])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -543,4 +800,56 @@ mod tests {
 
         assert!(!item.is_selectable());
     }
+
+    #[test]
+    fn test_checkbox_and_radio_items() {
+        let wrap = Rc::new(RefCell::new(false));
+        let mode = Rc::new(RefCell::new(0));
+        let mut menu = MenuBuilder::new()
+            .checkbox("~W~ord wrap", 1100, 0, &wrap)
+            .radio("~I~nsert", 1101, 0, &mode, 0)
+            .radio("~O~verwrite", 1102, 0, &mode, 1)
+            .build();
+
+        assert!(menu.items[0].is_checkable());
+        assert_eq!(menu.items[0].check().mark(), Some(' '));
+        menu.items[0].check().activate();
+        assert!(*wrap.borrow());
+        assert_eq!(menu.items[0].check().mark(), Some('✓'));
+
+        assert!(menu.items[1].is_checked());
+        menu.items[2].check().activate();
+        assert_eq!(*mode.borrow(), 1);
+        assert!(!menu.items[1].is_checked());
+
+        // Unchecking a radio item leaves the group alone
+        menu.items[2].set_checked(false);
+        assert_eq!(*mode.borrow(), 1);
+        menu.items[1].set_checked(true);
+        assert_eq!(*mode.borrow(), 0);
+        assert_eq!(MenuItem::new("~O~pen", 100, 0, 0).check().mark(), None);
+    }
+
+    #[test]
+    fn test_item_by_command_mut() {
+        let recent = MenuBuilder::new().item("~n~otes.txt", 1200, 0).build();
+        let mut menu = MenuBuilder::new()
+            .item("~O~pen", 100, 0)
+            .submenu("~R~ecent", 0, recent)
+            .build();
+
+        let item = menu.item_by_command_mut(1200).unwrap();
+        item.set_text("~t~odo.txt");
+        item.set_enabled(false);
+        item.set_shortcut(Some("Ctrl+1"));
+
+        let item = menu.item_by_command(1200).unwrap();
+        assert_eq!(item.text(), "~t~odo.txt");
+        assert_eq!(item.shortcut(), Some("Ctrl+1"));
+        assert!(!item.is_selectable());
+        assert!(menu.item_by_command(999).is_none());
+
+        menu.clear();
+        assert!(menu.is_empty());
+    }
 }
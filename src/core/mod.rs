@@ -5,6 +5,7 @@
 //! This module provides the essential building blocks for the Turbo Vision
 //! framework including:
 //! - **Geometry primitives** ([`geometry`]): [`Point`](geometry::Point), [`Rect`](geometry::Rect) for layout
+//! - **Layout** ([`layout`]): Grow modes and stack/grid splitting of areas
 //! - **Event handling** ([`event`]): [`Event`](event::Event), [`KeyCode`](event::KeyCode), mouse events
 //! - **Drawing utilities** ([`draw`]): [`Cell`](draw::Cell), [`Buffer`](draw::Buffer), [`Attr`](draw::Attr) for terminal rendering
 //! - **Command system** ([`command`], [`command_set`]): Action management and command routing
@@ -13,6 +14,8 @@
 //! - **State management** ([`state`]): View state flags and constants
 //! - **Clipboard** ([`clipboard`]): Copy/paste support
 //! - **History** ([`history`]): Input history management
+//! - **Internationalization** ([`i18n`]): Message catalog for built-in and application strings
+//! - **Key bindings** ([`keymap`]): Configurable key chords and sequences mapped to commands
 //!
 //! # Examples
 //!
@@ -55,9 +58,13 @@ pub mod error;
 pub mod event;
 pub mod geometry;
 pub mod history;
+pub mod i18n;
+pub mod keymap;
+pub mod layout;
 pub mod menu_data;
 pub mod palette;
 pub mod palette_chain;
+pub(crate) mod quoted;
 pub mod screenshot;
 pub mod state;
 pub mod status_data;
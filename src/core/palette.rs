@@ -46,9 +46,30 @@ pub const LABEL_NORMAL: u8 = 1; // Normal label text
 pub const LABEL_SELECTED: u8 = 2; // Selected label
 pub const LABEL_SHORTCUT: u8 = 3; // Shortcut letter
 
+// TabView palette indices (maps to CP_TAB_VIEW)
+pub const TAB_VIEW_FRAME: u8 = 1; // Tab strip lines and separators
+pub const TAB_VIEW_NORMAL: u8 = 2; // Inactive tab title
+pub const TAB_VIEW_SELECTED: u8 = 3; // Active tab title
+pub const TAB_VIEW_SHORTCUT: u8 = 4; // Shortcut letter
+
+// ProgressBar/Gauge palette indices (maps to CP_PROGRESS_BAR)
+pub const PROGRESS_TRACK: u8 = 1; // Unfilled part of the bar
+pub const PROGRESS_FILLED: u8 = 2; // Filled part of the bar
+pub const PROGRESS_TEXT: u8 = 3; // Percentage or value text
+
+// DatePicker/TimePicker palette indices (maps to CP_DATE_PICKER)
+pub const DATE_NORMAL: u8 = 1; // Field text
+pub const DATE_FOCUSED: u8 = 2; // Field text when focused
+pub const DATE_SELECTED: u8 = 3; // Part under the cursor
+pub const DATE_BUTTON: u8 = 4; // Drop-down or spin button
+pub const DATE_ARROW: u8 = 5; // Button arrow
+
 // StaticText palette indices (maps to CP_STATIC_TEXT)
 pub const STATIC_TEXT_NORMAL: u8 = 1; // Normal static text
 
+// ErrorText palette indices (maps to CP_ERROR_TEXT)
+pub const ERROR_TEXT_NORMAL: u8 = 1; // Inline validation message
+
 // ParamText palette indices (same as StaticText)
 pub const PARAM_TEXT_NORMAL: u8 = 1; // Normal param text
 
@@ -650,6 +671,12 @@ pub mod palettes {
         6,  // 1: Normal text color
     ];
 
+    // ErrorText palette - the highlighted label color, so messages stand out
+    #[rustfmt::skip]
+    pub const CP_ERROR_TEXT: &[u8] = &[
+        9,  // 1: Message text
+    ];
+
     // InputLine palette - from Borland cpInputLine "\x13\x13\x14\x15" (19, 19, 20, 21)
     // These are dialog-relative indices that should map to dialog palette positions
     #[rustfmt::skip]
@@ -664,12 +691,38 @@ pub mod palettes {
         7, 8, 9, 9, 13, 13,  // 1-6: Normal fg/bg, Light fg/bg, Disabled fg/bg
     ];
 
+    // TabView palette - same dialog entries as static text and labels
+    #[rustfmt::skip]
+    pub const CP_TAB_VIEW: &[u8] = &[
+        6, 7, 8, 9,  // 1-4: Frame, normal tab, active tab, shortcut
+    ];
+
+    // ProgressBar/Gauge palette - scroll bar entries for the bar, static text for the value
+    #[rustfmt::skip]
+    pub const CP_PROGRESS_BAR: &[u8] = &[
+        4, 5, 6,  // 1-3: Track, filled, text
+    ];
+
+    // DatePicker/TimePicker palette - input line entries for the field, history
+    // button entries for the button
+    #[rustfmt::skip]
+    pub const CP_DATE_PICKER: &[u8] = &[
+        19, 19, 20, 22, 23,  // 1-5: Normal, focused, selected part, button, arrow
+    ];
+
     // ListBox palette
     #[rustfmt::skip]
     pub const CP_LISTBOX: &[u8] = &[
         26, 26, 27, 28,  // 1-4: Normal, focused, selected, divider
     ];
 
+    // Command palette - input line entry for the query, list box entries
+    // for the commands, dark gray on cyan for disabled commands
+    #[rustfmt::skip]
+    pub const CP_COMMAND_PALETTE: &[u8] = &[
+        19, 26, 27, 28, 31,  // 1-5: Query, normal, selected, shortcut, disabled
+    ];
+
     // ScrollBar palette
     #[rustfmt::skip]
     pub const CP_SCROLLBAR: &[u8] = &[
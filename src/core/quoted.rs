@@ -0,0 +1,76 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Quoted text in the crate's line-based files (history, resources, desktop
+//! state): `"` and `\` are escaped with a backslash, and line breaks are
+//! written as `\n` / `\r` so each value stays on one line.
+
+/// Quote text so that [`unquote`] and [`read_quoted`] read it back unchanged
+pub(crate) fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(ch);
+            }
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Read quoted text whose opening `"` has already been taken from `chars`,
+/// up to and including the closing `"`
+///
+/// Returns None if the text is not terminated.
+pub(crate) fn read_quoted(chars: &mut impl Iterator<Item = char>) -> Option<String> {
+    let mut text = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(text),
+            '\\' => match chars.next()? {
+                'n' => text.push('\n'),
+                'r' => text.push('\r'),
+                escaped => text.push(escaped),
+            },
+            ch => text.push(ch),
+        }
+    }
+}
+
+/// The text of a whole quoted string, None if `text` is anything else
+pub(crate) fn unquote(text: &str) -> Option<String> {
+    let mut chars = text.strip_prefix('"')?.chars();
+    let unquoted = read_quoted(&mut chars)?;
+    chars.next().is_none().then_some(unquoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoted_text_round_trips() {
+        let text = "say \"hi\"\\\r\nbye";
+        let quoted = quote(text);
+        assert!(!quoted.contains('\n'));
+        assert_eq!(unquote(&quoted).as_deref(), Some(text));
+
+        let line = format!("{quoted} rest");
+        let mut chars = line.chars();
+        chars.next();
+        assert_eq!(read_quoted(&mut chars).as_deref(), Some(text));
+        assert_eq!(chars.as_str(), " rest");
+    }
+
+    #[test]
+    fn malformed_text_is_rejected() {
+        assert_eq!(unquote("\"open"), None);
+        assert_eq!(unquote("\"a\"b\""), None);
+        assert_eq!(unquote("plain"), None);
+    }
+}
@@ -22,6 +22,7 @@ pub const SF_DEFAULT: StateFlags = 0x400;
 pub const SF_EXPOSED: StateFlags = 0x800;
 pub const SF_CLOSED: StateFlags = 0x1000; // Window marked for removal (Rust-specific)
 pub const SF_RESIZING: StateFlags = 0x2000; // Window is being resized (Rust-specific)
+pub const SF_DROPPED_DOWN: StateFlags = 0x4000; // Drop-down list open over the siblings (Rust-specific)
 
 // TView Option masks
 pub const OF_SELECTABLE: u16 = 0x001;
@@ -37,6 +38,19 @@ pub const OF_CENTER_Y: u16 = 0x200;
 pub const OF_CENTERED: u16 = 0x300;
 pub const OF_VALIDATE: u16 = 0x400; // View should be validated on focus release (Borland: ofValidate)
 
+/// Grow mode flags - how a view follows its owner when the owner is resized
+/// Matches Borland: TView::growMode. 0 keeps the view fixed relative to the
+/// owner's top-left corner.
+pub type GrowMode = u8;
+
+// TView GrowMode masks
+pub const GF_GROW_LO_X: GrowMode = 0x01; // Left edge follows the owner's width change
+pub const GF_GROW_LO_Y: GrowMode = 0x02; // Top edge follows the owner's height change
+pub const GF_GROW_HI_X: GrowMode = 0x04; // Right edge follows the owner's width change
+pub const GF_GROW_HI_Y: GrowMode = 0x08; // Bottom edge follows the owner's height change
+pub const GF_GROW_ALL: GrowMode = 0x0F; // Move with the owner's bottom-right corner
+pub const GF_GROW_REL: GrowMode = 0x10; // Scale the moving edges in proportion to the owner
+
 /// Shadow size storage - initialized once at startup based on terminal cell aspect ratio
 static SHADOW_SIZE_CELL: OnceLock<(i16, i16)> = OnceLock::new();
 
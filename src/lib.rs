@@ -222,8 +222,8 @@ pub mod views;
 #[cfg(feature = "ssh")]
 pub mod ssh;
 
-// Test utilities (only available with test-util feature)
-#[cfg(feature = "test-util")]
+// Test utilities (only available with test-util feature, and to our own unit tests)
+#[cfg(any(test, feature = "test-util"))]
 pub mod test_util;
 
 // Re-export commonly used types
@@ -239,7 +239,9 @@ pub mod prelude {
         CM_CANCEL,
         CM_CLOSE,
         CM_CLOSE_FILE,
+        CM_COMMAND_PALETTE,
         CM_COMMAND_SET_CHANGED,
+        CM_CONTEXT_MENU,
         CM_CONTROLS_DEMO,
         CM_COPY,
         CM_CUT,
@@ -282,6 +284,7 @@ pub mod prelude {
         CM_TOGGLE_STATUSBAR,
         // Edit operations
         CM_UNDO,
+        CM_WINDOW_LIST,
         CM_YES,
         // View commands
         CM_ZOOM_IN,
@@ -146,7 +146,10 @@ impl CrosstermBackend {
             _ => return None,
         };
 
-        Some(Event::mouse(event_type, pos, buttons, is_double_click))
+        // Keep Shift/Ctrl so views can extend or toggle a selection on click
+        let mut event = Event::mouse(event_type, pos, buttons, is_double_click);
+        event.key_modifiers = mouse.modifiers;
+        Some(event)
     }
 }
 
@@ -0,0 +1,95 @@
+// (C) 2026 - Enzo Lombardi
+
+//! Headless backend for off-screen rendering.
+//!
+//! This module provides the [`HeadlessBackend`], a [`Backend`] that never
+//! touches a real terminal. It reports a fixed size, discards all output and
+//! yields no input events. Combined with [`Terminal::with_backend`] it gives a
+//! fully functional double-buffered [`Terminal`] that views can draw into,
+//! which is what snapshot tests and other non-interactive tooling need.
+//!
+//! [`Terminal`]: super::Terminal
+//! [`Terminal::with_backend`]: super::Terminal::with_backend
+
+use std::io;
+use std::time::Duration;
+
+use super::backend::{Backend, Capabilities};
+use crate::core::event::Event;
+
+/// Terminal backend that renders nowhere.
+///
+/// # Example
+///
+/// ```rust
+/// use turbo_vision::terminal::{HeadlessBackend, Terminal};
+///
+/// let terminal = Terminal::with_backend(Box::new(HeadlessBackend::new(80, 25))).unwrap();
+/// assert_eq!(terminal.size(), (80, 25));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HeadlessBackend {
+    width: u16,
+    height: u16,
+}
+
+impl HeadlessBackend {
+    /// Create a headless backend with the given size in cells.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self { width, height }
+    }
+}
+
+impl Backend for HeadlessBackend {
+    fn init(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn cleanup(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok((self.width, self.height))
+    }
+
+    fn poll_event(&mut self, _timeout: Duration) -> io::Result<Option<Event>> {
+        // Never block: there is no input source.
+        Ok(None)
+    }
+
+    fn write_raw(&mut self, _data: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn show_cursor(&mut self, _x: u16, _y: u16) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            mouse: false,
+            colors_256: true,
+            true_color: true,
+            bracketed_paste: false,
+            focus_events: false,
+            kitty_keyboard: false,
+        }
+    }
+
+    fn bell(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn clear_screen(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -18,13 +18,13 @@
 //! - Modifier combinations (Shift, Alt, Ctrl)
 
 use crate::core::event::{
-    Event, EventType, KB_ALT_A, KB_ALT_B, KB_ALT_C, KB_ALT_D, KB_ALT_E, KB_ALT_F, KB_ALT_G,
-    KB_ALT_H, KB_ALT_I, KB_ALT_J, KB_ALT_K, KB_ALT_L, KB_ALT_M, KB_ALT_N, KB_ALT_O, KB_ALT_P,
-    KB_ALT_Q, KB_ALT_R, KB_ALT_S, KB_ALT_T, KB_ALT_U, KB_ALT_V, KB_ALT_W, KB_ALT_X, KB_ALT_Y,
-    KB_ALT_Z, KB_BACKSPACE, KB_DEL, KB_DOWN, KB_END, KB_ENTER, KB_ESC, KB_F1, KB_F2, KB_F3, KB_F4,
-    KB_F5, KB_F6, KB_F7, KB_F8, KB_F9, KB_F10, KB_F11, KB_F12, KB_HOME, KB_INS, KB_LEFT, KB_PGDN,
-    KB_PGUP, KB_RIGHT, KB_SHIFT_TAB, KB_TAB, KB_UP, MB_LEFT_BUTTON, MB_MIDDLE_BUTTON,
-    MB_RIGHT_BUTTON,
+    Event, EventType, KB_ALT_0, KB_ALT_1, KB_ALT_A, KB_ALT_B, KB_ALT_C, KB_ALT_D, KB_ALT_E,
+    KB_ALT_F, KB_ALT_G, KB_ALT_H, KB_ALT_I, KB_ALT_J, KB_ALT_K, KB_ALT_L, KB_ALT_M, KB_ALT_N,
+    KB_ALT_O, KB_ALT_P, KB_ALT_Q, KB_ALT_R, KB_ALT_S, KB_ALT_T, KB_ALT_U, KB_ALT_V, KB_ALT_W,
+    KB_ALT_X, KB_ALT_Y, KB_ALT_Z, KB_BACKSPACE, KB_DEL, KB_DOWN, KB_END, KB_ENTER, KB_ESC, KB_F1,
+    KB_F2, KB_F3, KB_F4, KB_F5, KB_F6, KB_F7, KB_F8, KB_F9, KB_F10, KB_F11, KB_F12, KB_HOME,
+    KB_INS, KB_LEFT, KB_PGDN, KB_PGUP, KB_RIGHT, KB_SHIFT_TAB, KB_TAB, KB_UP, MB_LEFT_BUTTON,
+    MB_MIDDLE_BUTTON, MB_RIGHT_BUTTON,
 };
 use crate::core::geometry::Point;
 
@@ -121,8 +121,8 @@ impl InputParser {
         match buf[1] {
             b'[' => self.parse_csi(),
             b'O' => self.parse_ss3(),
-            c if c.is_ascii_alphabetic() => {
-                // ESC + letter = Alt+letter
+            c if c.is_ascii_alphanumeric() => {
+                // ESC + letter or digit = Alt+letter or Alt+digit
                 if let Some(alt_code) = char_to_alt_code((c as char).to_ascii_lowercase()) {
                     Some((Event::keyboard(alt_code), 2))
                 } else {
@@ -356,7 +356,7 @@ impl Default for InputParser {
     }
 }
 
-/// Convert a lowercase letter to its Alt+letter key code.
+/// Convert a lowercase letter or a digit to its Alt+key code.
 fn char_to_alt_code(c: char) -> Option<u16> {
     match c {
         'a' => Some(KB_ALT_A),
@@ -385,6 +385,8 @@ fn char_to_alt_code(c: char) -> Option<u16> {
         'x' => Some(KB_ALT_X),
         'y' => Some(KB_ALT_Y),
         'z' => Some(KB_ALT_Z),
+        '0' => Some(KB_ALT_0),
+        '1'..='9' => Some(KB_ALT_1 + (c as u16 - '1' as u16) * 0x100),
         _ => None,
     }
 }
@@ -472,6 +474,9 @@ mod tests {
 
         let events = parser.parse(b"\x1bf");
         assert_eq!(events[0].key_code, KB_ALT_F);
+
+        let events = parser.parse(b"\x1b3");
+        assert_eq!(events[0].key_code, crate::core::event::KB_ALT_3);
     }
 
     #[test]
@@ -17,6 +17,8 @@
 //! allowing turbo-vision to work with different terminal transports:
 //!
 //! - [`CrosstermBackend`] - Local terminal via crossterm (default)
+//! - [`HeadlessBackend`] - Off-screen rendering for tests and tooling
+//! - [`ReplayBackend`](recording::ReplayBackend) - Replays a recorded input session
 //! - `SshBackend` - Remote terminal via SSH (requires `ssh` feature)
 //!
 //! # Examples
@@ -54,6 +56,8 @@
 
 mod backend;
 mod crossterm_backend;
+mod headless_backend;
+pub mod recording;
 pub mod remote_input;
 
 #[cfg(feature = "ssh")]
@@ -63,6 +67,7 @@ mod ssh_backend;
 
 pub use backend::{Backend, Capabilities};
 pub use crossterm_backend::CrosstermBackend;
+pub use headless_backend::HeadlessBackend;
 
 #[cfg(feature = "ssh")]
 pub use input_parser::InputParser;
@@ -76,7 +81,6 @@ use crate::core::event::Event;
 use crate::core::geometry::{Point, Rect};
 use crate::core::palette::Attr;
 use std::io::{self, Write};
-use std::sync::mpsc::Receiver;
 use std::time::Duration;
 
 /// Terminal abstraction for rendering and input handling.
@@ -92,9 +96,11 @@ pub struct Terminal {
     height: u16,
     clip_stack: Vec<Rect>,
     pending_event: Option<Event>,
-    /// Receiver for keyboard events injected by the remote-input listener.
+    /// Events and queries from the remote-input listener.
     /// `None` unless [`enable_remote_input`](Self::enable_remote_input) was called.
-    injected_rx: Option<Receiver<Event>>,
+    remote: Option<remote_input::RemoteSession>,
+    /// Session recorder; `None` unless [`start_recording`](Self::start_recording) was called.
+    recorder: Option<recording::EventRecorder>,
 }
 
 impl Terminal {
@@ -179,7 +185,8 @@ impl Terminal {
             height,
             clip_stack: Vec::new(),
             pending_event: None,
-            injected_rx: None,
+            remote: None,
+            recorder: None,
         })
     }
 
@@ -244,6 +251,23 @@ impl Terminal {
         (self.width as i16, self.height as i16)
     }
 
+    /// Query the current size of the screen behind this terminal.
+    ///
+    /// Unlike [`size`](Self::size), which reports the buffer size, this asks
+    /// the backend, so it detects manual resizes. A size requested through the
+    /// remote-input `RESIZE` command takes precedence.
+    pub fn screen_size(&self) -> io::Result<(i16, i16)> {
+        let (width, height) = match self
+            .remote
+            .as_ref()
+            .and_then(remote_input::RemoteSession::size)
+        {
+            Some(size) => size,
+            None => self.backend.size()?,
+        };
+        Ok((width.cast_signed(), height.cast_signed()))
+    }
+
     /// Query actual terminal size from the system.
     ///
     /// This is useful for detecting manual resizes.
@@ -349,7 +373,7 @@ impl Terminal {
     }
 
     /// Get the current effective clipping region (intersection of all regions on stack).
-    fn get_clip_rect(&self) -> Option<Rect> {
+    pub fn get_clip_rect(&self) -> Option<Rect> {
         if self.clip_stack.is_empty() {
             None
         } else {
@@ -514,14 +538,15 @@ impl Terminal {
         self.pending_event = Some(event);
     }
 
-    /// Enable the remote keyboard-input listener on the given TCP port.
+    /// Enable the remote-input listener on the given TCP port.
     ///
     /// This is **off by default**. Once enabled, the terminal listens on
-    /// `127.0.0.1:port` and injects key chords received over the socket into the
-    /// event stream returned by [`poll_event`](Self::poll_event), as if they had
-    /// been typed. See [`remote_input`] for the wire format. Intended for
-    /// testing and automation (for example, triggering Ctrl+F12 screenshots on
-    /// terminals that do not forward that chord).
+    /// `127.0.0.1:port` and injects key chords and mouse gestures received over
+    /// the socket into the event stream returned by [`poll_event`](Self::poll_event),
+    /// as if they had been typed. Queries (screen text, focused view, waiting
+    /// for text) are answered from the current buffer. See [`remote_input`] for
+    /// the wire format. Intended for testing and automation (for example,
+    /// triggering Ctrl+F12 screenshots on terminals that do not forward that chord).
     ///
     /// # Errors
     ///
@@ -529,10 +554,46 @@ impl Terminal {
     pub fn enable_remote_input(&mut self, port: u16) -> io::Result<()> {
         let (tx, rx) = std::sync::mpsc::channel();
         remote_input::spawn(port, tx)?;
-        self.injected_rx = Some(rx);
+        self.remote = Some(remote_input::RemoteSession::new(rx));
         Ok(())
     }
 
+    /// Check whether the remote-input listener is enabled.
+    pub fn has_remote_input(&self) -> bool {
+        self.remote.is_some()
+    }
+
+    /// Publish a description of the focused view for the remote `FOCUS` query.
+    pub(crate) fn set_remote_focus(&mut self, focus: Option<String>) {
+        if let Some(remote) = &mut self.remote {
+            remote.set_focus(focus);
+        }
+    }
+
+    /// Start recording every input event to `path` (JSON lines).
+    ///
+    /// Events re-queued with [`put_event`](Self::put_event) are not recorded a
+    /// second time. See [`recording`] for the file format and for replaying a
+    /// session with [`ReplayBackend`](recording::ReplayBackend).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created.
+    pub fn start_recording(&mut self, path: impl AsRef<std::path::Path>) -> io::Result<()> {
+        self.recorder = Some(recording::EventRecorder::create(path)?);
+        Ok(())
+    }
+
+    /// Stop recording input events. Does nothing if no recording is active.
+    pub fn stop_recording(&mut self) {
+        self.recorder = None;
+    }
+
+    /// Check whether input events are currently being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
     /// Poll for an event with timeout.
     pub fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
         // Check for pending event first
@@ -540,14 +601,25 @@ impl Terminal {
             return Ok(Some(event));
         }
 
-        // Then any event injected by the remote-input listener.
-        if let Some(rx) = &self.injected_rx {
-            if let Ok(event) = rx.try_recv() {
-                return Ok(Some(event));
+        // Then any event injected by the remote-input listener. Queries queued
+        // ahead of it are answered from the buffer drawn for this frame.
+        let injected = match &mut self.remote {
+            Some(remote) => remote.next_event(&self.buffer),
+            None => None,
+        };
+        let event = match injected {
+            Some(event) => Some(event),
+            None => self.backend.poll_event(timeout)?,
+        };
+
+        if let (Some(recorder), Some(event)) = (&mut self.recorder, &event) {
+            if let Err(e) = recorder.record(event) {
+                log::warn!("Event recording stopped: {e}");
+                self.recorder = None;
             }
         }
 
-        self.backend.poll_event(timeout)
+        Ok(event)
     }
 
     /// Read an event (blocking).
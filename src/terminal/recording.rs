@@ -0,0 +1,485 @@
+// (C) 2026 - Enzo Lombardi
+
+//! Recording and deterministic replay of input sessions.
+//!
+//! Every [`Event`] returned by [`Terminal::poll_event`] can be appended to a
+//! recording file together with the time (in milliseconds) since recording
+//! started. Such a file can later be fed back through a [`ReplayBackend`],
+//! which wraps any other [`Backend`] for output and yields the recorded events
+//! as input. This makes it possible to reproduce user-reported bugs, script
+//! demos, and turn manual QA sessions into regression tests (for example by
+//! replaying into a [`HeadlessBackend`](super::HeadlessBackend) and comparing
+//! a snapshot of the result, see `test_util::snapshot` in the `test-util`
+//! feature).
+//!
+//! # File format
+//!
+//! Recordings are JSON lines, one flat object per event. Every object has a
+//! `t` field (milliseconds since start) and a `type` field; the remaining
+//! fields depend on the type:
+//!
+//! ```text
+//! {"t":0,"type":"key","code":7181,"mods":0}
+//! {"t":250,"type":"mouse_down","x":28,"y":23,"buttons":1,"double":false,"mods":2}
+//! {"t":310,"type":"mouse_up","x":28,"y":23,"buttons":0,"double":false,"mods":2}
+//! {"t":900,"type":"command","command":1}
+//! ```
+//!
+//! Event types are `key`, `mouse_down`, `mouse_up`, `mouse_move`,
+//! `mouse_auto`, `wheel_up`, `wheel_down`, `command` and `broadcast`. Key
+//! codes are the Borland-style codes from [`crate::core::event`] and `mods`
+//! holds the crossterm modifier bits, for key and mouse events alike (so a
+//! Ctrl+click replays as one). Blank lines and lines starting with `#`
+//! are ignored, so recordings can be annotated by hand.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use turbo_vision::app::Application;
+//! use turbo_vision::terminal::{CrosstermBackend, Terminal};
+//! use turbo_vision::terminal::recording::{self, ReplayBackend, ReplayTiming};
+//!
+//! # fn main() -> turbo_vision::core::error::Result<()> {
+//! // Record a session...
+//! let mut app = Application::new()?;
+//! app.start_recording("session.jsonl")?;
+//! app.run();
+//! drop(app);
+//!
+//! // ...and replay it later at the original speed.
+//! let events = recording::load("session.jsonl")?;
+//! let backend = ReplayBackend::new(Box::new(CrosstermBackend::new()?), events)
+//!     .with_timing(ReplayTiming::RealTime);
+//! let mut app = Application::with_terminal(Terminal::with_backend(Box::new(backend))?);
+//! app.run();
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crossterm::event::KeyModifiers;
+
+use super::backend::{Backend, Capabilities};
+use crate::core::command::CM_QUIT;
+use crate::core::event::{Event, EventType, MouseEvent};
+use crate::core::geometry::Point;
+
+/// An event together with its offset from the start of the recording.
+#[derive(Debug, Clone, Copy)]
+pub struct RecordedEvent {
+    /// Time since the recording started.
+    pub at: Duration,
+    /// The recorded event.
+    pub event: Event,
+}
+
+/// Appends events to a recording file as they happen.
+pub struct EventRecorder {
+    writer: BufWriter<File>,
+    start: Instant,
+}
+
+impl EventRecorder {
+    /// Create (or truncate) a recording file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            start: Instant::now(),
+        })
+    }
+
+    /// Append one event, timestamped relative to the start of the recording.
+    ///
+    /// Events of type [`EventType::Nothing`] are skipped. The line is flushed
+    /// immediately so a crash does not lose the events that led up to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the file fails.
+    pub fn record(&mut self, event: &Event) -> io::Result<()> {
+        if let Some(line) = format_event(self.start.elapsed(), event) {
+            writeln!(self.writer, "{line}")?;
+            self.writer.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Serialize an event into one recording line, or `None` for
+/// [`EventType::Nothing`].
+pub fn format_event(at: Duration, event: &Event) -> Option<String> {
+    let t = at.as_millis();
+    let mouse = |kind: &str| {
+        format!(
+            r#"{{"t":{t},"type":"{kind}","x":{},"y":{},"buttons":{},"double":{},"mods":{}}}"#,
+            event.mouse.pos.x,
+            event.mouse.pos.y,
+            event.mouse.buttons,
+            event.mouse.double_click,
+            event.key_modifiers.bits()
+        )
+    };
+    let line = match event.what {
+        EventType::Nothing => return None,
+        EventType::Keyboard => format!(
+            r#"{{"t":{t},"type":"key","code":{},"mods":{}}}"#,
+            event.key_code,
+            event.key_modifiers.bits()
+        ),
+        EventType::MouseDown => mouse("mouse_down"),
+        EventType::MouseUp => mouse("mouse_up"),
+        EventType::MouseMove => mouse("mouse_move"),
+        EventType::MouseAuto => mouse("mouse_auto"),
+        EventType::MouseWheelUp => mouse("wheel_up"),
+        EventType::MouseWheelDown => mouse("wheel_down"),
+        EventType::Command => {
+            format!(
+                r#"{{"t":{t},"type":"command","command":{}}}"#,
+                event.command
+            )
+        }
+        EventType::Broadcast => {
+            format!(
+                r#"{{"t":{t},"type":"broadcast","command":{}}}"#,
+                event.command
+            )
+        }
+    };
+    Some(line)
+}
+
+/// Parse one recording line.
+///
+/// Returns `None` for blank lines, `#` comments and lines that are not a
+/// valid event object.
+pub fn parse_event(line: &str) -> Option<RecordedEvent> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let body = line.strip_prefix('{')?.strip_suffix('}')?;
+
+    let mut t = None;
+    let mut kind = None;
+    let mut fields: Vec<(&str, &str)> = Vec::new();
+    for pair in body.split(',') {
+        let (key, value) = pair.split_once(':')?;
+        let key = key.trim().trim_matches('"');
+        let value = value.trim();
+        match key {
+            "t" => t = value.parse::<u64>().ok(),
+            "type" => kind = Some(value.trim_matches('"')),
+            _ => fields.push((key, value)),
+        }
+    }
+    let field = |name: &str| fields.iter().find(|(k, _)| *k == name).map(|(_, v)| *v);
+    let num = |name: &str| field(name).and_then(|v| v.parse::<i64>().ok());
+    let mods = || -> Option<KeyModifiers> {
+        Some(KeyModifiers::from_bits_truncate(
+            u8::try_from(num("mods").unwrap_or(0)).ok()?,
+        ))
+    };
+
+    let mouse = |what: EventType| -> Option<Event> {
+        Some(Event {
+            what,
+            mouse: MouseEvent {
+                pos: Point::new(
+                    i16::try_from(num("x")?).ok()?,
+                    i16::try_from(num("y")?).ok()?,
+                ),
+                buttons: u8::try_from(num("buttons").unwrap_or(0)).ok()?,
+                double_click: field("double") == Some("true"),
+            },
+            key_modifiers: mods()?,
+            ..Event::nothing()
+        })
+    };
+
+    let event = match kind? {
+        "key" => Event {
+            what: EventType::Keyboard,
+            key_code: u16::try_from(num("code")?).ok()?,
+            key_modifiers: mods()?,
+            ..Event::nothing()
+        },
+        "mouse_down" => mouse(EventType::MouseDown)?,
+        "mouse_up" => mouse(EventType::MouseUp)?,
+        "mouse_move" => mouse(EventType::MouseMove)?,
+        "mouse_auto" => mouse(EventType::MouseAuto)?,
+        "wheel_up" => mouse(EventType::MouseWheelUp)?,
+        "wheel_down" => mouse(EventType::MouseWheelDown)?,
+        "command" => Event::command(u16::try_from(num("command")?).ok()?),
+        "broadcast" => Event::broadcast(u16::try_from(num("command")?).ok()?),
+        _ => return None,
+    };
+
+    Some(RecordedEvent {
+        at: Duration::from_millis(t?),
+        event,
+    })
+}
+
+/// Load every event of a recording file, in order.
+///
+/// Malformed lines are logged and skipped.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or read.
+pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<RecordedEvent>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut events = Vec::new();
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        match parse_event(&line) {
+            Some(recorded) => events.push(recorded),
+            None if line.trim().is_empty() || line.trim_start().starts_with('#') => {}
+            None => log::warn!("Recording line {}: unparseable {line:?}", number + 1),
+        }
+    }
+    Ok(events)
+}
+
+/// How a [`ReplayBackend`] paces the recorded events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReplayTiming {
+    /// Deliver the next event on every poll, ignoring timestamps.
+    ///
+    /// Replay is fully deterministic and as fast as the event loop runs,
+    /// which is what regression tests want.
+    #[default]
+    Immediate,
+    /// Wait until each event's timestamp (relative to the first poll), so the
+    /// session plays back at its original speed. Useful for demos.
+    RealTime,
+}
+
+/// Backend that replays a recorded session as input.
+///
+/// All output (drawing, cursor, bell) is forwarded to the wrapped backend, so
+/// the replay can be watched on a real terminal or rendered headlessly.
+/// Events coming from the wrapped backend itself are ignored while replaying.
+pub struct ReplayBackend {
+    inner: Box<dyn Backend>,
+    events: VecDeque<RecordedEvent>,
+    timing: ReplayTiming,
+    started: Option<Instant>,
+    quit_when_done: bool,
+}
+
+impl ReplayBackend {
+    /// Wrap `inner`, replaying `events` in order.
+    pub fn new(inner: Box<dyn Backend>, events: Vec<RecordedEvent>) -> Self {
+        Self {
+            inner,
+            events: events.into(),
+            timing: ReplayTiming::default(),
+            started: None,
+            quit_when_done: true,
+        }
+    }
+
+    /// Set how events are paced (default: [`ReplayTiming::Immediate`]).
+    #[must_use]
+    pub fn with_timing(mut self, timing: ReplayTiming) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// Whether to emit a [`CM_QUIT`] command once every event was delivered
+    /// (default: `true`), so that [`Application::run`](crate::app::Application::run)
+    /// returns at the end of the replay.
+    #[must_use]
+    pub fn quit_when_done(mut self, quit: bool) -> Self {
+        self.quit_when_done = quit;
+        self
+    }
+
+    /// Number of recorded events not yet delivered.
+    pub fn remaining(&self) -> usize {
+        self.events.len()
+    }
+}
+
+impl Backend for ReplayBackend {
+    fn init(&mut self) -> io::Result<()> {
+        self.inner.init()
+    }
+
+    fn cleanup(&mut self) -> io::Result<()> {
+        self.inner.cleanup()
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        self.inner.size()
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let started = *self.started.get_or_insert_with(Instant::now);
+
+        let Some(next) = self.events.front() else {
+            if self.quit_when_done {
+                self.quit_when_done = false;
+                return Ok(Some(Event::command(CM_QUIT)));
+            }
+            std::thread::sleep(timeout);
+            return Ok(None);
+        };
+
+        if self.timing == ReplayTiming::RealTime {
+            if let Some(wait) = next.at.checked_sub(started.elapsed()) {
+                if wait > timeout {
+                    std::thread::sleep(timeout);
+                    return Ok(None);
+                }
+                std::thread::sleep(wait);
+            }
+        }
+
+        Ok(self.events.pop_front().map(|recorded| recorded.event))
+    }
+
+    fn write_raw(&mut self, data: &[u8]) -> io::Result<()> {
+        self.inner.write_raw(data)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    fn show_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.inner.show_cursor(x, y)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
+    fn suspend(&mut self) -> io::Result<()> {
+        self.inner.suspend()
+    }
+
+    fn resume(&mut self) -> io::Result<()> {
+        self.inner.resume()
+    }
+
+    fn cell_aspect_ratio(&self) -> (i16, i16) {
+        self.inner.cell_aspect_ratio()
+    }
+
+    fn bell(&mut self) -> io::Result<()> {
+        self.inner.bell()
+    }
+
+    fn clear_screen(&mut self) -> io::Result<()> {
+        self.inner.clear_screen()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::{KB_ALT_X, KB_ENTER, MB_LEFT_BUTTON};
+    use crate::terminal::{HeadlessBackend, Terminal};
+
+    fn round_trip(event: Event) -> Event {
+        let line = format_event(Duration::from_millis(42), &event).unwrap();
+        let parsed = parse_event(&line).unwrap();
+        assert_eq!(parsed.at, Duration::from_millis(42));
+        parsed.event
+    }
+
+    #[test]
+    fn key_events_round_trip() {
+        let mut event = Event::keyboard(KB_ENTER);
+        event.key_modifiers = KeyModifiers::SHIFT;
+        let parsed = round_trip(event);
+        assert_eq!(parsed.what, EventType::Keyboard);
+        assert_eq!(parsed.key_code, KB_ENTER);
+        assert_eq!(parsed.key_modifiers, KeyModifiers::SHIFT);
+    }
+
+    #[test]
+    fn mouse_and_command_events_round_trip() {
+        let mut click = Event::mouse(
+            EventType::MouseDown,
+            Point::new(28, 23),
+            MB_LEFT_BUTTON,
+            true,
+        );
+        click.key_modifiers = KeyModifiers::CONTROL;
+        let parsed = round_trip(click);
+        assert_eq!(parsed.what, EventType::MouseDown);
+        assert_eq!(parsed.mouse.pos, Point::new(28, 23));
+        assert_eq!(parsed.mouse.buttons, MB_LEFT_BUTTON);
+        assert!(parsed.mouse.double_click);
+        assert_eq!(parsed.key_modifiers, KeyModifiers::CONTROL);
+
+        // Recordings without modifiers still load
+        let old =
+            parse_event(r#"{"t":5,"type":"mouse_up","x":1,"y":2,"buttons":0,"double":false}"#)
+                .unwrap();
+        assert_eq!(old.event.key_modifiers, KeyModifiers::NONE);
+
+        let parsed = round_trip(Event::broadcast(CM_QUIT));
+        assert_eq!(parsed.what, EventType::Broadcast);
+        assert_eq!(parsed.command, CM_QUIT);
+    }
+
+    #[test]
+    fn comments_and_garbage_are_rejected() {
+        assert!(parse_event("").is_none());
+        assert!(parse_event("# a note").is_none());
+        assert!(parse_event(r#"{"t":1,"type":"bogus"}"#).is_none());
+        assert!(parse_event(r#"{"type":"key","code":13}"#).is_none());
+        assert!(format_event(Duration::ZERO, &Event::nothing()).is_none());
+    }
+
+    #[test]
+    fn recorded_session_replays_through_terminal() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+
+        let mut recorder = EventRecorder::create(&path).unwrap();
+        recorder.record(&Event::keyboard(KB_ENTER)).unwrap();
+        recorder.record(&Event::nothing()).unwrap();
+        recorder.record(&Event::keyboard(KB_ALT_X)).unwrap();
+        drop(recorder);
+
+        let events = load(&path).unwrap();
+        assert_eq!(events.len(), 2);
+
+        let backend = ReplayBackend::new(Box::new(HeadlessBackend::new(20, 5)), events);
+        let mut terminal = Terminal::with_backend(Box::new(backend)).unwrap();
+        let mut replayed = Vec::new();
+        while let Some(event) = terminal.poll_event(Duration::ZERO).unwrap() {
+            replayed.push(event);
+        }
+        let codes: Vec<_> = replayed
+            .iter()
+            .map(|e| (e.what, e.key_code, e.command))
+            .collect();
+        assert_eq!(
+            codes,
+            vec![
+                (EventType::Keyboard, KB_ENTER, 0),
+                (EventType::Keyboard, KB_ALT_X, 0),
+                (EventType::Command, 0, CM_QUIT),
+            ]
+        );
+    }
+}
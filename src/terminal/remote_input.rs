@@ -1,11 +1,12 @@
 // (C) 2026 - Enzo Lombardi
 
-//! Remote keyboard input over TCP (disabled by default).
+//! Remote input and inspection over TCP (disabled by default).
 //!
 //! This is a testing/automation aid: when enabled, turbo-vision listens on a
-//! local TCP port and converts incoming text lines into keyboard events that
+//! local TCP port and converts incoming text lines into input events that
 //! are injected into the application's event loop, exactly as if the keys had
-//! been pressed.
+//! been pressed or the mouse used. Query lines let a test driver inspect the
+//! running application and get an answer back on the same connection.
 //!
 //! Each line may contain one or more whitespace-separated key chords; the
 //! chords are parsed by [`parse_key_chord`](crate::core::event::parse_key_chord)
@@ -23,107 +24,468 @@
 //! [`Terminal::enable_remote_input`](crate::terminal::Terminal::enable_remote_input)
 //! or [`Application::enable_remote_input`](crate::app::Application::enable_remote_input).
 //!
+//! # Input lines
+//!
+//! Coordinates are 0-indexed cells. Verbs are case-insensitive.
+//!
+//! | Line | Effect |
+//! |------|--------|
+//! | `CLICK x y` / `LCLICK` / `RCLICK` / `MCLICK` | Button down + up |
+//! | `DCLICK x y` | Left double-click |
+//! | `DRAG x1 y1 x2 y2` | Left button down, move cell by cell, up |
+//! | `MOVE x y` | Mouse move without buttons |
+//! | `WHEEL UP x y` / `WHEEL DOWN x y` | Mouse wheel |
+//! | `TYPE text` | Types `text` literally (optionally `"quoted"`) |
+//! | `RESIZE w h` | Resizes the screen and broadcasts `CM_REDRAW` |
+//! | anything else | Key chords, e.g. `CTRL+F12 ALT+X` |
+//!
+//! Input lines produce no reply.
+//!
+//! # Query lines
+//!
+//! Queries are answered once every input sent before them on the connection
+//! has been delivered to the application, so they observe its effects.
+//!
+//! | Line | Reply |
+//! |------|-------|
+//! | `PING` | `OK` |
+//! | `SCREEN` | `OK <n>` followed by the `n` screen rows |
+//! | `REGION x y w h` | `OK <n>` followed by the `n` rows of the region |
+//! | `FOCUS` | `OK <Type> id=<n> bounds=<ax>,<ay>,<bx>,<by> path=<A>/<B>/...` or `OK none` |
+//! | `WAITFOR [ms] text` | `OK` once `text` is on screen, `TIMEOUT` after `ms` (default 5000) |
+//!
+//! Malformed queries are answered with `ERR <message>`.
+//!
 //! # Example
 //!
 //! ```bash
 //! # With remote input enabled on port 8888:
 //! printf 'CTRL+F12\n'   | nc 127.0.0.1 8888   # key chord(s)
 //! printf 'CLICK 28 23\n' | nc 127.0.0.1 8888   # left-click at cell (28,23)
+//! printf 'TYPE "hello world"\nWAITFOR 2000 hello\n' | nc 127.0.0.1 8888
 //! ```
-//!
-//! Mouse lines use `CLICK x y` (left button), `RCLICK x y`, or `MCLICK x y`
-//! with 0-indexed cell coordinates; any other line is parsed as key chords.
 
-use std::io::{BufRead, BufReader};
-use std::net::TcpListener;
-use std::sync::mpsc::Sender;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, Sender};
 use std::thread;
+use std::time::{Duration, Instant};
 
+use crate::core::command::CM_REDRAW;
+use crate::core::draw::Cell;
 use crate::core::event::{
     Event, EventType, MB_LEFT_BUTTON, MB_MIDDLE_BUTTON, MB_RIGHT_BUTTON, parse_key_chord,
 };
 use crate::core::geometry::Point;
 
+/// Default `WAITFOR` timeout when the line does not give one.
+pub const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A question about the running application, answered over the connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    /// Reply `OK` (useful to synchronize with earlier input).
+    Ping,
+    /// Reply with the text of every screen row.
+    Screen,
+    /// Reply with the text of a rectangular screen region.
+    Region {
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    },
+    /// Reply with the focused view, its `ViewId` and bounds.
+    Focus,
+    /// Reply once `text` appears on screen, or `TIMEOUT` after `timeout`.
+    WaitFor { text: String, timeout: Duration },
+}
+
+/// One parsed protocol line.
+#[derive(Debug, Clone)]
+pub enum Request {
+    /// Events to inject, in order.
+    Events(Vec<Event>),
+    /// Resize the screen to `width` x `height` cells.
+    Resize { width: u16, height: u16 },
+    /// A query to answer.
+    Query(Query),
+}
+
+/// Message sent from the listener threads to the [`Terminal`](crate::terminal::Terminal).
+pub(crate) enum RemoteMessage {
+    Event(Event),
+    Resize { width: u16, height: u16 },
+    Query(Query, Sender<String>),
+}
+
 /// Parse one protocol line into the events it should inject.
 ///
-/// Two line forms are recognized:
-/// * Mouse: `CLICK x y` (left button), `RCLICK x y`, or `MCLICK x y` — emits a
-///   button-down then button-up at the given 0-indexed cell coordinates.
-/// * Keys: anything else is treated as whitespace-separated key chords (see
-///   [`parse_key_chord`]), e.g. `CTRL+F12 ALT+X`.
-///
-/// Returns an empty vector for a blank or unparseable line.
+/// Only input lines produce events (see the [module docs](self)); queries,
+/// `RESIZE`, and blank or unparseable lines yield an empty vector.
 pub fn parse_line(line: &str) -> Vec<Event> {
-    let mut tokens = line.split_whitespace();
-    let Some(first) = tokens.next() else {
-        return Vec::new();
+    match parse_request(line) {
+        Ok(Some(Request::Events(events))) => events,
+        _ => Vec::new(),
+    }
+}
+
+/// Parse one protocol line.
+///
+/// Returns `Ok(None)` for a blank line and `Err` with a message for a
+/// malformed one.
+pub fn parse_request(line: &str) -> Result<Option<Request>, String> {
+    let trimmed = line.trim();
+    let Some(verb) = trimmed.split_whitespace().next() else {
+        return Ok(None);
     };
+    let rest = trimmed[verb.len()..].trim_start();
+    let args: Vec<&str> = rest.split_whitespace().collect();
+    let verb = verb.to_ascii_uppercase();
 
-    let button = match first.to_ascii_uppercase().as_str() {
+    let button = match verb.as_str() {
         "CLICK" | "LCLICK" => Some(MB_LEFT_BUTTON),
         "RCLICK" => Some(MB_RIGHT_BUTTON),
         "MCLICK" => Some(MB_MIDDLE_BUTTON),
         _ => None,
     };
+    if let Some(query) = parse_query(&verb, &args, rest) {
+        return query.map(|query| Some(Request::Query(query)));
+    }
 
     if let Some(button) = button {
-        let coords: Vec<&str> = tokens.collect();
-        if let [xs, ys] = coords[..] {
-            if let (Ok(x), Ok(y)) = (xs.parse::<i16>(), ys.parse::<i16>()) {
-                let pos = Point::new(x, y);
-                return vec![
-                    Event::mouse(EventType::MouseDown, pos, button, false),
-                    Event::mouse(EventType::MouseUp, pos, 0, false),
-                ];
+        let pos = parse_point(&args, &verb)?;
+        return Ok(Some(Request::Events(click(pos, button, false))));
+    }
+
+    let request = match verb.as_str() {
+        "DCLICK" => {
+            let pos = parse_point(&args, &verb)?;
+            let mut events = click(pos, MB_LEFT_BUTTON, false);
+            events.extend(click(pos, MB_LEFT_BUTTON, true));
+            Request::Events(events)
+        }
+        "DRAG" => {
+            let numbers = parse_numbers::<i16>(&args, 4, "DRAG x1 y1 x2 y2")?;
+            Request::Events(drag(
+                Point::new(numbers[0], numbers[1]),
+                Point::new(numbers[2], numbers[3]),
+            ))
+        }
+        "MOVE" => {
+            let pos = parse_point(&args, &verb)?;
+            Request::Events(vec![Event::mouse(EventType::MouseMove, pos, 0, false)])
+        }
+        "WHEEL" => {
+            let (direction, coords) = args.split_first().ok_or("usage: WHEEL UP|DOWN x y")?;
+            let what = match direction.to_ascii_uppercase().as_str() {
+                "UP" => EventType::MouseWheelUp,
+                "DOWN" => EventType::MouseWheelDown,
+                _ => return Err("usage: WHEEL UP|DOWN x y".into()),
+            };
+            let pos = parse_point(coords, &verb)?;
+            Request::Events(vec![Event::mouse(what, pos, 0, false)])
+        }
+        "TYPE" => Request::Events(
+            unquote(rest)
+                .chars()
+                .filter(|c| !c.is_control())
+                .filter_map(|c| u16::try_from(u32::from(c)).ok())
+                .map(Event::keyboard)
+                .collect(),
+        ),
+        "RESIZE" => {
+            let numbers = parse_numbers::<u16>(&args, 2, "RESIZE w h")?;
+            if numbers[0] == 0 || numbers[1] == 0 {
+                return Err("RESIZE needs a non-zero size".into());
+            }
+            Request::Resize {
+                width: numbers[0],
+                height: numbers[1],
+            }
+        }
+        _ => {
+            // Otherwise: one or more key chords.
+            let events: Vec<Event> = trimmed
+                .split_whitespace()
+                .filter_map(parse_key_chord)
+                .collect();
+            if events.is_empty() {
+                return Err(format!("unrecognized line {trimmed:?}"));
+            }
+            Request::Events(events)
+        }
+    };
+    Ok(Some(request))
+}
+
+/// Parse the arguments of a query verb; `None` if `verb` is not a query.
+fn parse_query(verb: &str, args: &[&str], rest: &str) -> Option<Result<Query, String>> {
+    let query = match verb {
+        "PING" => Query::Ping,
+        "SCREEN" => Query::Screen,
+        "FOCUS" => Query::Focus,
+        "REGION" => {
+            let n = match parse_numbers::<u16>(args, 4, "REGION x y w h") {
+                Ok(n) => n,
+                Err(e) => return Some(Err(e)),
+            };
+            Query::Region {
+                x: n[0],
+                y: n[1],
+                width: n[2],
+                height: n[3],
+            }
+        }
+        "WAITFOR" => {
+            let (timeout, text) = match args.first().map(|a| a.parse::<u64>()) {
+                Some(Ok(ms)) if args.len() > 1 => (
+                    Duration::from_millis(ms),
+                    rest[args[0].len()..].trim_start(),
+                ),
+                _ => (DEFAULT_WAIT_TIMEOUT, rest),
+            };
+            let text = unquote(text);
+            if text.is_empty() {
+                return Some(Err("usage: WAITFOR [ms] text".into()));
+            }
+            Query::WaitFor {
+                text: text.to_string(),
+                timeout,
             }
         }
-        return Vec::new(); // malformed mouse line
+        _ => return None,
+    };
+    Some(Ok(query))
+}
+
+fn click(pos: Point, button: u8, double_click: bool) -> Vec<Event> {
+    vec![
+        Event::mouse(EventType::MouseDown, pos, button, double_click),
+        Event::mouse(EventType::MouseUp, pos, 0, false),
+    ]
+}
+
+/// Press at `from`, move one cell at a time along a straight line, release at `to`.
+fn drag(from: Point, to: Point) -> Vec<Event> {
+    let mut events = vec![Event::mouse(
+        EventType::MouseDown,
+        from,
+        MB_LEFT_BUTTON,
+        false,
+    )];
+    let dx = i32::from(to.x) - i32::from(from.x);
+    let dy = i32::from(to.y) - i32::from(from.y);
+    let steps = dx.abs().max(dy.abs());
+    for step in 1..=steps {
+        // Intermediate points stay between `from` and `to`, so they fit in i16.
+        let x = i32::from(from.x) + dx * step / steps;
+        let y = i32::from(from.y) + dy * step / steps;
+        let pos = Point::new(
+            i16::try_from(x).unwrap_or(to.x),
+            i16::try_from(y).unwrap_or(to.y),
+        );
+        events.push(Event::mouse(
+            EventType::MouseMove,
+            pos,
+            MB_LEFT_BUTTON,
+            false,
+        ));
     }
+    events.push(Event::mouse(EventType::MouseUp, to, 0, false));
+    events
+}
+
+fn parse_point(args: &[&str], verb: &str) -> Result<Point, String> {
+    let n = parse_numbers::<i16>(args, 2, &format!("{verb} x y"))?;
+    Ok(Point::new(n[0], n[1]))
+}
 
-    // Otherwise: one or more key chords.
-    line.split_whitespace()
-        .filter_map(parse_key_chord)
+fn parse_numbers<T>(args: &[&str], count: usize, usage: &str) -> Result<Vec<T>, String>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    if args.len() != count {
+        return Err(format!("usage: {usage}"));
+    }
+    args.iter()
+        .map(|a| {
+            a.parse::<T>()
+                .map_err(|e| format!("usage: {usage} ({a}: {e})"))
+        })
         .collect()
 }
 
-/// Bind a TCP listener on `127.0.0.1:port` and forward parsed key events to `tx`.
+/// Strip one pair of surrounding double quotes, if present.
+fn unquote(text: &str) -> &str {
+    text.strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .unwrap_or(text)
+}
+
+/// A `WAITFOR` query still waiting for its text to appear.
+struct PendingWait {
+    text: String,
+    deadline: Instant,
+    reply: Sender<String>,
+}
+
+/// Terminal-side state of an enabled remote-input listener.
+pub(crate) struct RemoteSession {
+    rx: Receiver<RemoteMessage>,
+    waits: Vec<PendingWait>,
+    /// Description of the focused view, published by the application after drawing.
+    focus: Option<String>,
+    /// Screen size requested with `RESIZE`, overriding the backend's size.
+    size: Option<(u16, u16)>,
+}
+
+impl RemoteSession {
+    pub(crate) fn new(rx: Receiver<RemoteMessage>) -> Self {
+        Self {
+            rx,
+            waits: Vec::new(),
+            focus: None,
+            size: None,
+        }
+    }
+
+    pub(crate) fn size(&self) -> Option<(u16, u16)> {
+        self.size
+    }
+
+    pub(crate) fn set_focus(&mut self, focus: Option<String>) {
+        self.focus = focus;
+    }
+
+    /// Answer pending waits, then return the next input event, answering any
+    /// queries queued before it against `screen`.
+    pub(crate) fn next_event(&mut self, screen: &[Vec<Cell>]) -> Option<Event> {
+        self.check_waits(screen);
+        loop {
+            match self.rx.try_recv().ok()? {
+                RemoteMessage::Event(event) => return Some(event),
+                RemoteMessage::Resize { width, height } => {
+                    self.size = Some((width, height));
+                    return Some(Event::broadcast(CM_REDRAW));
+                }
+                RemoteMessage::Query(query, reply) => self.answer(query, reply, screen),
+            }
+        }
+    }
+
+    fn answer(&mut self, query: Query, reply: Sender<String>, screen: &[Vec<Cell>]) {
+        let answer = match query {
+            Query::Ping => "OK\n".to_string(),
+            Query::Screen => lines_reply(&screen_lines(screen, 0, 0, u16::MAX, u16::MAX)),
+            Query::Region {
+                x,
+                y,
+                width,
+                height,
+            } => lines_reply(&screen_lines(screen, x, y, width, height)),
+            Query::Focus => format!("OK {}\n", self.focus.as_deref().unwrap_or("none")),
+            Query::WaitFor { text, timeout } => {
+                if screen_contains(screen, &text) {
+                    "OK\n".to_string()
+                } else {
+                    self.waits.push(PendingWait {
+                        text,
+                        deadline: Instant::now() + timeout,
+                        reply,
+                    });
+                    return;
+                }
+            }
+        };
+        // The client may have disconnected; nothing to do then.
+        let _ = reply.send(answer);
+    }
+
+    fn check_waits(&mut self, screen: &[Vec<Cell>]) {
+        if self.waits.is_empty() {
+            return;
+        }
+        let now = Instant::now();
+        self.waits.retain(|wait| {
+            let answer = if screen_contains(screen, &wait.text) {
+                "OK\n"
+            } else if now >= wait.deadline {
+                "TIMEOUT\n"
+            } else {
+                return true;
+            };
+            let _ = wait.reply.send(answer.to_string());
+            false
+        });
+    }
+}
+
+/// Text of the rows of a screen region, clipped to the screen, with trailing
+/// blanks removed.
+fn screen_lines(screen: &[Vec<Cell>], x: u16, y: u16, width: u16, height: u16) -> Vec<String> {
+    screen
+        .iter()
+        .skip(usize::from(y))
+        .take(usize::from(height))
+        .map(|row| {
+            let line: String = row
+                .iter()
+                .skip(usize::from(x))
+                .take(usize::from(width))
+                .filter(|c| c.ch != '\0')
+                .map(|c| c.ch)
+                .collect();
+            line.trim_end().to_string()
+        })
+        .collect()
+}
+
+fn screen_contains(screen: &[Vec<Cell>], text: &str) -> bool {
+    screen_lines(screen, 0, 0, u16::MAX, u16::MAX)
+        .iter()
+        .any(|line| line.contains(text))
+}
+
+fn lines_reply(lines: &[String]) -> String {
+    let mut reply = format!("OK {}\n", lines.len());
+    for line in lines {
+        reply.push_str(line);
+        reply.push('\n');
+    }
+    reply
+}
+
+/// Bind a TCP listener on `127.0.0.1:port` and forward parsed requests to `tx`.
 ///
 /// The listener and per-connection handlers run on detached background threads,
 /// so this returns as soon as the socket is bound. Each accepted connection is
-/// read line by line; every whitespace-separated chord on a line is parsed and,
-/// if valid, sent through `tx`. Unparseable chords are logged and skipped.
+/// read line by line; input lines are forwarded as events, query lines are
+/// forwarded with a reply channel and the handler blocks until the answer has
+/// been written back. Malformed lines are logged; malformed queries also get
+/// an `ERR` reply.
 ///
 /// # Errors
 ///
 /// Returns an error if the port cannot be bound (e.g. already in use).
-pub fn spawn(port: u16, tx: Sender<Event>) -> std::io::Result<()> {
+pub(crate) fn spawn(port: u16, tx: Sender<RemoteMessage>) -> std::io::Result<()> {
     let listener = TcpListener::bind(("127.0.0.1", port))?;
-    log::info!("Remote key input listening on 127.0.0.1:{port}");
+    log::info!("Remote input listening on 127.0.0.1:{port}");
 
     thread::spawn(move || {
         for stream in listener.incoming() {
             let stream = match stream {
                 Ok(s) => s,
                 Err(e) => {
-                    log::warn!("Remote key input: accept failed: {e}");
+                    log::warn!("Remote input: accept failed: {e}");
                     continue;
                 }
             };
             let tx = tx.clone();
             thread::spawn(move || {
-                let reader = BufReader::new(stream);
-                for line in reader.lines() {
-                    let Ok(line) = line else { break };
-                    let events = parse_line(&line);
-                    if events.is_empty() && !line.trim().is_empty() {
-                        log::warn!("Remote input: unparseable line {line:?}");
-                    }
-                    for event in events {
-                        // If the receiver is gone the app has exited; stop.
-                        if tx.send(event).is_err() {
-                            return;
-                        }
-                    }
+                if let Err(e) = serve_connection(stream, &tx) {
+                    log::debug!("Remote input: connection closed: {e}");
                 }
             });
         }
@@ -132,6 +494,52 @@ pub fn spawn(port: u16, tx: Sender<Event>) -> std::io::Result<()> {
     Ok(())
 }
 
+fn serve_connection(stream: TcpStream, tx: &Sender<RemoteMessage>) -> std::io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    let reader = BufReader::new(stream);
+    for line in reader.lines() {
+        let line = line?;
+        let messages = match parse_request(&line) {
+            Ok(None) => continue,
+            Ok(Some(Request::Events(events))) => {
+                events.into_iter().map(RemoteMessage::Event).collect()
+            }
+            Ok(Some(Request::Resize { width, height })) => {
+                vec![RemoteMessage::Resize { width, height }]
+            }
+            Ok(Some(Request::Query(query))) => {
+                let (reply_tx, reply_rx) = mpsc::channel();
+                if tx.send(RemoteMessage::Query(query, reply_tx)).is_err() {
+                    return Ok(());
+                }
+                // The app drops the sender unanswered only when it shuts down.
+                let Ok(reply) = reply_rx.recv() else {
+                    return Ok(());
+                };
+                writer.write_all(reply.as_bytes())?;
+                writer.flush()?;
+                continue;
+            }
+            Err(message) => {
+                log::warn!("Remote input: {message}");
+                // Only queries expect a reply; input lines stay silent.
+                let verb = line.split_whitespace().next().unwrap_or_default();
+                if parse_query(&verb.to_ascii_uppercase(), &[], "").is_some() {
+                    writeln!(writer, "ERR {message}")?;
+                }
+                continue;
+            }
+        };
+        for message in messages {
+            // If the receiver is gone the app has exited; stop.
+            if tx.send(message).is_err() {
+                return Ok(());
+            }
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,5 +569,142 @@ mod tests {
         assert!(parse_line("   ").is_empty());
         assert!(parse_line("CLICK 1").is_empty());
         assert!(parse_line("CLICK x y").is_empty());
+        assert!(parse_line("SCREEN").is_empty());
+    }
+
+    #[test]
+    fn parses_mouse_gestures() {
+        let evs = parse_line("DCLICK 3 4");
+        assert_eq!(evs.len(), 4);
+        assert!(!evs[0].mouse.double_click);
+        assert!(evs[2].mouse.double_click);
+
+        let evs = parse_line("DRAG 0 0 3 1");
+        let kinds: Vec<EventType> = evs.iter().map(|e| e.what).collect();
+        assert_eq!(
+            kinds,
+            [
+                EventType::MouseDown,
+                EventType::MouseMove,
+                EventType::MouseMove,
+                EventType::MouseMove,
+                EventType::MouseUp
+            ]
+        );
+        assert_eq!(evs[3].mouse.pos, Point::new(3, 1));
+        assert_eq!(evs[3].mouse.buttons, MB_LEFT_BUTTON);
+
+        let evs = parse_line("wheel down 5 6");
+        assert_eq!(evs[0].what, EventType::MouseWheelDown);
+        assert_eq!(evs[0].mouse.pos, Point::new(5, 6));
+    }
+
+    #[test]
+    fn type_sends_literal_characters() {
+        let codes: Vec<u16> = parse_line("TYPE \"Hi there\"")
+            .iter()
+            .map(|e| e.key_code)
+            .collect();
+        assert_eq!(
+            codes,
+            "Hi there".chars().map(|c| c as u16).collect::<Vec<_>>()
+        );
+        // Key names are not interpreted inside TYPE.
+        assert_eq!(parse_line("TYPE ENTER").len(), 5);
+    }
+
+    fn query(line: &str) -> Query {
+        match parse_request(line) {
+            Ok(Some(Request::Query(query))) => query,
+            other => panic!("{line:?} is not a query: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_resize_and_queries() {
+        assert!(matches!(
+            parse_request("RESIZE 100 30"),
+            Ok(Some(Request::Resize {
+                width: 100,
+                height: 30
+            }))
+        ));
+        assert_eq!(
+            query("REGION 1 2 3 4"),
+            Query::Region {
+                x: 1,
+                y: 2,
+                width: 3,
+                height: 4
+            }
+        );
+        assert_eq!(
+            query("WAITFOR 250 \"Save as\""),
+            Query::WaitFor {
+                text: "Save as".into(),
+                timeout: Duration::from_millis(250)
+            }
+        );
+        assert_eq!(
+            query("waitfor Ready"),
+            Query::WaitFor {
+                text: "Ready".into(),
+                timeout: DEFAULT_WAIT_TIMEOUT
+            }
+        );
+        parse_request("REGION 1 2").unwrap_err();
+        assert!(matches!(parse_request("  "), Ok(None)));
+    }
+
+    #[test]
+    fn session_answers_queries_from_screen() {
+        use crate::core::palette::Attr;
+
+        let attr = Attr::from_u8(0x07);
+        let screen: Vec<Vec<Cell>> = ["Hello     ", "  world   "]
+            .iter()
+            .map(|row| row.chars().map(|ch| Cell::new(ch, attr)).collect())
+            .collect();
+        let (tx, rx) = mpsc::channel();
+        let mut session = RemoteSession::new(rx);
+        let ask = |query| {
+            let (reply_tx, reply_rx) = mpsc::channel();
+            tx.send(RemoteMessage::Query(query, reply_tx)).unwrap();
+            reply_rx
+        };
+
+        let screen_reply = ask(Query::Screen);
+        let region_reply = ask(Query::Region {
+            x: 2,
+            y: 1,
+            width: 3,
+            height: 5,
+        });
+        let focus_reply = ask(Query::Focus);
+        let wait_reply = ask(Query::WaitFor {
+            text: "later".into(),
+            timeout: Duration::from_secs(30),
+        });
+        tx.send(RemoteMessage::Resize {
+            width: 90,
+            height: 30,
+        })
+        .unwrap();
+
+        let event = session.next_event(&screen).expect("resize event");
+        assert_eq!(event.what, EventType::Broadcast);
+        assert_eq!(event.command, CM_REDRAW);
+        assert_eq!(session.size(), Some((90, 30)));
+        assert_eq!(screen_reply.recv().unwrap(), "OK 2\nHello\n  world\n");
+        assert_eq!(region_reply.recv().unwrap(), "OK 1\nwor\n");
+        assert_eq!(focus_reply.recv().unwrap(), "OK none\n");
+        wait_reply.try_recv().unwrap_err();
+
+        let mut later = screen.clone();
+        for (cell, ch) in later[0].iter_mut().zip("later".chars()) {
+            cell.ch = ch;
+        }
+        assert!(session.next_event(&later).is_none());
+        assert_eq!(wait_reply.recv().unwrap(), "OK\n");
     }
 }
@@ -22,6 +22,10 @@
 //! terminal.put_char(0, 0, 'H');
 //! assert_eq!(terminal.get_char(0, 0), Some('H'));
 //! ```
+//!
+//! For golden-file tests of real rendering, see [`snapshot`].
+
+pub mod snapshot;
 
 use crate::core::draw::Cell;
 use crate::core::event::Event;
@@ -60,7 +64,7 @@ pub struct MockTerminal {
 impl MockTerminal {
     /// Creates a new mock terminal with the specified dimensions.
     pub fn new(width: u16, height: u16) -> Self {
-        use crate::core::palette::{Attr, TvColor};
+        use crate::core::palette::TvColor;
         let default_attr = Attr::new(TvColor::LightGray, TvColor::Black);
         let default_cell = Cell::new(' ', default_attr);
         let buffer = vec![vec![default_cell; width as usize]; height as usize];
@@ -202,7 +206,7 @@ impl MockTerminal {
 
     /// Clears the entire terminal (fills with spaces).
     pub fn clear(&mut self) {
-        use crate::core::palette::{Attr, TvColor};
+        use crate::core::palette::TvColor;
         let default_attr = Attr::new(TvColor::LightGray, TvColor::Black);
         let default_cell = Cell::new(' ', default_attr);
         for row in &mut self.buffer {
@@ -248,7 +252,7 @@ mod send_assertions {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::palette::{Attr, TvColor};
+    use crate::core::palette::TvColor;
 
     #[test]
     fn test_mock_terminal_basic() {
@@ -0,0 +1,357 @@
+// (C) 2026 - Enzo Lombardi
+
+//! Snapshot (golden-file) testing for rendered screens.
+//!
+//! A [`Snapshot`] captures a rendered terminal buffer as plain text: the
+//! characters of every row followed by an *attribute map* that assigns one
+//! letter per distinct color attribute, plus a legend explaining the letters.
+//! Snapshots are compared against checked-in golden files with
+//! [`assert_snapshot`], which prints a line diff on mismatch.
+//!
+//! Set the `TV_UPDATE_SNAPSHOTS` environment variable to write the golden
+//! files instead of comparing. Without it a missing golden file fails the
+//! test, so a new or misspelled snapshot cannot pass unnoticed:
+//!
+//! ```bash
+//! TV_UPDATE_SNAPSHOTS=1 cargo test --features test-util
+//! ```
+//!
+//! # Golden file format
+//!
+//! ```text
+//! # turbo-vision snapshot 12x3
+//! -- text --
+//! |╔══ Hi ══╗  |
+//! |║        ║  |
+//! |╚════════╝  |
+//! -- attrs --
+//! |AAAAAAAAAABB|
+//! |AAAAAAAAAABB|
+//! |AAAAAAAAAABB|
+//! -- legend --
+//! A = White on Blue
+//! B = LightGray on Black
+//! ```
+//!
+//! Rows are fenced with `|` so trailing blanks stay visible. The padding cell
+//! that follows a wide character is omitted from the text section, so wide
+//! glyphs line up as they do on screen; the attribute map always has exactly
+//! one letter per cell.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::test_util::snapshot::Snapshot;
+//! use turbo_vision::views::static_text::StaticText;
+//!
+//! let mut text = StaticText::new(Rect::new(0, 0, 10, 1), "Hello");
+//! let snapshot = Snapshot::of_view(&mut text, 10, 1);
+//! assert_eq!(snapshot.text_lines(), vec!["Hello     "]);
+//! // In a test: assert_snapshot("tests/snapshots/hello.snap", &snapshot);
+//! ```
+
+use crate::app::Application;
+use crate::core::draw::Cell;
+use crate::core::palette::{Attr, TvColor};
+use crate::terminal::{HeadlessBackend, Terminal};
+use crate::views::View;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Environment variable that switches [`assert_snapshot`] to update mode.
+pub const UPDATE_ENV_VAR: &str = "TV_UPDATE_SNAPSHOTS";
+
+/// Letters used to label distinct attributes in the attribute map.
+const ATTR_LABELS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// A captured screen: characters and attributes of a fixed-size buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    width: usize,
+    height: usize,
+    cells: Vec<Vec<Cell>>,
+}
+
+impl Snapshot {
+    /// Capture a copy of a terminal-style cell buffer.
+    pub fn from_buffer(buffer: &[Vec<Cell>]) -> Self {
+        let height = buffer.len();
+        let width = buffer.first().map_or(0, Vec::len);
+        Self {
+            width,
+            height,
+            cells: buffer.to_vec(),
+        }
+    }
+
+    /// Capture the current back buffer of a terminal.
+    pub fn from_terminal(terminal: &Terminal) -> Self {
+        Self::from_buffer(terminal.buffer())
+    }
+
+    /// Render a single view into a fresh off-screen terminal and capture it.
+    ///
+    /// The view is drawn at its own bounds, so those should lie inside
+    /// `width` x `height` for a meaningful result.
+    pub fn of_view(view: &mut dyn View, width: u16, height: u16) -> Self {
+        let mut terminal = headless_terminal(width, height);
+        view.draw(&mut terminal);
+        view.update_cursor(&mut terminal);
+        Self::from_terminal(&terminal)
+    }
+
+    /// Draw the whole application (desktop, menu bar, status line, overlays)
+    /// and capture its terminal.
+    ///
+    /// Use [`Application::with_terminal`] and [`headless_terminal`] to build
+    /// an application with a fixed screen size.
+    pub fn of_app(app: &mut Application) -> Self {
+        app.draw();
+        Self::from_terminal(&app.terminal)
+    }
+
+    /// Width of the captured buffer in cells.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the captured buffer in cells.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the captured cell at `(x, y)`, if inside the buffer.
+    pub fn cell(&self, x: usize, y: usize) -> Option<Cell> {
+        self.cells.get(y).and_then(|row| row.get(x)).copied()
+    }
+
+    /// The text of each row, without fences and without wide-char padding.
+    pub fn text_lines(&self) -> Vec<String> {
+        self.cells
+            .iter()
+            .map(|row| row.iter().filter(|c| c.ch != '\0').map(|c| c.ch).collect())
+            .collect()
+    }
+
+    /// Serialize the snapshot into the golden file format.
+    pub fn to_golden(&self) -> String {
+        let mut legend: Vec<Attr> = Vec::new();
+        let mut out = String::new();
+
+        let _ = writeln!(
+            out,
+            "# turbo-vision snapshot {}x{}",
+            self.width, self.height
+        );
+        out.push_str("-- text --\n");
+        for line in self.text_lines() {
+            let _ = writeln!(out, "|{line}|");
+        }
+
+        out.push_str("-- attrs --\n");
+        for row in &self.cells {
+            out.push('|');
+            for cell in row {
+                let index = legend
+                    .iter()
+                    .position(|a| *a == cell.attr)
+                    .unwrap_or_else(|| {
+                        legend.push(cell.attr);
+                        legend.len() - 1
+                    });
+                out.push(ATTR_LABELS.get(index).map_or('?', |&b| char::from(b)));
+            }
+            out.push_str("|\n");
+        }
+
+        out.push_str("-- legend --\n");
+        for (index, attr) in legend.iter().enumerate() {
+            let label = ATTR_LABELS.get(index).map_or('?', |&b| char::from(b));
+            let _ = writeln!(out, "{label} = {}", describe_attr(*attr));
+        }
+        out
+    }
+}
+
+/// Create an off-screen terminal of the given size.
+pub fn headless_terminal(width: u16, height: u16) -> Terminal {
+    Terminal::with_backend(Box::new(HeadlessBackend::new(width, height)))
+        .expect("headless backend never fails to initialize")
+}
+
+/// Compare `snapshot` against the golden file at `path`.
+///
+/// Relative paths are resolved against the current directory, which for
+/// `cargo test` is the crate root. If [`UPDATE_ENV_VAR`] is set, the file is
+/// (re)written and the assertion passes.
+///
+/// # Panics
+///
+/// Panics with a line diff when the rendering differs from the golden file,
+/// if the golden file does not exist (outside update mode), or if it cannot
+/// be read or written.
+pub fn assert_snapshot(path: impl AsRef<Path>, snapshot: &Snapshot) {
+    let path = path.as_ref();
+    let actual = snapshot.to_golden();
+    let update = std::env::var_os(UPDATE_ENV_VAR).is_some();
+
+    if update {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .unwrap_or_else(|e| panic!("cannot create {}: {e}", parent.display()));
+        }
+        std::fs::write(path, &actual)
+            .unwrap_or_else(|e| panic!("cannot write snapshot {}: {e}", path.display()));
+        return;
+    }
+
+    assert!(
+        path.exists(),
+        "missing snapshot {}\nRe-run with {UPDATE_ENV_VAR}=1 to create it.",
+        path.display()
+    );
+    let expected = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("cannot read snapshot {}: {e}", path.display()));
+    // Tolerate CRLF checkouts of golden files.
+    let expected = expected.replace("\r\n", "\n");
+    assert!(
+        expected == actual,
+        "snapshot mismatch for {}\n{}\nRe-run with {UPDATE_ENV_VAR}=1 to accept the new rendering.",
+        path.display(),
+        diff_lines(&expected, &actual)
+    );
+}
+
+/// Produce a readable line diff: every differing line as a `-`/`+` pair with
+/// its line number, and a caret row pointing at the first differing column.
+pub fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut out = String::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        let e = expected.get(i).copied();
+        let a = actual.get(i).copied();
+        if e == a {
+            continue;
+        }
+        let _ = writeln!(out, "line {}:", i + 1);
+        if let Some(e) = e {
+            let _ = writeln!(out, "  - {e}");
+        }
+        if let Some(a) = a {
+            let _ = writeln!(out, "  + {a}");
+        }
+        if let (Some(e), Some(a)) = (e, a) {
+            let column = e.chars().zip(a.chars()).take_while(|(x, y)| x == y).count();
+            let _ = writeln!(out, "    {}^", " ".repeat(column));
+        }
+    }
+    out
+}
+
+/// Human-readable name of an attribute, e.g. `White on Blue`.
+fn describe_attr(attr: Attr) -> String {
+    format!("{} on {}", describe_color(attr.fg), describe_color(attr.bg))
+}
+
+fn describe_color(color: TvColor) -> String {
+    match color {
+        TvColor::Rgb { r, g, b } => format!("#{r:02x}{g:02x}{b:02x}"),
+        named => format!("{named:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::{CM_CANCEL, CM_OK};
+    use crate::core::geometry::{Point, Rect};
+    use crate::core::menu_data::{Menu, MenuItem};
+    use crate::views::button::Button;
+    use crate::views::dialog::Dialog;
+    use crate::views::menu_box::MenuBox;
+
+    fn golden(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("snapshots")
+            .join(name)
+    }
+
+    #[test]
+    fn golden_format_labels_attributes_in_order() {
+        let blue = Attr::new(TvColor::White, TvColor::Blue);
+        let gray = Attr::new(TvColor::LightGray, TvColor::Black);
+        let snapshot = Snapshot::from_buffer(&[vec![
+            Cell::new('a', blue),
+            Cell::new('b', gray),
+            Cell::new(' ', blue),
+        ]]);
+
+        assert_eq!(
+            snapshot.to_golden(),
+            "# turbo-vision snapshot 3x1\n\
+             -- text --\n|ab |\n\
+             -- attrs --\n|ABA|\n\
+             -- legend --\nA = White on Blue\nB = LightGray on Black\n"
+        );
+    }
+
+    #[test]
+    fn diff_points_at_first_changed_column() {
+        let diff = diff_lines("|abc|\n|def|", "|abc|\n|dXf|");
+        assert_eq!(diff, "line 2:\n  - |def|\n  + |dXf|\n      ^\n");
+    }
+
+    #[test]
+    fn dialog_with_buttons_matches_golden() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 30, 8), "Confirm");
+        dialog.add(Box::new(Button::new(
+            Rect::new(3, 4, 13, 6),
+            "~O~K",
+            CM_OK,
+            true,
+        )));
+        dialog.add(Box::new(Button::new(
+            Rect::new(15, 4, 27, 6),
+            "Cancel",
+            CM_CANCEL,
+            false,
+        )));
+        dialog.set_initial_focus();
+
+        let snapshot = Snapshot::of_view(&mut dialog, 34, 10);
+        assert_snapshot(golden("dialog_with_buttons.snap"), &snapshot);
+    }
+
+    #[test]
+    fn menu_box_matches_golden() {
+        let menu = Menu::from_items(vec![
+            MenuItem::with_shortcut("~N~ew", 100, 0, "Ctrl+N", 0),
+            MenuItem::with_shortcut("~O~pen...", 101, 0, "F3", 0),
+            MenuItem::separator(),
+            MenuItem::new("E~x~it", 102, 0, 0),
+        ]);
+        let mut menu_box = MenuBox::new(Point::new(1, 0), menu);
+
+        let snapshot = Snapshot::of_view(&mut menu_box, 30, 8);
+        assert_snapshot(golden("menu_box.snap"), &snapshot);
+    }
+
+    #[test]
+    fn missing_golden_file_fails() {
+        if std::env::var_os(UPDATE_ENV_VAR).is_some() {
+            return; // Update mode would create the file
+        }
+        let snapshot = Snapshot::from_buffer(&[vec![Cell::new(
+            ' ',
+            Attr::new(TvColor::White, TvColor::Blue),
+        )]]);
+        let path = golden("does_not_exist.snap");
+        let result = std::panic::catch_unwind(|| assert_snapshot(&path, &snapshot));
+        assert!(result.is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,387 @@
+// (C) 2026 - Enzo Lombardi
+
+//! Accessibility - roles, labels and values of views, and announcements for screen readers.
+//!
+//! A text-mode UI is repainted as a grid of cells, which screen readers cannot
+//! make sense of. This module gives views a semantic description and lets the
+//! application speak what matters:
+//!
+//! - Views describe themselves through [`View::accessible`](super::View::accessible),
+//!   returning an [`AccessibleInfo`] (role, label and optional value).
+//! - An [`AnnouncementSink`] receives one line of text per announcement. The
+//!   application announces focus changes and `message_box` text to it.
+//!   [`LineSink`] writes lines to stderr or a file (e.g. piped into a speech
+//!   synthesizer); any `FnMut(&str)` closure is a sink too.
+//! - [`accessible_lines`] renders a view tree linearly, one line per control,
+//!   which is what the application's *accessible mode* announces when a new
+//!   window or dialog gets the focus.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::core::command::CM_OK;
+//! use turbo_vision::views::accessibility::accessible_lines;
+//! use turbo_vision::views::button::Button;
+//! use turbo_vision::views::dialog::Dialog;
+//! use turbo_vision::views::static_text::StaticText;
+//!
+//! let mut dialog = Dialog::new(Rect::new(0, 0, 30, 8), "Confirm");
+//! dialog.add(Box::new(StaticText::new(Rect::new(2, 1, 26, 2), "Save changes?")));
+//! dialog.add(Box::new(Button::new(Rect::new(2, 3, 12, 5), "~O~K", CM_OK, true)));
+//! dialog.set_initial_focus();
+//!
+//! assert_eq!(
+//!     accessible_lines(&dialog),
+//!     vec!["Confirm, dialog", "  Save changes?, text", "> OK, button"]
+//! );
+//! ```
+
+use super::view::{View, ViewId, focus_path};
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// What kind of control a view is, as a screen reader would call it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Window,
+    Dialog,
+    Button,
+    CheckBox,
+    RadioButton,
+    InputLine,
+    ComboBox,
+    SpinButton,
+    DateField,
+    Label,
+    Text,
+    List,
+    Table,
+    TabList,
+    ProgressBar,
+    Editor,
+    Menu,
+    StatusLine,
+}
+
+impl Role {
+    /// Lower-case name used in announcements (e.g. `check box`)
+    pub fn name(self) -> &'static str {
+        match self {
+            Role::Window => "window",
+            Role::Dialog => "dialog",
+            Role::Button => "button",
+            Role::CheckBox => "check box",
+            Role::RadioButton => "radio button",
+            Role::InputLine => "edit",
+            Role::ComboBox => "combo box",
+            Role::SpinButton => "spin button",
+            Role::DateField => "date",
+            Role::Label => "label",
+            Role::Text => "text",
+            Role::List => "list",
+            Role::Table => "table",
+            Role::TabList => "tab list",
+            Role::ProgressBar => "progress bar",
+            Role::Editor => "editor",
+            Role::Menu => "menu",
+            Role::StatusLine => "status line",
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Semantic description of a view: its role, label and current value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessibleInfo {
+    pub role: Role,
+    /// Human-readable name, without `~` hotkey markers
+    pub label: String,
+    /// Current value (text of an input line, checked state, selected item...)
+    pub value: Option<String>,
+}
+
+impl AccessibleInfo {
+    /// Create a description; `label` may contain `~` hotkey markers, which are removed
+    pub fn new(role: Role, label: &str) -> Self {
+        Self {
+            role,
+            label: plain_text(label),
+            value: None,
+        }
+    }
+
+    /// Set the current value
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
+
+/// Spoken form: `label, role` or `label, role, value`
+impl fmt::Display for AccessibleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.label.is_empty() {
+            write!(f, "{}", self.role)?;
+        } else {
+            write!(f, "{}, {}", self.label, self.role)?;
+        }
+        if let Some(value) = &self.value {
+            write!(f, ", {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Strip `~` hotkey markers and control characters (such as the `\x03`
+/// centering prefix) and collapse line breaks into spaces
+pub fn plain_text(text: &str) -> String {
+    text.split(['\n', '\r'])
+        .map(|line| {
+            line.chars()
+                .filter(|&c| c != '~' && !c.is_control())
+                .collect::<String>()
+        })
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Receiver of announcements for assistive technology
+pub trait AnnouncementSink {
+    /// Announce one line of text
+    fn announce(&mut self, text: &str);
+}
+
+impl<F: FnMut(&str)> AnnouncementSink for F {
+    fn announce(&mut self, text: &str) {
+        self(text);
+    }
+}
+
+/// Line-oriented sink: writes every announcement as one line and flushes it
+pub struct LineSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> LineSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl LineSink<io::Stderr> {
+    /// Announce to standard error (the UI itself owns standard output)
+    pub fn stderr() -> Self {
+        Self::new(io::stderr())
+    }
+}
+
+impl LineSink<File> {
+    /// Append announcements to a file (or a named pipe) at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn append(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::new(file))
+    }
+}
+
+impl<W: Write> AnnouncementSink for LineSink<W> {
+    fn announce(&mut self, text: &str) {
+        // Announcements are best effort: a closed reader must not break the UI.
+        let _ = writeln!(self.writer, "{text}").and_then(|()| self.writer.flush());
+    }
+}
+
+/// Render a view tree linearly, one line per accessible view
+///
+/// Views are visited depth-first in Z-order (the order they were added). Each
+/// line is indented two spaces per nesting level; the line of the focused
+/// control starts with `> ` instead. Views without a description are skipped
+/// but their subviews are still visited. Controls without a label of their own
+/// take the text of the [`Label`](super::label::Label) linked to them, and
+/// linked labels get no line of their own.
+pub fn accessible_lines(root: &dyn View) -> Vec<String> {
+    let mut lines = Vec::new();
+    collect_lines(root, None, 0, true, &mut lines);
+    lines
+}
+
+/// Describe the focused control below `root` (e.g. `Name, edit, report.txt`)
+///
+/// Returns `None` if nothing below `root` has the focus or the focused control
+/// has no description.
+pub fn describe_focused(root: &dyn View) -> Option<String> {
+    let path = focus_path(root);
+    let &(id, leaf) = path.last()?;
+    let parent = match path.len() {
+        1 => root,
+        n => path[n - 2].1,
+    };
+    let info = labelled(leaf.accessible()?, linked_label(parent, id).as_deref());
+    Some(info.to_string())
+}
+
+fn labelled(mut info: AccessibleInfo, linked: Option<&str>) -> AccessibleInfo {
+    if info.label.is_empty() {
+        if let Some(label) = linked {
+            info.label = plain_text(label);
+        }
+    }
+    info
+}
+
+/// Label text of the sibling label linked to the subview `id` of `parent`
+fn linked_label(parent: &dyn View, id: ViewId) -> Option<String> {
+    parent
+        .subviews()
+        .into_iter()
+        .filter(|(_, view)| view.label_link() == Some(id))
+        .find_map(|(_, view)| view.accessible())
+        .map(|info| info.label)
+}
+
+fn collect_lines(
+    view: &dyn View,
+    linked: Option<&str>,
+    depth: usize,
+    on_focus_path: bool,
+    lines: &mut Vec<String>,
+) {
+    let focused_id = view.current().map(|(id, _)| id);
+    let is_focused_leaf = on_focus_path && depth > 0 && focused_id.is_none();
+
+    let mut child_depth = depth;
+    // A linked label is announced as part of its control, not on its own.
+    let info = view.accessible().filter(|_| view.label_link().is_none());
+    if let Some(info) = info {
+        let indent = if is_focused_leaf {
+            format!("{}> ", "  ".repeat(depth.saturating_sub(1)))
+        } else {
+            "  ".repeat(depth)
+        };
+        lines.push(format!("{indent}{}", labelled(info, linked)));
+        child_depth += 1;
+    }
+
+    for (id, child) in view.subviews() {
+        let label = linked_label(view, id);
+        let child_on_path = on_focus_path && Some(id) == focused_id;
+        collect_lines(child, label.as_deref(), child_depth, child_on_path, lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::geometry::Rect;
+    use crate::views::checkbox::CheckBox;
+    use crate::views::dialog::Dialog;
+    use crate::views::input_line::InputLine;
+    use crate::views::label::Label;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn plain_text_strips_markup() {
+        assert_eq!(plain_text("~F~ile name"), "File name");
+        assert_eq!(
+            plain_text("\x03Centered\nsecond line"),
+            "Centered second line"
+        );
+    }
+
+    #[test]
+    fn info_display_includes_value() {
+        let info = AccessibleInfo::new(Role::CheckBox, "~B~old").with_value("checked");
+        assert_eq!(info.to_string(), "Bold, check box, checked");
+    }
+
+    #[test]
+    fn linear_rendering_marks_focused_control() {
+        let data = Rc::new(RefCell::new("report.txt".to_string()));
+        let mut dialog = Dialog::new(Rect::new(0, 0, 40, 10), "Save As");
+        let input = dialog.add(Box::new(InputLine::new(Rect::new(12, 1, 36, 2), 64, data)));
+        let mut label = Label::new(Rect::new(2, 1, 12, 2), "~N~ame");
+        label.set_link(input);
+        dialog.add(Box::new(label));
+        dialog.add(Box::new(CheckBox::new(Rect::new(2, 3, 20, 4), "~B~ackup")));
+        dialog.set_initial_focus();
+
+        assert_eq!(
+            accessible_lines(&dialog),
+            vec![
+                "Save As, dialog",
+                "> Name, edit, report.txt",
+                "  Backup, check box, not checked",
+            ]
+        );
+    }
+
+    #[test]
+    fn closures_are_sinks() {
+        let mut heard = Vec::new();
+        let mut sink = |text: &str| heard.push(text.to_string());
+        sink.announce("hello");
+        assert_eq!(heard, ["hello"]);
+    }
+
+    #[test]
+    fn application_announces_focus_changes() {
+        use crate::app::Application;
+        use crate::core::command::{CM_CANCEL, CM_OK};
+        use crate::core::event::{Event, KB_TAB};
+        use crate::test_util::snapshot::headless_terminal;
+        use crate::views::button::Button;
+
+        let heard = Rc::new(RefCell::new(Vec::new()));
+        let mut app = Application::with_terminal(headless_terminal(60, 20));
+        let sink = Rc::clone(&heard);
+        app.set_announcement_sink(Some(Box::new(move |text: &str| {
+            sink.borrow_mut().push(text.to_string());
+        })));
+
+        let mut dialog = Dialog::new(Rect::new(5, 3, 40, 12), "Confirm");
+        dialog.add(Box::new(Button::new(
+            Rect::new(2, 4, 12, 6),
+            "~O~K",
+            CM_OK,
+            true,
+        )));
+        dialog.add(Box::new(Button::new(
+            Rect::new(14, 4, 26, 6),
+            "Cancel",
+            CM_CANCEL,
+            false,
+        )));
+        dialog.set_initial_focus();
+        app.desktop.add(Box::new(dialog));
+
+        app.draw();
+        app.draw();
+        assert_eq!(*heard.borrow(), ["Confirm, dialog", "OK, button"]);
+
+        app.handle_event(&mut Event::keyboard(KB_TAB));
+        app.draw();
+        assert_eq!(
+            heard.borrow().last().map(String::as_str),
+            Some("Cancel, button")
+        );
+        assert_eq!(
+            app.accessible_text(),
+            vec!["Confirm, dialog", "  OK, button", "> Cancel, button"]
+        );
+    }
+}
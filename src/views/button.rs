@@ -94,6 +94,11 @@ impl View for Button {
         self.bounds
     }
 
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(AccessibleInfo::new(Role::Button, &self.title))
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
     }
@@ -28,6 +28,7 @@ use crate::core::command::{CM_OK, CommandId};
 use crate::core::event::{Event, EventType};
 use crate::core::geometry::{Point, Rect};
 use crate::core::history::HistoryManager;
+use crate::core::i18n::{text_width, tr};
 use crate::terminal::Terminal;
 use std::cell::RefCell;
 use std::path::PathBuf;
@@ -313,8 +314,18 @@ impl ChDirDialog {
         let history_id = history_id.unwrap_or(DEFAULT_HISTORY_ID);
         // Widened dialog bounds: TRect( 5, 2, 75, 21 ) - 70 columns instead of 48
         // This is absolute screen coordinates, will be centered by ofCentered flag
-        let dialog_bounds = Rect::new(5, 2, 75, 21);
-        let mut dialog = Dialog::new(dialog_bounds, "Change Directory");
+        // Translated button texts widen the button column (and the dialog) when needed
+        let ok_text = tr("~O~K");
+        let chdir_text = tr("~C~hdir");
+        let revert_text = tr("~R~evert");
+        let button_width = [&ok_text, &chdir_text, &revert_text]
+            .iter()
+            .map(|text| text_width(text) + 4)
+            .max()
+            .unwrap_or(10)
+            .max(10);
+        let dialog_bounds = Rect::new(5, 2, 65 + button_width, 21);
+        let mut dialog = Dialog::new(dialog_bounds, &tr("Change Directory"));
 
         // Get current directory for initial value
         let current_dir = std::env::current_dir()
@@ -329,8 +340,9 @@ impl ChDirDialog {
         let dir_input_id = dialog.add(Box::new(dir_input));
 
         // Label "Directory name" - Borland: (2, 2)
-        let label_bounds = Rect::new(2, 2, 20, 2);
-        let dir_label = Label::new(label_bounds, "Directory ~n~ame");
+        let name_text = tr("Directory ~n~ame");
+        let label_bounds = Rect::new(2, 2, 2 + (text_width(&name_text) + 2).max(18), 2);
+        let dir_label = Label::new(label_bounds, &name_text);
         dialog.add(Box::new(dir_label));
 
         // History button - adjusted: TRect( 48, 3, 51, 4 )
@@ -364,25 +376,26 @@ impl ChDirDialog {
         let dir_list_id = dialog.add(Box::new(shared_listbox));
 
         // Label "Directory tree" - Borland: (2, 5)
-        let tree_label_bounds = Rect::new(2, 5, 20, 5);
-        let tree_label = Label::new(tree_label_bounds, "Directory ~t~ree");
+        let tree_text = tr("Directory ~t~ree");
+        let tree_label_bounds = Rect::new(2, 5, 2 + (text_width(&tree_text) + 2).max(18), 5);
+        let tree_label = Label::new(tree_label_bounds, &tree_text);
         dialog.add(Box::new(tree_label));
 
         // OK button - adjusted: TRect( 53, 6, 63, 8 )
-        let ok_bounds = Rect::new(53, 6, 63, 8);
-        let ok_button = Button::new(ok_bounds, "~O~K", CM_OK, true);
+        let ok_bounds = Rect::new(53, 6, 53 + button_width, 8);
+        let ok_button = Button::new(ok_bounds, &ok_text, CM_OK, true);
         let ok_button_id = dialog.add(Box::new(ok_button));
 
         // Chdir button - adjusted: TRect( 53, 9, 63, 11 )
-        let chdir_bounds = Rect::new(53, 9, 63, 11);
-        let mut chdir_button = Button::new(chdir_bounds, "~C~hdir", CM_CHANGE_DIR, false);
+        let chdir_bounds = Rect::new(53, 9, 53 + button_width, 11);
+        let mut chdir_button = Button::new(chdir_bounds, &chdir_text, CM_CHANGE_DIR, false);
         chdir_button.set_broadcast(true); // Broadcast instead of ending dialog
         chdir_button.set_selectable(false); // Not part of focus cycle
         let chdir_button_id = dialog.add(Box::new(chdir_button));
 
         // Revert button - adjusted: TRect( 53, 12, 63, 14 )
-        let revert_bounds = Rect::new(53, 12, 63, 14);
-        let mut revert_button = Button::new(revert_bounds, "~R~evert", CM_REVERT, false);
+        let revert_bounds = Rect::new(53, 12, 53 + button_width, 14);
+        let mut revert_button = Button::new(revert_bounds, &revert_text, CM_REVERT, false);
         revert_button.set_broadcast(true); // Broadcast instead of ending dialog
         revert_button.set_selectable(false); // Not part of focus cycle
         dialog.add(Box::new(revert_button));
@@ -423,7 +436,7 @@ impl ChDirDialog {
                 if let Err(e) = std::env::set_current_dir(&path) {
                     // Invalid directory - show error and re-execute dialog
                     // Matches Borland: valid() returns False, shows error, and keeps dialog open
-                    let error_msg = format!("Invalid directory\n\n{}", e);
+                    let error_msg = format!("{}\n\n{}", tr("Invalid directory"), e);
                     message_box_error(app, &error_msg);
 
                     // Re-execute the dialog to let user try again
@@ -459,6 +472,18 @@ impl View for ChDirDialog {
         self.dialog.bounds()
     }
 
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.dialog.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.dialog.subviews()
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        self.dialog.accessible()
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.dialog.set_bounds(bounds);
     }
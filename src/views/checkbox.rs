@@ -21,6 +21,7 @@
 //   );
 
 use super::cluster::{Cluster, ClusterState};
+use super::dialog_data::FieldValue;
 use super::view::View;
 use crate::core::event::Event;
 use crate::core::geometry::Rect;
@@ -73,10 +74,30 @@ impl View for CheckBox {
         self.bounds
     }
 
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let value = if self.is_checked() {
+            "checked"
+        } else {
+            "not checked"
+        };
+        Some(AccessibleInfo::new(Role::CheckBox, &self.label).with_value(value))
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
     }
 
+    fn get_data(&self) -> Option<FieldValue> {
+        Some(FieldValue::Bool(self.is_checked()))
+    }
+
+    fn set_data(&mut self, value: &FieldValue) {
+        if let FieldValue::Bool(checked) = value {
+            self.set_checked(*checked);
+        }
+    }
+
     fn handle_event(&mut self, event: &mut Event) {
         // Use Cluster trait's standard event handling
         self.handle_cluster_event(event);
@@ -14,6 +14,7 @@ use super::{View, ViewId};
 use crate::core::command::{CM_CANCEL, CM_OK};
 use crate::core::event::Event;
 use crate::core::geometry::Rect;
+use crate::core::i18n::{text_width, tr};
 use crate::core::palette::Attr;
 use crate::terminal::Terminal;
 
@@ -35,18 +36,33 @@ impl ColorDialog {
     /// * `title` - Dialog title
     /// * `initial_attr` - Initial color attribute to show
     pub fn new(bounds: Rect, title: &str, initial_attr: Attr) -> Self {
+        let instructions = tr("Select foreground and background colors:");
+        let sample = tr("Sample text with\nselected colors");
+        let ok_text = tr("OK");
+        let cancel_text = tr("Cancel");
+        let ok_width = (text_width(&ok_text) + 4).max(10);
+        let cancel_width = (text_width(&cancel_text) + 4).max(10);
+
+        // Widen the dialog when translated texts do not fit
+        let sample_width = sample.lines().map(text_width).max().unwrap_or(0);
+        let width = bounds
+            .width()
+            .max(text_width(&instructions) + 6)
+            .max(28 + sample_width + 4)
+            .max(ok_width + cancel_width + 6);
+        let bounds = Rect::new(bounds.a.x, bounds.a.y, bounds.a.x + width, bounds.b.y);
         let mut dialog = Dialog::new(bounds, title);
 
         // Instructions
         dialog.add(Box::new(StaticText::new(
             Rect::new(2, 2, bounds.width() - 4, 3),
-            "Select foreground and background colors:",
+            &instructions,
         )));
 
         // Foreground color selector
         dialog.add(Box::new(StaticText::new(
-            Rect::new(2, 4, 20, 5),
-            "Foreground:",
+            Rect::new(2, 4, 26, 5),
+            &tr("Foreground:"),
         )));
 
         let fg_selector = ColorSelector::new(Rect::new(2, 5, 26, 8));
@@ -54,8 +70,8 @@ impl ColorDialog {
 
         // Background color selector
         dialog.add(Box::new(StaticText::new(
-            Rect::new(2, 9, 20, 10),
-            "Background:",
+            Rect::new(2, 9, 26, 10),
+            &tr("Background:"),
         )));
 
         let bg_selector = ColorSelector::new(Rect::new(2, 10, 26, 13));
@@ -64,34 +80,36 @@ impl ColorDialog {
         // Preview area (would show the colors in action)
         dialog.add(Box::new(StaticText::new(
             Rect::new(28, 5, bounds.width() - 4, 6),
-            "Preview:",
+            &tr("Preview:"),
         )));
         dialog.add(Box::new(StaticText::new(
             Rect::new(28, 6, bounds.width() - 4, 8),
-            "Sample text with\nselected colors",
+            &sample,
         )));
 
         // Buttons
+        let cancel_x = bounds.width() - 2 - cancel_width;
+        let ok_x = cancel_x - 2 - ok_width;
         dialog.add(Box::new(Button::new(
             Rect::new(
-                bounds.width() - 24,
+                ok_x,
                 bounds.height() - 4,
-                bounds.width() - 14,
+                ok_x + ok_width,
                 bounds.height() - 2,
             ),
-            "OK",
+            &ok_text,
             CM_OK,
             true,
         )));
 
         dialog.add(Box::new(Button::new(
             Rect::new(
-                bounds.width() - 12,
+                cancel_x,
                 bounds.height() - 4,
-                bounds.width() - 2,
+                cancel_x + cancel_width,
                 bounds.height() - 2,
             ),
-            "Cancel",
+            &cancel_text,
             CM_CANCEL,
             false,
         )));
@@ -131,6 +149,18 @@ impl View for ColorDialog {
         self.dialog.bounds()
     }
 
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.dialog.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.dialog.subviews()
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        self.dialog.accessible()
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.dialog.set_bounds(bounds);
     }
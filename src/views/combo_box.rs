@@ -0,0 +1,800 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Combo box view - input line with a drop-down list of fixed choices.
+//!
+//! A [`ComboBox`] is an [`InputLine`] followed by a `▼` button. The button,
+//! Alt+Down or F4 open a [`ListBox`] below (or above) the control; Enter or a
+//! click picks an item, Esc closes the list. While closed, Up and Down step
+//! through the choices.
+//!
+//! An *editable* combo box accepts any text and filters the list as you type,
+//! showing the choices that contain the text. Enter then keeps the typed text
+//! unless it names a choice exactly or the list cursor was moved onto one
+//! with the arrow keys. A *read-only* one only takes
+//! values from the list; typing a letter jumps to the next choice starting
+//! with it.
+//!
+//! The chosen item is bound to an `Rc<RefCell<Option<usize>>>` (index into the
+//! choices, `None` for free text) and the text to an `Rc<RefCell<String>>`,
+//! like the data of an [`InputLine`].
+//!
+//! ```rust
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::combo_box::ComboBoxBuilder;
+//!
+//! let selection = Rc::new(RefCell::new(Some(1)));
+//! let combo = ComboBoxBuilder::new()
+//!     .bounds(Rect::new(2, 2, 22, 3))
+//!     .items(vec!["Red".to_string(), "Green".to_string(), "Blue".to_string()])
+//!     .selection(Rc::clone(&selection))
+//!     .editable(false)
+//!     .build();
+//! assert_eq!(combo.text(), "Green");
+//! ```
+
+use super::dialog_data::FieldValue;
+use super::input_line::InputLine;
+use super::list_viewer::{ListViewer, MatchMode, match_range};
+use super::listbox::ListBox;
+use super::view::{View, draw_shadow_bounds, write_line_to_terminal};
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{
+    Event, EventType, KB_DOWN, KB_ENTER, KB_ESC, KB_F4, KB_PGDN, KB_PGUP, KB_SHIFT_TAB, KB_TAB,
+    KB_UP, MB_LEFT_BUTTON,
+};
+use crate::core::geometry::Rect;
+use crate::core::state::{SF_DROPPED_DOWN, StateFlags};
+use crate::terminal::Terminal;
+use crossterm::event::KeyModifiers;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Columns taken by the drop-down button
+const BUTTON_WIDTH: i16 = 3;
+
+/// Default number of list rows shown at once
+const DEFAULT_DROP_ROWS: usize = 8;
+
+// Palette indices (maps to CP_HISTORY, like the history button)
+const COMBO_BUTTON: u8 = 1;
+const COMBO_ARROW: u8 = 2;
+
+/// Combo box - an input line plus a drop-down list of choices
+pub struct ComboBox {
+    bounds: Rect,
+    input: InputLine,
+    list: ListBox,
+    items: Vec<String>,
+    /// Indices into `items` shown in the list
+    shown: Vec<usize>,
+    selection: Rc<RefCell<Option<usize>>>,
+    editable: bool,
+    drop_rows: usize,
+    /// Where the list was last placed (screen coordinates)
+    popup: Rect,
+    /// The list cursor was moved since the list was last filled
+    list_moved: bool,
+    state: StateFlags,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl ComboBox {
+    /// Create an editable combo box over `items`, bound to `selection`
+    ///
+    /// The text starts as the selected item, if any.
+    pub fn new(bounds: Rect, items: Vec<String>, selection: Rc<RefCell<Option<usize>>>) -> Self {
+        Self::with_data(
+            bounds,
+            items,
+            selection,
+            Rc::new(RefCell::new(String::new())),
+            255,
+        )
+    }
+
+    /// Create an editable combo box whose text is bound to `data`
+    pub fn with_data(
+        bounds: Rect,
+        items: Vec<String>,
+        selection: Rc<RefCell<Option<usize>>>,
+        data: Rc<RefCell<String>>,
+        max_length: usize,
+    ) -> Self {
+        let mut combo = Self {
+            bounds,
+            input: InputLine::new(Self::input_bounds(bounds), max_length, data),
+            list: ListBox::new(Rect::new(0, 0, 0, 0), 0),
+            items,
+            shown: Vec::new(),
+            selection,
+            editable: true,
+            drop_rows: DEFAULT_DROP_ROWS,
+            popup: Rect::new(0, 0, 0, 0),
+            list_moved: false,
+            state: 0,
+            palette_chain: None,
+        };
+        let selected = *combo.selection.borrow();
+        match selected.filter(|&index| index < combo.items.len()) {
+            Some(index) => combo.select(index),
+            None => combo.sync_selection(),
+        }
+        combo
+    }
+
+    fn input_bounds(bounds: Rect) -> Rect {
+        Rect::new(
+            bounds.a.x,
+            bounds.a.y,
+            (bounds.b.x - BUTTON_WIDTH).max(bounds.a.x),
+            bounds.a.y + 1,
+        )
+    }
+
+    fn button_bounds(&self) -> Rect {
+        Rect::new(
+            self.input.bounds().b.x,
+            self.bounds.a.y,
+            self.bounds.b.x,
+            self.bounds.a.y + 1,
+        )
+    }
+
+    /// Allow free text (`true`, the default) or only values from the list
+    pub fn set_editable(&mut self, editable: bool) {
+        self.editable = editable;
+        if !editable {
+            self.sync_selection();
+        }
+    }
+
+    pub fn is_editable(&self) -> bool {
+        self.editable
+    }
+
+    /// Maximum number of list rows shown at once
+    pub fn set_drop_rows(&mut self, rows: usize) {
+        self.drop_rows = rows.max(1);
+    }
+
+    /// Replace the choices; the selection is looked up again by text
+    pub fn set_items(&mut self, items: Vec<String>) {
+        self.items = items;
+        self.close();
+        self.sync_selection();
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Index of the chosen item, `None` for free text (or nothing chosen)
+    pub fn selection(&self) -> Option<usize> {
+        *self.selection.borrow()
+    }
+
+    /// Choose an item (and show its text), or clear the choice
+    pub fn set_selection(&mut self, index: Option<usize>) {
+        if let Some(index) = index.filter(|&index| index < self.items.len()) {
+            self.select(index);
+        } else {
+            *self.selection.borrow_mut() = None;
+            self.input.set_text(String::new());
+        }
+    }
+
+    /// Current text
+    pub fn text(&self) -> String {
+        self.input.get_text()
+    }
+
+    pub fn is_dropped_down(&self) -> bool {
+        self.state & SF_DROPPED_DOWN != 0
+    }
+
+    fn select(&mut self, index: usize) {
+        *self.selection.borrow_mut() = Some(index);
+        self.input.set_text(self.items[index].clone());
+    }
+
+    /// Bind the selection to the item matching the text exactly
+    fn sync_selection(&mut self) {
+        let text = self.input.get_text();
+        let index = self.items.iter().position(|item| *item == text);
+        *self.selection.borrow_mut() = index;
+    }
+
+    /// Show the items containing `filter` (all items for an empty filter)
+    fn filter(&mut self, filter: &str) {
+        self.shown = (0..self.items.len())
+            .filter(|&index| {
+                filter.is_empty()
+                    || match_range(MatchMode::Substring, filter, &self.items[index]).is_some()
+            })
+            .collect();
+        self.list.set_items(
+            self.shown
+                .iter()
+                .map(|&index| self.items[index].clone())
+                .collect(),
+        );
+        let current = self
+            .selection()
+            .and_then(|selected| self.shown.iter().position(|&index| index == selected));
+        self.popup = self.place_popup(None);
+        self.list.set_bounds(self.popup);
+        self.list.set_selection(current.unwrap_or(0));
+        self.list_moved = false;
+    }
+
+    /// Open the list with all choices
+    pub fn open(&mut self) {
+        self.filter("");
+        if !self.shown.is_empty() {
+            self.state |= SF_DROPPED_DOWN;
+            self.list.set_focus(true);
+        }
+    }
+
+    /// Close the list without choosing
+    pub fn close(&mut self) {
+        self.state &= !SF_DROPPED_DOWN;
+        self.list.set_focus(false);
+    }
+
+    /// Enter on the open list: an editable combo box keeps typed text that is
+    /// not a choice unless the list cursor was moved, otherwise take the item
+    /// under the cursor
+    fn accept_typed(&mut self) {
+        let highlighted = self
+            .list
+            .get_selection()
+            .and_then(|row| self.shown.get(row))
+            .map(|&index| &self.items[index]);
+        let exact = highlighted.is_some_and(|item| *item == self.input.get_text());
+        if self.editable && !self.list_moved && !exact {
+            self.close();
+        } else {
+            self.accept();
+        }
+    }
+
+    /// Take the item under the list cursor and close the list
+    fn accept(&mut self) {
+        if let Some(&index) = self
+            .list
+            .get_selection()
+            .and_then(|row| self.shown.get(row))
+        {
+            self.select(index);
+        }
+        self.close();
+    }
+
+    /// Rectangle for the list: below the control, or above it when `limits`
+    /// (the area the owner can draw in) leave more room there
+    fn place_popup(&self, limits: Option<Rect>) -> Rect {
+        let wanted = i16::try_from(self.shown.len().clamp(1, self.drop_rows)).unwrap_or(1);
+        let (left, right) = (self.bounds.a.x, self.bounds.b.x);
+        let Some(limits) = limits else {
+            return Rect::new(left, self.bounds.b.y, right, self.bounds.b.y + wanted);
+        };
+        let below = limits.b.y - self.bounds.b.y;
+        let above = self.bounds.a.y - limits.a.y;
+        if below >= wanted || below >= above {
+            let rows = wanted.min(below).max(1);
+            Rect::new(left, self.bounds.b.y, right, self.bounds.b.y + rows)
+        } else {
+            let rows = wanted.min(above).max(1);
+            Rect::new(left, self.bounds.a.y - rows, right, self.bounds.a.y)
+        }
+    }
+
+    /// Next item after the selection whose text starts with `ch`, wrapping around
+    fn find_by_letter(&self, ch: char) -> Option<usize> {
+        let count = self.items.len();
+        let start = self.selection().map_or(0, |index| index + 1);
+        let needle = ch.to_string();
+        (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|&index| match_range(MatchMode::Prefix, &needle, &self.items[index]).is_some())
+    }
+
+    /// Move the selection by `delta` items (closed list)
+    fn step(&mut self, delta: isize) {
+        if self.items.is_empty() {
+            return;
+        }
+        let last = self.items.len() - 1;
+        let next = match self.selection() {
+            Some(index) => index.saturating_add_signed(delta).min(last),
+            None if delta < 0 => last,
+            None => 0,
+        };
+        self.select(next);
+    }
+
+    /// Let the input line edit the text, then filter the list by it
+    fn edit(&mut self, event: &mut Event) {
+        let before = self.input.get_text();
+        self.input.handle_event(event);
+        let text = self.input.get_text();
+        if text == before {
+            return;
+        }
+        self.sync_selection();
+        self.filter(&text);
+        if text.is_empty() || self.shown.is_empty() {
+            self.close();
+        } else {
+            self.state |= SF_DROPPED_DOWN;
+            self.list.set_focus(true);
+        }
+    }
+
+    fn handle_key(&mut self, event: &mut Event) {
+        let alt = event.key_modifiers.contains(KeyModifiers::ALT);
+        let dropped = self.is_dropped_down();
+        match event.key_code {
+            KB_DOWN if alt => {
+                if dropped {
+                    self.close();
+                } else {
+                    self.open();
+                }
+                event.clear();
+            }
+            KB_F4 => {
+                if dropped {
+                    self.close();
+                } else {
+                    self.open();
+                }
+                event.clear();
+            }
+            KB_UP if alt && dropped => {
+                self.close();
+                event.clear();
+            }
+            KB_ENTER if dropped => {
+                self.accept_typed();
+                event.clear();
+            }
+            KB_ESC if dropped => {
+                self.close();
+                event.clear();
+            }
+            KB_TAB | KB_SHIFT_TAB => self.close(),
+            KB_UP | KB_DOWN | KB_PGUP | KB_PGDN if dropped => {
+                self.list.handle_event(event);
+                self.list_moved = true;
+                event.clear();
+            }
+            KB_UP if !alt => {
+                self.step(-1);
+                event.clear();
+            }
+            KB_DOWN if !alt => {
+                self.step(1);
+                event.clear();
+            }
+            _ if self.editable => self.edit(event),
+            _ if dropped => self.list.handle_event(event),
+            key @ 0x20..0x7F => {
+                let ch = char::from_u32(u32::from(key)).unwrap_or(' ');
+                if let Some(index) = self.find_by_letter(ch) {
+                    self.select(index);
+                }
+                event.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_mouse(&mut self, event: &mut Event) {
+        if event.what != EventType::MouseDown {
+            // Mouse moves and releases over the open list belong to it
+            if self.is_dropped_down() {
+                event.clear();
+            }
+            return;
+        }
+        if event.mouse.buttons & MB_LEFT_BUTTON == 0 {
+            return;
+        }
+        let pos = event.mouse.pos;
+        if self.is_dropped_down() {
+            if self.popup.contains(pos) {
+                let row = usize::try_from(pos.y - self.popup.a.y).unwrap_or(0);
+                self.list.set_selection(self.list.top_item() + row);
+                self.accept();
+            } else {
+                // The button or anywhere else closes the list
+                self.close();
+            }
+            event.clear();
+        } else if self.button_bounds().contains(pos)
+            || (!self.editable && self.bounds.contains(pos))
+        {
+            self.open();
+            event.clear();
+        }
+    }
+}
+
+impl View for ComboBox {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        self.input.set_bounds(Self::input_bounds(bounds));
+        self.popup = self.place_popup(None);
+        self.list.set_bounds(self.popup);
+    }
+
+    /// The chosen index, or the text when the combo box is editable
+    fn get_data(&self) -> Option<FieldValue> {
+        Some(if self.editable {
+            FieldValue::Text(self.text())
+        } else {
+            FieldValue::Index(self.selection())
+        })
+    }
+
+    fn set_data(&mut self, value: &FieldValue) {
+        match value {
+            FieldValue::Index(index) => self.set_selection(*index),
+            FieldValue::Text(text) if self.editable => {
+                self.input.set_text(text.clone());
+                self.sync_selection();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        self.input.draw(terminal);
+
+        let button = self.button_bounds();
+        let width = usize::from(button.width_clamped().cast_unsigned());
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', self.map_color(COMBO_BUTTON), width);
+        buf.put_char(width / 2, '▼', self.map_color(COMBO_ARROW));
+        write_line_to_terminal(terminal, button.a.x, button.a.y, &buf);
+
+        if self.is_dropped_down() {
+            // Stay inside the owner (its clip region includes its frame)
+            let limits = terminal.get_clip_rect().map(|mut clip| {
+                clip.grow(-1, -1);
+                clip
+            });
+            let popup = self.place_popup(limits);
+            if popup != self.popup {
+                let current = self.list.get_selection().unwrap_or(0);
+                self.popup = popup;
+                self.list.set_bounds(popup);
+                self.list.set_selection(current);
+            }
+            self.list.draw(terminal);
+            draw_shadow_bounds(terminal, self.popup);
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::MouseDown | EventType::MouseMove | EventType::MouseUp => {
+                self.handle_mouse(event);
+            }
+            EventType::Keyboard if self.is_focused() => self.handle_key(event),
+            _ => {}
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.set_state_flag(crate::core::state::SF_FOCUSED, focused);
+        self.input.set_focus(focused);
+        if !focused {
+            self.close();
+        }
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        if self.editable {
+            self.input.update_cursor(terminal);
+        } else {
+            let _ = terminal.hide_cursor();
+        }
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let value = match self.selection() {
+            Some(index) => format!("{}, {} of {}", self.text(), index + 1, self.items.len()),
+            None => self.text(),
+        };
+        Some(AccessibleInfo::new(Role::ComboBox, "").with_value(value))
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        // The input line and the list map their colors through the owner
+        self.input.set_palette_chain(node.clone());
+        self.list.set_palette_chain(node.clone());
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_HISTORY))
+    }
+}
+
+/// Builder for creating combo boxes with a fluent API.
+///
+/// # Examples
+///
+/// ```ignore
+/// use turbo_vision::views::combo_box::ComboBoxBuilder;
+/// use turbo_vision::core::geometry::Rect;
+/// use std::rc::Rc;
+/// use std::cell::RefCell;
+///
+/// let text = Rc::new(RefCell::new(String::new()));
+/// let combo = ComboBoxBuilder::new()
+///     .bounds(Rect::new(10, 5, 40, 6))
+///     .items(fonts)
+///     .data(text.clone())
+///     .build();
+/// ```
+pub struct ComboBoxBuilder {
+    bounds: Option<Rect>,
+    items: Vec<String>,
+    selection: Option<Rc<RefCell<Option<usize>>>>,
+    data: Option<Rc<RefCell<String>>>,
+    max_length: usize,
+    editable: bool,
+    drop_rows: usize,
+}
+
+impl ComboBoxBuilder {
+    /// Creates a new `ComboBoxBuilder` with default values.
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            items: Vec::new(),
+            selection: None,
+            data: None,
+            max_length: 255,
+            editable: true,
+            drop_rows: DEFAULT_DROP_ROWS,
+        }
+    }
+
+    /// Sets the combo box bounds, one row high (required).
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the choices.
+    #[must_use]
+    pub fn items(mut self, items: Vec<String>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Binds the index of the chosen item (optional).
+    #[must_use]
+    pub fn selection(mut self, selection: Rc<RefCell<Option<usize>>>) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Binds the text (optional).
+    #[must_use]
+    pub fn data(mut self, data: Rc<RefCell<String>>) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Sets the maximum text length (default: 255).
+    #[must_use]
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Allows free text (default: true) or only values from the list.
+    #[must_use]
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Sets the maximum number of list rows shown at once (default: 8).
+    #[must_use]
+    pub fn drop_rows(mut self, rows: usize) -> Self {
+        self.drop_rows = rows;
+        self
+    }
+
+    /// Builds the `ComboBox`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if bounds are not set.
+    pub fn build(self) -> ComboBox {
+        let bounds = self.bounds.expect("ComboBox bounds must be set");
+        let mut combo = ComboBox::with_data(
+            bounds,
+            self.items,
+            self.selection.unwrap_or_default(),
+            self.data.unwrap_or_default(),
+            self.max_length,
+        );
+        combo.set_editable(self.editable);
+        combo.set_drop_rows(self.drop_rows);
+        combo
+    }
+
+    /// Builds the `ComboBox` as a Box.
+    pub fn build_boxed(self) -> Box<ComboBox> {
+        Box::new(self.build())
+    }
+}
+
+impl Default for ComboBoxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::CM_OK;
+    use crate::core::event::KB_BACKSPACE;
+    use crate::core::geometry::Point;
+    use crate::test_util::snapshot::Snapshot;
+    use crate::views::button::Button;
+    use crate::views::group::Group;
+
+    fn colors() -> Vec<String> {
+        ["Red", "Green", "Blue", "Black"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn key(combo: &mut ComboBox, key_code: u16, modifiers: KeyModifiers) {
+        let mut event = Event::keyboard(key_code);
+        event.key_modifiers = modifiers;
+        combo.handle_event(&mut event);
+    }
+
+    fn combo(editable: bool) -> (ComboBox, Rc<RefCell<Option<usize>>>) {
+        let selection = Rc::new(RefCell::new(None));
+        let mut combo = ComboBoxBuilder::new()
+            .bounds(Rect::new(0, 0, 12, 1))
+            .items(colors())
+            .selection(Rc::clone(&selection))
+            .editable(editable)
+            .build();
+        combo.set_focus(true);
+        (combo, selection)
+    }
+
+    #[test]
+    fn alt_down_opens_and_enter_picks() {
+        let (mut combo, selection) = combo(false);
+        key(&mut combo, KB_DOWN, KeyModifiers::ALT);
+        assert!(combo.is_dropped_down());
+        key(&mut combo, KB_DOWN, KeyModifiers::NONE);
+        key(&mut combo, KB_ENTER, KeyModifiers::NONE);
+        assert!(!combo.is_dropped_down());
+        assert_eq!(*selection.borrow(), Some(1));
+        assert_eq!(combo.text(), "Green");
+
+        // Closed: arrows step, letters jump to the next match
+        key(&mut combo, KB_DOWN, KeyModifiers::NONE);
+        assert_eq!(combo.text(), "Blue");
+        key(&mut combo, u16::from(b'b'), KeyModifiers::NONE);
+        assert_eq!(combo.text(), "Black");
+        assert_eq!(*selection.borrow(), Some(3));
+    }
+
+    #[test]
+    fn typing_filters_the_list() {
+        let (mut combo, selection) = combo(true);
+        key(&mut combo, u16::from(b'l'), KeyModifiers::NONE);
+        assert!(combo.is_dropped_down());
+        key(&mut combo, u16::from(b'a'), KeyModifiers::NONE);
+        let shown: Vec<_> = combo
+            .shown
+            .iter()
+            .map(|&i| combo.items[i].as_str())
+            .collect();
+        assert_eq!(shown, ["Black"]);
+        assert_eq!(*selection.borrow(), None);
+
+        // Enter keeps the typed text until the list cursor is moved
+        key(&mut combo, KB_ENTER, KeyModifiers::NONE);
+        assert!(!combo.is_dropped_down());
+        assert_eq!(combo.text(), "la");
+        assert_eq!(*selection.borrow(), None);
+
+        key(&mut combo, u16::from(b'c'), KeyModifiers::NONE);
+        key(&mut combo, KB_DOWN, KeyModifiers::NONE);
+        key(&mut combo, KB_ENTER, KeyModifiers::NONE);
+        assert_eq!(combo.text(), "Black");
+        assert_eq!(*selection.borrow(), Some(3));
+
+        // Free text does not match a choice
+        key(&mut combo, KB_BACKSPACE, KeyModifiers::NONE);
+        assert_eq!(combo.text(), "Blac");
+        assert_eq!(*selection.borrow(), None);
+        key(&mut combo, KB_ESC, KeyModifiers::NONE);
+        assert!(!combo.is_dropped_down());
+    }
+
+    #[test]
+    fn open_list_draws_over_siblings_and_takes_clicks() {
+        let selection = Rc::new(RefCell::new(Some(0)));
+        let mut group = Group::new(Rect::new(0, 0, 20, 8));
+        let combo = ComboBoxBuilder::new()
+            .bounds(Rect::new(0, 0, 12, 1))
+            .items(colors())
+            .selection(Rc::clone(&selection))
+            .editable(false)
+            .build_boxed();
+        group.add(combo);
+        group.add(Box::new(Button::new(
+            Rect::new(0, 2, 10, 4),
+            "~O~K",
+            CM_OK,
+            false,
+        )));
+        group.set_initial_focus();
+
+        let mut click = Event::mouse(
+            EventType::MouseDown,
+            Point::new(10, 0),
+            MB_LEFT_BUTTON,
+            false,
+        );
+        group.handle_event(&mut click);
+        let snapshot = Snapshot::of_view(&mut group, 20, 8);
+        assert!(snapshot.text_lines()[4].starts_with("Black"));
+
+        // A click on the list over the button picks the item
+        let mut click = Event::mouse(
+            EventType::MouseDown,
+            Point::new(2, 2),
+            MB_LEFT_BUTTON,
+            false,
+        );
+        group.handle_event(&mut click);
+        assert_eq!(click.what, EventType::Nothing);
+        assert_eq!(*selection.borrow(), Some(1));
+    }
+}
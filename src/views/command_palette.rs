@@ -0,0 +1,655 @@
+// (C) 2026 - Enzo Lombardi
+
+//! Command palette - find and run any menu command by typing part of its name.
+//!
+//! The palette lists every command of the menu bar (as "File: Open",
+//! "Edit: Find" and so on) plus commands registered by the application.
+//! Typing narrows the list to the entries containing the typed characters in
+//! order, best matches first; Up, Down, Page Up and Page Down move in the list, Enter
+//! or a double click picks the entry and Esc closes the palette. Commands
+//! that are disabled in the command set are shown grayed out and cannot be
+//! picked.
+//!
+//! [`Application::show_command_palette`](crate::app::Application::show_command_palette)
+//! opens it on `CM_COMMAND_PALETTE` (Ctrl+Shift+P) and posts the picked
+//! command.
+
+use super::ViewId;
+use super::accessibility::{AccessibleInfo, Role};
+use super::dialog::Dialog;
+use super::list_viewer::{ListViewer, ListViewerState};
+use super::menu_bar::MenuBar;
+use super::view::{View, write_line_to_terminal};
+use crate::core::command::{CM_OK, CommandId};
+use crate::core::command_set;
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{Event, EventType, KB_BACKSPACE, KB_ENTER, MB_LEFT_BUTTON, key_name};
+use crate::core::geometry::Rect;
+use crate::core::menu_data::{Menu, MenuItem};
+use crate::core::state::StateFlags;
+use crate::terminal::Terminal;
+use crossterm::event::KeyModifiers;
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::rc::Rc;
+
+// Palette indices (maps to CP_COMMAND_PALETTE)
+const PALETTE_INPUT: u8 = 1;
+const PALETTE_NORMAL: u8 = 2;
+const PALETTE_SELECTED: u8 = 3;
+const PALETTE_SHORTCUT: u8 = 4;
+const PALETTE_DISABLED: u8 = 5;
+
+/// One command offered by the palette
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaletteEntry {
+    /// Display text, without `~` hotkey markers
+    pub text: String,
+    pub command: CommandId,
+    /// Key shortcut shown right-aligned (e.g. "Ctrl+O")
+    pub shortcut: Option<String>,
+    /// False for menu items created disabled
+    pub enabled: bool,
+}
+
+impl PaletteEntry {
+    /// Create an entry; `~` hotkey markers are removed from `text`
+    pub fn new(text: &str, command: CommandId) -> Self {
+        Self {
+            text: text.replace('~', ""),
+            command,
+            shortcut: None,
+            enabled: true,
+        }
+    }
+
+    /// Set the shortcut text shown next to the entry
+    #[must_use]
+    pub fn with_shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Check if the entry can be picked: the item is enabled and so is its
+    /// command in the global command set
+    pub fn is_enabled(&self) -> bool {
+        self.enabled && command_set::command_enabled(self.command)
+    }
+}
+
+/// Collect the commands of a menu bar, named after the menus leading to them
+/// ("File: Open", "File: Recent: notes.txt")
+pub fn menu_entries(menu_bar: &MenuBar) -> Vec<PaletteEntry> {
+    let mut entries = Vec::new();
+    for submenu in menu_bar.submenus() {
+        collect_entries(&submenu.name.replace('~', ""), &submenu.menu, &mut entries);
+    }
+    entries
+}
+
+fn collect_entries(prefix: &str, menu: &Menu, entries: &mut Vec<PaletteEntry>) {
+    for item in &menu.items {
+        match item {
+            MenuItem::Regular {
+                text,
+                command,
+                key_code,
+                enabled,
+                shortcut,
+                ..
+            } => {
+                let mut entry = PaletteEntry::new(&format!("{prefix}: {text}"), *command);
+                entry.shortcut = shortcut.clone().or_else(|| match *key_code {
+                    0 => None,
+                    key_code => key_name(key_code),
+                });
+                entry.enabled = *enabled;
+                entries.push(entry);
+            }
+            MenuItem::SubMenu { text, menu, .. } => {
+                let prefix = format!("{prefix}: {}", text.replace('~', ""));
+                collect_entries(&prefix, menu, entries);
+            }
+            MenuItem::Separator => {}
+        }
+    }
+}
+
+/// Score how well `pattern` matches `text`, ignoring case
+///
+/// Returns `None` unless `text` contains the characters of `pattern` in
+/// order. Higher is better: runs of adjacent characters and characters at
+/// the start of a word count extra, so "fo" ranks "File: Open" below "Format".
+pub fn fuzzy_score(pattern: &str, text: &str) -> Option<u32> {
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut last: Option<usize> = None;
+    for wanted in pattern.chars().flat_map(char::to_lowercase) {
+        let found = pos + text[pos..].iter().position(|&ch| ch == wanted)?;
+        score += 1;
+        if last.is_some_and(|last| last + 1 == found) {
+            score += 4;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        last = Some(found);
+        pos = found + 1;
+    }
+    Some(score)
+}
+
+/// Query line and matching entries; picking an entry ends the dialog
+struct PaletteList {
+    bounds: Rect,
+    entries: Vec<PaletteEntry>,
+    /// Indices into `entries`, best match first
+    matches: Vec<usize>,
+    query: String,
+    chosen: Rc<RefCell<Option<CommandId>>>,
+    list_state: ListViewerState,
+    state: StateFlags,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl PaletteList {
+    fn new(
+        bounds: Rect,
+        entries: Vec<PaletteEntry>,
+        chosen: Rc<RefCell<Option<CommandId>>>,
+    ) -> Self {
+        let mut list = Self {
+            bounds,
+            entries,
+            matches: Vec::new(),
+            query: String::new(),
+            chosen,
+            list_state: ListViewerState::new(),
+            state: 0,
+            palette_chain: None,
+        };
+        list.apply_query();
+        list
+    }
+
+    fn apply_query(&mut self) {
+        let mut scored: Vec<(u32, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                fuzzy_score(&self.query, &entry.text).map(|score| (score, index))
+            })
+            .collect();
+        // Stable sort: equal scores keep the menu order
+        scored.sort_by_key(|&(score, _)| Reverse(score));
+        self.matches = scored.into_iter().map(|(_, index)| index).collect();
+        self.list_state.set_range(self.matches.len());
+        if !self.matches.is_empty() {
+            let visible_rows = self.visible_rows();
+            self.list_state.focus_item(0, visible_rows);
+        }
+    }
+
+    fn entry_at(&self, item: usize) -> Option<&PaletteEntry> {
+        self.matches.get(item).map(|&index| &self.entries[index])
+    }
+
+    /// Edit the query with a key; returns true if the key was consumed
+    fn handle_query_key(&mut self, event: &Event) -> bool {
+        if event
+            .key_modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return false;
+        }
+        match event.key_code {
+            KB_BACKSPACE => {
+                self.query.pop();
+            }
+            key @ (0x20..0x7F | 0xA0..0x100) => {
+                self.query.extend(char::from_u32(u32::from(key)));
+            }
+            _ => return false,
+        }
+        self.apply_query();
+        true
+    }
+
+    /// Pick the focused entry: remember its command and end the dialog
+    fn choose(&mut self, event: &mut Event) {
+        let command = self
+            .list_state
+            .focused
+            .and_then(|item| self.entry_at(item))
+            .filter(|entry| entry.is_enabled())
+            .map(|entry| entry.command);
+        match command {
+            Some(command) => {
+                *self.chosen.borrow_mut() = Some(command);
+                *event = Event::command(CM_OK);
+            }
+            None => event.clear(),
+        }
+    }
+
+    /// List item under a screen row, if any (the first row is the query)
+    fn item_at_row(&self, y: i16) -> Option<usize> {
+        let row = usize::try_from(y - self.bounds.a.y - 1).ok()?;
+        let item = self.list_state.top_item + row;
+        (item < self.matches.len()).then_some(item)
+    }
+}
+
+impl View for PaletteList {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+        let color_input = self.map_color(PALETTE_INPUT);
+        let color_normal = self.map_color(PALETTE_NORMAL);
+        let color_selected = self.map_color(PALETTE_SELECTED);
+        let color_shortcut = self.map_color(PALETTE_SHORTCUT);
+        let color_disabled = self.map_color(PALETTE_DISABLED);
+
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', color_input, width);
+        buf.move_str(0, &format!("> {}", self.query), color_input);
+        write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
+
+        for (row, y) in (0..self.visible_rows()).zip(self.bounds.a.y + 1..) {
+            let mut buf = DrawBuffer::new(width);
+            let item = self.list_state.top_item + row;
+            match self.entry_at(item) {
+                Some(entry) => {
+                    let focused = Some(item) == self.list_state.focused;
+                    let (color, shortcut_color) = if focused {
+                        (color_selected, color_selected)
+                    } else if entry.is_enabled() {
+                        (color_normal, color_shortcut)
+                    } else {
+                        (color_disabled, color_disabled)
+                    };
+                    buf.move_char(0, ' ', color, width);
+                    buf.move_str(1, &entry.text, color);
+                    if let Some(shortcut) = &entry.shortcut {
+                        let len = shortcut.chars().count();
+                        if len + 2 < width {
+                            buf.move_str(width - len - 1, shortcut, shortcut_color);
+                        }
+                    }
+                }
+                None => buf.move_char(0, ' ', color_normal, width),
+            }
+            write_line_to_terminal(terminal, self.bounds.a.x, y, &buf);
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::Keyboard => {
+                if event.key_code == KB_ENTER {
+                    self.choose(event);
+                } else if self.handle_query_key(event) {
+                    event.clear();
+                } else {
+                    self.handle_list_event(event);
+                }
+            }
+            EventType::MouseDown => {
+                if !self.bounds.contains(event.mouse.pos)
+                    || event.mouse.buttons & MB_LEFT_BUTTON == 0
+                {
+                    return;
+                }
+                if let Some(item) = self.item_at_row(event.mouse.pos.y) {
+                    let visible_rows = self.visible_rows();
+                    self.list_state.focus_item(item, visible_rows);
+                    if event.mouse.double_click {
+                        self.choose(event);
+                        return;
+                    }
+                }
+                event.clear();
+            }
+            EventType::MouseWheelUp | EventType::MouseWheelDown
+                if self.bounds.contains(event.mouse.pos) =>
+            {
+                let visible_rows = self.visible_rows();
+                if event.what == EventType::MouseWheelUp {
+                    self.list_state.focus_prev(visible_rows);
+                } else {
+                    self.list_state.focus_next(visible_rows);
+                }
+                event.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        if self.is_focused() {
+            let column = i16::try_from(self.query.chars().count() + 2).unwrap_or(i16::MAX);
+            let x = self.bounds.a.x.saturating_add(column);
+            let _ = terminal.show_cursor(x.cast_unsigned(), self.bounds.a.y.cast_unsigned());
+        } else {
+            let _ = terminal.hide_cursor();
+        }
+    }
+
+    fn accessible(&self) -> Option<AccessibleInfo> {
+        let info = AccessibleInfo::new(Role::List, &self.query);
+        let focused = self.list_state.focused.and_then(|item| self.entry_at(item));
+        Some(match focused {
+            Some(entry) => info.with_value(entry.text.clone()),
+            None => info.with_value("empty"),
+        })
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_COMMAND_PALETTE))
+    }
+}
+
+impl ListViewer for PaletteList {
+    fn list_state(&self) -> &ListViewerState {
+        &self.list_state
+    }
+
+    fn list_state_mut(&mut self) -> &mut ListViewerState {
+        &mut self.list_state
+    }
+
+    fn get_text(&self, item: usize, _max_len: usize) -> String {
+        self.entry_at(item)
+            .map(|entry| entry.text.clone())
+            .unwrap_or_default()
+    }
+
+    /// Rows below the query line
+    fn visible_rows(&self) -> usize {
+        usize::from(self.bounds.height_clamped().cast_unsigned()).saturating_sub(1)
+    }
+}
+
+/// Command palette dialog
+pub struct CommandPalette {
+    dialog: Dialog,
+    chosen: Rc<RefCell<Option<CommandId>>>,
+}
+
+impl CommandPalette {
+    /// Create a palette offering `entries`, in this order while the query
+    /// is empty
+    pub fn new(bounds: Rect, title: &str, entries: Vec<PaletteEntry>) -> Self {
+        let mut dialog = Dialog::new(bounds, title);
+        let chosen = Rc::new(RefCell::new(None));
+        let list = PaletteList::new(
+            Rect::new(1, 1, bounds.width() - 1, bounds.height() - 1),
+            entries,
+            Rc::clone(&chosen),
+        );
+        dialog.add(Box::new(list));
+        dialog.set_initial_focus();
+        Self { dialog, chosen }
+    }
+
+    /// Execute the palette modally
+    ///
+    /// Returns the picked command, or None if the palette was cancelled
+    pub fn execute(&mut self, app: &mut crate::app::Application) -> Option<CommandId> {
+        *self.chosen.borrow_mut() = None;
+        if self.dialog.execute(app) == CM_OK {
+            *self.chosen.borrow()
+        } else {
+            None
+        }
+    }
+}
+
+impl View for CommandPalette {
+    fn bounds(&self) -> Rect {
+        self.dialog.bounds()
+    }
+
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.dialog.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.dialog.subviews()
+    }
+
+    fn accessible(&self) -> Option<AccessibleInfo> {
+        self.dialog.accessible()
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.dialog.set_bounds(bounds);
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        self.dialog.draw(terminal);
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        self.dialog.handle_event(event);
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn state(&self) -> StateFlags {
+        self.dialog.state()
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.dialog.set_state(state);
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        self.dialog.get_palette()
+    }
+}
+
+/// Builder for creating command palettes with a fluent API.
+///
+/// # Examples
+///
+/// ```ignore
+/// use turbo_vision::views::command_palette::{CommandPaletteBuilder, PaletteEntry};
+/// use turbo_vision::core::command::CM_OPEN;
+/// use turbo_vision::core::geometry::Rect;
+///
+/// let palette = CommandPaletteBuilder::new()
+///     .bounds(Rect::new(10, 2, 70, 20))
+///     .entries(menu_entries(&menu_bar))
+///     .entry(PaletteEntry::new("Open recent", CM_OPEN_RECENT))
+///     .build();
+/// ```
+pub struct CommandPaletteBuilder {
+    bounds: Option<Rect>,
+    title: String,
+    entries: Vec<PaletteEntry>,
+}
+
+impl CommandPaletteBuilder {
+    /// Creates a new `CommandPaletteBuilder` with default values.
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            title: "Commands".to_string(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Sets the palette bounds (required).
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the palette title (default: "Commands").
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Adds the given entries.
+    #[must_use]
+    pub fn entries(mut self, entries: Vec<PaletteEntry>) -> Self {
+        self.entries.extend(entries);
+        self
+    }
+
+    /// Adds a single entry.
+    #[must_use]
+    pub fn entry(mut self, entry: PaletteEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Builds the `CommandPalette`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if bounds have not been set.
+    pub fn build(self) -> CommandPalette {
+        let bounds = self.bounds.expect("CommandPalette bounds must be set");
+        CommandPalette::new(bounds, &self.title, self.entries)
+    }
+}
+
+impl Default for CommandPaletteBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::{CM_CUT, CM_FIND, CM_NEW, CM_OPEN, CM_QUIT};
+    use crate::core::event::{KB_DOWN, KB_F3};
+    use crate::core::menu_data::MenuBuilder;
+    use crate::views::menu_bar::SubMenu;
+
+    fn list(entries: Vec<PaletteEntry>) -> (PaletteList, Rc<RefCell<Option<CommandId>>>) {
+        let chosen = Rc::new(RefCell::new(None));
+        let list = PaletteList::new(Rect::new(0, 0, 40, 8), entries, Rc::clone(&chosen));
+        (list, chosen)
+    }
+
+    fn type_text(list: &mut PaletteList, text: &str) {
+        for ch in text.chars() {
+            list.handle_event(&mut Event::keyboard(ch as u16));
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        assert_eq!(fuzzy_score("xyz", "File: Open"), None);
+        assert!(fuzzy_score("OPEN", "File: Open").is_some());
+        // Adjacent and word-start characters rank higher
+        assert!(fuzzy_score("fo", "Format") > fuzzy_score("fo", "File: Open"));
+        assert!(fuzzy_score("fo", "File: Open") > fuzzy_score("fo", "Info"));
+    }
+
+    #[test]
+    fn test_menu_entries() {
+        let mut menu_bar = MenuBar::new(Rect::new(0, 0, 80, 1));
+        let recent = MenuBuilder::new().item("~n~otes.txt", CM_OPEN, 0).build();
+        let file = MenuBuilder::new()
+            .item_with_shortcut("~N~ew", CM_NEW, 0, "Ctrl+N")
+            .item("~O~pen...", CM_OPEN, KB_F3)
+            .submenu("~R~ecent", 0, recent)
+            .separator()
+            .item("E~x~it", CM_QUIT, 0)
+            .build();
+        menu_bar.add_submenu(SubMenu::new("~F~ile", file));
+
+        let entries = menu_entries(&menu_bar);
+        let texts: Vec<&str> = entries.iter().map(|entry| entry.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "File: New",
+                "File: Open...",
+                "File: Recent: notes.txt",
+                "File: Exit"
+            ]
+        );
+        assert_eq!(entries[0].shortcut.as_deref(), Some("Ctrl+N"));
+        assert_eq!(entries[1].shortcut.as_deref(), Some("F3"));
+        assert_eq!(entries[3].shortcut, None);
+    }
+
+    #[test]
+    fn test_palette_filters_and_chooses() {
+        let (mut list, chosen) = list(vec![
+            PaletteEntry::new("File: ~O~pen", CM_OPEN),
+            PaletteEntry::new("Search: ~F~ind", CM_FIND),
+            PaletteEntry::new("File: ~N~ew", CM_NEW),
+        ]);
+        assert_eq!(list.matches.len(), 3);
+
+        type_text(&mut list, "fin");
+        assert_eq!(list.query, "fin");
+        assert_eq!(list.get_text(0, 40), "Search: Find");
+
+        list.handle_event(&mut Event::keyboard(KB_BACKSPACE));
+        list.handle_event(&mut Event::keyboard(KB_BACKSPACE));
+        assert_eq!(list.query, "f");
+        list.handle_event(&mut Event::keyboard(KB_DOWN));
+
+        let mut event = Event::keyboard(KB_ENTER);
+        list.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Command);
+        assert_eq!(event.command, CM_OK);
+        assert_eq!(*chosen.borrow(), Some(list.entry_at(1).unwrap().command));
+    }
+
+    #[test]
+    fn test_palette_skips_disabled_entries() {
+        let mut entry = PaletteEntry::new("Edit: Cu~t~", CM_CUT);
+        entry.enabled = false;
+        let (mut list, chosen) = list(vec![entry]);
+
+        let mut event = Event::keyboard(KB_ENTER);
+        list.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(*chosen.borrow(), None);
+    }
+}
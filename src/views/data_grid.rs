@@ -0,0 +1,1053 @@
+// (C) 2026 - Enzo Lombardi
+
+//! DataGrid view - multi-column table with headers, sorting and resizable columns.
+//!
+//! A [`DataGrid`] shows the rows of a [`GridDataSource`] in typed columns:
+//!
+//! - a header row with the column titles; clicking a sortable header sorts
+//!   by that column (clicking again reverses the order), and dragging the
+//!   divider at the right end of a header resizes the column
+//! - per-column alignment and per-cell colors ([`GridDataSource::cell_color`])
+//! - single or multiple selection: in [`SelectionMode::Multiple`] the keys and
+//!   clicks of multi-select list boxes apply (Space/Ins toggle the focused
+//!   row, Shift+arrows and Shift+click select a range, Ctrl+click toggles a
+//!   row, Ctrl+A selects all)
+//! - horizontal scrolling by whole columns (Left/Right) and optional
+//!   scrollbars
+//! - type-ahead search in the sorted column (the first one when unsorted)
+//!
+//! The grid only asks the data source for the rows on screen, so sources
+//! backed by files or databases can expose millions of rows.
+//!
+//! Navigation builds on [`ListViewerState`], like [`ListBox`](super::listbox::ListBox).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::data_grid::{Alignment, Column, DataGrid};
+//!
+//! let rows = vec![
+//!     vec!["main.rs".to_string(), "1204".to_string()],
+//!     vec!["lib.rs".to_string(), "87".to_string()],
+//! ];
+//! let columns = vec![
+//!     Column::new("Name", 20),
+//!     Column::new("Size", 8).align(Alignment::Right),
+//! ];
+//! let mut grid = DataGrid::new(Rect::new(0, 0, 40, 10), columns, Box::new(rows), 0);
+//! grid.sort_by(1, turbo_vision::views::data_grid::SortDirection::Ascending);
+//! assert_eq!(grid.source().cell_text(0, 0), "lib.rs");
+//! ```
+
+use super::list_viewer::{ListViewer, ListViewerState};
+use super::scrollbar::ScrollBar;
+use super::view::{View, write_line_to_terminal};
+use crate::core::command::CommandId;
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{Event, EventType, KB_ENTER, KB_LEFT, KB_RIGHT, MB_LEFT_BUTTON};
+use crate::core::geometry::Rect;
+use crate::core::palette::{
+    Attr, LISTBOX_DIVIDER, LISTBOX_FOCUSED, LISTBOX_NORMAL, LISTBOX_SELECTED,
+};
+use crate::core::state::StateFlags;
+use crate::terminal::Terminal;
+use crossterm::event::KeyModifiers;
+use std::cmp::Ordering;
+use unicode_width::UnicodeWidthChar;
+
+/// Horizontal alignment of the text in a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Sort order of a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// The opposite direction
+    #[must_use]
+    pub fn reversed(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+/// How many rows can be selected at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// The focused row is the selection
+    #[default]
+    Single,
+    /// Any set of rows; the focused row moves independently of the selection
+    Multiple,
+}
+
+/// Column definition: header title, width and alignment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub title: String,
+    /// Width in columns, not counting the divider
+    pub width: u16,
+    /// Smallest width the user can drag the column to
+    pub min_width: u16,
+    pub alignment: Alignment,
+    /// Whether clicking the header sorts by this column
+    pub sortable: bool,
+}
+
+impl Column {
+    /// Left-aligned, sortable column
+    pub fn new(title: &str, width: u16) -> Self {
+        Self {
+            title: title.to_string(),
+            width,
+            min_width: 1,
+            alignment: Alignment::Left,
+            sortable: true,
+        }
+    }
+
+    #[must_use]
+    pub fn align(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    #[must_use]
+    pub fn min_width(mut self, min_width: u16) -> Self {
+        self.min_width = min_width.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// Rows shown by a [`DataGrid`]
+///
+/// Only the rows on screen are requested, so implementations can load them
+/// lazily.
+pub trait GridDataSource {
+    /// Total number of rows
+    fn row_count(&self) -> usize;
+
+    /// Text of a cell
+    fn cell_text(&self, row: usize, column: usize) -> String;
+
+    /// Color of a cell, or `None` for the grid's normal color
+    ///
+    /// Not used for selected rows, which are always drawn in the selection color.
+    fn cell_color(&self, _row: usize, _column: usize) -> Option<Attr> {
+        None
+    }
+
+    /// Reorder the rows by `column`; return `false` if sorting is not supported
+    fn sort(&mut self, _column: usize, _direction: SortDirection) -> bool {
+        false
+    }
+}
+
+/// In-memory rows; sorting compares cells with [`compare_cells`]
+impl GridDataSource for Vec<Vec<String>> {
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn cell_text(&self, row: usize, column: usize) -> String {
+        self.get(row)
+            .and_then(|cells| cells.get(column))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn sort(&mut self, column: usize, direction: SortDirection) -> bool {
+        let empty = String::new();
+        self.sort_by(|a, b| {
+            let order = compare_cells(
+                a.get(column).unwrap_or(&empty),
+                b.get(column).unwrap_or(&empty),
+            );
+            match direction {
+                SortDirection::Ascending => order,
+                SortDirection::Descending => order.reverse(),
+            }
+        });
+        true
+    }
+}
+
+/// Compare two cell texts: numerically if both are numbers, else as text
+/// (case-insensitive first, then exact)
+pub fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.total_cmp(&y),
+        _ => a
+            .to_lowercase()
+            .cmp(&b.to_lowercase())
+            .then_with(|| a.cmp(b)),
+    }
+}
+
+/// Column divider drag in progress
+#[derive(Debug, Clone, Copy)]
+struct ColumnDrag {
+    column: usize,
+    start_x: i16,
+    start_width: u16,
+}
+
+/// DataGrid - scrollable table of rows with typed columns
+pub struct DataGrid {
+    bounds: Rect,
+    columns: Vec<Column>,
+    source: Box<dyn GridDataSource>,
+    list_state: ListViewerState,
+    state: StateFlags,
+    on_select_command: CommandId,
+    /// First column shown at the left edge (horizontal scroll position)
+    left_column: usize,
+    sort: Option<(usize, SortDirection)>,
+    drag: Option<ColumnDrag>,
+    h_scrollbar: Option<Box<ScrollBar>>,
+    v_scrollbar: Option<Box<ScrollBar>>,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl DataGrid {
+    /// Create a grid; `on_select_command` is sent on Enter or double-click
+    pub fn new(
+        bounds: Rect,
+        columns: Vec<Column>,
+        source: Box<dyn GridDataSource>,
+        on_select_command: CommandId,
+    ) -> Self {
+        let list_state = ListViewerState::with_range(source.row_count());
+        Self {
+            bounds,
+            columns,
+            source,
+            list_state,
+            state: 0,
+            on_select_command,
+            left_column: 0,
+            sort: None,
+            drag: None,
+            h_scrollbar: None,
+            v_scrollbar: None,
+            palette_chain: None,
+        }
+    }
+
+    /// Add a vertical scrollbar on the right edge and a horizontal one at the bottom
+    #[must_use]
+    pub fn with_scrollbars(mut self, add_scrollbars: bool) -> Self {
+        if add_scrollbars {
+            self.v_scrollbar = Some(Box::new(ScrollBar::new_vertical(Rect::new(0, 0, 1, 1))));
+            self.h_scrollbar = Some(Box::new(ScrollBar::new_horizontal(Rect::new(0, 0, 1, 1))));
+            self.layout_scrollbars();
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.set_selection_mode(mode);
+        self
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        if self.list_state.multi_select {
+            SelectionMode::Multiple
+        } else {
+            SelectionMode::Single
+        }
+    }
+
+    /// Change the selection mode; clears the selection
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        self.list_state
+            .set_multi_select(mode == SelectionMode::Multiple);
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Set the width of `column` (at least its minimum width)
+    pub fn set_column_width(&mut self, column: usize, width: u16) {
+        if let Some(col) = self.columns.get_mut(column) {
+            col.width = width.max(col.min_width);
+            self.update_scrollbars();
+        }
+    }
+
+    pub fn source(&self) -> &dyn GridDataSource {
+        self.source.as_ref()
+    }
+
+    /// Mutable access to the data; call [`refresh`](Self::refresh) after changing rows
+    pub fn source_mut(&mut self) -> &mut dyn GridDataSource {
+        self.source.as_mut()
+    }
+
+    /// Re-read the row count after the data source changed
+    pub fn refresh(&mut self) {
+        let rows = self.source.row_count();
+        self.list_state.set_range(rows);
+        self.update_scrollbars();
+    }
+
+    /// Sort by `column` if it is sortable and the source supports sorting
+    ///
+    /// The selection is cleared, since row indices change.
+    pub fn sort_by(&mut self, column: usize, direction: SortDirection) {
+        if !self.columns.get(column).is_some_and(|c| c.sortable) {
+            return;
+        }
+        if self.source.sort(column, direction) {
+            self.sort = Some((column, direction));
+            self.list_state.clear_selection();
+        }
+    }
+
+    /// Column and direction of the current sort order
+    pub fn sort_order(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    pub fn focused_row(&self) -> Option<usize> {
+        self.list_state.focused
+    }
+
+    pub fn set_focused_row(&mut self, row: usize) {
+        let visible_rows = self.visible_rows();
+        self.list_state.focus_item(row, visible_rows);
+        self.update_scrollbars();
+    }
+
+    /// Selected rows in ascending order
+    ///
+    /// In single selection mode this is the focused row.
+    pub fn selected_rows(&self) -> Vec<usize> {
+        self.list_state.selected_indices()
+    }
+
+    pub fn is_row_selected(&self, row: usize) -> bool {
+        self.list_state.is_item_selected(row)
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.list_state.clear_selection();
+    }
+
+    /// First column shown at the left edge
+    pub fn left_column(&self) -> usize {
+        self.left_column
+    }
+
+    /// Scroll horizontally so that `column` is the first one shown
+    pub fn set_left_column(&mut self, column: usize) {
+        self.left_column = column.min(self.columns.len().saturating_sub(1));
+        self.update_scrollbars();
+    }
+
+    /// Area for the header and rows (bounds minus scrollbars)
+    fn content_area(&self) -> Rect {
+        let mut area = self.bounds;
+        if self.v_scrollbar.is_some() {
+            area.b.x -= 1;
+        }
+        if self.h_scrollbar.is_some() {
+            area.b.y -= 1;
+        }
+        area
+    }
+
+    fn layout_scrollbars(&mut self) {
+        let bounds = self.bounds;
+        let has_h = self.h_scrollbar.is_some();
+        let has_v = self.v_scrollbar.is_some();
+        if let Some(ref mut v_bar) = self.v_scrollbar {
+            v_bar.set_bounds(Rect::new(
+                bounds.b.x - 1,
+                bounds.a.y + 1, // Beside the rows, not the header
+                bounds.b.x,
+                bounds.b.y - i16::from(has_h),
+            ));
+        }
+        if let Some(ref mut h_bar) = self.h_scrollbar {
+            h_bar.set_bounds(Rect::new(
+                bounds.a.x,
+                bounds.b.y - 1,
+                bounds.b.x - i16::from(has_v),
+                bounds.b.y,
+            ));
+        }
+        self.update_scrollbars();
+    }
+
+    fn update_scrollbars(&mut self) {
+        let visible_rows = self.visible_rows();
+        let rows = self.list_state.range;
+        let to_i32 = |n: usize| i32::try_from(n).unwrap_or(i32::MAX);
+        if let Some(ref mut v_bar) = self.v_scrollbar {
+            v_bar.set_params(
+                to_i32(self.list_state.top_item),
+                0,
+                to_i32(rows.saturating_sub(visible_rows)),
+                to_i32(visible_rows.max(1)),
+                1,
+            );
+            v_bar.set_total(to_i32(rows));
+        }
+        let columns = self.columns.len();
+        if let Some(ref mut h_bar) = self.h_scrollbar {
+            h_bar.set_params(
+                to_i32(self.left_column),
+                0,
+                to_i32(columns.saturating_sub(1)),
+                1,
+                1,
+            );
+        }
+    }
+
+    /// Screen x where each shown column starts, with the column index
+    fn column_starts(&self) -> Vec<(usize, i16)> {
+        let mut x = self.content_area().a.x;
+        let mut starts = Vec::new();
+        for (i, column) in self.columns.iter().enumerate().skip(self.left_column) {
+            starts.push((i, x));
+            x = x
+                .saturating_add(column.width.cast_signed())
+                .saturating_add(1);
+        }
+        starts
+    }
+
+    /// Column whose divider is at screen x (the cell right after its text)
+    fn divider_at(&self, x: i16) -> Option<usize> {
+        self.column_starts()
+            .into_iter()
+            .find(|&(i, start)| start + self.columns[i].width.cast_signed() == x)
+            .map(|(i, _)| i)
+    }
+
+    /// Column shown at screen x
+    fn column_at(&self, x: i16) -> Option<usize> {
+        self.column_starts()
+            .into_iter()
+            .find(|&(i, start)| x >= start && x < start + self.columns[i].width.cast_signed())
+            .map(|(i, _)| i)
+    }
+
+    /// Column searched by type-ahead: the sorted column, else the first one
+    fn search_column(&self) -> usize {
+        self.sort.map_or(0, |(column, _)| column)
+    }
+
+    /// Row text used for accessibility and `ListViewer::get_text`
+    fn row_text(&self, row: usize) -> String {
+        (0..self.columns.len())
+            .map(|column| self.source.cell_text(row, column))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn scroll_horizontally(&mut self, delta: isize) {
+        let column = self.left_column.saturating_add_signed(delta);
+        self.set_left_column(column);
+    }
+
+    fn handle_keyboard(&mut self, event: &mut Event) {
+        match event.key_code {
+            KB_LEFT => self.scroll_horizontally(-1),
+            KB_RIGHT => self.scroll_horizontally(1),
+            KB_ENTER => {
+                *event = Event::command(self.on_select_command);
+                return;
+            }
+            // Vertical navigation and selection keys are shared with list boxes
+            _ => {
+                if self.handle_list_event(event) {
+                    self.update_scrollbars();
+                }
+                return;
+            }
+        }
+        event.clear();
+    }
+
+    fn handle_mouse_down(&mut self, event: &mut Event) {
+        let pos = event.mouse.pos;
+        let area = self.content_area();
+        if event.mouse.buttons & MB_LEFT_BUTTON == 0 || !area.contains(pos) {
+            return;
+        }
+
+        // Header row: resize on a divider, sort elsewhere
+        if pos.y == area.a.y {
+            if let Some(column) = self.divider_at(pos.x) {
+                self.drag = Some(ColumnDrag {
+                    column,
+                    start_x: pos.x,
+                    start_width: self.columns[column].width,
+                });
+            } else if let Some(column) = self.column_at(pos.x) {
+                let direction = match self.sort {
+                    Some((sorted, direction)) if sorted == column => direction.reversed(),
+                    _ => SortDirection::Ascending,
+                };
+                self.sort_by(column, direction);
+            }
+            event.clear();
+            return;
+        }
+
+        let row = self.list_state.top_item + (pos.y - area.a.y - 1).cast_unsigned() as usize;
+        if row >= self.list_state.range {
+            event.clear();
+            return;
+        }
+        let visible_rows = self.visible_rows();
+        self.list_state.focus_item(row, visible_rows);
+        if self.list_state.multi_select {
+            if event.key_modifiers.contains(KeyModifiers::SHIFT) {
+                self.list_state.select_range(row);
+            } else if event.key_modifiers.contains(KeyModifiers::CONTROL) {
+                self.list_state.toggle_selected(row);
+            } else {
+                self.list_state.select_only(row);
+            }
+        }
+        self.update_scrollbars();
+
+        if event.mouse.double_click {
+            *event = Event::command(self.on_select_command);
+        } else {
+            event.clear();
+        }
+    }
+
+    /// Forward an event to the scrollbars and follow their new positions
+    fn handle_scrollbar_event(&mut self, event: &mut Event) {
+        let pos = event.mouse.pos;
+        let dragging = matches!(event.what, EventType::MouseMove | EventType::MouseUp);
+        if let Some(ref mut v_bar) = self.v_scrollbar {
+            if dragging || v_bar.bounds().contains(pos) {
+                v_bar.handle_event(event);
+                let top = usize::try_from(v_bar.get_value()).unwrap_or(0);
+                if top != self.list_state.top_item {
+                    self.list_state.top_item = top;
+                }
+            }
+        }
+        if let Some(ref mut h_bar) = self.h_scrollbar {
+            if dragging || h_bar.bounds().contains(pos) {
+                h_bar.handle_event(event);
+                self.left_column = usize::try_from(h_bar.get_value()).unwrap_or(0);
+            }
+        }
+    }
+
+    /// Pad or cut `text` to exactly `width` columns with the given alignment
+    fn fit(text: &str, width: usize, alignment: Alignment) -> String {
+        let mut fitted = String::new();
+        let mut used = 0;
+        for ch in text.chars() {
+            let w = ch.width().unwrap_or(0);
+            if used + w > width {
+                break;
+            }
+            fitted.push(ch);
+            used += w;
+        }
+        let pad = width - used;
+        let (left, right) = match alignment {
+            Alignment::Left => (0, pad),
+            Alignment::Center => (pad / 2, pad - pad / 2),
+            Alignment::Right => (pad, 0),
+        };
+        format!("{}{fitted}{}", " ".repeat(left), " ".repeat(right))
+    }
+}
+
+impl View for DataGrid {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        self.layout_scrollbars();
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let area = self.content_area();
+        let width = usize::from(area.width_clamped().cast_unsigned());
+        let height = usize::from(area.height_clamped().cast_unsigned());
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let color_normal = if self.is_focused() {
+            self.map_color(LISTBOX_FOCUSED)
+        } else {
+            self.map_color(LISTBOX_NORMAL)
+        };
+        let color_selected = self.map_color(LISTBOX_SELECTED);
+        let color_header = self.map_color(LISTBOX_DIVIDER);
+        let starts = self.column_starts();
+
+        // Header: titles, sort indicator and dividers
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', color_header, width);
+        for &(i, start) in &starts {
+            let x = (start - area.a.x).cast_unsigned() as usize;
+            let column = &self.columns[i];
+            let col_width = column.width as usize;
+            let mut title = Self::fit(&column.title, col_width, column.alignment);
+            if let Some((sorted, direction)) = self.sort {
+                if sorted == i && col_width > 0 {
+                    let arrow = match direction {
+                        SortDirection::Ascending => '▲',
+                        SortDirection::Descending => '▼',
+                    };
+                    title = Self::fit(&column.title, col_width - 1, column.alignment);
+                    title.push(arrow);
+                }
+            }
+            buf.move_str(x, &title, color_header);
+            buf.put_char(x + col_width, '│', color_header);
+        }
+        write_line_to_terminal(terminal, area.a.x, area.a.y, &buf);
+
+        // Rows
+        for (line, y) in (1..height).zip(area.a.y + 1..) {
+            let mut buf = DrawBuffer::new(width);
+            let row = self.list_state.top_item + line - 1;
+            if row < self.list_state.range {
+                let is_cursor = self.list_state.focused == Some(row);
+                let row_color = if self.is_row_selected(row) {
+                    color_selected
+                } else if is_cursor {
+                    // Focused but unselected row in multiple selection mode
+                    color_header
+                } else {
+                    color_normal
+                };
+                buf.move_char(0, ' ', row_color, width);
+                for &(i, start) in &starts {
+                    let x = (start - area.a.x).cast_unsigned() as usize;
+                    let column = &self.columns[i];
+                    let text = self.source.cell_text(row, i);
+                    let color = if row_color == color_normal {
+                        self.source.cell_color(row, i).unwrap_or(color_normal)
+                    } else {
+                        row_color
+                    };
+                    let col_width = column.width as usize;
+                    buf.move_str(x, &Self::fit(&text, col_width, column.alignment), color);
+                    buf.put_char(x + col_width, '│', row_color);
+                }
+            } else {
+                buf.move_char(0, ' ', color_normal, width);
+            }
+            // Matches are highlighted in the searched column when it is left-aligned
+            let search_x = starts
+                .iter()
+                .find(|&&(i, _)| {
+                    i == self.search_column() && self.columns[i].alignment == Alignment::Left
+                })
+                .map_or(width, |&(_, start)| {
+                    (start - area.a.x).cast_unsigned() as usize
+                });
+            self.draw_type_ahead(&mut buf, row, search_x, line - 1);
+            write_line_to_terminal(terminal, area.a.x, y, &buf);
+        }
+
+        if let Some(ref mut v_bar) = self.v_scrollbar {
+            v_bar.draw(terminal);
+        }
+        if let Some(ref mut h_bar) = self.h_scrollbar {
+            h_bar.draw(terminal);
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        // Column divider drag
+        if let Some(drag) = self.drag {
+            match event.what {
+                EventType::MouseMove => {
+                    let width =
+                        i32::from(drag.start_width) + i32::from(event.mouse.pos.x - drag.start_x);
+                    self.set_column_width(drag.column, u16::try_from(width).unwrap_or(0));
+                    event.clear();
+                    return;
+                }
+                EventType::MouseUp => {
+                    self.drag = None;
+                    event.clear();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        match event.what {
+            EventType::Keyboard if self.is_focused() => self.handle_keyboard(event),
+            EventType::MouseDown => {
+                self.handle_scrollbar_event(event);
+                if event.what == EventType::MouseDown {
+                    self.handle_mouse_down(event);
+                }
+            }
+            EventType::MouseMove | EventType::MouseUp => self.handle_scrollbar_event(event),
+            EventType::MouseWheelUp | EventType::MouseWheelDown
+                if self.bounds.contains(event.mouse.pos) =>
+            {
+                let visible_rows = self.visible_rows();
+                if event.what == EventType::MouseWheelUp {
+                    self.list_state.focus_prev(visible_rows);
+                } else {
+                    self.list_state.focus_next(visible_rows);
+                }
+                self.update_scrollbars();
+                event.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn set_list_selection(&mut self, index: usize) {
+        self.set_focused_row(index);
+    }
+
+    fn get_list_selection(&self) -> usize {
+        self.list_state.focused.unwrap_or(0)
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let info = AccessibleInfo::new(Role::Table, "");
+        Some(match self.list_state.focused {
+            Some(row) => info.with_value(format!(
+                "{}, row {} of {}",
+                self.row_text(row),
+                row + 1,
+                self.list_state.range
+            )),
+            None => info.with_value("empty"),
+        })
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_LISTBOX))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ListViewer for DataGrid {
+    fn list_state(&self) -> &ListViewerState {
+        &self.list_state
+    }
+
+    fn list_state_mut(&mut self) -> &mut ListViewerState {
+        &mut self.list_state
+    }
+
+    fn get_text(&self, item: usize, max_len: usize) -> String {
+        self.row_text(item).chars().take(max_len).collect()
+    }
+
+    fn is_selected(&self, item: usize) -> bool {
+        self.is_row_selected(item)
+    }
+
+    fn search_text(&self, item: usize) -> String {
+        if self.columns.is_empty() {
+            return String::new();
+        }
+        self.source.cell_text(item, self.search_column())
+    }
+
+    /// Rows below the header
+    fn visible_rows(&self) -> usize {
+        usize::from(self.content_area().height_clamped().cast_unsigned()).saturating_sub(1)
+    }
+}
+
+/// Builder for creating data grids with a fluent API.
+pub struct DataGridBuilder {
+    bounds: Option<Rect>,
+    columns: Vec<Column>,
+    source: Option<Box<dyn GridDataSource>>,
+    on_select_command: CommandId,
+    selection_mode: SelectionMode,
+    scrollbars: bool,
+}
+
+impl DataGridBuilder {
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            columns: Vec::new(),
+            source: None,
+            on_select_command: 0,
+            selection_mode: SelectionMode::Single,
+            scrollbars: false,
+        }
+    }
+
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    #[must_use]
+    pub fn column(mut self, column: Column) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    #[must_use]
+    pub fn source(mut self, source: Box<dyn GridDataSource>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    #[must_use]
+    pub fn on_select_command(mut self, command: CommandId) -> Self {
+        self.on_select_command = command;
+        self
+    }
+
+    #[must_use]
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    #[must_use]
+    pub fn scrollbars(mut self, scrollbars: bool) -> Self {
+        self.scrollbars = scrollbars;
+        self
+    }
+
+    pub fn build(self) -> DataGrid {
+        let bounds = self.bounds.expect("DataGrid bounds must be set");
+        let source = self
+            .source
+            .unwrap_or_else(|| Box::new(Vec::<Vec<String>>::new()));
+        DataGrid::new(bounds, self.columns, source, self.on_select_command)
+            .with_selection_mode(self.selection_mode)
+            .with_scrollbars(self.scrollbars)
+    }
+
+    pub fn build_boxed(self) -> Box<DataGrid> {
+        Box::new(self.build())
+    }
+}
+
+impl Default for DataGridBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::{KB_DOWN, KB_END};
+    use crate::core::geometry::Point;
+    use crate::test_util::snapshot::Snapshot;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn files() -> Vec<Vec<String>> {
+        [("main.rs", "1204"), ("lib.rs", "87"), ("app.rs", "950")]
+            .iter()
+            .map(|(name, size)| vec![name.to_string(), size.to_string()])
+            .collect()
+    }
+
+    fn grid() -> DataGrid {
+        let columns = vec![
+            Column::new("Name", 8),
+            Column::new("Size", 5).align(Alignment::Right),
+        ];
+        DataGrid::new(Rect::new(0, 0, 20, 5), columns, Box::new(files()), 0)
+    }
+
+    fn click(x: i16, y: i16) -> Event {
+        Event::mouse(
+            EventType::MouseDown,
+            Point::new(x, y),
+            MB_LEFT_BUTTON,
+            false,
+        )
+    }
+
+    #[test]
+    fn draws_header_and_aligned_cells() {
+        let mut grid = grid();
+        let lines = Snapshot::of_view(&mut grid, 20, 5).text_lines();
+        assert_eq!(lines[0], "Name    │ Size│     ");
+        assert_eq!(lines[1], "main.rs │ 1204│     ");
+        assert_eq!(lines[2], "lib.rs  │   87│     ");
+    }
+
+    #[test]
+    fn header_click_sorts_and_reverses() {
+        let mut grid = grid();
+        grid.handle_event(&mut click(10, 0));
+        assert_eq!(grid.sort_order(), Some((1, SortDirection::Ascending)));
+        assert_eq!(grid.source().cell_text(0, 0), "lib.rs");
+
+        grid.handle_event(&mut click(10, 0));
+        assert_eq!(grid.sort_order(), Some((1, SortDirection::Descending)));
+        assert_eq!(grid.source().cell_text(0, 0), "main.rs");
+
+        let lines = Snapshot::of_view(&mut grid, 20, 5).text_lines();
+        assert_eq!(lines[0], "Name    │Size▼│     ");
+    }
+
+    #[test]
+    fn dragging_divider_resizes_column() {
+        let mut grid = grid();
+        grid.handle_event(&mut click(8, 0));
+        let mut drag = Event::mouse(
+            EventType::MouseMove,
+            Point::new(12, 0),
+            MB_LEFT_BUTTON,
+            false,
+        );
+        grid.handle_event(&mut drag);
+        grid.handle_event(&mut Event::mouse(
+            EventType::MouseUp,
+            Point::new(12, 0),
+            0,
+            false,
+        ));
+        assert_eq!(grid.columns()[0].width, 12);
+        assert_eq!(grid.sort_order(), None);
+
+        // Never narrower than the minimum width
+        grid.handle_event(&mut click(12, 0));
+        let mut drag = Event::mouse(
+            EventType::MouseMove,
+            Point::new(0, 0),
+            MB_LEFT_BUTTON,
+            false,
+        );
+        grid.handle_event(&mut drag);
+        assert_eq!(grid.columns()[0].width, 1);
+    }
+
+    #[test]
+    fn multiple_selection_with_space_shift_and_ctrl() {
+        let mut grid = grid().with_selection_mode(SelectionMode::Multiple);
+        grid.set_focus(true);
+
+        grid.handle_event(&mut Event::keyboard(' ' as u16));
+        let mut shift_down = Event::keyboard(KB_DOWN);
+        shift_down.key_modifiers = KeyModifiers::SHIFT;
+        grid.handle_event(&mut shift_down);
+        assert_eq!(grid.selected_rows(), [0, 1]);
+
+        let mut ctrl_click = click(3, 3);
+        ctrl_click.key_modifiers = KeyModifiers::CONTROL;
+        grid.handle_event(&mut ctrl_click);
+        assert_eq!(grid.selected_rows(), [0, 1, 2]);
+
+        grid.handle_event(&mut click(3, 1));
+        assert_eq!(grid.selected_rows(), [0]);
+    }
+
+    #[test]
+    fn horizontal_scroll_by_column() {
+        let mut grid = grid();
+        grid.set_focus(true);
+        grid.handle_event(&mut Event::keyboard(KB_RIGHT));
+        assert_eq!(grid.left_column(), 1);
+        let lines = Snapshot::of_view(&mut grid, 20, 5).text_lines();
+        assert_eq!(lines[1], " 1204│              ");
+        grid.handle_event(&mut Event::keyboard(KB_RIGHT));
+        assert_eq!(grid.left_column(), 1);
+    }
+
+    /// A million rows, counting how many cells the grid asks for
+    struct Numbers(Rc<Cell<usize>>);
+
+    impl GridDataSource for Numbers {
+        fn row_count(&self) -> usize {
+            1_000_000
+        }
+
+        fn cell_text(&self, row: usize, _column: usize) -> String {
+            self.0.set(self.0.get() + 1);
+            row.to_string()
+        }
+    }
+
+    #[test]
+    fn only_visible_rows_are_requested() {
+        let requests = Rc::new(Cell::new(0));
+        let source = Box::new(Numbers(Rc::clone(&requests)));
+        let mut grid = DataGrid::new(
+            Rect::new(0, 0, 20, 6),
+            vec![Column::new("N", 10)],
+            source,
+            0,
+        );
+        grid.set_focus(true);
+        grid.handle_event(&mut Event::keyboard(KB_END));
+
+        let lines = Snapshot::of_view(&mut grid, 20, 6).text_lines();
+        assert_eq!(requests.get(), 5);
+        assert_eq!(lines[5].trim_end(), "999999    │");
+    }
+}
@@ -0,0 +1,1715 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Date and time picker views - fields edited part by part, plus a month calendar.
+//!
+//! A [`DatePicker`] shows a date in the order and with the separator of the
+//! current locale ([`DateFormat::current`]): `18.10.2026` in German,
+//! `18/10/2026` in Italian, `10/18/2026` in US English and `2026-10-18`
+//! otherwise. Left and Right move between day, month and year; Up and Down
+//! change the part under the cursor; digits type over it, moving on once the
+//! part is complete. Alt+Down, F4 or the `▼` button drop down a
+//! [`MonthCalendar`]: arrows move by day and week, Page Up and Page Down by
+//! month, Enter or a click picks the day, Esc closes it.
+//!
+//! A [`TimePicker`] edits hours, minutes and optionally seconds the same way,
+//! in 24-hour form or with an AM/PM part where the locale uses one.
+//!
+//! The value is bound to an `Rc<RefCell<NaiveDate>>` (or `NaiveTime`), like
+//! the text of an [`InputLine`](super::input_line::InputLine). A
+//! [`ValidatorRef`] set on the picker checks the formatted text when the dialog
+//! closes.
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::date_picker::{DateFormat, DatePickerBuilder};
+//!
+//! let date = Rc::new(RefCell::new(NaiveDate::from_ymd_opt(2026, 10, 18).unwrap()));
+//! let picker = DatePickerBuilder::new()
+//!     .bounds(Rect::new(2, 2, 16, 3))
+//!     .date(Rc::clone(&date))
+//!     .format(DateFormat::for_locale("de"))
+//!     .build();
+//! assert_eq!(picker.text(), "18.10.2026");
+//! ```
+
+use super::dialog_data::FieldValue;
+use super::validator::ValidatorRef;
+use super::view::{View, draw_shadow_bounds, write_line_to_terminal};
+use crate::core::command::{CM_CANCEL, CommandId};
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{
+    Event, EventType, KB_BACKSPACE, KB_DOWN, KB_END, KB_ENTER, KB_ESC, KB_F4, KB_HOME, KB_LEFT,
+    KB_PGDN, KB_PGUP, KB_RIGHT, KB_SHIFT_TAB, KB_TAB, KB_UP, MB_LEFT_BUTTON,
+};
+use crate::core::geometry::{Point, Rect};
+use crate::core::i18n;
+use crate::core::palette::{
+    DATE_ARROW, DATE_BUTTON, DATE_FOCUSED, DATE_NORMAL, DATE_SELECTED, LISTBOX_DIVIDER,
+    LISTBOX_FOCUSED, LISTBOX_SELECTED,
+};
+use crate::core::state::{SF_DROPPED_DOWN, StateFlags};
+use crate::terminal::Terminal;
+use chrono::{Datelike, Months, NaiveDate, NaiveTime, TimeDelta, Timelike, Weekday};
+use crossterm::event::KeyModifiers;
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::rc::Rc;
+
+/// Columns taken by the drop-down button
+const BUTTON_WIDTH: i16 = 3;
+
+/// Columns taken by the time picker's `▲▼` arrows
+const ARROWS_WIDTH: i16 = 2;
+
+/// Size of a [`MonthCalendar`]: seven 3-column day cells plus a margin, and a
+/// title row, a weekday row and up to six weeks
+pub const CALENDAR_WIDTH: i16 = 22;
+pub const CALENDAR_HEIGHT: i16 = 8;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Weekday abbreviations, Monday first
+const WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Number of days in `month` of `year`
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month >= 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|first| first.pred_opt())
+        .map_or(31, |last| last.day())
+}
+
+/// Columns from `first` to `day` in a week starting on `first`
+fn weekday_column(day: Weekday, first: Weekday) -> u32 {
+    (day.num_days_from_monday() + 7 - first.num_days_from_monday()) % 7
+}
+
+/// Localized name of the month of `date`, with the year
+fn month_title(date: NaiveDate) -> String {
+    format!(
+        "{} {}",
+        i18n::tr(MONTH_NAMES[date.month0() as usize]),
+        date.year()
+    )
+}
+
+/// Part of a date, in the order a [`DateFormat`] shows them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePart {
+    Day,
+    Month,
+    Year,
+}
+
+impl DatePart {
+    fn width(self) -> usize {
+        match self {
+            DatePart::Year => 4,
+            DatePart::Day | DatePart::Month => 2,
+        }
+    }
+}
+
+/// How dates are shown: part order, separator and first day of the week
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateFormat {
+    pub order: [DatePart; 3],
+    pub separator: char,
+    pub first_weekday: Weekday,
+}
+
+impl DateFormat {
+    /// `2026-10-18`, weeks starting on Monday
+    pub const ISO: DateFormat = DateFormat {
+        order: [DatePart::Year, DatePart::Month, DatePart::Day],
+        separator: '-',
+        first_weekday: Weekday::Mon,
+    };
+
+    /// Format used in `locale` (e.g. `de`, `it-CH`, `en-US`); ISO for
+    /// locales without a known convention
+    pub fn for_locale(locale: &str) -> Self {
+        const DMY: [DatePart; 3] = [DatePart::Day, DatePart::Month, DatePart::Year];
+        let mut parts = locale.split(['-', '_', '.']);
+        let language = parts.next().unwrap_or("").to_ascii_lowercase();
+        let region = parts.next().map(str::to_ascii_uppercase);
+        match (language.as_str(), region.as_deref()) {
+            ("en", Some("US")) => DateFormat {
+                order: [DatePart::Month, DatePart::Day, DatePart::Year],
+                separator: '/',
+                first_weekday: Weekday::Sun,
+            },
+            ("de" | "cs" | "da" | "fi" | "nb" | "pl" | "ru" | "tr", _) => DateFormat {
+                order: DMY,
+                separator: '.',
+                first_weekday: Weekday::Mon,
+            },
+            ("el" | "es" | "fr" | "it" | "pt", _) | ("en", Some(_)) => DateFormat {
+                order: DMY,
+                separator: '/',
+                first_weekday: Weekday::Mon,
+            },
+            _ => DateFormat::ISO,
+        }
+    }
+
+    /// Format for the locale selected with `i18n::set_locale()`
+    pub fn current() -> Self {
+        Self::for_locale(&i18n::locale())
+    }
+
+    pub fn format(&self, date: NaiveDate) -> String {
+        let mut text = String::new();
+        for (index, part) in self.order.iter().enumerate() {
+            if index > 0 {
+                text.push(self.separator);
+            }
+            let _ = match part {
+                DatePart::Day => write!(text, "{:02}", date.day()),
+                DatePart::Month => write!(text, "{:02}", date.month()),
+                DatePart::Year => write!(text, "{:04}", date.year()),
+            };
+        }
+        text
+    }
+
+    /// Read a date written in this format, `None` if it is not a valid date
+    pub fn parse(&self, text: &str) -> Option<NaiveDate> {
+        let mut fields = text.trim().split(self.separator);
+        let (mut day, mut month, mut year) = (None, None, None);
+        for part in self.order {
+            let value = fields.next()?.trim().parse::<u32>().ok()?;
+            match part {
+                DatePart::Day => day = Some(value),
+                DatePart::Month => month = Some(value),
+                DatePart::Year => year = i32::try_from(value).ok(),
+            }
+        }
+        if fields.next().is_some() {
+            return None;
+        }
+        NaiveDate::from_ymd_opt(year?, month?, day?)
+    }
+
+    /// Column where each part starts, in display order
+    fn starts(self) -> [usize; 3] {
+        let mut starts = [0; 3];
+        let mut column = 0;
+        for (index, part) in self.order.iter().enumerate() {
+            starts[index] = column;
+            column += part.width() + 1;
+        }
+        starts
+    }
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+/// How times are shown: 24-hour or with AM/PM, with or without seconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimeFormat {
+    pub twelve_hour: bool,
+    pub seconds: bool,
+}
+
+impl TimeFormat {
+    /// Format used in `locale`: 12-hour for US English, 24-hour otherwise
+    pub fn for_locale(locale: &str) -> Self {
+        let normalized = locale.replace('_', "-").to_ascii_lowercase();
+        TimeFormat {
+            twelve_hour: normalized.starts_with("en-us"),
+            seconds: false,
+        }
+    }
+
+    /// Format for the locale selected with `i18n::set_locale()`
+    pub fn current() -> Self {
+        Self::for_locale(&i18n::locale())
+    }
+
+    pub fn format(&self, time: NaiveTime) -> String {
+        let mut text = if self.twelve_hour {
+            format!("{:02}:{:02}", time.hour12().1, time.minute())
+        } else {
+            format!("{:02}:{:02}", time.hour(), time.minute())
+        };
+        if self.seconds {
+            let _ = write!(text, ":{:02}", time.second());
+        }
+        if self.twelve_hour {
+            text.push_str(if time.hour12().0 { " PM" } else { " AM" });
+        }
+        text
+    }
+
+    /// Read a time written in this format, `None` if it is not a valid time
+    pub fn parse(&self, text: &str) -> Option<NaiveTime> {
+        let mut text = text.trim();
+        let mut pm = None;
+        if self.twelve_hour {
+            let (clock, meridiem) = text.rsplit_once(' ')?;
+            pm = match meridiem.to_ascii_uppercase().as_str() {
+                "AM" => Some(false),
+                "PM" => Some(true),
+                _ => return None,
+            };
+            text = clock;
+        }
+        let fields: Vec<u32> = text
+            .split(':')
+            .map(|field| field.parse::<u32>().ok())
+            .collect::<Option<_>>()?;
+        let wanted = if self.seconds { 3 } else { 2 };
+        if fields.len() != wanted {
+            return None;
+        }
+        let mut hour = fields[0];
+        if let Some(pm) = pm {
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            hour = hour % 12 + if pm { 12 } else { 0 };
+        }
+        NaiveTime::from_hms_opt(hour, fields[1], fields.get(2).copied().unwrap_or(0))
+    }
+
+    /// (start column, width) of each part, in display order
+    fn spans(self) -> Vec<(usize, usize)> {
+        let mut spans = vec![(0, 2), (3, 2)];
+        if self.seconds {
+            spans.push((6, 2));
+        }
+        if self.twelve_hour {
+            let start = spans.last().map_or(0, |&(start, width)| start + width + 1);
+            spans.push((start, 2));
+        }
+        spans
+    }
+}
+
+/// Digits typed into the part under the cursor, not yet applied
+#[derive(Default)]
+struct PartCursor {
+    part: usize,
+    typed: String,
+}
+
+impl PartCursor {
+    /// Add a digit to a part `width` digits wide whose largest value is `max`;
+    /// returns the value once no further digit fits
+    fn type_digit(&mut self, digit: char, width: usize, max: u32) -> Option<u32> {
+        self.typed.push(digit);
+        let value = self.typed.parse::<u32>().unwrap_or(0);
+        let complete = self.typed.len() >= width || value.saturating_mul(10) > max;
+        if complete {
+            self.typed.clear();
+        }
+        complete.then_some(value)
+    }
+
+    /// The pending digits as a value, clearing them
+    fn take(&mut self) -> Option<u32> {
+        let value = self.typed.parse::<u32>().ok();
+        self.typed.clear();
+        value
+    }
+
+    fn move_to(&mut self, part: usize) {
+        self.typed.clear();
+        self.part = part;
+    }
+}
+
+/// Draw `text` with the part spans, highlighting the current one (showing
+/// its pending digits) when focused
+fn draw_field(
+    buf: &mut DrawBuffer,
+    text: &str,
+    spans: &[(usize, usize)],
+    cursor: &PartCursor,
+    attrs: (crate::core::palette::Attr, crate::core::palette::Attr),
+    focused: bool,
+) {
+    let (attr, selected) = attrs;
+    buf.move_str(0, text, attr);
+    if !focused {
+        return;
+    }
+    if let Some(&(start, width)) = spans.get(cursor.part) {
+        if cursor.typed.is_empty() {
+            let part: String = text.chars().skip(start).take(width).collect();
+            buf.move_str(start, &part, selected);
+        } else {
+            buf.move_str(start, &format!("{:>width$}", cursor.typed), selected);
+        }
+    }
+}
+
+/// Month calendar - a grid of the days of one month with a day cursor
+///
+/// Left and Right move by day, Up and Down by week, Page Up and Page Down by month
+/// (with Ctrl, by year), Home and End to the first and last day.
+pub struct MonthCalendar {
+    bounds: Rect,
+    cursor: NaiveDate,
+    min: NaiveDate,
+    max: NaiveDate,
+    first_weekday: Weekday,
+    state: StateFlags,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl MonthCalendar {
+    /// Create a calendar showing the month of `date`, with the cursor on it
+    pub fn new(bounds: Rect, date: NaiveDate) -> Self {
+        Self {
+            bounds,
+            cursor: date,
+            min: NaiveDate::MIN,
+            max: NaiveDate::MAX,
+            first_weekday: DateFormat::current().first_weekday,
+            state: 0,
+            palette_chain: None,
+        }
+    }
+
+    /// Day under the cursor
+    pub fn cursor(&self) -> NaiveDate {
+        self.cursor
+    }
+
+    /// Move the cursor (and the month shown) to `date`, kept within the range
+    pub fn set_cursor(&mut self, date: NaiveDate) {
+        self.cursor = date.clamp(self.min, self.max);
+    }
+
+    /// Limit the days the cursor can reach
+    pub fn set_range(&mut self, min: NaiveDate, max: NaiveDate) {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        self.set_cursor(self.cursor);
+    }
+
+    pub fn set_first_weekday(&mut self, first: Weekday) {
+        self.first_weekday = first;
+    }
+
+    /// Move the cursor by `days` (negative for earlier days)
+    pub fn move_days(&mut self, days: i64) {
+        if let Some(date) = self.cursor.checked_add_signed(TimeDelta::days(days)) {
+            self.set_cursor(date);
+        }
+    }
+
+    /// Move the cursor by `months`, keeping the day where the month allows
+    pub fn move_months(&mut self, months: i32) {
+        let moved = if months < 0 {
+            self.cursor
+                .checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            self.cursor
+                .checked_add_months(Months::new(months.unsigned_abs()))
+        };
+        if let Some(date) = moved {
+            self.set_cursor(date);
+        }
+    }
+
+    /// Grid cell of the first day of the shown month
+    fn first_cell(&self) -> u32 {
+        let first = self.cursor.with_day(1).unwrap_or(self.cursor);
+        weekday_column(first.weekday(), self.first_weekday)
+    }
+
+    /// Day shown at `pos` (screen coordinates), if any
+    pub fn day_at(&self, pos: Point) -> Option<NaiveDate> {
+        let column = u32::try_from(pos.x - self.bounds.a.x).ok()? / 3;
+        let row = u32::try_from(pos.y - self.bounds.a.y - 2).ok()?;
+        if column >= 7 || row >= 6 {
+            return None;
+        }
+        let day = (row * 7 + column + 1).checked_sub(self.first_cell())?;
+        self.cursor.with_day(day)
+    }
+
+    fn handle_key(&mut self, event: &mut Event) {
+        let ctrl = event.key_modifiers.contains(KeyModifiers::CONTROL);
+        match event.key_code {
+            KB_LEFT => self.move_days(-1),
+            KB_RIGHT => self.move_days(1),
+            KB_UP => self.move_days(-7),
+            KB_DOWN => self.move_days(7),
+            KB_PGUP => self.move_months(if ctrl { -12 } else { -1 }),
+            KB_PGDN => self.move_months(if ctrl { 12 } else { 1 }),
+            KB_HOME => self.set_cursor(self.cursor.with_day(1).unwrap_or(self.cursor)),
+            KB_END => {
+                let last = days_in_month(self.cursor.year(), self.cursor.month());
+                self.set_cursor(self.cursor.with_day(last).unwrap_or(self.cursor));
+            }
+            _ => return,
+        }
+        event.clear();
+    }
+
+    /// Handle a click: the title arrows change the month, a day moves the
+    /// cursor to it; returns `true` if a day was clicked
+    fn click(&mut self, pos: Point) -> bool {
+        if pos.y == self.bounds.a.y {
+            if pos.x == self.bounds.a.x + 1 {
+                self.move_months(-1);
+            } else if pos.x == self.bounds.b.x - 2 {
+                self.move_months(1);
+            }
+            return false;
+        }
+        match self.day_at(pos) {
+            Some(day) => {
+                self.set_cursor(day);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl View for MonthCalendar {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+        let normal = self.map_color(LISTBOX_FOCUSED);
+        let header = self.map_color(LISTBOX_DIVIDER);
+        let selected = self.map_color(LISTBOX_SELECTED);
+        let (x, y) = (self.bounds.a.x, self.bounds.a.y);
+
+        // Title: ◄ Month Year ►
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', normal, width);
+        let title = month_title(self.cursor);
+        buf.move_str(
+            width.saturating_sub(title.chars().count()) / 2,
+            &title,
+            normal,
+        );
+        buf.put_char(1, '◄', header);
+        buf.put_char(width.saturating_sub(2), '►', header);
+        write_line_to_terminal(terminal, x, y, &buf);
+
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', header, width);
+        for column in 0..7 {
+            let day = (0..column).fold(self.first_weekday, |day, _| day.succ());
+            let name = i18n::tr(WEEKDAY_NAMES[day.num_days_from_monday() as usize]);
+            buf.move_str(column * 3 + 1, &name, header);
+        }
+        write_line_to_terminal(terminal, x, y + 1, &buf);
+
+        let first = self.first_cell();
+        let days = days_in_month(self.cursor.year(), self.cursor.month());
+        for (row, line_y) in (0_u32..6).zip(y + 2..self.bounds.b.y) {
+            let mut buf = DrawBuffer::new(width);
+            buf.move_char(0, ' ', normal, width);
+            for column in 0..7_u32 {
+                let day = (row * 7 + column + 1).wrapping_sub(first);
+                if day == 0 || day > days {
+                    continue;
+                }
+                let attr = if day == self.cursor.day() {
+                    selected
+                } else {
+                    normal
+                };
+                buf.move_str(column as usize * 3, &format!("{day:>3}"), attr);
+            }
+            write_line_to_terminal(terminal, x, line_y, &buf);
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::Keyboard if self.is_focused() => self.handle_key(event),
+            EventType::MouseDown if self.bounds.contains(event.mouse.pos) => {
+                self.click(event.mouse.pos);
+                event.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(AccessibleInfo::new(Role::DateField, "").with_value(self.cursor.to_string()))
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_LISTBOX))
+    }
+}
+
+/// Date picker - a date field edited part by part, with a drop-down calendar
+pub struct DatePicker {
+    bounds: Rect,
+    date: Rc<RefCell<NaiveDate>>,
+    format: DateFormat,
+    cursor: PartCursor,
+    min: NaiveDate,
+    max: NaiveDate,
+    validator: Option<ValidatorRef>,
+    calendar: MonthCalendar,
+    /// Where the calendar was last placed (screen coordinates)
+    popup: Rect,
+    state: StateFlags,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl DatePicker {
+    /// Create a date picker bound to `date`, in the current locale's format
+    pub fn new(bounds: Rect, date: Rc<RefCell<NaiveDate>>) -> Self {
+        let current = *date.borrow();
+        let format = DateFormat::current();
+        let mut picker = Self {
+            bounds,
+            date,
+            format,
+            cursor: PartCursor::default(),
+            min: NaiveDate::MIN,
+            max: NaiveDate::MAX,
+            validator: None,
+            calendar: MonthCalendar::new(Rect::new(0, 0, 0, 0), current),
+            popup: Rect::new(0, 0, 0, 0),
+            state: 0,
+            palette_chain: None,
+        };
+        picker.set_format(format);
+        picker
+    }
+
+    pub fn set_format(&mut self, format: DateFormat) {
+        self.format = format;
+        self.calendar.set_first_weekday(format.first_weekday);
+        self.cursor.move_to(0);
+    }
+
+    pub fn format(&self) -> DateFormat {
+        self.format
+    }
+
+    /// Limit the dates that can be picked
+    pub fn set_range(&mut self, min: NaiveDate, max: NaiveDate) {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        self.calendar.set_range(self.min, self.max);
+        self.set_date(self.date());
+    }
+
+    /// Check the formatted text with `validator` when the dialog closes
+    pub fn set_validator(&mut self, validator: ValidatorRef) {
+        self.validator = Some(validator);
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        *self.date.borrow()
+    }
+
+    /// Show `date` (clamped to the range)
+    pub fn set_date(&mut self, date: NaiveDate) {
+        *self.date.borrow_mut() = date.clamp(self.min, self.max);
+    }
+
+    /// The date as shown
+    pub fn text(&self) -> String {
+        self.format.format(self.date())
+    }
+
+    pub fn is_dropped_down(&self) -> bool {
+        self.state & SF_DROPPED_DOWN != 0
+    }
+
+    /// Drop down the calendar on the current date
+    pub fn open(&mut self) {
+        self.commit();
+        self.calendar.set_cursor(self.date());
+        self.popup = self.place_popup(None);
+        self.calendar.set_bounds(self.popup);
+        self.calendar.set_focus(true);
+        self.state |= SF_DROPPED_DOWN;
+    }
+
+    /// Close the calendar without picking
+    pub fn close(&mut self) {
+        self.state &= !SF_DROPPED_DOWN;
+        self.calendar.set_focus(false);
+    }
+
+    fn accept(&mut self) {
+        self.set_date(self.calendar.cursor());
+        self.close();
+    }
+
+    fn field_bounds(&self) -> Rect {
+        Rect::new(
+            self.bounds.a.x,
+            self.bounds.a.y,
+            (self.bounds.b.x - BUTTON_WIDTH).max(self.bounds.a.x),
+            self.bounds.a.y + 1,
+        )
+    }
+
+    fn button_bounds(&self) -> Rect {
+        Rect::new(
+            self.field_bounds().b.x,
+            self.bounds.a.y,
+            self.bounds.b.x,
+            self.bounds.a.y + 1,
+        )
+    }
+
+    /// Rectangle for the calendar: below the field, or above it when `limits`
+    /// (the area the owner can draw in) leave more room there
+    fn place_popup(&self, limits: Option<Rect>) -> Rect {
+        let mut left = self.bounds.a.x;
+        let (top, bottom) = (self.bounds.a.y, self.bounds.b.y);
+        let Some(limits) = limits else {
+            return Rect::new(
+                left,
+                bottom,
+                left + CALENDAR_WIDTH,
+                bottom + CALENDAR_HEIGHT,
+            );
+        };
+        if left + CALENDAR_WIDTH > limits.b.x {
+            left = (limits.b.x - CALENDAR_WIDTH).max(limits.a.x);
+        }
+        if limits.b.y - bottom >= CALENDAR_HEIGHT || limits.b.y - bottom >= top - limits.a.y {
+            Rect::new(
+                left,
+                bottom,
+                left + CALENDAR_WIDTH,
+                bottom + CALENDAR_HEIGHT,
+            )
+        } else {
+            Rect::new(left, top - CALENDAR_HEIGHT, left + CALENDAR_WIDTH, top)
+        }
+    }
+
+    fn spans(&self) -> Vec<(usize, usize)> {
+        self.format
+            .starts()
+            .iter()
+            .zip(self.format.order)
+            .map(|(&start, part)| (start, part.width()))
+            .collect()
+    }
+
+    /// Set a part of the date, keeping the day within the month
+    fn apply(&mut self, part: DatePart, value: u32) {
+        let date = self.date();
+        let (mut year, mut month, mut day) = (date.year(), date.month(), date.day());
+        match part {
+            DatePart::Day => day = value,
+            DatePart::Month => month = value.clamp(1, 12),
+            DatePart::Year => year = i32::try_from(value.clamp(1, 9999)).unwrap_or(year),
+        }
+        let day = day.clamp(1, days_in_month(year, month));
+        if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
+            self.set_date(date);
+        }
+    }
+
+    /// Apply digits typed into the current part
+    fn commit(&mut self) {
+        if let Some(value) = self.cursor.take() {
+            self.apply(self.format.order[self.cursor.part], value);
+        }
+    }
+
+    /// Change the current part by `delta` days, months or years
+    fn step(&mut self, delta: i32) {
+        let date = self.date();
+        let moved = match self.format.order[self.cursor.part] {
+            DatePart::Day => date.checked_add_signed(TimeDelta::days(i64::from(delta))),
+            DatePart::Month | DatePart::Year => {
+                let months = if self.format.order[self.cursor.part] == DatePart::Year {
+                    delta.saturating_mul(12)
+                } else {
+                    delta
+                };
+                let span = Months::new(months.unsigned_abs());
+                if months < 0 {
+                    date.checked_sub_months(span)
+                } else {
+                    date.checked_add_months(span)
+                }
+            }
+        };
+        if let Some(date) = moved {
+            self.set_date(date);
+        }
+    }
+
+    fn type_digit(&mut self, digit: char) {
+        let part = self.format.order[self.cursor.part];
+        let max = match part {
+            DatePart::Day => 31,
+            DatePart::Month => 12,
+            DatePart::Year => 9999,
+        };
+        if let Some(value) = self.cursor.type_digit(digit, part.width(), max) {
+            self.apply(part, value);
+            self.cursor.move_to((self.cursor.part + 1).min(2));
+        }
+    }
+
+    fn handle_key(&mut self, event: &mut Event) {
+        let alt = event.key_modifiers.contains(KeyModifiers::ALT);
+        if self.is_dropped_down() {
+            match event.key_code {
+                KB_ENTER => self.accept(),
+                KB_ESC | KB_F4 => self.close(),
+                KB_UP if alt => self.close(),
+                KB_TAB | KB_SHIFT_TAB => {
+                    self.close();
+                    return;
+                }
+                _ => self.calendar.handle_event(event),
+            }
+            event.clear();
+            return;
+        }
+        match event.key_code {
+            KB_DOWN if alt => self.open(),
+            KB_F4 => self.open(),
+            KB_UP => {
+                self.commit();
+                self.step(1);
+            }
+            KB_DOWN => {
+                self.commit();
+                self.step(-1);
+            }
+            KB_LEFT => {
+                self.commit();
+                self.cursor.move_to(self.cursor.part.saturating_sub(1));
+            }
+            KB_RIGHT => {
+                self.commit();
+                self.cursor.move_to((self.cursor.part + 1).min(2));
+            }
+            KB_HOME => {
+                self.commit();
+                self.cursor.move_to(0);
+            }
+            KB_END => {
+                self.commit();
+                self.cursor.move_to(2);
+            }
+            KB_BACKSPACE => {
+                self.cursor.typed.pop();
+            }
+            key => {
+                let ch = char::from_u32(u32::from(key)).unwrap_or('\0');
+                if ch.is_ascii_digit() {
+                    self.type_digit(ch);
+                } else if ch == self.format.separator {
+                    self.commit();
+                    self.cursor.move_to((self.cursor.part + 1).min(2));
+                } else {
+                    if key == KB_TAB || key == KB_SHIFT_TAB {
+                        self.commit();
+                    }
+                    return;
+                }
+            }
+        }
+        event.clear();
+    }
+
+    fn handle_mouse(&mut self, event: &mut Event) {
+        if event.what != EventType::MouseDown {
+            // Mouse moves and releases over the open calendar belong to it
+            if self.is_dropped_down() {
+                event.clear();
+            }
+            return;
+        }
+        if event.mouse.buttons & MB_LEFT_BUTTON == 0 {
+            return;
+        }
+        let pos = event.mouse.pos;
+        if self.is_dropped_down() {
+            if self.popup.contains(pos) {
+                if self.calendar.click(pos) {
+                    self.accept();
+                }
+            } else {
+                // The button or anywhere else closes the calendar
+                self.close();
+            }
+            event.clear();
+        } else if self.button_bounds().contains(pos) {
+            self.open();
+            event.clear();
+        } else if self.field_bounds().contains(pos) {
+            self.commit();
+            let column = usize::try_from(pos.x - self.bounds.a.x).unwrap_or(0);
+            let part = self
+                .spans()
+                .iter()
+                .rposition(|&(start, _)| start <= column)
+                .unwrap_or(0);
+            self.cursor.move_to(part);
+            event.clear();
+        }
+    }
+}
+
+impl View for DatePicker {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        self.popup = self.place_popup(None);
+        self.calendar.set_bounds(self.popup);
+    }
+
+    fn get_data(&self) -> Option<FieldValue> {
+        Some(FieldValue::Date(self.date()))
+    }
+
+    fn set_data(&mut self, value: &FieldValue) {
+        if let FieldValue::Date(date) = value {
+            self.set_date(*date);
+        }
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let field = self.field_bounds();
+        let width = usize::from(field.width_clamped().cast_unsigned());
+        let attr = self.map_color(if self.is_focused() {
+            DATE_FOCUSED
+        } else {
+            DATE_NORMAL
+        });
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', attr, width);
+        draw_field(
+            &mut buf,
+            &self.text(),
+            &self.spans(),
+            &self.cursor,
+            (attr, self.map_color(DATE_SELECTED)),
+            self.is_focused() && !self.is_dropped_down(),
+        );
+        write_line_to_terminal(terminal, field.a.x, field.a.y, &buf);
+
+        let button = self.button_bounds();
+        let width = usize::from(button.width_clamped().cast_unsigned());
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', self.map_color(DATE_BUTTON), width);
+        buf.put_char(width / 2, '▼', self.map_color(DATE_ARROW));
+        write_line_to_terminal(terminal, button.a.x, button.a.y, &buf);
+
+        if self.is_dropped_down() {
+            // Stay inside the owner (its clip region includes its frame)
+            let limits = terminal.get_clip_rect().map(|mut clip| {
+                clip.grow(-1, -1);
+                clip
+            });
+            self.popup = self.place_popup(limits);
+            self.calendar.set_bounds(self.popup);
+            self.calendar.draw(terminal);
+            draw_shadow_bounds(terminal, self.popup);
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::MouseDown | EventType::MouseMove | EventType::MouseUp => {
+                self.handle_mouse(event);
+            }
+            EventType::Keyboard if self.is_focused() => self.handle_key(event),
+            _ => {}
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.set_state_flag(crate::core::state::SF_FOCUSED, focused);
+        if !focused {
+            self.commit();
+            self.close();
+        }
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        // The highlighted part shows where typing goes
+        let _ = terminal.hide_cursor();
+    }
+
+    // Matches Borland: TInputLine::valid() - the validator checks the text,
+    // except when the dialog is cancelled
+    fn valid(&mut self, command: CommandId) -> bool {
+        self.commit();
+        match &self.validator {
+            Some(validator) if command != CM_CANCEL => validator.borrow().valid(&self.text()),
+            _ => true,
+        }
+    }
+
+    fn error_message(&self) -> Option<String> {
+        self.validator
+            .as_ref()
+            .map(|validator| validator.borrow().error_message())
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(AccessibleInfo::new(Role::DateField, "").with_value(self.text()))
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        // The calendar maps its colors through the owner
+        self.calendar.set_palette_chain(node.clone());
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_DATE_PICKER))
+    }
+}
+
+/// Time picker - a time field edited part by part, with `▲▼` arrows
+pub struct TimePicker {
+    bounds: Rect,
+    time: Rc<RefCell<NaiveTime>>,
+    format: TimeFormat,
+    cursor: PartCursor,
+    validator: Option<ValidatorRef>,
+    state: StateFlags,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl TimePicker {
+    /// Create a time picker bound to `time`, in the current locale's format
+    pub fn new(bounds: Rect, time: Rc<RefCell<NaiveTime>>) -> Self {
+        Self {
+            bounds,
+            time,
+            format: TimeFormat::current(),
+            cursor: PartCursor::default(),
+            validator: None,
+            state: 0,
+            palette_chain: None,
+        }
+    }
+
+    pub fn set_format(&mut self, format: TimeFormat) {
+        self.format = format;
+        self.cursor.move_to(0);
+    }
+
+    pub fn format(&self) -> TimeFormat {
+        self.format
+    }
+
+    /// Check the formatted text with `validator` when the dialog closes
+    pub fn set_validator(&mut self, validator: ValidatorRef) {
+        self.validator = Some(validator);
+    }
+
+    pub fn time(&self) -> NaiveTime {
+        *self.time.borrow()
+    }
+
+    pub fn set_time(&mut self, time: NaiveTime) {
+        *self.time.borrow_mut() = time;
+    }
+
+    /// The time as shown
+    pub fn text(&self) -> String {
+        self.format.format(self.time())
+    }
+
+    fn field_bounds(&self) -> Rect {
+        Rect::new(
+            self.bounds.a.x,
+            self.bounds.a.y,
+            (self.bounds.b.x - ARROWS_WIDTH).max(self.bounds.a.x),
+            self.bounds.a.y + 1,
+        )
+    }
+
+    fn arrows_bounds(&self) -> Rect {
+        Rect::new(
+            self.field_bounds().b.x,
+            self.bounds.a.y,
+            self.bounds.b.x,
+            self.bounds.a.y + 1,
+        )
+    }
+
+    fn last_part(&self) -> usize {
+        self.format.spans().len() - 1
+    }
+
+    /// Whether `part` is the AM/PM part
+    fn is_meridiem(&self, part: usize) -> bool {
+        self.format.twelve_hour && part == self.last_part()
+    }
+
+    /// Set the hour (as shown), minute or second
+    fn apply(&mut self, part: usize, value: u32) {
+        let time = self.time();
+        let changed = match part {
+            0 if self.format.twelve_hour => {
+                let hour = value.clamp(1, 12) % 12 + if time.hour12().0 { 12 } else { 0 };
+                time.with_hour(hour)
+            }
+            0 => time.with_hour(value.min(23)),
+            1 => time.with_minute(value.min(59)),
+            _ => time.with_second(value.min(59)),
+        };
+        if let Some(time) = changed {
+            self.set_time(time);
+        }
+    }
+
+    fn commit(&mut self) {
+        if let Some(value) = self.cursor.take() {
+            self.apply(self.cursor.part, value);
+        }
+    }
+
+    /// Change the current part by `delta`, wrapping around midnight
+    fn step(&mut self, delta: i64) {
+        let unit = match self.cursor.part {
+            _ if self.is_meridiem(self.cursor.part) => TimeDelta::hours(12),
+            0 => TimeDelta::hours(1),
+            1 => TimeDelta::minutes(1),
+            _ => TimeDelta::seconds(1),
+        };
+        let (time, _) = self
+            .time()
+            .overflowing_add_signed(unit * i32::try_from(delta).unwrap_or(0));
+        self.set_time(time);
+    }
+
+    fn type_char(&mut self, ch: char) -> bool {
+        let part = self.cursor.part;
+        if self.is_meridiem(part) {
+            let pm = match ch.to_ascii_lowercase() {
+                'a' => false,
+                'p' => true,
+                _ => return false,
+            };
+            if self.time().hour12().0 != pm {
+                self.step(1);
+            }
+            return true;
+        }
+        if !ch.is_ascii_digit() {
+            return false;
+        }
+        let max = match part {
+            0 if self.format.twelve_hour => 12,
+            0 => 23,
+            _ => 59,
+        };
+        if let Some(value) = self.cursor.type_digit(ch, 2, max) {
+            self.apply(part, value);
+            self.cursor.move_to((part + 1).min(self.last_part()));
+        }
+        true
+    }
+
+    fn handle_key(&mut self, event: &mut Event) {
+        let last = self.last_part();
+        match event.key_code {
+            KB_UP | KB_DOWN => {
+                self.commit();
+                self.step(if event.key_code == KB_UP { 1 } else { -1 });
+            }
+            KB_LEFT => {
+                self.commit();
+                self.cursor.move_to(self.cursor.part.saturating_sub(1));
+            }
+            KB_RIGHT => {
+                self.commit();
+                self.cursor.move_to((self.cursor.part + 1).min(last));
+            }
+            KB_HOME => {
+                self.commit();
+                self.cursor.move_to(0);
+            }
+            KB_END => {
+                self.commit();
+                self.cursor.move_to(last);
+            }
+            KB_BACKSPACE => {
+                self.cursor.typed.pop();
+            }
+            key => {
+                let ch = char::from_u32(u32::from(key)).unwrap_or('\0');
+                if ch == ':' || ch == ' ' {
+                    self.commit();
+                    self.cursor.move_to((self.cursor.part + 1).min(last));
+                } else if !self.type_char(ch) {
+                    if key == KB_TAB || key == KB_SHIFT_TAB {
+                        self.commit();
+                    }
+                    return;
+                }
+            }
+        }
+        event.clear();
+    }
+
+    fn handle_mouse(&mut self, event: &mut Event) {
+        let pos = event.mouse.pos;
+        match event.what {
+            EventType::MouseWheelUp | EventType::MouseWheelDown if self.bounds.contains(pos) => {
+                self.commit();
+                self.step(if event.what == EventType::MouseWheelUp {
+                    1
+                } else {
+                    -1
+                });
+            }
+            EventType::MouseDown | EventType::MouseAuto
+                if event.mouse.buttons & MB_LEFT_BUTTON != 0
+                    && self.arrows_bounds().contains(pos) =>
+            {
+                // The left arrow counts up, the right one down
+                self.commit();
+                self.step(if pos.x == self.arrows_bounds().a.x {
+                    1
+                } else {
+                    -1
+                });
+            }
+            EventType::MouseDown if self.field_bounds().contains(pos) => {
+                self.commit();
+                let column = usize::try_from(pos.x - self.bounds.a.x).unwrap_or(0);
+                let part = self
+                    .format
+                    .spans()
+                    .iter()
+                    .rposition(|&(start, _)| start <= column)
+                    .unwrap_or(0);
+                self.cursor.move_to(part);
+            }
+            _ => return,
+        }
+        event.clear();
+    }
+}
+
+impl View for TimePicker {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn get_data(&self) -> Option<FieldValue> {
+        Some(FieldValue::Time(self.time()))
+    }
+
+    fn set_data(&mut self, value: &FieldValue) {
+        if let FieldValue::Time(time) = value {
+            self.set_time(*time);
+        }
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let field = self.field_bounds();
+        let width = usize::from(field.width_clamped().cast_unsigned());
+        let attr = self.map_color(if self.is_focused() {
+            DATE_FOCUSED
+        } else {
+            DATE_NORMAL
+        });
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', attr, width);
+        draw_field(
+            &mut buf,
+            &self.text(),
+            &self.format.spans(),
+            &self.cursor,
+            (attr, self.map_color(DATE_SELECTED)),
+            self.is_focused(),
+        );
+        write_line_to_terminal(terminal, field.a.x, field.a.y, &buf);
+
+        let arrows = self.arrows_bounds();
+        let width = usize::from(arrows.width_clamped().cast_unsigned());
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', self.map_color(DATE_BUTTON), width);
+        buf.put_char(0, '▲', self.map_color(DATE_ARROW));
+        if width > 1 {
+            buf.put_char(1, '▼', self.map_color(DATE_ARROW));
+        }
+        write_line_to_terminal(terminal, arrows.a.x, arrows.a.y, &buf);
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::Keyboard if self.is_focused() => self.handle_key(event),
+            EventType::MouseDown
+            | EventType::MouseAuto
+            | EventType::MouseWheelUp
+            | EventType::MouseWheelDown => self.handle_mouse(event),
+            _ => {}
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.set_state_flag(crate::core::state::SF_FOCUSED, focused);
+        if !focused {
+            self.commit();
+        }
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        // The highlighted part shows where typing goes
+        let _ = terminal.hide_cursor();
+    }
+
+    // Matches Borland: TInputLine::valid() - the validator checks the text,
+    // except when the dialog is cancelled
+    fn valid(&mut self, command: CommandId) -> bool {
+        self.commit();
+        match &self.validator {
+            Some(validator) if command != CM_CANCEL => validator.borrow().valid(&self.text()),
+            _ => true,
+        }
+    }
+
+    fn error_message(&self) -> Option<String> {
+        self.validator
+            .as_ref()
+            .map(|validator| validator.borrow().error_message())
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(AccessibleInfo::new(Role::SpinButton, "").with_value(self.text()))
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_DATE_PICKER))
+    }
+}
+
+/// Builder for creating date pickers with a fluent API.
+///
+/// # Examples
+///
+/// ```ignore
+/// use turbo_vision::views::date_picker::DatePickerBuilder;
+/// use turbo_vision::core::geometry::Rect;
+/// use std::rc::Rc;
+/// use std::cell::RefCell;
+///
+/// let due = Rc::new(RefCell::new(chrono::Local::now().date_naive()));
+/// let picker = DatePickerBuilder::new()
+///     .bounds(Rect::new(10, 5, 24, 6))
+///     .date(due.clone())
+///     .build();
+/// ```
+pub struct DatePickerBuilder {
+    bounds: Option<Rect>,
+    date: Option<Rc<RefCell<NaiveDate>>>,
+    format: Option<DateFormat>,
+    range: Option<(NaiveDate, NaiveDate)>,
+    validator: Option<ValidatorRef>,
+}
+
+impl DatePickerBuilder {
+    /// Creates a new `DatePickerBuilder` with default values.
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            date: None,
+            format: None,
+            range: None,
+            validator: None,
+        }
+    }
+
+    /// Sets the picker bounds, one row high (required).
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Binds the date (optional, default: today).
+    #[must_use]
+    pub fn date(mut self, date: Rc<RefCell<NaiveDate>>) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the format (default: the current locale's).
+    #[must_use]
+    pub fn format(mut self, format: DateFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Limits the dates that can be picked (optional).
+    #[must_use]
+    pub fn range(mut self, min: NaiveDate, max: NaiveDate) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Sets a validator for the formatted text (optional).
+    #[must_use]
+    pub fn validator(mut self, validator: ValidatorRef) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Builds the `DatePicker`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if bounds are not set.
+    pub fn build(self) -> DatePicker {
+        let bounds = self.bounds.expect("DatePicker bounds must be set");
+        let date = self
+            .date
+            .unwrap_or_else(|| Rc::new(RefCell::new(chrono::Local::now().date_naive())));
+        let mut picker = DatePicker::new(bounds, date);
+        if let Some(format) = self.format {
+            picker.set_format(format);
+        }
+        if let Some((min, max)) = self.range {
+            picker.set_range(min, max);
+        }
+        if let Some(validator) = self.validator {
+            picker.set_validator(validator);
+        }
+        picker
+    }
+
+    /// Builds the `DatePicker` as a Box.
+    pub fn build_boxed(self) -> Box<DatePicker> {
+        Box::new(self.build())
+    }
+}
+
+impl Default for DatePickerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for creating time pickers with a fluent API.
+///
+/// # Examples
+///
+/// ```ignore
+/// use turbo_vision::views::date_picker::{TimeFormat, TimePickerBuilder};
+/// use turbo_vision::core::geometry::Rect;
+///
+/// let picker = TimePickerBuilder::new()
+///     .bounds(Rect::new(10, 7, 20, 8))
+///     .format(TimeFormat { twelve_hour: false, seconds: true })
+///     .build();
+/// ```
+pub struct TimePickerBuilder {
+    bounds: Option<Rect>,
+    time: Option<Rc<RefCell<NaiveTime>>>,
+    format: Option<TimeFormat>,
+    validator: Option<ValidatorRef>,
+}
+
+impl TimePickerBuilder {
+    /// Creates a new `TimePickerBuilder` with default values.
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            time: None,
+            format: None,
+            validator: None,
+        }
+    }
+
+    /// Sets the picker bounds, one row high (required).
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Binds the time (optional, default: midnight).
+    #[must_use]
+    pub fn time(mut self, time: Rc<RefCell<NaiveTime>>) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Sets the format (default: the current locale's).
+    #[must_use]
+    pub fn format(mut self, format: TimeFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets a validator for the formatted text (optional).
+    #[must_use]
+    pub fn validator(mut self, validator: ValidatorRef) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Builds the `TimePicker`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if bounds are not set.
+    pub fn build(self) -> TimePicker {
+        let bounds = self.bounds.expect("TimePicker bounds must be set");
+        let mut picker = TimePicker::new(bounds, self.time.unwrap_or_default());
+        if let Some(format) = self.format {
+            picker.set_format(format);
+        }
+        if let Some(validator) = self.validator {
+            picker.set_validator(validator);
+        }
+        picker
+    }
+
+    /// Builds the `TimePicker` as a Box.
+    pub fn build_boxed(self) -> Box<TimePicker> {
+        Box::new(self.build())
+    }
+}
+
+impl Default for TimePickerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::KeyCode;
+    use crate::test_util::snapshot::Snapshot;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn key(view: &mut dyn View, key_code: KeyCode, modifiers: KeyModifiers) {
+        let mut event = Event::keyboard(key_code);
+        event.key_modifiers = modifiers;
+        view.handle_event(&mut event);
+    }
+
+    fn type_text(view: &mut dyn View, text: &str) {
+        for ch in text.bytes() {
+            key(view, u16::from(ch), KeyModifiers::NONE);
+        }
+    }
+
+    fn date_picker(locale: &str, date: NaiveDate) -> (DatePicker, Rc<RefCell<NaiveDate>>) {
+        let data = Rc::new(RefCell::new(date));
+        let mut picker = DatePickerBuilder::new()
+            .bounds(Rect::new(0, 0, 14, 1))
+            .date(Rc::clone(&data))
+            .format(DateFormat::for_locale(locale))
+            .build();
+        picker.set_focus(true);
+        (picker, data)
+    }
+
+    #[test]
+    fn formats_follow_the_locale() {
+        let date = ymd(2026, 3, 7);
+        assert_eq!(DateFormat::for_locale("de-DE").format(date), "07.03.2026");
+        assert_eq!(DateFormat::for_locale("it").format(date), "07/03/2026");
+        assert_eq!(
+            DateFormat::for_locale("en_US.UTF-8").format(date),
+            "03/07/2026"
+        );
+        assert_eq!(DateFormat::for_locale("en").format(date), "2026-03-07");
+        assert_eq!(DateFormat::for_locale("de").parse("31.02.2026"), None);
+
+        let time = NaiveTime::from_hms_opt(14, 5, 0).unwrap();
+        let us = TimeFormat::for_locale("en-US");
+        assert_eq!(us.format(time), "02:05 PM");
+        assert_eq!(us.parse("02:05 PM"), Some(time));
+        assert_eq!(TimeFormat::for_locale("it").format(time), "14:05");
+    }
+
+    #[test]
+    fn parts_are_stepped_and_typed() {
+        let (mut picker, date) = date_picker("de", ymd(2026, 1, 31));
+        // Day part first: Up moves to the next day
+        key(&mut picker, KB_UP, KeyModifiers::NONE);
+        assert_eq!(*date.borrow(), ymd(2026, 2, 1));
+
+        // Typing a complete day moves on to the month; the day is kept within it
+        type_text(&mut picker, "30");
+        assert_eq!(picker.cursor.part, 1);
+        assert_eq!(*date.borrow(), ymd(2026, 2, 28));
+        type_text(&mut picker, "4");
+        assert_eq!(*date.borrow(), ymd(2026, 4, 28));
+
+        // The year applies once complete or when leaving the part
+        type_text(&mut picker, "19");
+        assert_eq!(date.borrow().year(), 2026);
+        key(&mut picker, KB_LEFT, KeyModifiers::NONE);
+        assert_eq!(*date.borrow(), ymd(19, 4, 28));
+        key(&mut picker, KB_RIGHT, KeyModifiers::NONE);
+        key(&mut picker, KB_DOWN, KeyModifiers::NONE);
+        assert_eq!(*date.borrow(), ymd(18, 4, 28));
+    }
+
+    #[test]
+    fn calendar_drops_down_and_picks() {
+        let (mut picker, date) = date_picker("it", ymd(2026, 10, 18));
+        key(&mut picker, KB_DOWN, KeyModifiers::ALT);
+        assert!(picker.is_dropped_down());
+
+        // A week later, then the next month
+        key(&mut picker, KB_DOWN, KeyModifiers::NONE);
+        key(&mut picker, KB_PGDN, KeyModifiers::NONE);
+        assert_eq!(*date.borrow(), ymd(2026, 10, 18));
+        key(&mut picker, KB_ENTER, KeyModifiers::NONE);
+        assert!(!picker.is_dropped_down());
+        assert_eq!(*date.borrow(), ymd(2026, 11, 25));
+
+        // A click on a day picks it: November 2026 starts on a Sunday
+        key(&mut picker, KB_F4, KeyModifiers::NONE);
+        let snapshot = Snapshot::of_view(&mut picker, 24, 10);
+        let lines = snapshot.text_lines();
+        assert!(lines[1].contains("Novembre 2026") || lines[1].contains("November 2026"));
+        assert!(lines[3].trim_end().ends_with('1'));
+        let mut click = Event::mouse(
+            EventType::MouseDown,
+            Point::new(1, 4),
+            MB_LEFT_BUTTON,
+            false,
+        );
+        picker.handle_event(&mut click);
+        assert_eq!(*date.borrow(), ymd(2026, 11, 2));
+    }
+
+    #[test]
+    fn time_picker_wraps_and_toggles_meridiem() {
+        let time = Rc::new(RefCell::new(NaiveTime::from_hms_opt(23, 59, 0).unwrap()));
+        let mut picker = TimePickerBuilder::new()
+            .bounds(Rect::new(0, 0, 12, 1))
+            .time(Rc::clone(&time))
+            .format(TimeFormat::for_locale("en-US"))
+            .build();
+        picker.set_focus(true);
+        assert_eq!(picker.text(), "11:59 PM");
+
+        key(&mut picker, KB_RIGHT, KeyModifiers::NONE);
+        key(&mut picker, KB_UP, KeyModifiers::NONE);
+        assert_eq!(picker.text(), "12:00 AM");
+
+        key(&mut picker, KB_HOME, KeyModifiers::NONE);
+        type_text(&mut picker, "0930p");
+        assert_eq!(*time.borrow(), NaiveTime::from_hms_opt(21, 30, 0).unwrap());
+    }
+}
@@ -5,6 +5,7 @@
 use super::background::Background;
 use super::group::Group;
 use super::view::{View, ViewId};
+use super::window::WN_NO_NUMBER;
 use crate::core::event::Event;
 use crate::core::geometry::Rect;
 use crate::terminal::Terminal;
@@ -48,6 +49,10 @@ impl Desktop {
         // NOTE: We don't set owner pointer to avoid unsafe casting
     }
 
+    /// Add a window (or any view) on top of the others and focus it
+    ///
+    /// A window without a number gets the lowest number from 1 to 9 that no
+    /// other window uses, shown in its frame and selected with Alt+number.
     pub fn add(&mut self, mut view: Box<dyn View>) -> ViewId {
         use crate::core::state::{OF_CENTER_X, OF_CENTER_Y, OF_CENTERED};
 
@@ -85,6 +90,20 @@ impl Desktop {
             self.children.child_at_mut(last_idx).init_after_add();
         }
 
+        // Number the new window unless it has a number already
+        // Borland applications pass the next free number to the TWindow
+        // constructor; modal views (help, dialogs run on the desktop) get none
+        if num_children > 0 {
+            use crate::core::state::SF_MODAL;
+            let number = self.free_window_number();
+            let window = self.children.child_at_mut(num_children - 1);
+            if window.window_number() == WN_NO_NUMBER && (window.state() & SF_MODAL) == 0 {
+                if let Some(number) = number {
+                    window.set_window_number(number);
+                }
+            }
+        }
+
         // Focus on the newly added window (last child)
         if num_children > 0 {
             let last_idx = num_children - 1;
@@ -487,16 +506,63 @@ impl Desktop {
             return true; // Already on top
         }
 
-        // Unfocus current top window
-        self.children.child_at_mut(last_idx).set_focus(false);
+        // Bring the target window to front and focus it (moving the group's
+        // focus too, so keyboard events reach the new top window)
+        let new_top = self.children.bring_to_front(index);
+        self.children.set_focus_to(new_top);
+
+        true
+    }
+
+    /// Lowest window number from 1 to 9 that no window uses
+    pub fn free_window_number(&self) -> Option<u8> {
+        (1..=9).find(|&number| {
+            (1..self.children.len()).all(|i| self.children.child_at(i).window_number() != number)
+        })
+    }
 
-        // Bring the target window to front
-        self.children.bring_to_front(index);
+    /// Bring the window with `number` to the front (Alt+1..9)
+    /// Matches Borland: `cmSelectWindowNum` broadcast, answered by the
+    /// selectable window with that `TWindow::number`
+    ///
+    /// Returns false if no window has the number or a modal view is on top.
+    pub fn select_window_number(&mut self, number: u8) -> bool {
+        use crate::core::state::{OF_SELECTABLE, SF_MODAL};
 
-        // Focus the new top window
-        let new_top = self.children.len() - 1;
-        self.children.child_at_mut(new_top).set_focus(true);
+        if number == WN_NO_NUMBER || self.children.len() <= 1 {
+            return false;
+        }
+        let top = self.children.child_at(self.children.len() - 1);
+        if (top.state() & SF_MODAL) != 0 {
+            return false;
+        }
+        let found = (1..self.children.len()).find(|&i| {
+            let window = self.children.child_at(i);
+            window.window_number() == number && (window.options() & OF_SELECTABLE) != 0
+        });
+        match found.and_then(|i| self.children.view_id_at(i)) {
+            Some(view_id) => self.bring_to_front(view_id),
+            None => false,
+        }
+    }
 
+    /// Ask a window to close, as a click on its close button does
+    /// Matches Borland: `TWindow::close()` - `valid(cmClose)` first
+    ///
+    /// Sends `CM_CLOSE` to the window, which marks itself `SF_CLOSED` for the
+    /// next [`remove_closed_windows`](Self::remove_closed_windows), unless it
+    /// leaves closing to its owner (see `Window::set_auto_close`). Returns
+    /// false if the window was not found or `valid()` refused.
+    pub fn close_window(&mut self, view_id: ViewId) -> bool {
+        use crate::core::command::CM_CLOSE;
+
+        let Some(window) = self.children.child_by_id_mut(view_id) else {
+            return false;
+        };
+        if !window.valid(CM_CLOSE) {
+            return false;
+        }
+        window.handle_event(&mut Event::command(CM_CLOSE));
         true
     }
 
@@ -531,6 +597,14 @@ impl View for Desktop {
         self.bounds
     }
 
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.children.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.children.subviews()
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
         self.children.set_bounds(bounds);
@@ -723,4 +797,30 @@ mod tests {
         let fake_id = crate::views::view::ViewId::from_u16(9999);
         assert!(!desktop.bring_to_front(fake_id));
     }
+
+    #[test]
+    fn test_window_numbers() {
+        let mut desktop = Desktop::new(Rect::new(0, 1, 80, 24));
+        let id1 = desktop.add(Box::new(Window::new(Rect::new(5, 5, 30, 15), "Win 1")));
+        let id2 = desktop.add(Box::new(Window::new(Rect::new(10, 6, 35, 16), "Win 2")));
+        let mut fixed = Window::new(Rect::new(15, 7, 40, 17), "Win 7");
+        fixed.set_number(7);
+        desktop.add(Box::new(fixed));
+        let numbers: Vec<u8> = (0..3)
+            .map(|i| desktop.child_at(i).window_number())
+            .collect();
+        assert_eq!(numbers, [1, 2, 7]);
+
+        // Alt+1 brings window 1 to the front; unused numbers do nothing
+        assert!(desktop.select_window_number(1));
+        assert_eq!(desktop.current().map(|(id, _)| id), Some(id1));
+        assert!(!desktop.select_window_number(5));
+        assert!(!desktop.select_window_number(WN_NO_NUMBER));
+
+        // A closed window's number is free again
+        assert!(desktop.close_window(id2));
+        assert!(desktop.remove_closed_windows());
+        assert!(!desktop.contains_id(id2));
+        assert_eq!(desktop.free_window_number(), Some(2));
+    }
 }
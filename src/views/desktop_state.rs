@@ -0,0 +1,442 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Desktop state - saving and restoring the open windows.
+//!
+//! Matches Borland: `TStreamable` with `TDeskTop` written to and read back
+//! from a stream. Here a view describes itself as a [`ViewState`] through
+//! [`View::save_state`], the desktop collects them into a [`DesktopState`]
+//! (back to front, so the last one is the focused window) and writes it as
+//! text. On restore, a [`WindowFactories`] registry turns each state back
+//! into a window, and [`View::restore_state`] applies the rest.
+//!
+//! [`EditWindow`] and [`HelpWindow`] always save themselves; a plain
+//! [`Window`](super::window::Window) opts in with
+//! [`set_state_kind`](super::window::Window::set_state_kind), and saves the
+//! state of its children, such as the lines of a
+//! [`TerminalWidget`](super::terminal_widget::TerminalWidget).
+//!
+//! ```text
+//! # Turbo Vision desktop
+//! view edit_window 0 1 60 20
+//!     title "main.pas"
+//!     file "src/main.pas"
+//!     cursor "4 12"
+//!     delta "0 0"
+//! end
+//! view output 5 10 75 22
+//!     title "Output"
+//!     restore "10 12 50 20"
+//!     child 0 terminal 6 11 74 21
+//!         line "Compiling..."
+//!     end
+//! end
+//! ```
+//!
+//! ```rust
+//! use turbo_vision::views::desktop_state::{DesktopState, ViewState};
+//! use turbo_vision::core::geometry::Rect;
+//!
+//! let mut window = ViewState::new("notes", Rect::new(2, 2, 40, 12));
+//! window.set("title", "Notes");
+//! let state = DesktopState { windows: vec![window] };
+//! let text = state.to_text();
+//! assert_eq!(DesktopState::parse(&text).unwrap(), state);
+//! ```
+
+use super::desktop::Desktop;
+use super::edit_window::EditWindow;
+use super::help_file::HelpFile;
+use super::help_window::HelpWindow;
+use super::resource::{Arg, error_at, tokenize};
+use super::view::View;
+use crate::core::error::{Result, TurboVisionError};
+use crate::core::geometry::{Point, Rect};
+use crate::core::quoted::quote;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::rc::Rc;
+
+/// Saved state of one view: its kind, bounds, named values and children
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewState {
+    /// Name of the factory that rebuilds the view (e.g. `edit_window`)
+    pub kind: String,
+    /// Bounds in screen coordinates
+    pub bounds: Rect,
+    values: Vec<(String, String)>,
+    children: Vec<(usize, ViewState)>,
+}
+
+impl ViewState {
+    pub fn new(kind: &str, bounds: Rect) -> Self {
+        Self {
+            kind: kind.to_string(),
+            bounds,
+            values: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Set a value, replacing any earlier one with the same key
+    pub fn set(&mut self, key: &str, value: impl Into<String>) {
+        self.values.retain(|(name, _)| name != key);
+        self.push(key, value);
+    }
+
+    /// Append a value; keys may repeat (e.g. one `line` per line of text)
+    pub fn push(&mut self, key: &str, value: impl Into<String>) {
+        self.values.push((key.to_string(), value.into()));
+    }
+
+    /// First value stored under `key`
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// First value stored under `key`, parsed
+    pub fn get_parsed<T: std::str::FromStr>(&self, key: &str) -> Option<T> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// All values in the order they were added, as `(key, value)` pairs
+    pub fn values(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    pub fn set_point(&mut self, key: &str, point: Point) {
+        self.set(key, format!("{} {}", point.x, point.y));
+    }
+
+    pub fn get_point(&self, key: &str) -> Option<Point> {
+        match parse_numbers(self.get(key)?)[..] {
+            [x, y] => Some(Point::new(x, y)),
+            _ => None,
+        }
+    }
+
+    pub fn set_rect(&mut self, key: &str, rect: Rect) {
+        self.set(
+            key,
+            format!("{} {} {} {}", rect.a.x, rect.a.y, rect.b.x, rect.b.y),
+        );
+    }
+
+    pub fn get_rect(&self, key: &str) -> Option<Rect> {
+        match parse_numbers(self.get(key)?)[..] {
+            [x1, y1, x2, y2] => Some(Rect::new(x1, y1, x2, y2)),
+            _ => None,
+        }
+    }
+
+    /// Record the state of the subview at `index` of a container
+    pub fn add_child(&mut self, index: usize, state: ViewState) {
+        self.children.push((index, state));
+    }
+
+    /// States of subviews with their index in the container
+    pub fn children(&self) -> &[(usize, ViewState)] {
+        &self.children
+    }
+
+    fn write(&self, text: &mut String, depth: usize, index: Option<usize>) {
+        let indent = "    ".repeat(depth);
+        let Rect { a, b } = self.bounds;
+        let _ = match index {
+            Some(index) => writeln!(
+                text,
+                "{indent}child {index} {} {} {} {} {}",
+                self.kind, a.x, a.y, b.x, b.y
+            ),
+            None => writeln!(
+                text,
+                "{indent}view {} {} {} {} {}",
+                self.kind, a.x, a.y, b.x, b.y
+            ),
+        };
+        for (key, value) in &self.values {
+            let _ = writeln!(text, "{indent}    {key} {}", quote(value));
+        }
+        for (index, child) in &self.children {
+            child.write(text, depth + 1, Some(*index));
+        }
+        let _ = writeln!(text, "{indent}end");
+    }
+}
+
+fn parse_numbers(text: &str) -> Vec<i16> {
+    text.split_whitespace()
+        .map_while(|number| number.parse().ok())
+        .collect()
+}
+
+/// The windows of a desktop, back to front
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DesktopState {
+    pub windows: Vec<ViewState>,
+}
+
+impl DesktopState {
+    /// Write the state in the desktop file format
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# Turbo Vision desktop\n");
+        for window in &self.windows {
+            window.write(&mut text, 0, None);
+        }
+        text
+    }
+
+    /// Read the desktop file format
+    pub fn parse(text: &str) -> Result<Self> {
+        // Views being read, innermost last, with their index in the parent
+        let mut open: Vec<(Option<usize>, ViewState)> = Vec::new();
+        let mut windows = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let number = index + 1;
+            let (args, _) = tokenize(line, number)?;
+            let words: Vec<&str> = args
+                .iter()
+                .map(|arg| match arg {
+                    Arg::Word(word) | Arg::Text(word) => word.as_str(),
+                })
+                .collect();
+            match words[..] {
+                [] => {}
+                ["end"] => {
+                    let (index, state) = open
+                        .pop()
+                        .ok_or_else(|| error_at(number, "`end` without a view"))?;
+                    match (open.last_mut(), index) {
+                        (Some((_, parent)), Some(index)) => parent.add_child(index, state),
+                        (None, None) => windows.push(state),
+                        _ => return Err(error_at(number, "misplaced `end`")),
+                    }
+                }
+                ["view", kind, ref rect @ ..] if open.is_empty() => {
+                    open.push((None, ViewState::new(kind, parse_rect(rect, number)?)));
+                }
+                ["child", index, kind, ref rect @ ..] if !open.is_empty() => {
+                    let index = index
+                        .parse()
+                        .map_err(|error| error_at(number, format!("child index: {error}")))?;
+                    open.push((Some(index), ViewState::new(kind, parse_rect(rect, number)?)));
+                }
+                [key, value] if matches!(args[1], Arg::Text(_)) => match open.last_mut() {
+                    Some((_, state)) => state.push(key, value),
+                    None => return Err(error_at(number, "value outside a view")),
+                },
+                _ => return Err(error_at(number, format!("unexpected `{}`", line.trim()))),
+            }
+        }
+        if !open.is_empty() {
+            return Err(TurboVisionError::parse("view is never closed with `end`"));
+        }
+        Ok(Self { windows })
+    }
+
+    /// Write the state to a file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_text())
+            .map_err(|error| TurboVisionError::file_operation(path, error))
+    }
+
+    /// Read the state from a file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| TurboVisionError::file_operation(path, error))?;
+        Self::parse(&text)
+    }
+}
+
+fn parse_rect(words: &[&str], line: usize) -> Result<Rect> {
+    let numbers: Vec<i16> = words.iter().map_while(|word| word.parse().ok()).collect();
+    match numbers[..] {
+        [x1, y1, x2, y2] if words.len() == 4 => Ok(Rect::new(x1, y1, x2, y2)),
+        _ => Err(error_at(line, "bounds must be X1 Y1 X2 Y2")),
+    }
+}
+
+/// Builds a window from its saved state, or `None` to skip it
+pub type WindowFactory = Box<dyn Fn(&ViewState) -> Option<Box<dyn View>>>;
+
+/// Window factories by kind, used to restore a desktop
+///
+/// `new()` knows `edit_window` (reopening its file) and `help_window` (when
+/// it was showing a help file on disk); applications register their own
+/// kinds, or replace these to customize the windows.
+pub struct WindowFactories {
+    factories: HashMap<String, WindowFactory>,
+}
+
+impl WindowFactories {
+    pub fn new() -> Self {
+        let mut factories = Self::empty();
+        factories.register("edit_window", |state| {
+            EditWindow::from_state(state).map(|window| Box::new(window) as Box<dyn View>)
+        });
+        factories.register("help_window", |state| {
+            let help_file = HelpFile::new(state.get("help_file")?).ok()?;
+            let window = HelpWindow::from_state(state, Rc::new(RefCell::new(help_file)));
+            Some(Box::new(window))
+        });
+        factories
+    }
+
+    /// A registry without the built-in factories
+    pub fn empty() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Register (or replace) the factory for `kind`
+    pub fn register(
+        &mut self,
+        kind: &str,
+        factory: impl Fn(&ViewState) -> Option<Box<dyn View>> + 'static,
+    ) {
+        self.factories.insert(kind.to_string(), Box::new(factory));
+    }
+
+    /// Build a window, or `None` for an unknown kind or a failing factory
+    pub fn create(&self, state: &ViewState) -> Option<Box<dyn View>> {
+        self.factories.get(&state.kind)?(state)
+    }
+}
+
+impl Default for WindowFactories {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Desktop {
+    /// Collect the state of the windows that save themselves, back to front
+    pub fn save_state(&self) -> DesktopState {
+        DesktopState {
+            windows: (0..self.child_count())
+                .filter_map(|index| self.child_at(index).save_state())
+                .collect(),
+        }
+    }
+
+    /// Rebuild saved windows on top of the current ones
+    ///
+    /// Windows whose kind has no factory, or whose factory gives up (e.g. a
+    /// file that no longer exists), are skipped. Returns how many windows
+    /// were restored; the last one ends up focused.
+    pub fn restore_state(&mut self, state: &DesktopState, factories: &WindowFactories) -> usize {
+        let mut restored = 0;
+        for window_state in &state.windows {
+            let Some(view) = factories.create(window_state) else {
+                continue;
+            };
+            self.add(view);
+            // A layout saved on a larger screen is clipped to the desktop
+            let bounds = window_state.bounds.intersect(&self.get_bounds());
+            let window = self.child_at_mut(self.child_count() - 1);
+            if !bounds.is_empty() {
+                window.set_bounds(bounds);
+            }
+            window.restore_state(window_state);
+            restored += 1;
+        }
+        restored
+    }
+
+    /// Save the window layout to a file
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.save_state().save(path)
+    }
+
+    /// Restore the windows saved with [`save_to_file`](Self::save_to_file)
+    pub fn restore_from_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        factories: &WindowFactories,
+    ) -> Result<usize> {
+        let state = DesktopState::load(path)?;
+        Ok(self.restore_state(&state, factories))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::terminal_widget::TerminalWidget;
+    use crate::views::window::Window;
+
+    #[test]
+    fn text_format_round_trips() {
+        let mut output = ViewState::new("output", Rect::new(5, 10, 75, 22));
+        output.set("title", "Say \"hi\"\\now");
+        output.set_rect("restore", Rect::new(10, 12, 50, 20));
+        let mut terminal = ViewState::new("terminal", Rect::new(6, 11, 74, 21));
+        terminal.push("line", "first # not a comment");
+        terminal.push("line", "second\nline");
+        output.add_child(0, terminal);
+        let state = DesktopState {
+            windows: vec![output, ViewState::new("empty", Rect::new(0, 1, 10, 5))],
+        };
+
+        let parsed = DesktopState::parse(&state.to_text()).unwrap();
+        assert_eq!(parsed, state);
+        let output = &parsed.windows[0];
+        assert_eq!(output.get_rect("restore"), Some(Rect::new(10, 12, 50, 20)));
+        let lines: Vec<_> = output.children()[0].1.values().map(|(_, v)| v).collect();
+        assert_eq!(lines, ["first # not a comment", "second\nline"]);
+
+        let error = DesktopState::parse("view a 1 2 3 4\n    child x b 1 2 3 4\n")
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("line 2"));
+    }
+
+    #[test]
+    fn desktop_restores_windows_in_order() {
+        let mut desktop = Desktop::new(Rect::new(0, 1, 80, 24));
+        let mut window = Window::new(Rect::new(2, 2, 40, 12), "Output");
+        window.set_state_kind("output");
+        let mut terminal = TerminalWidget::new(Rect::new(0, 0, 36, 8));
+        terminal.append_line("hello".to_string());
+        window.add(Box::new(terminal));
+        desktop.add(Box::new(window));
+        // Windows without a kind are not saved
+        desktop.add(Box::new(Window::new(Rect::new(0, 0, 20, 5), "Scratch")));
+        desktop.add(Box::new(EditWindow::new(
+            Rect::new(10, 3, 70, 20),
+            "Untitled",
+        )));
+        desktop.zoom_top_window();
+
+        let state = desktop.save_state();
+        assert_eq!(state.windows.len(), 2);
+        assert_eq!(state.windows[1].bounds, Rect::new(0, 1, 80, 24));
+
+        let mut factories = WindowFactories::new();
+        factories.register("output", |state| {
+            let mut window = Window::new(state.bounds, "");
+            window.set_state_kind("output");
+            window.add(Box::new(TerminalWidget::new(Rect::new(0, 0, 36, 8))));
+            Some(Box::new(window))
+        });
+        let mut restored = Desktop::new(Rect::new(0, 1, 80, 24));
+        let text = state.to_text();
+        let count = restored.restore_state(&DesktopState::parse(&text).unwrap(), &factories);
+        assert_eq!(count, 2);
+        assert_eq!(restored.child_at(0).bounds(), Rect::new(2, 3, 40, 13));
+        assert_eq!(restored.save_state(), state);
+
+        // Unzooming goes back to the size before the zoom
+        restored.zoom_top_window();
+        assert_eq!(restored.child_at(1).bounds(), Rect::new(10, 4, 70, 21));
+    }
+}
@@ -2,17 +2,22 @@
 
 //! Dialog view - modal window for user interaction with OK/Cancel buttons.
 
-use super::view::{View, ViewId};
+use super::form_validator::{FormIdle, FormStatus, FormValidator};
+use super::menu_box::popup_menu;
+use super::view::{View, ViewId, find_context_menu, key_contexts, send_context_command};
 use super::window::Window;
-use crate::core::command::{CM_CANCEL, CommandId};
-use crate::core::event::{Event, EventType, KB_ENTER, KB_ESC_ESC};
-use crate::core::geometry::Rect;
+use crate::core::command::{CM_CANCEL, CM_CONTEXT_MENU, CM_IDLE, CommandId};
+use crate::core::event::{Event, EventType, KB_ENTER, KB_ESC_ESC, MB_RIGHT_BUTTON};
+use crate::core::geometry::{Point, Rect};
+use crate::core::keymap::{KeyContext, KeyResolution};
 use crate::terminal::Terminal;
 use std::time::Duration;
 
 pub struct Dialog {
     window: Window,
     result: CommandId,
+    /// Form-level checks run before closing with OK/Yes
+    form: Option<FormValidator>,
 }
 
 impl Dialog {
@@ -20,6 +25,7 @@ impl Dialog {
         Self {
             window: Window::new_for_dialog(bounds, title),
             result: CM_CANCEL,
+            form: None,
         }
     }
 
@@ -74,12 +80,44 @@ impl Dialog {
         self.window.child_by_id_mut(view_id)
     }
 
+    /// Focus the child with the given `ViewId`
+    /// Returns false if it is not found or cannot take the focus
+    pub fn focus_by_view_id(&mut self, view_id: ViewId) -> bool {
+        self.window.focus_by_view_id(view_id)
+    }
+
+    /// Check the whole form before closing with OK/Yes - see
+    /// [`form_validator`](super::form_validator)
+    pub fn set_form_validator(&mut self, form: FormValidator) {
+        self.form = Some(form);
+    }
+
+    pub fn form_validator(&self) -> Option<&FormValidator> {
+        self.form.as_ref()
+    }
+
+    /// Check the form as closing with `command` would, showing the errors
+    /// Without a form validator, every control is only asked for `valid()`
+    pub fn check_form(&mut self, command: CommandId) -> FormStatus {
+        match &mut self.form {
+            Some(form) => form.submit(&mut self.window, command),
+            None if self.window.valid(command) => FormStatus::Valid,
+            None => FormStatus::Invalid,
+        }
+    }
+
     /// Remove a child by its ViewId
     /// Returns true if a child was found and removed, false otherwise
     pub fn remove_by_id(&mut self, view_id: ViewId) -> bool {
         self.window.remove_by_id(view_id)
     }
 
+    /// Change how a child follows the dialog when it is resized (`GF_*` flags)
+    /// Returns false if the `ViewId` is not found
+    pub fn set_grow_mode(&mut self, view_id: ViewId, mode: crate::core::state::GrowMode) -> bool {
+        self.window.set_grow_mode(view_id, mode)
+    }
+
     /// Set the dialog title
     pub fn set_title(&mut self, title: &str) {
         self.window.set_title(title);
@@ -181,6 +219,7 @@ impl Dialog {
             }
 
             self.update_cursor(&mut app.terminal);
+            app.publish_focus(Some(self));
             let _ = app.terminal.flush();
 
             // Poll for event with 20ms timeout (matches magiblot's eventTimeoutMs)
@@ -200,10 +239,34 @@ impl Dialog {
                         continue;
                     }
 
+                    // Translate key bindings (dialog context and the focused control's).
+                    // Global bindings are left out: their commands are meant for the
+                    // application, and any command below 1000 would end the dialog.
+                    let mut contexts = key_contexts(self);
+                    contexts.retain(|&context| context != KeyContext::Global);
+                    match app.keymap_mut().resolve(&contexts, &event) {
+                        KeyResolution::Command(command) => event = Event::command(command),
+                        KeyResolution::Pending | KeyResolution::Discarded => continue,
+                        KeyResolution::Unbound => {}
+                    }
+                    if event.what == EventType::Command && event.command == CM_CONTEXT_MENU {
+                        self.show_context_menu(app, None);
+                        continue;
+                    }
+                    let right_click = event.what == EventType::MouseDown
+                        && event.mouse.buttons & MB_RIGHT_BUTTON != 0;
+                    let mouse_pos = event.mouse.pos;
+
                     // Event received - handle it immediately without calling idle()
                     // Matches magiblot: idle() is NOT called when events are present
                     self.handle_event(&mut event);
 
+                    // A right-click the clicked control left alone opens its context menu
+                    if right_click && event.what == EventType::MouseDown {
+                        self.show_context_menu(app, Some(mouse_pos));
+                        continue;
+                    }
+
                     // If the event was converted to a command (e.g., KB_ENTER -> CM_OK),
                     // we need to process it again so the command handler runs
                     // Matches Borland: putEvent() re-queues the converted event
@@ -214,7 +277,7 @@ impl Dialog {
                 None => {
                     // Timeout with no events - call idle() to update animations, etc.
                     // Matches magiblot: idle() only called when truly idle
-                    app.idle();
+                    self.idle(app);
                 }
             }
 
@@ -229,6 +292,32 @@ impl Dialog {
 
         self.result
     }
+
+    /// Open the context menu of the control under `pos`, or of the focused
+    /// control without a position, and send the picked command to that
+    /// control, then to the dialog if the control leaves it unhandled
+    /// Matches magiblot: `popupMenu()` with the control as receiver
+    fn show_context_menu(&mut self, app: &mut crate::app::Application, pos: Option<Point>) {
+        let Some((menu, at, owner)) = find_context_menu(self, pos) else {
+            return;
+        };
+        self.draw(&mut app.terminal);
+        let (width, height) = app.terminal.size();
+        let command = popup_menu(&mut app.terminal, at, menu, Rect::new(0, 0, width, height));
+        if command != 0 {
+            let mut event = send_context_command(self, owner, command);
+            if event.what != EventType::Nothing {
+                self.handle_event(&mut event);
+            }
+        }
+    }
+
+    /// Idle time in `execute()`: the application's, then this dialog's, which
+    /// misses the application's `CM_IDLE` broadcast as it is not on the desktop
+    fn idle(&mut self, app: &mut crate::app::Application) {
+        app.idle();
+        self.handle_event(&mut Event::broadcast(CM_IDLE));
+    }
 }
 
 impl View for Dialog {
@@ -236,6 +325,31 @@ impl View for Dialog {
         self.window.bounds()
     }
 
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(AccessibleInfo::new(Role::Dialog, self.window.get_title()))
+    }
+
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.window.current()
+    }
+
+    fn key_context(&self) -> Option<crate::core::keymap::KeyContext> {
+        Some(crate::core::keymap::KeyContext::Dialog)
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.window.subviews()
+    }
+
+    fn subviews_mut(&mut self) -> Vec<(ViewId, &mut dyn View)> {
+        self.window.subviews_mut()
+    }
+
+    fn focus_subview(&mut self, id: ViewId) -> bool {
+        self.window.focus_subview(id)
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.window.set_bounds(bounds);
     }
@@ -249,11 +363,23 @@ impl View for Dialog {
         // This is critical: if a focused Memo/EditorWindow handles Enter, it will clear the event
         // Borland's TDialog calls TWindow::handleEvent() FIRST (tdialog.cc line 47)
         self.window.handle_event(event);
+        self.end_modal_from_child();
 
         // Now check if the event is still active after children processed it
         // If a child (like Memo/EditorWindow) handled Enter, event.what will be EventType::None
         // This matches Borland's TDialog architecture (tdialog.cc lines 48-86)
 
+        if event.what == EventType::Broadcast && event.command == CM_IDLE {
+            // Let the form pick up deferred check results
+            if let Some(form) = &mut self.form {
+                match form.idle(&mut self.window) {
+                    FormIdle::Unchanged => {}
+                    FormIdle::Changed => super::view::request_redraw(),
+                    FormIdle::Finish(command) => self.window.end_modal(command),
+                }
+            }
+        }
+
         // Handle Keyboard events (if not already handled by children)
         // IMPORTANT: Only handle dialog-specific keys when modal!
         // Non-modal dialogs should let keyboard events pass to parent handlers
@@ -307,6 +433,14 @@ impl View for Dialog {
                         self.window.end_modal(CM_CANCEL);
                         event.clear();
                     }
+                    CM_OK | CM_YES if self.form.is_some() => {
+                        // Close only if the form is valid; a pending form
+                        // closes later, from the CM_IDLE broadcast
+                        if self.check_form(event.command) == FormStatus::Valid {
+                            self.window.end_modal(event.command);
+                        }
+                        event.clear();
+                    }
                     CM_OK | CM_YES | CM_NO => {
                         // OK/Yes/No button pressed
                         // End the modal loop with the command
@@ -425,6 +559,24 @@ impl Dialog {
         }
         None
     }
+
+    /// End a modal dialog when a child has ended its own modal state
+    ///
+    /// Matches Borland: `TView::endModal()` ends the modal state of the view's
+    /// top view, so a control can close the dialog that holds it.
+    fn end_modal_from_child(&mut self) {
+        use crate::core::state::SF_MODAL;
+
+        if self.state() & SF_MODAL == 0 || self.get_end_state() != 0 {
+            return;
+        }
+        let command = (0..self.child_count())
+            .map(|i| self.child_at(i).get_end_state())
+            .find(|&command| command != 0);
+        if let Some(command) = command {
+            self.window.end_modal(command);
+        }
+    }
 }
 
 /// Builder for creating dialogs with a fluent API.
@@ -743,4 +895,31 @@ mod tests {
         assert_eq!(dialog.bounds(), Rect::new(5, 5, 50, 20));
         assert_ne!(dialog.state() & SF_MODAL, 0, "Should be modal");
     }
+
+    #[test]
+    fn test_focus_path_reaches_focused_control() {
+        use crate::views::button::Button;
+        use crate::views::{focus_path, short_type_name};
+
+        let mut dialog = Dialog::new(Rect::new(0, 0, 30, 8), "Focus");
+        dialog.add(Box::new(Button::new(
+            Rect::new(2, 2, 12, 4),
+            "One",
+            100,
+            false,
+        )));
+        let second = dialog.add(Box::new(Button::new(
+            Rect::new(14, 2, 24, 4),
+            "Two",
+            101,
+            false,
+        )));
+        dialog.set_focus_to_child(1);
+
+        let path = focus_path(&dialog);
+        let &(id, view) = path.last().expect("dialog has a focused child");
+        assert_eq!(id, second);
+        assert_eq!(short_type_name(view.type_name()), "Button");
+        assert_eq!(short_type_name(dialog.type_name()), "Dialog");
+    }
 }
@@ -0,0 +1,504 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Dialog data - moving a record in and out of a dialog's controls.
+//!
+//! Matches Borland: `TDialog::setData()` / `getData()`, which copy a record
+//! into the controls in insertion order, and `TInputLine` handing its text
+//! to the validator's `Transfer()` when `voTransfer` is set. Here the record
+//! implements [`DialogData`] and maps its fields to controls by [`ViewId`]
+//! through [`DataFields`], so the order of the controls does not matter.
+//!
+//! Each control carries one [`FieldValue`] through [`View::get_data`] and
+//! [`View::set_data`]: a check box a `Bool`, a list box an `Index`, a spin
+//! input an `Int`, a date picker a `Date`. An [`InputLine`] carries `Text`,
+//! or whatever its validator converts to when the validator has
+//! [`VO_TRANSFER`] set: a [`RangeValidator`] gives an `Int` and a
+//! [`LookupValidator`] the `Index` of the matching value, which is a handy
+//! way to store an enum.
+//!
+//! ```rust
+//! use turbo_vision::views::dialog::Dialog;
+//! use turbo_vision::views::dialog_data::{DataFields, DialogData};
+//! use turbo_vision::views::checkbox::CheckBox;
+//! use turbo_vision::views::input_line::InputLine;
+//! use turbo_vision::views::view::ViewId;
+//! use turbo_vision::core::geometry::Rect;
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//!
+//! struct Options {
+//!     name: String,
+//!     backup: bool,
+//! }
+//!
+//! /// The options with the ids of the controls showing them
+//! struct OptionsForm {
+//!     options: Options,
+//!     name: ViewId,
+//!     backup: ViewId,
+//! }
+//!
+//! impl DialogData for OptionsForm {
+//!     fn write_fields(&self, fields: &mut DataFields) {
+//!         fields.set_text(self.name, &self.options.name);
+//!         fields.set_bool(self.backup, self.options.backup);
+//!     }
+//!
+//!     fn read_fields(&mut self, fields: &DataFields) {
+//!         if let Some(name) = fields.text(self.name) {
+//!             self.options.name = name.to_string();
+//!         }
+//!         if let Some(backup) = fields.bool(self.backup) {
+//!             self.options.backup = backup;
+//!         }
+//!     }
+//! }
+//!
+//! let mut dialog = Dialog::new(Rect::new(0, 0, 40, 10), "Options");
+//! let name = InputLine::new(Rect::new(2, 2, 30, 3), 40, Rc::new(RefCell::new(String::new())));
+//! let mut form = OptionsForm {
+//!     options: Options { name: "main.pas".to_string(), backup: true },
+//!     name: dialog.add(Box::new(name)),
+//!     backup: dialog.add(Box::new(CheckBox::new(Rect::new(2, 4, 30, 5), "~B~ackup"))),
+//! };
+//!
+//! dialog.set_record(&form);
+//! // ... dialog.execute(app), or execute_with_data(app, &mut form) for both steps
+//! form.options = Options { name: String::new(), backup: false };
+//! dialog.get_record(&mut form);
+//! assert_eq!(form.options.name, "main.pas");
+//! assert!(form.options.backup);
+//! ```
+//!
+//! [`InputLine`]: super::input_line::InputLine
+//! [`VO_TRANSFER`]: super::validator::VO_TRANSFER
+//! [`RangeValidator`]: super::validator::RangeValidator
+//! [`LookupValidator`]: super::lookup_validator::LookupValidator
+
+use super::dialog::Dialog;
+use super::view::{View, ViewId};
+use crate::core::command::{CM_OK, CommandId};
+use chrono::{NaiveDate, NaiveTime};
+use std::collections::HashMap;
+
+/// Typed value carried by one control
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+    /// Plain text of an input line or editable combo box
+    Text(String),
+    /// Number; fixed-point units when the control has decimals
+    Int(i64),
+    /// Check box or radio button state
+    Bool(bool),
+    /// Chosen item of a list, `None` for no choice
+    Index(Option<usize>),
+    Date(NaiveDate),
+    Time(NaiveTime),
+}
+
+/// Values of a dialog's controls, keyed by the controls' `ViewId`s
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DataFields {
+    values: HashMap<ViewId, FieldValue>,
+}
+
+impl DataFields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, id: ViewId, value: FieldValue) {
+        self.values.insert(id, value);
+    }
+
+    pub fn get(&self, id: ViewId) -> Option<&FieldValue> {
+        self.values.get(&id)
+    }
+
+    /// All values, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (ViewId, &FieldValue)> {
+        self.values.iter().map(|(&id, value)| (id, value))
+    }
+
+    pub fn set_text(&mut self, id: ViewId, text: impl Into<String>) {
+        self.set(id, FieldValue::Text(text.into()));
+    }
+
+    pub fn set_int(&mut self, id: ViewId, value: i64) {
+        self.set(id, FieldValue::Int(value));
+    }
+
+    pub fn set_bool(&mut self, id: ViewId, value: bool) {
+        self.set(id, FieldValue::Bool(value));
+    }
+
+    pub fn set_index(&mut self, id: ViewId, index: Option<usize>) {
+        self.set(id, FieldValue::Index(index));
+    }
+
+    pub fn set_date(&mut self, id: ViewId, date: NaiveDate) {
+        self.set(id, FieldValue::Date(date));
+    }
+
+    pub fn set_time(&mut self, id: ViewId, time: NaiveTime) {
+        self.set(id, FieldValue::Time(time));
+    }
+
+    /// Select the radio button `ids[index]` and clear the others
+    ///
+    /// The usual way to show an enum, with one button per variant.
+    pub fn set_choice(&mut self, ids: &[ViewId], index: usize) {
+        for (i, &id) in ids.iter().enumerate() {
+            self.set_bool(id, i == index);
+        }
+    }
+
+    pub fn text(&self, id: ViewId) -> Option<&str> {
+        match self.get(id) {
+            Some(FieldValue::Text(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn int(&self, id: ViewId) -> Option<i64> {
+        match self.get(id) {
+            Some(FieldValue::Int(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn bool(&self, id: ViewId) -> Option<bool> {
+        match self.get(id) {
+            Some(FieldValue::Bool(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Chosen item, `None` if there is none or the value is not an index
+    pub fn index(&self, id: ViewId) -> Option<usize> {
+        match self.get(id) {
+            Some(FieldValue::Index(index)) => *index,
+            _ => None,
+        }
+    }
+
+    pub fn date(&self, id: ViewId) -> Option<NaiveDate> {
+        match self.get(id) {
+            Some(FieldValue::Date(date)) => Some(*date),
+            _ => None,
+        }
+    }
+
+    pub fn time(&self, id: ViewId) -> Option<NaiveTime> {
+        match self.get(id) {
+            Some(FieldValue::Time(time)) => Some(*time),
+            _ => None,
+        }
+    }
+
+    /// Position of the selected radio button among `ids`
+    pub fn choice(&self, ids: &[ViewId]) -> Option<usize> {
+        ids.iter().position(|&id| self.bool(id) == Some(true))
+    }
+}
+
+/// A record shown and edited by a dialog
+/// Matches Borland: the data record passed to `TDialog::setData()` / `getData()`
+pub trait DialogData {
+    /// Put the record's values into `fields`, before the dialog runs
+    fn write_fields(&self, fields: &mut DataFields);
+
+    /// Take the edited values back from `fields`
+    ///
+    /// Only the controls that carry a value appear, so a field missing from
+    /// `fields` should keep its current value.
+    fn read_fields(&mut self, fields: &DataFields);
+}
+
+impl Dialog {
+    /// Fill the controls from `data`, including those inside tab pages and
+    /// nested groups
+    /// Matches Borland: `TDialog::setData()`
+    pub fn set_record(&mut self, data: &impl DialogData) {
+        let mut fields = DataFields::new();
+        data.write_fields(&mut fields);
+        set_fields(self, &fields);
+    }
+
+    /// Copy the values of the controls into `data`, including those inside
+    /// tab pages and nested groups
+    /// Matches Borland: `TDialog::getData()`
+    pub fn get_record(&self, data: &mut impl DialogData) {
+        let mut fields = DataFields::new();
+        get_fields(self, &mut fields);
+        data.read_fields(&fields);
+    }
+
+    /// Run the dialog on `data`: fill the controls, execute, and write the
+    /// values back only if the dialog ends with `CM_OK`
+    pub fn execute_with_data(
+        &mut self,
+        app: &mut crate::app::Application,
+        data: &mut impl DialogData,
+    ) -> CommandId {
+        self.set_record(data);
+        let result = self.execute(app);
+        if result == CM_OK {
+            self.get_record(data);
+        }
+        result
+    }
+}
+
+/// Matches Borland: `TGroup::setData()` handing the record to every subview
+fn set_fields(group: &mut dyn View, fields: &DataFields) {
+    for (id, view) in group.subviews_mut() {
+        if let Some(value) = fields.get(id) {
+            view.set_data(value);
+        }
+        set_fields(view, fields);
+    }
+}
+
+/// Matches Borland: `TGroup::getData()` collecting from every subview
+fn get_fields(group: &dyn View, fields: &mut DataFields) {
+    for (id, view) in group.subviews() {
+        if let Some(value) = view.get_data() {
+            fields.set(id, value);
+        }
+        get_fields(view, fields);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::geometry::Rect;
+    use crate::views::checkbox::CheckBox;
+    use crate::views::date_picker::DatePickerBuilder;
+    use crate::views::input_line::InputLine;
+    use crate::views::listbox::ListBox;
+    use crate::views::lookup_validator::LookupValidator;
+    use crate::views::radiobutton::RadioButton;
+    use crate::views::spin_input::SpinInputBuilder;
+    use crate::views::tab_view::TabView;
+    use crate::views::validator::{RangeValidator, VO_TRANSFER, ValidatorRef};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Align {
+        Left,
+        Center,
+        Right,
+    }
+
+    const ALIGNS: [Align; 3] = [Align::Left, Align::Center, Align::Right];
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Settings {
+        name: String,
+        width: i64,
+        price: i64,
+        color: Align,
+        align: Align,
+        wrap: bool,
+        font: Option<usize>,
+        tabs: i64,
+        due: NaiveDate,
+    }
+
+    struct Ids {
+        name: ViewId,
+        width: ViewId,
+        price: ViewId,
+        color: ViewId,
+        align: [ViewId; 3],
+        wrap: ViewId,
+        font: ViewId,
+        tabs: ViewId,
+        due: ViewId,
+    }
+
+    struct Record<'a> {
+        settings: Settings,
+        ids: &'a Ids,
+    }
+
+    impl DialogData for Record<'_> {
+        fn write_fields(&self, fields: &mut DataFields) {
+            let (s, ids) = (&self.settings, self.ids);
+            fields.set_text(ids.name, &s.name);
+            fields.set_int(ids.width, s.width);
+            fields.set_int(ids.price, s.price);
+            let color = ALIGNS.iter().position(|&a| a == s.color);
+            fields.set_index(ids.color, color);
+            let align = ALIGNS.iter().position(|&a| a == s.align).unwrap();
+            fields.set_choice(&ids.align, align);
+            fields.set_bool(ids.wrap, s.wrap);
+            fields.set_index(ids.font, s.font);
+            fields.set_int(ids.tabs, s.tabs);
+            fields.set_date(ids.due, s.due);
+        }
+
+        fn read_fields(&mut self, fields: &DataFields) {
+            let (s, ids) = (&mut self.settings, self.ids);
+            s.name = fields.text(ids.name).unwrap_or_default().to_string();
+            s.width = fields.int(ids.width).unwrap_or(s.width);
+            s.price = fields.int(ids.price).unwrap_or(s.price);
+            s.color = fields.index(ids.color).map_or(s.color, |i| ALIGNS[i]);
+            s.align = fields.choice(&ids.align).map_or(s.align, |i| ALIGNS[i]);
+            s.wrap = fields.bool(ids.wrap).unwrap_or(s.wrap);
+            s.font = fields.index(ids.font);
+            s.tabs = fields.int(ids.tabs).unwrap_or(s.tabs);
+            s.due = fields.date(ids.due).unwrap_or(s.due);
+        }
+    }
+
+    type Shown = Rc<RefCell<String>>;
+
+    fn input(dialog: &mut Dialog, y: i16, validator: Option<ValidatorRef>) -> (ViewId, Shown) {
+        let data = Rc::new(RefCell::new(String::new()));
+        let mut line = InputLine::new(Rect::new(2, y, 30, y + 1), 20, Rc::clone(&data));
+        if let Some(validator) = validator {
+            line.set_validator(validator);
+        }
+        (dialog.add(Box::new(line)), data)
+    }
+
+    /// The dialog, its ids, and the text of the width, price and color lines
+    fn build() -> (Dialog, Ids, [Shown; 3]) {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 60, 20), "Settings");
+        let (name, _) = input(&mut dialog, 1, None);
+        let width = RangeValidator::with_options(1, 200, VO_TRANSFER);
+        let (width, width_text) = input(&mut dialog, 2, Some(Rc::new(RefCell::new(width))));
+        let mut price = RangeValidator::with_decimals(0, 100_000, 2);
+        price.set_options(VO_TRANSFER);
+        let (price, price_text) = input(&mut dialog, 3, Some(Rc::new(RefCell::new(price))));
+        let mut colors = LookupValidator::new_case_insensitive(
+            ["Left", "Center", "Right"].map(String::from).to_vec(),
+        );
+        colors.set_options(VO_TRANSFER);
+        let (color, color_text) = input(&mut dialog, 4, Some(Rc::new(RefCell::new(colors))));
+        let align = [5, 6, 7].map(|y| {
+            dialog.add(Box::new(RadioButton::new(
+                Rect::new(2, y, 20, y + 1),
+                "~A~lign",
+                1,
+            )))
+        });
+        let wrap = dialog.add(Box::new(CheckBox::new(Rect::new(2, 8, 20, 9), "~W~rap")));
+        let mut fonts = ListBox::new(Rect::new(30, 1, 50, 6), 0);
+        fonts.set_items(["Mono", "Serif", "Sans"].map(String::from).to_vec());
+        let font = dialog.add(Box::new(fonts));
+        let tabs = dialog.add(Box::new(
+            SpinInputBuilder::new()
+                .bounds(Rect::new(2, 10, 12, 11))
+                .range(1, 16)
+                .build(),
+        ));
+        let due = dialog.add(Box::new(
+            DatePickerBuilder::new()
+                .bounds(Rect::new(2, 12, 16, 13))
+                .build(),
+        ));
+        let ids = Ids {
+            name,
+            width,
+            price,
+            color,
+            align,
+            wrap,
+            font,
+            tabs,
+            due,
+        };
+        (dialog, ids, [width_text, price_text, color_text])
+    }
+
+    #[test]
+    fn record_round_trips_through_controls() {
+        let (mut dialog, ids, shown) = build();
+        let settings = Settings {
+            name: "report".to_string(),
+            width: 132,
+            price: 1250,
+            color: Align::Right,
+            align: Align::Center,
+            wrap: true,
+            font: Some(1),
+            tabs: 4,
+            due: NaiveDate::from_ymd_opt(2025, 3, 14).unwrap(),
+        };
+        let mut record = Record {
+            settings: settings.clone(),
+            ids: &ids,
+        };
+        dialog.set_record(&record);
+
+        let shown = shown.map(|text| text.borrow().clone());
+        assert_eq!(shown, ["132", "12.50", "Right"]);
+
+        record.settings = Settings {
+            name: String::new(),
+            width: 0,
+            price: 0,
+            color: Align::Left,
+            align: Align::Left,
+            wrap: false,
+            font: None,
+            tabs: 0,
+            due: NaiveDate::MIN,
+        };
+        dialog.get_record(&mut record);
+        assert_eq!(record.settings, settings);
+    }
+
+    #[test]
+    fn transfer_needs_the_option() {
+        let (mut dialog, ids, _) = build();
+        let line = dialog.child_by_id_mut(ids.width).unwrap();
+        line.set_data(&FieldValue::Text("abc".to_string()));
+        // Not a number: the text comes back as is, so the field keeps its value
+        assert_eq!(
+            dialog.child_by_id(ids.width).unwrap().get_data(),
+            Some(FieldValue::Text("abc".to_string()))
+        );
+
+        let data = Rc::new(RefCell::new("42".to_string()));
+        let mut plain = InputLine::new(Rect::new(0, 0, 10, 1), 10, data);
+        plain.set_validator(Rc::new(RefCell::new(RangeValidator::new(0, 100))));
+        assert_eq!(plain.get_data(), Some(FieldValue::Text("42".to_string())));
+    }
+
+    #[test]
+    fn record_reaches_controls_on_tab_pages() {
+        struct Wrap {
+            wrap: bool,
+            id: ViewId,
+        }
+
+        impl DialogData for Wrap {
+            fn write_fields(&self, fields: &mut DataFields) {
+                fields.set_bool(self.id, self.wrap);
+            }
+
+            fn read_fields(&mut self, fields: &DataFields) {
+                self.wrap = fields.bool(self.id).unwrap_or(self.wrap);
+            }
+        }
+
+        let mut dialog = Dialog::new(Rect::new(0, 0, 40, 12), "Options");
+        let mut tabs = TabView::new(Rect::new(1, 1, 38, 10));
+        let mut page = tabs.new_page();
+        let id = page.add(Box::new(CheckBox::new(Rect::new(1, 1, 20, 2), "~W~rap")));
+        tabs.add_page("Editor", page);
+        dialog.add(Box::new(tabs));
+
+        let mut record = Wrap { wrap: true, id };
+        dialog.set_record(&record);
+        record.wrap = false;
+        dialog.get_record(&mut record);
+        assert!(record.wrap);
+    }
+}
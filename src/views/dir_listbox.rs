@@ -23,7 +23,8 @@
 //   └─ Program Files
 
 use super::list_viewer::{ListViewer, ListViewerState};
-use super::view::View;
+use super::view::{View, write_line_to_terminal};
+use crate::core::draw::DrawBuffer;
 use crate::core::event::{Event, EventType, KB_ENTER};
 use crate::core::geometry::Rect;
 use crate::core::state::StateFlags;
@@ -290,6 +291,13 @@ impl ListViewer for DirListBox {
             String::new()
         }
     }
+
+    fn search_text(&self, item: usize) -> String {
+        self.entries
+            .get(item)
+            .map(|entry| entry.name.clone())
+            .unwrap_or_default()
+    }
 }
 
 impl View for DirListBox {
@@ -335,15 +343,13 @@ impl View for DirListBox {
             };
 
             // Pad with spaces to fill width - all chars drawn with same color
-            let padded = format!("{:width$}", text, width = width);
-
-            for (x, ch) in padded.chars().take(width).enumerate() {
-                terminal.write_cell(
-                    (self.bounds.a.x + x as i16) as u16,
-                    (self.bounds.a.y + y as i16) as u16,
-                    crate::core::draw::Cell::new(ch, color),
-                );
-            }
+            let mut buf = DrawBuffer::new(width);
+            buf.move_char(0, ' ', color, width);
+            buf.move_str(0, &text, color);
+            // The directory name follows the tree lines
+            let name_x = text.chars().count() - self.search_text(item_idx).chars().count();
+            self.draw_type_ahead(&mut buf, item_idx, name_x, y);
+            write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + y as i16, &buf);
         }
     }
 
@@ -8,10 +8,11 @@
 // A simple window that contains an EditorWindow with scrollbars and indicator.
 // Provides a ready-to-use editor window for text editing.
 
+use super::desktop_state::ViewState;
 use super::editor::EditorWindow;
 use super::indicator::Indicator;
 use super::scrollbar::ScrollBar;
-use super::view::View;
+use super::view::{View, ViewId};
 use super::window::Window;
 use crate::core::event::{Event, EventType};
 use crate::core::geometry::{Point, Rect};
@@ -111,6 +112,14 @@ impl View for SharedEditor {
         self.0.borrow().can_focus()
     }
 
+    fn key_context(&self) -> Option<crate::core::keymap::KeyContext> {
+        self.0.borrow().key_context()
+    }
+
+    fn context_menu(&self, local: Point) -> Option<crate::core::menu_data::Menu> {
+        self.0.borrow().context_menu(local)
+    }
+
     fn set_focus(&mut self, focused: bool) {
         self.0.borrow_mut().set_focus(focused);
     }
@@ -275,6 +284,18 @@ impl EditWindow {
         self.window.set_title(title);
     }
 
+    /// Rebuild an edit window saved with the desktop, reopening its file
+    ///
+    /// Returns None if the file can no longer be read. The cursor and zoom
+    /// are applied afterwards by `restore_state()`.
+    pub fn from_state(state: &ViewState) -> Option<Self> {
+        let mut edit_window = Self::new(state.bounds, state.get("title").unwrap_or(""));
+        if let Some(file) = state.get("file") {
+            edit_window.load_file(file).ok()?;
+        }
+        Some(edit_window)
+    }
+
     /// Synchronize frame children (scrollbars, indicator) positions with window bounds
     /// Called from draw() to ensure positions are always correct, preventing visual lag during resize
     /// IMPORTANT: Always update positions regardless of size to prevent elements "staying behind"
@@ -328,6 +349,30 @@ impl View for EditWindow {
         self.window.bounds()
     }
 
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.window.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.window.subviews()
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        self.window.accessible()
+    }
+
+    fn window_number(&self) -> u8 {
+        self.window.number()
+    }
+
+    fn set_window_number(&mut self, number: u8) {
+        self.window.set_number(number);
+    }
+
+    fn is_modified(&self) -> bool {
+        self.editor.borrow().is_modified()
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         // Window handles updating all children (including scrollbars, indicator, and editor)
         self.window.set_bounds(bounds);
@@ -536,6 +581,38 @@ impl View for EditWindow {
     fn set_end_state(&mut self, command: crate::core::command::CommandId) {
         self.window.set_end_state(command);
     }
+
+    fn zoom(&mut self, max_bounds: Rect) {
+        self.window.zoom(max_bounds);
+        // Reposition the scrollbars and indicator for the new size
+        self.set_bounds(self.window.bounds());
+    }
+
+    fn set_parent_bounds(&mut self, bounds: Rect) {
+        self.window.set_parent_bounds(bounds);
+    }
+
+    fn save_state(&self) -> Option<ViewState> {
+        let mut state = self.window.save_window_state("edit_window");
+        let editor = self.editor.borrow();
+        if let Some(file) = editor.get_filename() {
+            state.set("file", file);
+        }
+        state.set_point("cursor", editor.cursor());
+        state.set_point("delta", editor.get_delta());
+        Some(state)
+    }
+
+    fn restore_state(&mut self, state: &ViewState) {
+        self.window.restore_window_state(state);
+        let mut editor = self.editor.borrow_mut();
+        if let Some(delta) = state.get_point("delta") {
+            editor.set_delta(delta);
+        }
+        if let Some(cursor) = state.get_point("cursor") {
+            editor.set_cursor(cursor);
+        }
+    }
 }
 
 #[cfg(test)]
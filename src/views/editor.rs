@@ -7,19 +7,23 @@ use super::scrollbar::ScrollBar;
 use super::syntax::SyntaxHighlighter;
 use super::view::{View, write_line_to_terminal};
 use crate::core::clipboard;
+use crate::core::command::{CM_COPY, CM_CUT, CM_PASTE, CM_REDO, CM_SELECT_ALL, CM_UNDO, CommandId};
 use crate::core::draw::DrawBuffer;
 use crate::core::event::{
     Event, EventType, KB_BACKSPACE, KB_DEL, KB_DOWN, KB_END, KB_ENTER, KB_HOME, KB_LEFT, KB_PGDN,
     KB_PGUP, KB_RIGHT, KB_TAB, KB_UP, MB_LEFT_BUTTON,
 };
 use crate::core::geometry::{Point, Rect};
+use crate::core::i18n::tr;
+use crate::core::menu_data::{Menu, MenuItem, MenuItemBuilder, clipboard_menu};
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
 use std::cell::RefCell;
 use std::cmp::min;
 use std::rc::Rc;
 
-// Control key codes
+// Control key codes (also bound in the editor context of the application
+// keymap, see core::keymap, which turns them into commands first)
 const KB_CTRL_A: u16 = 0x0001; // Ctrl+A - Select All
 const KB_CTRL_C: u16 = 0x0003; // Ctrl+C - Copy
 #[expect(dead_code, reason = "Reserved for future find/replace functionality")]
@@ -283,6 +287,18 @@ impl EditorWindow {
         self.cursor
     }
 
+    /// Move the cursor (clamped to the text), clearing the selection and
+    /// scrolling only if the cursor would be out of view.
+    pub fn set_cursor(&mut self, pos: Point) {
+        self.set_cursor_with_selection(pos, false);
+    }
+
+    /// Set the scroll offset; the cursor should then be placed with
+    /// `set_cursor()`, which keeps it visible.
+    pub fn set_delta(&mut self, delta: Point) {
+        self.delta = Point::new(delta.x.max(0), delta.y.max(0));
+    }
+
     /// Scroll the editor so that the given 0-based line is visible,
     /// moving the cursor to the beginning of that line.
     pub fn scroll_to_line(&mut self, line: usize) {
@@ -1300,11 +1316,55 @@ impl EditorWindow {
     }
 }
 
+impl EditorWindow {
+    /// Run an editing command; returns false if it is not one
+    fn handle_command(&mut self, command: CommandId) -> bool {
+        match command {
+            CM_SELECT_ALL => self.select_all(),
+            CM_COPY => {
+                self.clip_copy();
+            }
+            CM_CUT => {
+                self.clip_cut();
+            }
+            CM_PASTE => {
+                self.clip_paste();
+            }
+            CM_UNDO => self.undo(),
+            CM_REDO => self.redo(),
+            _ => return false,
+        }
+        true
+    }
+}
+
 impl View for EditorWindow {
     fn bounds(&self) -> Rect {
         self.bounds
     }
 
+    fn key_context(&self) -> Option<crate::core::keymap::KeyContext> {
+        Some(crate::core::keymap::KeyContext::Editor)
+    }
+
+    fn context_menu(&self, _local: Point) -> Option<Menu> {
+        let mut menu = clipboard_menu(self.has_selection(), !self.read_only);
+        let undo = MenuItemBuilder::new()
+            .text(tr("~U~ndo"))
+            .command(CM_UNDO)
+            .shortcut("Ctrl+Z")
+            .enabled(self.can_undo() && !self.read_only)
+            .build();
+        menu.items.splice(0..0, [undo, MenuItem::separator()]);
+        Some(menu)
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let line = format!("line {} of {}", self.cursor.y + 1, self.line_count());
+        Some(AccessibleInfo::new(Role::Editor, self.get_filename().unwrap_or("")).with_value(line))
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
         // Note: Scrollbars and indicator are now children of the Window, not the EditorWindow
@@ -1456,8 +1516,9 @@ impl View for EditorWindow {
     fn handle_event(&mut self, event: &mut Event) {
         // Handle mouse events (matching Borland TEditor::handleEvent - teditor.cc:454-493)
         if event.what == EventType::MouseDown {
-            // Only handle mouse events if focused
-            if !self.is_focused() {
+            // Only handle left clicks, and only if focused; a right-click
+            // keeps the selection for the context menu
+            if !self.is_focused() || event.mouse.buttons & MB_LEFT_BUTTON == 0 {
                 return;
             }
 
@@ -1572,6 +1633,15 @@ impl View for EditorWindow {
             }
         }
 
+        // Editing commands, from the menu or from the keymap's editor bindings
+        // Matches Borland: TEditor::handleEvent() evCommand (cmCut, cmCopy, cmPaste, cmUndo)
+        if event.what == EventType::Command && self.is_focused() {
+            if self.handle_command(event.command) {
+                event.clear();
+            }
+            return;
+        }
+
         if event.what == EventType::Keyboard {
             // Only handle keyboard events if focused
             if !self.is_focused() {
@@ -1664,27 +1734,27 @@ impl View for EditorWindow {
                     event.clear();
                 }
                 KB_CTRL_A => {
-                    self.select_all();
+                    self.handle_command(CM_SELECT_ALL);
                     event.clear();
                 }
                 KB_CTRL_C => {
-                    self.clip_copy();
+                    self.handle_command(CM_COPY);
                     event.clear();
                 }
                 KB_CTRL_X => {
-                    self.clip_cut();
+                    self.handle_command(CM_CUT);
                     event.clear();
                 }
                 KB_CTRL_V => {
-                    self.clip_paste();
+                    self.handle_command(CM_PASTE);
                     event.clear();
                 }
                 KB_CTRL_Z => {
-                    self.undo();
+                    self.handle_command(CM_UNDO);
                     event.clear();
                 }
                 KB_CTRL_Y => {
-                    self.redo();
+                    self.handle_command(CM_REDO);
                     event.clear();
                 }
                 key_code => {
@@ -1754,6 +1824,7 @@ impl View for EditorWindow {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::keymap::KeyContext;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -1776,6 +1847,26 @@ mod tests {
         assert!(!editor.is_modified());
     }
 
+    #[test]
+    fn test_editor_editing_commands() {
+        let mut editor = EditorWindow::new(Rect::new(0, 0, 80, 25));
+        editor.set_text("hello");
+        editor.set_focus(true);
+        assert_eq!(editor.key_context(), Some(KeyContext::Editor));
+
+        let mut event = Event::command(CM_SELECT_ALL);
+        editor.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert!(editor.has_selection());
+
+        // Without a keymap in front, the keys still work
+        editor.set_text("hello");
+        let mut event = Event::keyboard(KB_CTRL_A);
+        editor.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert!(editor.has_selection());
+    }
+
     #[test]
     fn test_editor_save_as() {
         let bounds = Rect::new(0, 0, 80, 25);
@@ -1960,4 +2051,36 @@ mod tests {
         assert_eq!(editor.get_text(), "");
         assert!(!editor.is_modified());
     }
+
+    #[test]
+    fn test_editor_context_menu() {
+        use crate::core::event::MB_RIGHT_BUTTON;
+
+        let mut editor = EditorWindow::new(Rect::new(0, 0, 80, 25));
+        editor.set_text("hello");
+        editor.set_focus(true);
+        editor.handle_event(&mut Event::command(CM_SELECT_ALL));
+
+        // A right-click keeps the selection for the menu's Cut and Copy
+        let mut event = Event::mouse(
+            EventType::MouseDown,
+            Point::new(2, 0),
+            MB_RIGHT_BUTTON,
+            false,
+        );
+        editor.handle_event(&mut event);
+        assert_eq!(event.what, EventType::MouseDown);
+        assert!(editor.has_selection());
+
+        let menu = editor.context_menu(Point::new(2, 0)).unwrap();
+        let enabled = |command| menu.item_by_command(command).unwrap().is_selectable();
+        assert!(!enabled(CM_UNDO));
+        assert!(enabled(CM_CUT));
+        assert!(enabled(CM_COPY));
+
+        editor.set_read_only(true);
+        let menu = editor.context_menu(Point::new(2, 0)).unwrap();
+        assert!(!menu.item_by_command(CM_CUT).unwrap().is_selectable());
+        assert!(!menu.item_by_command(CM_PASTE).unwrap().is_selectable());
+    }
 }
@@ -11,6 +11,7 @@
 //! - Directory navigation (double-click directories or select and press Enter)
 //! - Wildcard filtering (e.g., "*.rs" shows only Rust files)
 //! - Parent directory navigation via ".."
+//! - Optional multi-file picking (`with_multi_select(true)` and `execute_multi()`)
 //!
 //! ## Usage
 //!
@@ -100,15 +101,33 @@
 /// - Any other text: `"~C~ustom"`
 ///
 /// The `~` character indicates the hotkey underline in the button text.
+///
+/// ## Picking Several Files
+///
+/// With `with_multi_select(true)` the file list accepts Space/Ins, Shift+arrows,
+/// Ctrl+click and Shift+click to mark files; `execute_multi()` then returns
+/// every marked file (or the single chosen file if none is marked):
+///
+/// ```rust,ignore
+/// let mut dialog = FileDialog::new(bounds, "Open Files", "*", None)
+///     .with_multi_select(true)
+///     .build();
+/// for path in dialog.execute_multi(&mut app) {
+///     println!("Selected: {}", path.display());
+/// }
+/// ```
 use super::View;
 use super::button::Button;
 use super::dialog::Dialog;
 use super::input_line::InputLine;
 use super::label::Label;
+use super::list_viewer::ListViewer;
 use super::listbox::ListBox;
+use super::view::ViewId;
 use crate::core::command::{CM_CANCEL, CM_FILE_FOCUSED, CM_OK, CommandId};
 use crate::core::event::{Event, EventType};
 use crate::core::geometry::Rect;
+use crate::core::i18n::{text_width, tr};
 use crate::terminal::Terminal;
 use std::cell::RefCell;
 use std::fs;
@@ -121,6 +140,9 @@ const CMD_FILE_SELECTED: u16 = 1000;
 const CHILD_LISTBOX: usize = 4; // ListBox
 const CHILD_OK_BUTTON: usize = 5; // Open button
 
+// Narrowest file name input before the dialog is widened
+const MIN_INPUT_WIDTH: i16 = 20;
+
 pub struct FileDialog {
     dialog: Dialog,
     current_path: PathBuf,
@@ -130,6 +152,8 @@ pub struct FileDialog {
     selected_file_index: usize, // Track ListBox selection
     title: String,              // Store title for rebuilds
     button_label: String,       // "Open", "Save", etc.
+    multi_select: bool,         // Allow marking several files
+    picked: Vec<PathBuf>,       // Files returned by the last execute()
 }
 
 impl FileDialog {
@@ -151,6 +175,8 @@ impl FileDialog {
             selected_file_index: 0,
             title: title.to_string(),
             button_label: "~O~pen".to_string(), // Default to "Open"
+            multi_select: false,
+            picked: Vec::new(),
         }
     }
 
@@ -160,20 +186,49 @@ impl FileDialog {
         self
     }
 
+    /// Let the user mark several files in the list (see [`execute_multi`](Self::execute_multi))
+    #[must_use]
+    pub fn with_multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
     pub fn build(mut self) -> Self {
-        let bounds = self.dialog.bounds();
-        let dialog_width = bounds.width();
+        let mut bounds = self.dialog.bounds();
+
+        // Translated texts; the button column widens to fit the longest one
+        let name_text = tr("~N~ame:");
+        let files_text = tr("~F~iles:");
+        let button_text = format!("  {}  ", tr(&self.button_label));
+        let cancel_text = format!(" {} ", tr("~C~ancel"));
+        let button_width = (text_width(&button_text).max(text_width(&cancel_text)) + 1).max(11);
+        let label_width = (text_width(&name_text).max(text_width(&files_text)) + 1).max(10);
+
+        // Widen the dialog when the translated texts leave too little room
+        // for the file name input
+        let dialog_width = bounds
+            .width()
+            .max(2 + label_width + MIN_INPUT_WIDTH + button_width + 4);
+        if dialog_width > bounds.width() {
+            bounds = Rect::new(
+                bounds.a.x,
+                bounds.a.y,
+                bounds.a.x + dialog_width,
+                bounds.b.y,
+            );
+            self.dialog.set_bounds(bounds);
+        }
 
         // Reserve space for buttons on the right
-        let content_width = dialog_width - 15;
+        let content_width = dialog_width - button_width - 4;
 
         // Label for file name input
-        let name_label = Label::new(Rect::new(2, 1, 12, 1), "~N~ame:");
+        let name_label = Label::new(Rect::new(2, 1, 2 + label_width, 1), &name_text);
         self.dialog.add(Box::new(name_label));
 
         // File name input line
         let file_input = InputLine::new(
-            Rect::new(12, 1, content_width, 2),
+            Rect::new(2 + label_width, 1, content_width, 2),
             255,
             self.file_name_data.clone(),
         );
@@ -185,7 +240,7 @@ impl FileDialog {
         self.dialog.add(Box::new(path_label));
 
         // Label for files list
-        let files_label = Label::new(Rect::new(2, 5, 12, 5), "~F~iles:");
+        let files_label = Label::new(Rect::new(2, 5, 2 + label_width, 5), &files_text);
         self.dialog.add(Box::new(files_label));
 
         // File list box - leave space on right for buttons
@@ -194,6 +249,10 @@ impl FileDialog {
             CMD_FILE_SELECTED,
         );
 
+        if self.multi_select {
+            file_list.set_multi_select(true);
+        }
+
         // Load directory contents first
         self.read_directory();
 
@@ -215,14 +274,13 @@ impl FileDialog {
         }
 
         // Buttons on the right side (vertically stacked)
-        let button_x = dialog_width - 14; // 15 = 12 (button width) + 2 (right margin) + 1 (end space)
+        let button_x = dialog_width - button_width - 3; // button + 2 (right margin) + 1 (end space)
         let mut button_y = 6;
 
         // Create button with appropriate label (Open, Save, etc.)
         // Pad the button label to maintain consistent button width
-        let button_text = format!("  {}  ", self.button_label);
         let open_button = Button::new(
-            Rect::new(button_x, button_y, button_x + 11, button_y + 2),
+            Rect::new(button_x, button_y, button_x + button_width, button_y + 2),
             &button_text,
             CM_OK,
             true,
@@ -231,8 +289,8 @@ impl FileDialog {
         button_y += 3;
 
         let cancel_button = Button::new(
-            Rect::new(button_x, button_y, button_x + 11, button_y + 2),
-            " ~C~ancel ",
+            Rect::new(button_x, button_y, button_x + button_width, button_y + 2),
+            &cancel_text,
             CM_CANCEL,
             false,
         );
@@ -246,6 +304,19 @@ impl FileDialog {
         self
     }
 
+    /// Run the dialog and return every picked file (empty if cancelled)
+    ///
+    /// Marked files are returned in list order; if no file is marked this is
+    /// the single file chosen as with [`execute`](Self::execute).
+    pub fn execute_multi(&mut self, app: &mut crate::app::Application) -> Vec<PathBuf> {
+        self.picked.clear();
+        match self.execute(app) {
+            Some(path) if self.picked.is_empty() => vec![path],
+            Some(_) => self.picked.clone(),
+            None => Vec::new(),
+        }
+    }
+
     pub fn execute(&mut self, app: &mut crate::app::Application) -> Option<PathBuf> {
         use crate::core::state::SF_MODAL;
 
@@ -349,31 +420,7 @@ impl FileDialog {
                                     // Check if input contains wildcards (*.txt, *.rs, etc)
                                     if self.contains_wildcards(&file_name) {
                                         // Borland pattern: Update wildcard and reload list, keep dialog open
-                                        // Matches: strcpy(wildCard, name); fileList->readDirectory()
-                                        self.wildcard = file_name.clone();
-                                        self.read_directory();
-
-                                        // Update ListBox items directly (don't rebuild entire dialog)
-                                        // Downcast to ListBox to call set_items()
-                                        if CHILD_LISTBOX < self.dialog.child_count() {
-                                            let view = self.dialog.child_at_mut(CHILD_LISTBOX);
-                                            if let Some(listbox) =
-                                                view.as_any_mut().downcast_mut::<ListBox>()
-                                            {
-                                                listbox.set_items(self.files.clone());
-                                                listbox.set_list_selection(0);
-                                            }
-                                        }
-
-                                        // Update input field to show the wildcard pattern
-                                        *self.file_name_data.borrow_mut() = self.wildcard.clone();
-
-                                        // Reset selection tracking
-                                        self.selected_file_index = 0;
-
-                                        // CRITICAL: Clear the end_state that was set by Dialog.handle_event()
-                                        // Dialog called end_modal(CM_OK) but we're staying open for wildcard filter
-                                        self.dialog.set_end_state(0);
+                                        self.apply_wildcard(&file_name);
 
                                         // Force full redraw to ensure ListBox visual updates
                                         // The Terminal's double-buffering system needs this to guarantee
@@ -384,6 +431,11 @@ impl FileDialog {
                                         continue;
                                     }
 
+                                    // Several marked files win over the input line
+                                    if let Some(path) = self.take_marked_files(&file_name) {
+                                        return Some(path);
+                                    }
+
                                     // Check if it's a directory navigation request or file selection
                                     if let Some(path) =
                                         self.handle_selection(&file_name, &mut app.terminal)
@@ -413,6 +465,10 @@ impl FileDialog {
                                 // So we just read what's already there and handle it
                                 let file_name = self.file_name_data.borrow().clone();
 
+                                if let Some(path) = self.take_marked_files(&file_name) {
+                                    return Some(path);
+                                }
+
                                 if !file_name.is_empty() {
                                     // Handle the selection (navigate into folder or return file)
                                     if let Some(path) =
@@ -438,6 +494,69 @@ impl FileDialog {
         }
     }
 
+    /// Filter the list with a wildcard typed into the input line
+    /// Matches Borland: `strcpy(wildCard, name); fileList->readDirectory()`
+    fn apply_wildcard(&mut self, pattern: &str) {
+        self.wildcard = pattern.to_string();
+        self.read_directory();
+
+        // Update ListBox items directly (don't rebuild entire dialog)
+        // Downcast to ListBox to call set_items()
+        if CHILD_LISTBOX < self.dialog.child_count() {
+            let view = self.dialog.child_at_mut(CHILD_LISTBOX);
+            if let Some(listbox) = view.as_any_mut().downcast_mut::<ListBox>() {
+                listbox.set_items(self.files.clone());
+                listbox.set_list_selection(0);
+            }
+        }
+
+        // Update input field to show the wildcard pattern
+        *self.file_name_data.borrow_mut() = self.wildcard.clone();
+
+        // Reset selection tracking
+        self.selected_file_index = 0;
+
+        // CRITICAL: Clear the end_state that was set by Dialog.handle_event()
+        // Dialog called end_modal(CM_OK) but we're staying open for wildcard filter
+        self.dialog.set_end_state(0);
+    }
+
+    /// Files marked in the list box (multi-select only), in list order
+    fn marked_files(&self) -> Vec<PathBuf> {
+        if !self.multi_select || CHILD_LISTBOX >= self.dialog.child_count() {
+            return Vec::new();
+        }
+        let view = self.dialog.child_at(CHILD_LISTBOX);
+        let Some(listbox) = view.as_any().downcast_ref::<ListBox>() else {
+            return Vec::new();
+        };
+        listbox
+            .selected_indices()
+            .into_iter()
+            .filter_map(|idx| self.files.get(idx))
+            .filter(|name| *name != ".." && !(name.starts_with('[') && name.ends_with(']')))
+            .map(|name| self.current_path.join(name))
+            .collect()
+    }
+
+    /// If any file is marked and `file_name` (the input line) does not open
+    /// a directory, remember the marked files for `execute_multi()` and return
+    /// the first
+    fn take_marked_files(&mut self, file_name: &str) -> Option<PathBuf> {
+        let opens_dir = file_name == ".."
+            || file_name.contains('/')
+            || (file_name.starts_with('[') && file_name.ends_with(']'));
+        if opens_dir {
+            return None;
+        }
+        let marked = self.marked_files();
+        if marked.is_empty() {
+            return None;
+        }
+        self.picked = marked;
+        self.picked.first().cloned()
+    }
+
     /// Sync the InputLine with the current ListBox selection
     /// Matches Borland: TFileList::focusItem() broadcasts cmFileFocused when selection changes
     /// We read the ListBox selection after it has processed events
@@ -559,6 +678,7 @@ impl FileDialog {
         let old_bounds = self.dialog.bounds();
         let old_title = self.title.clone();
         let old_button_label = self.button_label.clone();
+        let old_multi_select = self.multi_select;
 
         *self = Self::new(
             old_bounds,
@@ -567,6 +687,7 @@ impl FileDialog {
             Some(self.current_path.clone()),
         )
         .with_button_label(&old_button_label)
+        .with_multi_select(old_multi_select)
         .build();
 
         // Reset focus to listbox after directory navigation
@@ -713,6 +834,18 @@ impl View for FileDialog {
         self.dialog.bounds()
     }
 
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.dialog.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.dialog.subviews()
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        self.dialog.accessible()
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.dialog.set_bounds(bounds);
     }
@@ -761,6 +894,7 @@ pub struct FileDialogBuilder {
     initial_dir: Option<PathBuf>,
     button_label: String,
     resizable: bool,
+    multi_select: bool,
 }
 
 impl FileDialogBuilder {
@@ -773,6 +907,7 @@ impl FileDialogBuilder {
             initial_dir: None,
             button_label: "~O~pen".to_string(),
             resizable: true, // Resizable by default
+            multi_select: false,
         }
     }
 
@@ -822,6 +957,14 @@ impl FileDialogBuilder {
         self
     }
 
+    /// Sets whether several files can be marked and returned by
+    /// `execute_multi()` (default: false).
+    #[must_use]
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
     /// Builds the FileDialog.
     ///
     /// # Panics
@@ -832,6 +975,7 @@ impl FileDialogBuilder {
         let title = self.title.expect("FileDialog title must be set");
         let mut fd = FileDialog::new(bounds, &title, &self.wildcard, self.initial_dir)
             .with_button_label(&self.button_label)
+            .with_multi_select(self.multi_select)
             .build();
         fd.dialog.set_resizable(self.resizable);
         fd
@@ -848,3 +992,67 @@ impl Default for FileDialogBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_select_collects_marked_files_only() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "").unwrap();
+        fs::write(dir.path().join("b.txt"), "").unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+
+        let mut fd = FileDialog::new(
+            Rect::new(0, 0, 60, 20),
+            "Open",
+            "*",
+            Some(dir.path().to_path_buf()),
+        )
+        .with_multi_select(true)
+        .build();
+        assert!(fd.take_marked_files("a.txt").is_none());
+
+        // A single marked file is picked too
+        let b_index = fd.files.iter().position(|name| name == "b.txt").unwrap();
+        let view = fd.dialog.child_at_mut(CHILD_LISTBOX);
+        let listbox = view.as_any_mut().downcast_mut::<ListBox>().unwrap();
+        listbox.list_state_mut().toggle_selected(b_index);
+        assert_eq!(
+            fd.take_marked_files("a.txt"),
+            Some(dir.path().join("b.txt"))
+        );
+        assert_eq!(fd.picked, [dir.path().join("b.txt")]);
+
+        let view = fd.dialog.child_at_mut(CHILD_LISTBOX);
+        let listbox = view.as_any_mut().downcast_mut::<ListBox>().unwrap();
+        listbox.select_all();
+
+        assert_eq!(
+            fd.take_marked_files("a.txt"),
+            Some(dir.path().join("a.txt"))
+        );
+        assert_eq!(
+            fd.picked,
+            [dir.path().join("a.txt"), dir.path().join("b.txt")]
+        );
+    }
+
+    #[test]
+    fn long_translations_widen_the_dialog() {
+        crate::core::i18n::register("xx", "~F~iles:", "~V~ery long files label:").unwrap();
+        crate::core::i18n::register("xx", "~C~ancel", "~A~bort this file dialog").unwrap();
+        crate::core::i18n::set_locale("xx");
+        let fd = FileDialog::new(Rect::new(0, 0, 40, 20), "Open", "*", None).build();
+        crate::core::i18n::set_locale("en");
+
+        let input = fd.dialog.child_at(1).bounds(); // File name input
+        let label_end = fd.dialog.child_at(3).bounds().b.x; // "Files:" label
+        let button = fd.dialog.child_at(CHILD_OK_BUTTON).bounds();
+        assert!(fd.dialog.bounds().width() > 40);
+        assert!(input.width() >= MIN_INPUT_WIDTH);
+        assert!(input.b.x < button.a.x);
+        assert!(label_end <= input.a.x);
+    }
+}
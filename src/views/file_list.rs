@@ -16,7 +16,8 @@
 // - Integrates with ListViewer trait for consistent navigation
 
 use super::list_viewer::{ListViewer, ListViewerState};
-use super::view::View;
+use super::view::{View, write_line_to_terminal};
+use crate::core::draw::DrawBuffer;
 use crate::core::event::{Event, EventType};
 use crate::core::geometry::Rect;
 use crate::core::state::StateFlags;
@@ -177,7 +178,8 @@ impl FileList {
             self.files.extend(file_entries);
         }
 
-        // Update list state
+        // Update list state; selected indices refer to the old listing
+        self.list_state.clear_selection();
         self.list_state.set_range(self.files.len());
         if let Some(focused) = self.list_state.focused {
             if focused >= self.files.len() {
@@ -237,6 +239,18 @@ impl FileList {
         }
     }
 
+    /// Paths of the selected files (directories are never part of a pick)
+    ///
+    /// Without multiple selection this is the focused file, if any.
+    pub fn selected_files(&self) -> Vec<PathBuf> {
+        self.selected_indices()
+            .into_iter()
+            .filter_map(|idx| self.files.get(idx))
+            .filter(|entry| !entry.is_dir)
+            .map(|entry| entry.path.clone())
+            .collect()
+    }
+
     /// Get file count
     pub fn file_count(&self) -> usize {
         self.files.len()
@@ -281,7 +295,12 @@ impl View for FileList {
             let (text, color) = if item_idx < self.files.len() {
                 let text = self.get_text(item_idx, width);
                 let is_focused = self.is_focused() && Some(item_idx) == self.list_state.focused;
-                let color = if is_focused {
+                let is_marked = self.list_state.multi_select && self.is_selected(item_idx);
+                let color = if is_marked && is_focused {
+                    crate::core::palette::colors::LISTBOX_SELECTED_FOCUSED
+                } else if is_marked {
+                    crate::core::palette::colors::LISTBOX_SELECTED
+                } else if is_focused {
                     crate::core::palette::colors::LISTBOX_FOCUSED
                 } else {
                     crate::core::palette::colors::LISTBOX_NORMAL
@@ -291,15 +310,11 @@ impl View for FileList {
                 (String::new(), crate::core::palette::colors::LISTBOX_NORMAL)
             };
 
-            let padded = format!("{:width$}", text, width = width);
-
-            for (x, ch) in padded.chars().take(width).enumerate() {
-                terminal.write_cell(
-                    (self.bounds.a.x + x as i16) as u16,
-                    (self.bounds.a.y + y as i16) as u16,
-                    crate::core::draw::Cell::new(ch, color),
-                );
-            }
+            let mut buf = DrawBuffer::new(width);
+            buf.move_char(0, ' ', color, width);
+            buf.move_str(0, &text, color);
+            self.draw_type_ahead(&mut buf, item_idx, 0, y);
+            write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + y as i16, &buf);
         }
     }
 
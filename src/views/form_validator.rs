@@ -0,0 +1,742 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Form validation - rules across fields, inline messages and background checks.
+//!
+//! Borland validates one control at a time: `TDialog::valid()` asks each
+//! control, and a failing validator shows its message in a message box. A
+//! [`FormValidator`] attached with
+//! [`Dialog::set_form_validator`](super::dialog::Dialog::set_form_validator)
+//! checks the whole form when the dialog is about to close with `CM_OK` or
+//! `CM_YES`:
+//!
+//! 1. each control's own `valid()`, with its validator's message,
+//! 2. rules over the values of several controls, read as [`DataFields`]:
+//!    a password confirmation, a start date before an end date,
+//! 3. deferred checks, which run on a worker thread ("is this user name
+//!    still free?").
+//!
+//! On failure the dialog stays open, each message appears in the
+//! [`ErrorText`] bound to its control, and the first invalid control gets
+//! the focus. A message goes away as soon as its control is edited.
+//!
+//! A deferred check starts on its own once the value has been left alone for
+//! a moment, so the result is usually known by the time OK is pressed, and a
+//! failure shows up without pressing OK. If it is still running, the dialog
+//! shows "Checking…" and closes by itself when the checks pass. Results
+//! are polled on the `CM_IDLE` broadcast.
+//!
+//! ```rust
+//! use turbo_vision::views::dialog::Dialog;
+//! use turbo_vision::views::form_validator::{ErrorText, FormStatus, FormValidator};
+//! use turbo_vision::views::input_line::InputLine;
+//! use turbo_vision::core::command::CM_OK;
+//! use turbo_vision::core::geometry::Rect;
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//!
+//! let mut dialog = Dialog::new(Rect::new(0, 0, 50, 10), "Sign Up");
+//! let password = Rc::new(RefCell::new("secret".to_string()));
+//! let confirm = Rc::new(RefCell::new("secert".to_string()));
+//! let password_id = dialog.add(Box::new(InputLine::new(Rect::new(2, 1, 22, 2), 20, password)));
+//! let confirm_id = dialog.add(Box::new(InputLine::new(Rect::new(2, 3, 22, 4), 20, confirm)));
+//! let message_id = dialog.add(Box::new(ErrorText::new(Rect::new(23, 3, 48, 4))));
+//!
+//! dialog.set_form_validator(
+//!     FormValidator::new()
+//!         .must_match(password_id, confirm_id, "Passwords differ")
+//!         .error_text(confirm_id, message_id),
+//! );
+//! assert_eq!(dialog.check_form(CM_OK), FormStatus::Invalid);
+//! ```
+
+use super::dialog_data::{DataFields, FieldValue};
+use super::view::{View, ViewId, find_view_mut, write_line_to_terminal};
+use super::window::Window;
+use crate::core::command::CommandId;
+use crate::core::draw::DrawBuffer;
+use crate::core::event::Event;
+use crate::core::geometry::Rect;
+use crate::core::i18n::tr;
+use crate::core::palette::ERROR_TEXT_NORMAL;
+use crate::terminal::Terminal;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a value must stay unchanged before a deferred check starts
+const DEFERRED_DELAY: Duration = Duration::from_millis(300);
+
+/// A message for one control
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: ViewId,
+    pub message: String,
+}
+
+/// Outcome of checking a form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormStatus {
+    Valid,
+    /// Errors are shown and the first invalid control has the focus
+    Invalid,
+    /// Deferred checks are still running
+    Pending,
+}
+
+/// What a form did on `CM_IDLE`
+pub(super) enum FormIdle {
+    Unchanged,
+    /// Messages changed; the dialog needs a redraw
+    Changed,
+    /// The pending checks passed; close the dialog with this command
+    Finish(CommandId),
+}
+
+type RuleFn = Box<dyn Fn(&DataFields) -> Result<(), String>>;
+type DeferredFn = Arc<dyn Fn(&FieldValue) -> Result<(), String> + Send + Sync>;
+type CheckResult = Result<(), String>;
+
+/// A rule over several fields; its message is shown at the last one
+struct Rule {
+    fields: Vec<ViewId>,
+    check: RuleFn,
+}
+
+/// A check of one field that runs on a worker thread
+struct Deferred {
+    field: ViewId,
+    check: DeferredFn,
+    /// Value and result of the last finished check
+    done: Option<(FieldValue, CheckResult)>,
+    /// Value being checked and where its result arrives
+    running: Option<(FieldValue, Receiver<CheckResult>)>,
+    /// Value on the last poll and when it was first seen
+    seen: Option<(FieldValue, Instant)>,
+}
+
+impl Deferred {
+    fn result_for(&self, value: &FieldValue) -> Option<&CheckResult> {
+        self.done
+            .as_ref()
+            .filter(|(checked, _)| checked == value)
+            .map(|(_, result)| result)
+    }
+
+    fn is_running_for(&self, value: &FieldValue) -> bool {
+        self.running
+            .as_ref()
+            .is_some_and(|(checked, _)| checked == value)
+    }
+
+    fn start(&mut self, value: &FieldValue) {
+        if self.is_running_for(value) {
+            return;
+        }
+        let (sender, receiver) = mpsc::channel();
+        let check = Arc::clone(&self.check);
+        let checked = value.clone();
+        thread::spawn(move || {
+            // The form may be gone by now; then nobody wants the result
+            let _ = sender.send(check(&checked));
+        });
+        self.running = Some((value.clone(), receiver));
+    }
+
+    /// Pick up a finished result; true if there was one
+    fn poll(&mut self) -> bool {
+        let Some((value, receiver)) = self.running.take() else {
+            return false;
+        };
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => {
+                self.running = Some((value, receiver));
+                return false;
+            }
+            // The check panicked
+            Err(TryRecvError::Disconnected) => Err(tr("Check failed")),
+        };
+        self.done = Some((value, result));
+        true
+    }
+
+    /// Start a check once `value` has been unchanged for `DEFERRED_DELAY`
+    fn watch(&mut self, value: &FieldValue, now: Instant) {
+        let since = match &self.seen {
+            Some((seen, since)) if seen == value => *since,
+            _ => {
+                self.seen = Some((value.clone(), now));
+                now
+            }
+        };
+        if now.duration_since(since) >= DEFERRED_DELAY && self.result_for(value).is_none() {
+            self.start(value);
+        }
+    }
+}
+
+/// An error on screen, with the values that were checked
+struct Shown {
+    error: FieldError,
+    /// Editing any of these fields clears the error
+    fields: Vec<(ViewId, Option<FieldValue>)>,
+}
+
+impl Shown {
+    fn new(error: FieldError, fields: &[ViewId], values: &DataFields) -> Self {
+        let fields = fields
+            .iter()
+            .map(|&id| (id, values.get(id).cloned()))
+            .collect();
+        Self { error, fields }
+    }
+
+    fn is_current(&self, values: &DataFields) -> bool {
+        self.fields
+            .iter()
+            .all(|(id, value)| values.get(*id) == value.as_ref())
+    }
+}
+
+/// Form-level validation for a dialog (Rust-specific)
+#[derive(Default)]
+pub struct FormValidator {
+    rules: Vec<Rule>,
+    deferred: Vec<Deferred>,
+    /// Control -> the `ErrorText` showing its message
+    labels: HashMap<ViewId, ViewId>,
+    shown: Vec<Shown>,
+    /// Command to close with once the running checks pass
+    pending: Option<CommandId>,
+}
+
+impl FormValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a rule over the values of `fields`; an `Err` message is shown at
+    /// the last of them
+    #[must_use]
+    pub fn rule(
+        mut self,
+        fields: &[ViewId],
+        check: impl Fn(&DataFields) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.rules.push(Rule {
+            fields: fields.to_vec(),
+            check: Box::new(check),
+        });
+        self
+    }
+
+    /// `confirm` must hold the same value as `field`, as when typing a
+    /// password twice
+    #[must_use]
+    pub fn must_match(self, field: ViewId, confirm: ViewId, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.rule(&[field, confirm], move |values| {
+            if values.get(field) == values.get(confirm) {
+                Ok(())
+            } else {
+                Err(message.clone())
+            }
+        })
+    }
+
+    /// `start` must come before `end`: numbers, dates, times or text
+    ///
+    /// Values of different kinds, or missing ones, are not compared.
+    #[must_use]
+    pub fn must_precede(self, start: ViewId, end: ViewId, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.rule(&[start, end], move |values| {
+            let order = values
+                .get(start)
+                .zip(values.get(end))
+                .and_then(|(start, end)| compare(start, end));
+            match order {
+                Some(Ordering::Equal | Ordering::Greater) => Err(message.clone()),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    /// Check `field` on a worker thread, for slow checks such as asking a
+    /// server; the check gets the control's value
+    #[must_use]
+    pub fn deferred(
+        mut self,
+        field: ViewId,
+        check: impl Fn(&FieldValue) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.deferred.push(Deferred {
+            field,
+            check: Arc::new(check),
+            done: None,
+            running: None,
+            seen: None,
+        });
+        self
+    }
+
+    /// Show the messages for `field` in the [`ErrorText`] `label`
+    #[must_use]
+    pub fn error_text(mut self, field: ViewId, label: ViewId) -> Self {
+        self.labels.insert(field, label);
+        self
+    }
+
+    /// Errors currently shown
+    pub fn errors(&self) -> impl Iterator<Item = &FieldError> {
+        self.shown.iter().map(|shown| &shown.error)
+    }
+
+    /// True while closing waits for deferred checks
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Check the form as for closing `window` with `command`
+    pub(super) fn submit(&mut self, window: &mut Window, command: CommandId) -> FormStatus {
+        self.pending = None;
+        let (order, values) = collect(window);
+        let mut errors: Vec<Shown> = Vec::new();
+        let has_error =
+            |errors: &[Shown], field: ViewId| errors.iter().any(|shown| shown.error.field == field);
+
+        for &id in &order {
+            let Some(view) = find_view_mut(window, id) else {
+                continue;
+            };
+            if !view.valid(command) {
+                let message = view.error_message().unwrap_or_else(|| tr("Invalid value"));
+                let error = FieldError { field: id, message };
+                errors.push(Shown::new(error, &[id], &values));
+            }
+        }
+
+        // A rule on a field that is invalid by itself would only add noise
+        for rule in &self.rules {
+            if rule.fields.iter().any(|&field| has_error(&errors, field)) {
+                continue;
+            }
+            let (Some(&field), Err(message)) = (rule.fields.last(), (rule.check)(&values)) else {
+                continue;
+            };
+            errors.push(Shown::new(
+                FieldError { field, message },
+                &rule.fields,
+                &values,
+            ));
+        }
+
+        let mut waiting = false;
+        for check in &mut self.deferred {
+            let Some(value) = values.get(check.field) else {
+                continue;
+            };
+            if has_error(&errors, check.field) {
+                continue;
+            }
+            match check.result_for(value) {
+                Some(Ok(())) => {}
+                Some(Err(message)) => {
+                    let error = FieldError {
+                        field: check.field,
+                        message: message.clone(),
+                    };
+                    errors.push(Shown::new(error, &[check.field], &values));
+                }
+                None => {
+                    check.start(value);
+                    waiting = true;
+                }
+            }
+        }
+
+        self.shown = errors;
+        let first = order
+            .iter()
+            .copied()
+            .find(|&id| has_error(&self.shown, id))
+            .or_else(|| self.shown.first().map(|shown| shown.error.field));
+        let status = if let Some(first) = first {
+            window.focus_subview(first);
+            FormStatus::Invalid
+        } else if waiting {
+            self.pending = Some(command);
+            FormStatus::Pending
+        } else {
+            FormStatus::Valid
+        };
+        self.update_labels(window);
+        status
+    }
+
+    /// Clear edited errors, pick up deferred results and finish a pending close
+    pub(super) fn idle(&mut self, window: &mut Window) -> FormIdle {
+        let (_, values) = collect(window);
+        let count = self.shown.len();
+        self.shown.retain(|shown| shown.is_current(&values));
+        let mut changed = self.shown.len() != count;
+
+        let now = Instant::now();
+        for check in &mut self.deferred {
+            changed |= check.poll();
+            let Some(value) = values.get(check.field) else {
+                continue;
+            };
+            check.watch(value, now);
+            // Failures show up without waiting for OK
+            if let Some(Err(message)) = check.result_for(value) {
+                if !self
+                    .shown
+                    .iter()
+                    .any(|shown| shown.error.field == check.field)
+                {
+                    let error = FieldError {
+                        field: check.field,
+                        message: message.clone(),
+                    };
+                    self.shown.push(Shown::new(error, &[check.field], &values));
+                    changed = true;
+                }
+            }
+        }
+
+        if let Some(command) = self.pending {
+            let running = self.deferred.iter().any(|check| {
+                values
+                    .get(check.field)
+                    .is_some_and(|value| check.is_running_for(value))
+            });
+            if !running || !self.shown.is_empty() {
+                // Everything is known now: check again, in case of edits meanwhile
+                match self.submit(window, command) {
+                    FormStatus::Valid => return FormIdle::Finish(command),
+                    FormStatus::Invalid | FormStatus::Pending => return FormIdle::Changed,
+                }
+            }
+        }
+
+        if changed {
+            self.update_labels(window);
+            FormIdle::Changed
+        } else {
+            FormIdle::Unchanged
+        }
+    }
+
+    fn update_labels(&self, window: &mut Window) {
+        for (&field, &label) in &self.labels {
+            let waiting = self.pending.is_some()
+                && self
+                    .deferred
+                    .iter()
+                    .any(|check| check.field == field && check.running.is_some());
+            let message = self
+                .shown
+                .iter()
+                .find(|shown| shown.error.field == field)
+                .map(|shown| shown.error.message.clone())
+                .or_else(|| waiting.then(|| tr("Checking…")));
+            let text = find_view_mut(window, label)
+                .and_then(|view| view.as_any_mut().downcast_mut::<ErrorText>());
+            if let Some(text) = text {
+                text.set_message(message);
+            }
+        }
+    }
+}
+
+/// The window's controls in order, including those inside tab pages and
+/// nested groups, and the values they carry
+fn collect(window: &Window) -> (Vec<ViewId>, DataFields) {
+    let mut order = Vec::new();
+    let mut values = DataFields::new();
+    collect_into(window, &mut order, &mut values);
+    (order, values)
+}
+
+fn collect_into(group: &dyn View, order: &mut Vec<ViewId>, values: &mut DataFields) {
+    for (id, view) in group.subviews() {
+        if let Some(value) = view.get_data() {
+            values.set(id, value);
+        }
+        // A container checks its controls itself; they are listed instead
+        if view.subviews().is_empty() {
+            order.push(id);
+        } else {
+            collect_into(view, order, values);
+        }
+    }
+}
+
+/// Order of two values of the same kind
+fn compare(a: &FieldValue, b: &FieldValue) -> Option<Ordering> {
+    match (a, b) {
+        (FieldValue::Int(a), FieldValue::Int(b)) => Some(a.cmp(b)),
+        (FieldValue::Date(a), FieldValue::Date(b)) => Some(a.cmp(b)),
+        (FieldValue::Time(a), FieldValue::Time(b)) => Some(a.cmp(b)),
+        (FieldValue::Text(a), FieldValue::Text(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+/// One line of text next to a control, showing its validation message
+pub struct ErrorText {
+    bounds: Rect,
+    message: Option<String>,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl ErrorText {
+    pub fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            message: None,
+            palette_chain: None,
+        }
+    }
+
+    /// Show a message, or nothing
+    pub fn set_message(&mut self, message: Option<String>) {
+        self.message = message;
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl View for ErrorText {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+        let attr = self.map_color(ERROR_TEXT_NORMAL);
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', attr, width);
+        if let Some(message) = &self.message {
+            buf.move_str(0, message, attr);
+        }
+        write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
+    }
+
+    fn handle_event(&mut self, _event: &mut Event) {}
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let message = self.message.as_deref().unwrap_or_default();
+        Some(AccessibleInfo::new(Role::Text, message))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_ERROR_TEXT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::{CM_IDLE, CM_OK};
+    use crate::core::state::SF_MODAL;
+    use crate::views::date_picker::DatePickerBuilder;
+    use crate::views::dialog::Dialog;
+    use crate::views::input_line::InputLine;
+    use crate::views::tab_view::TabView;
+    use crate::views::validator::RangeValidator;
+    use chrono::NaiveDate;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn input(dialog: &mut Dialog, y: i16, text: &str) -> (ViewId, Rc<RefCell<String>>) {
+        let data = Rc::new(RefCell::new(text.to_string()));
+        let line = InputLine::new(Rect::new(2, y, 20, y + 1), 20, Rc::clone(&data));
+        (dialog.add(Box::new(line)), data)
+    }
+
+    fn message(dialog: &Dialog, label: ViewId) -> Option<String> {
+        let view = dialog.child_by_id(label).unwrap();
+        let text = view.as_any().downcast_ref::<ErrorText>().unwrap();
+        text.message().map(str::to_string)
+    }
+
+    fn focused(dialog: &Dialog) -> Option<ViewId> {
+        dialog.current().map(|(id, _)| id)
+    }
+
+    fn idle(dialog: &mut Dialog) {
+        dialog.handle_event(&mut Event::broadcast(CM_IDLE));
+    }
+
+    #[test]
+    fn rules_across_fields_show_inline_errors() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 60, 12), "Sign Up");
+        let (password, _) = input(&mut dialog, 1, "secret");
+        let (confirm, confirm_text) = input(&mut dialog, 2, "secert");
+        let confirm_error = dialog.add(Box::new(ErrorText::new(Rect::new(22, 2, 58, 3))));
+        let date = |day| NaiveDate::from_ymd_opt(2025, 5, day).unwrap();
+        let picker = |y, day| {
+            DatePickerBuilder::new()
+                .bounds(Rect::new(2, y, 16, y + 1))
+                .date(Rc::new(RefCell::new(date(day))))
+                .build()
+        };
+        let start = dialog.add(Box::new(picker(4, 20)));
+        let end = dialog.add(Box::new(picker(5, 10)));
+        let end_error = dialog.add(Box::new(ErrorText::new(Rect::new(22, 5, 58, 6))));
+        dialog.set_form_validator(
+            FormValidator::new()
+                .must_match(password, confirm, "Passwords differ")
+                .must_precede(start, end, "Ends before it starts")
+                .error_text(confirm, confirm_error)
+                .error_text(end, end_error),
+        );
+        dialog.focus_by_view_id(password);
+
+        assert_eq!(dialog.check_form(CM_OK), FormStatus::Invalid);
+        assert_eq!(
+            message(&dialog, confirm_error).as_deref(),
+            Some("Passwords differ")
+        );
+        assert_eq!(
+            message(&dialog, end_error).as_deref(),
+            Some("Ends before it starts")
+        );
+        assert_eq!(focused(&dialog), Some(confirm));
+
+        // Editing the field clears its message at once
+        *confirm_text.borrow_mut() = "secret".to_string();
+        idle(&mut dialog);
+        assert_eq!(message(&dialog, confirm_error), None);
+        assert_eq!(dialog.form_validator().unwrap().errors().count(), 1);
+        assert_eq!(dialog.check_form(CM_OK), FormStatus::Invalid);
+        assert_eq!(focused(&dialog), Some(end));
+
+        dialog
+            .child_by_id_mut(end)
+            .unwrap()
+            .set_data(&FieldValue::Date(date(25)));
+        assert_eq!(dialog.check_form(CM_OK), FormStatus::Valid);
+        assert_eq!(message(&dialog, end_error), None);
+    }
+
+    #[test]
+    fn control_validators_give_the_message() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 60, 12), "Page");
+        let data = Rc::new(RefCell::new("500".to_string()));
+        let mut line = InputLine::new(Rect::new(2, 1, 20, 2), 20, data);
+        line.set_validator(Rc::new(RefCell::new(RangeValidator::new(1, 200))));
+        let width = dialog.add(Box::new(line));
+        dialog.set_form_validator(FormValidator::new());
+
+        assert_eq!(dialog.check_form(CM_OK), FormStatus::Invalid);
+        let errors: Vec<_> = dialog.form_validator().unwrap().errors().cloned().collect();
+        assert_eq!(
+            errors,
+            [FieldError {
+                field: width,
+                message: "Value not in the range 1 - 200".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn controls_on_tab_pages_are_checked_and_focused() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 60, 12), "Page");
+        let mut tabs = TabView::new(Rect::new(1, 1, 58, 10));
+        let mut first = tabs.new_page();
+        let name = Rc::new(RefCell::new("main".to_string()));
+        first.add(Box::new(InputLine::new(Rect::new(1, 1, 20, 2), 20, name)));
+        tabs.add_page("General", first);
+        let mut second = tabs.new_page();
+        let data = Rc::new(RefCell::new("500".to_string()));
+        let mut line = InputLine::new(Rect::new(1, 1, 20, 2), 20, data);
+        line.set_validator(Rc::new(RefCell::new(RangeValidator::new(1, 200))));
+        let width = second.add(Box::new(line));
+        tabs.add_page("Layout", second);
+        dialog.add(Box::new(tabs));
+        dialog.set_form_validator(FormValidator::new());
+
+        assert_eq!(dialog.check_form(CM_OK), FormStatus::Invalid);
+        let errors: Vec<_> = dialog.form_validator().unwrap().errors().cloned().collect();
+        assert_eq!(
+            errors,
+            [FieldError {
+                field: width,
+                message: "Value not in the range 1 - 200".to_string()
+            }]
+        );
+        let path = crate::views::view::focus_path(&dialog);
+        assert_eq!(path.last().map(|&(id, _)| id), Some(width));
+    }
+
+    #[test]
+    fn deferred_checks_close_the_dialog_when_they_pass() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 60, 12), "Sign Up");
+        let (user, user_text) = input(&mut dialog, 1, "admin");
+        let user_error = dialog.add(Box::new(ErrorText::new(Rect::new(22, 1, 58, 2))));
+        dialog.set_form_validator(
+            FormValidator::new()
+                .deferred(user, |value| match value {
+                    FieldValue::Text(name) if name == "admin" => Err("Name taken".to_string()),
+                    _ => Ok(()),
+                })
+                .error_text(user, user_error),
+        );
+        let state = dialog.state();
+        dialog.set_state(state | SF_MODAL);
+
+        let wait = |dialog: &mut Dialog| {
+            for _ in 0..400 {
+                idle(dialog);
+                if !dialog.form_validator().unwrap().is_pending() {
+                    return;
+                }
+                thread::sleep(Duration::from_millis(5));
+            }
+            panic!("deferred check did not finish");
+        };
+
+        dialog.handle_event(&mut Event::command(CM_OK));
+        assert!(dialog.form_validator().unwrap().is_pending());
+        assert_eq!(message(&dialog, user_error).as_deref(), Some("Checking…"));
+        wait(&mut dialog);
+        assert_eq!(message(&dialog, user_error).as_deref(), Some("Name taken"));
+        assert_eq!(dialog.get_end_state(), 0);
+
+        *user_text.borrow_mut() = "guest".to_string();
+        dialog.handle_event(&mut Event::command(CM_OK));
+        assert_eq!(message(&dialog, user_error).as_deref(), Some("Checking…"));
+        wait(&mut dialog);
+        assert_eq!(dialog.get_end_state(), CM_OK);
+    }
+}
@@ -10,7 +10,7 @@ use crate::core::geometry::Rect;
 use crate::core::palette::Attr;
 use crate::core::state::{SF_ACTIVE, SF_DRAGGING, SF_RESIZING, StateFlags};
 use crate::terminal::Terminal;
-use unicode_width::UnicodeWidthStr;
+use unicode_width::UnicodeWidthChar;
 
 pub struct Frame {
     bounds: Rect,
@@ -22,6 +22,8 @@ pub struct Frame {
     state: StateFlags,
     /// Whether the frame is resizable (matches Borland's wfGrow flag)
     resizable: bool,
+    /// Window number shown near the top-right corner (Borland: `TWindow::number`)
+    number: u8,
     palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
 }
 
@@ -51,6 +53,7 @@ impl Frame {
             palette_type,
             state: SF_ACTIVE,
             resizable,
+            number: super::window::WN_NO_NUMBER,
             palette_chain: None,
         }
     }
@@ -66,6 +69,23 @@ impl Frame {
         self.title = title.to_string();
     }
 
+    /// Get the frame title
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    /// Set the window number shown in the top border
+    /// Matches Borland: `TFrame::draw()` shows the owner's `TWindow::number`;
+    /// only 1 to 9 are drawn, `WN_NO_NUMBER` (0) hides it
+    pub fn set_number(&mut self, number: u8) {
+        self.number = number;
+    }
+
+    /// Get the window number shown in the top border
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
     /// Get colors for frame elements based on palette type and state
     /// Matches Borland's getColor() with palette mapping (tframe.cc:43-64)
     /// Returns (frame_attr, close_icon_attr, title_attr)
@@ -145,10 +165,20 @@ impl View for Frame {
             buf.put_char(4, ']', frame_attr);
         }
 
-        // Add title after close button
-        let title_display_width = self.title.width();
-        if !self.title.is_empty() && width > title_display_width + 8 {
-            buf.move_str(6, &format!(" {} ", self.title), title_attr);
+        // Window number near the top-right corner
+        // Matches Borland: tframe.cc puts number+'0' at size.x-3 (no zoom icon here)
+        let numbered = (1..=9).contains(&self.number) && width > 8;
+        if numbered {
+            buf.put_char(width - 3, char::from(b'0' + self.number), frame_attr);
+        }
+
+        // Add title after close button, shortened to stop before the number
+        // Matches Borland: tframe.cc shortens the title when a number is shown
+        let title_end = if numbered { width - 4 } else { width - 1 };
+        let room = title_end.saturating_sub(8);
+        if !self.title.is_empty() && room > 0 {
+            let title = fit_width(&self.title, room).trim_end();
+            buf.move_str(6, &format!(" {title} "), title_attr);
         }
         write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
 
@@ -386,3 +416,14 @@ impl Default for FrameBuilder {
         Self::new()
     }
 }
+/// The longest start of `text` that fits in `width` columns
+fn fit_width(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (index, ch) in text.char_indices() {
+        used += ch.width().unwrap_or(0);
+        if used > width {
+            return &text[..index];
+        }
+    }
+    text
+}
@@ -2,11 +2,13 @@
 
 //! Group view - container for managing multiple child views with focus handling.
 
-use super::view::{View, ViewId, write_line_to_terminal};
+use super::view::{View, ViewId, contains_view, write_line_to_terminal};
 use crate::core::draw::DrawBuffer;
 use crate::core::event::{Event, EventType, KB_SHIFT_TAB, KB_TAB};
 use crate::core::geometry::Rect;
+use crate::core::layout::calc_bounds;
 use crate::core::palette::Attr;
+use crate::core::state::{GrowMode, SF_DROPPED_DOWN};
 use crate::terminal::Terminal;
 
 /// Group - a container for child views
@@ -14,7 +16,8 @@ use crate::terminal::Terminal;
 pub struct Group {
     bounds: Rect,
     children: Vec<Box<dyn View>>,
-    view_ids: Vec<ViewId>, // Parallel vec storing ID for each child
+    view_ids: Vec<ViewId>,     // Parallel vec storing ID for each child
+    grow_modes: Vec<GrowMode>, // Parallel vec storing each child's grow mode (Borland: growMode)
     focused: usize,
     background: Option<Attr>,
     end_state: crate::core::command::CommandId, // For execute() event loop (Borland: endState)
@@ -27,6 +30,7 @@ impl Group {
             bounds,
             children: Vec::new(),
             view_ids: Vec::new(),
+            grow_modes: Vec::new(),
             focused: 0,
             background: None,
             end_state: 0,
@@ -39,6 +43,7 @@ impl Group {
             bounds,
             children: Vec::new(),
             view_ids: Vec::new(),
+            grow_modes: Vec::new(),
             focused: 0,
             background: Some(background),
             end_state: 0,
@@ -59,6 +64,7 @@ impl Group {
         view.set_bounds(absolute_bounds);
 
         let view_id = ViewId::new();
+        self.grow_modes.push(view.grow_mode());
         self.children.push(view);
         self.view_ids.push(view_id);
         view_id
@@ -135,10 +141,12 @@ impl Group {
         // Remove the view and its corresponding ID from their current position
         let view = self.children.remove(index);
         let view_id = self.view_ids.remove(index);
+        let grow_mode = self.grow_modes.remove(index);
 
         // Add them to the end (front of z-order)
         self.children.push(view);
         self.view_ids.push(view_id);
+        self.grow_modes.push(grow_mode);
 
         // Update focused index if necessary
         let new_index = self.children.len() - 1;
@@ -164,10 +172,12 @@ impl Group {
         // Remove the view and its corresponding ID from their current position
         let view = self.children.remove(index);
         let view_id = self.view_ids.remove(index);
+        let grow_mode = self.grow_modes.remove(index);
 
         // Insert them at position 1 (right after element 0, which is typically background)
         self.children.insert(1, view);
         self.view_ids.insert(1, view_id);
+        self.grow_modes.insert(1, grow_mode);
 
         // Update focused index if necessary
         if self.focused == index {
@@ -180,6 +190,25 @@ impl Group {
         1 // Always returns 1 (the back position after index 0)
     }
 
+    /// Change how a child follows this group when it is resized
+    /// Matches Borland: setting TView::growMode
+    /// Returns false if the `ViewId` is not found
+    pub fn set_grow_mode(&mut self, view_id: ViewId, mode: GrowMode) -> bool {
+        match self.view_ids.iter().position(|&id| id == view_id) {
+            Some(index) => {
+                self.grow_modes[index] = mode;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get a child's grow mode by its `ViewId`
+    pub fn grow_mode_of(&self, view_id: ViewId) -> Option<GrowMode> {
+        let index = self.view_ids.iter().position(|&id| id == view_id)?;
+        self.grow_modes.get(index).copied()
+    }
+
     /// Get the ViewId of a child at the given index.
     /// Returns None if the index is out of bounds.
     pub fn view_id_at(&self, index: usize) -> Option<ViewId> {
@@ -197,6 +226,9 @@ impl Group {
             if index < self.view_ids.len() {
                 self.view_ids.remove(index);
             }
+            if index < self.grow_modes.len() {
+                self.grow_modes.remove(index);
+            }
 
             // Update focused index if needed
             if self.focused >= index && self.focused > 0 {
@@ -372,6 +404,18 @@ impl Group {
         }
     }
 
+    /// Check whether moving focus forward (or backward) would wrap around,
+    /// i.e. no focusable child follows (or precedes) the focused one.
+    /// Nested groups use this to hand Tab back to their owner.
+    pub fn focus_wraps(&self, forward: bool) -> bool {
+        let mut others = if forward {
+            self.focused + 1..self.children.len()
+        } else {
+            0..self.focused.min(self.children.len())
+        };
+        !others.any(|i| self.children[i].can_focus())
+    }
+
     pub fn select_next(&mut self) {
         if self.children.is_empty() {
             return;
@@ -432,27 +476,59 @@ impl View for Group {
         self.bounds
     }
 
-    fn set_bounds(&mut self, bounds: Rect) {
-        // Calculate the offset (how much the group moved)
-        let dx = bounds.a.x - self.bounds.a.x;
-        let dy = bounds.a.y - self.bounds.a.y;
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.focused_child()
+            .map(|child| (self.view_ids[self.focused], child))
+    }
 
-        // Calculate the size change (how much the group was resized)
-        let dw = bounds.width() - self.bounds.width();
-        let dh = bounds.height() - self.bounds.height();
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.view_ids
+            .iter()
+            .copied()
+            .zip(self.children.iter().map(|child| &**child))
+            .collect()
+    }
+
+    fn subviews_mut(&mut self) -> Vec<(ViewId, &mut dyn View)> {
+        self.view_ids
+            .iter()
+            .copied()
+            .zip(
+                self.children
+                    .iter_mut()
+                    .map(|child| &mut **child as &mut dyn View),
+            )
+            .collect()
+    }
 
-        // Update our bounds
+    fn focus_subview(&mut self, id: ViewId) -> bool {
+        if self.focus_by_view_id(id) {
+            return true;
+        }
+        let Some(index) = self
+            .children
+            .iter()
+            .position(|child| contains_view(&**child, id))
+        else {
+            return false;
+        };
+        self.set_focus_to(index);
+        self.children[index].focus_subview(id)
+    }
+
+    /// Move and resize the group; children move with it and are resized
+    /// according to their grow modes
+    /// Matches Borland: TGroup::changeBounds() calling calcBounds() on each subview
+    fn set_bounds(&mut self, bounds: Rect) {
+        let old = self.bounds;
         self.bounds = bounds;
 
-        // Update all children's bounds by the offset and size change
-        for child in &mut self.children {
-            let child_bounds = child.bounds();
-            let new_bounds = Rect::new(
-                child_bounds.a.x + dx,
-                child_bounds.a.y + dy,
-                child_bounds.b.x + dx + dw,
-                child_bounds.b.y + dy + dh,
-            );
+        for (child, &mode) in self.children.iter_mut().zip(&self.grow_modes) {
+            // Work relative to the group's origin, like Borland's subview bounds
+            let mut child_bounds = child.bounds();
+            child_bounds.move_by(-old.a.x, -old.a.y);
+            let mut new_bounds = calc_bounds(child_bounds, mode, old.size(), bounds.size());
+            new_bounds.move_by(bounds.a.x, bounds.a.y);
             child.set_bounds(new_bounds);
         }
     }
@@ -493,6 +569,13 @@ impl View for Group {
             }
         }
 
+        // An open drop-down (combo box list) must cover the siblings drawn after its owner
+        if self.focused < self.children.len()
+            && self.children[self.focused].state() & SF_DROPPED_DOWN != 0
+        {
+            self.children[self.focused].draw(terminal);
+        }
+
         // Pop clipping region
         terminal.pop_clip();
     }
@@ -509,18 +592,18 @@ impl View for Group {
         {
             let mouse_pos = event.mouse.pos;
 
-            // For MouseMove and MouseUp, check if the focused child is dragging or resizing
-            // If so, send the event to it even if mouse is outside its bounds
-            // This allows dragging and resizing beyond window boundaries (matches Borland behavior)
-            if (event.what == EventType::MouseMove || event.what == EventType::MouseUp)
-                && self.focused < self.children.len()
-            {
-                // Check if focused child is in dragging or resizing state
-                let child_state = self.children[self.focused].state();
-                if (child_state
-                    & (crate::core::state::SF_DRAGGING | crate::core::state::SF_RESIZING))
-                    != 0
-                {
+            // The focused child keeps the mouse while it is dragging or resizing, so
+            // moves and releases beyond its bounds reach it (matches Borland behavior),
+            // and while its drop-down is open, so it can pick an item outside its
+            // bounds or close on a click elsewhere
+            if self.focused < self.children.len() {
+                use crate::core::state::{SF_DRAGGING, SF_RESIZING};
+                let capturing = if event.what == EventType::MouseDown {
+                    SF_DROPPED_DOWN
+                } else {
+                    SF_DRAGGING | SF_RESIZING | SF_DROPPED_DOWN
+                };
+                if self.children[self.focused].state() & capturing != 0 {
                     self.children[self.focused].handle_event(event);
                     return;
                 }
@@ -911,6 +994,29 @@ mod tests {
         assert!(group.bounds.intersects(&group.children[2].bounds()));
     }
 
+    #[test]
+    fn test_set_bounds_honors_grow_modes() {
+        use crate::core::state::{GF_GROW_ALL, GF_GROW_HI_X};
+
+        let mut group = Group::new(Rect::new(0, 0, 40, 20));
+        let stretch = group.add(Box::new(DrawCountView::new(Rect::new(1, 1, 39, 19))));
+        let fixed = group.add(Box::new(DrawCountView::new(Rect::new(2, 2, 12, 4))));
+        let corner = group.add(Box::new(DrawCountView::new(Rect::new(30, 17, 38, 19))));
+        let wide = group.add(Box::new(DrawCountView::new(Rect::new(0, 0, 40, 1))));
+        assert!(group.set_grow_mode(fixed, 0));
+        assert!(group.set_grow_mode(corner, GF_GROW_ALL));
+        assert!(group.set_grow_mode(wide, GF_GROW_HI_X));
+        assert_eq!(group.grow_mode_of(wide), Some(GF_GROW_HI_X));
+
+        group.set_bounds(Rect::new(5, 5, 65, 30));
+
+        let bounds = |id| group.child_by_id(id).unwrap().bounds();
+        assert_eq!(bounds(stretch), Rect::new(6, 6, 64, 29));
+        assert_eq!(bounds(fixed), Rect::new(7, 7, 17, 9));
+        assert_eq!(bounds(corner), Rect::new(55, 27, 63, 29));
+        assert_eq!(bounds(wide), Rect::new(5, 5, 65, 6));
+    }
+
     #[test]
     fn test_child_by_id() {
         // Create a group and add children
@@ -1117,4 +1223,54 @@ mod tests {
         assert!(group.child_by_id(id3).is_some());
         assert!(group.child_by_id(new_id).is_some());
     }
+
+    #[test]
+    fn test_find_context_menu() {
+        use crate::core::command::{CM_CUT, CM_PASTE, CM_SELECT_ALL};
+        use crate::core::geometry::Point;
+        use crate::views::input_line::InputLine;
+        use crate::views::list_viewer::ListViewer;
+        use crate::views::listbox::ListBox;
+        use crate::views::view::{find_context_menu, send_context_command, views_at};
+
+        let mut group = Group::new(Rect::new(10, 5, 50, 20));
+        group.add(Box::new(DrawCountView::new(Rect::new(0, 0, 40, 1))));
+        let data = std::rc::Rc::new(std::cell::RefCell::new(String::from("text")));
+        let input = group.add(Box::new(InputLine::new(Rect::new(2, 2, 30, 3), 40, data)));
+        let list = group.add(Box::new(ListBox::new(Rect::new(2, 4, 30, 10), 1000)));
+        group.focus_by_view_id(input);
+
+        let path = views_at(&group, Point::new(15, 10));
+        assert_eq!(path.len(), 1);
+        assert_eq!(path[0].0, list);
+        assert!(views_at(&group, Point::new(45, 18)).is_empty());
+
+        // Right-click on the list box: its menu, at the mouse position
+        let (menu, at, owner) = find_context_menu(&group, Some(Point::new(15, 10))).unwrap();
+        assert_eq!(at, Point::new(15, 10));
+        assert_eq!(owner, Some(list));
+        assert!(!menu.item_by_command(CM_SELECT_ALL).unwrap().is_selectable());
+
+        // The header view has no menu and its owner neither
+        assert!(find_context_menu(&group, Some(Point::new(15, 5))).is_none());
+
+        // Menu key: the focused input line's, at its top-left corner
+        let (menu, at, owner) = find_context_menu(&group, None).unwrap();
+        assert_eq!(at, Point::new(12, 7));
+        assert_eq!(owner, Some(input));
+        assert!(!menu.item_by_command(CM_CUT).unwrap().is_selectable());
+        assert!(menu.item_by_command(CM_PASTE).unwrap().is_selectable());
+
+        // The picked command goes to the view that asked, focused or not
+        let listbox = group.child_by_id_mut(list).unwrap();
+        let listbox = listbox.as_any_mut().downcast_mut::<ListBox>().unwrap();
+        listbox.set_items(vec!["Alpha".to_string(), "Beta".to_string()]);
+        listbox.set_multi_select(true);
+        let event = send_context_command(&mut group, Some(list), CM_SELECT_ALL);
+        assert_eq!(event.what, EventType::Nothing);
+        let listbox = group.child_by_id(list).unwrap();
+        let listbox = listbox.as_any().downcast_ref::<ListBox>().unwrap();
+        assert_eq!(listbox.selected_indices(), vec![0, 1]);
+        assert!(!listbox.is_focused());
+    }
 }
@@ -416,6 +416,12 @@ impl HelpFile {
         help_file
     }
 
+    /// Add the topics of markdown text, such as a generated
+    /// [keyboard reference](crate::core::keymap::Keymap::to_markdown)
+    pub fn add_content(&mut self, content: &str) {
+        self.parse_markdown(content);
+    }
+
     /// Load and parse markdown file
     fn load(&mut self) -> std::io::Result<()> {
         let content = fs::read_to_string(&self.path)?;
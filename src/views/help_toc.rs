@@ -120,6 +120,18 @@ impl View for HelpToc {
         self.dialog.bounds()
     }
 
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.dialog.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.dialog.subviews()
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        self.dialog.accessible()
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.dialog.set_bounds(bounds);
     }
@@ -7,9 +7,10 @@
 //
 // A window containing a HelpViewer with navigation and topic selection.
 
+use super::desktop_state::ViewState;
 use super::help_file::HelpFile;
 use super::help_viewer::HelpViewer;
-use super::view::View;
+use super::view::{View, ViewId};
 use super::window::Window;
 use crate::core::command::{CM_CANCEL, CommandId};
 use crate::core::event::{
@@ -104,6 +105,14 @@ impl HelpWindow {
         }
     }
 
+    /// Rebuild a help window saved with the desktop, showing its last topic
+    pub fn from_state(state: &ViewState, help_file: Rc<RefCell<HelpFile>>) -> Self {
+        let title = state.get("title").unwrap_or("Help");
+        let mut help_window = Self::new(state.bounds, title, help_file);
+        help_window.restore_state(state);
+        help_window
+    }
+
     /// Show a topic by ID
     /// Does not add to history (use switchToTopic for navigation with history)
     pub fn show_topic(&mut self, topic_id: &str) -> bool {
@@ -249,6 +258,18 @@ impl View for HelpWindow {
         self.window.bounds()
     }
 
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.window.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.window.subviews()
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        self.window.accessible()
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.window.set_bounds(bounds);
         // Update viewer bounds to match window interior (ABSOLUTE coordinates)
@@ -367,6 +388,37 @@ impl View for HelpWindow {
     fn set_end_state(&mut self, command: crate::core::command::CommandId) {
         self.window.set_end_state(command);
     }
+
+    fn zoom(&mut self, max_bounds: Rect) {
+        self.window.zoom(max_bounds);
+        self.set_bounds(self.window.bounds());
+    }
+
+    fn set_parent_bounds(&mut self, bounds: Rect) {
+        self.window.set_parent_bounds(bounds);
+    }
+
+    fn save_state(&self) -> Option<ViewState> {
+        let mut state = self.window.save_window_state("help_window");
+        let path = self.help_file.borrow().path().to_string();
+        if !path.is_empty() {
+            state.set("help_file", path);
+        }
+        if let Some(topic) = self.current_topic() {
+            state.set("topic", topic);
+        }
+        Some(state)
+    }
+
+    fn restore_state(&mut self, state: &ViewState) {
+        self.window.restore_window_state(state);
+        let shown = state
+            .get("topic")
+            .is_some_and(|topic| self.show_topic(topic));
+        if !shown {
+            self.show_default_topic();
+        }
+    }
 }
 
 #[cfg(test)]
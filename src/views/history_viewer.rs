@@ -11,14 +11,29 @@
 // Usage:
 //   let viewer = HistoryViewer::new(bounds, history_id);
 //   // Viewer will display items from HistoryManager
+//
+// With fuzzy filtering on, typed characters narrow the list to the items
+// containing them in order ("srt" matches "search term"), Backspace widens
+// it again.
 
 use super::list_viewer::{ListViewer, ListViewerState};
 use super::view::View;
-use crate::core::event::Event;
+use crate::core::event::{Event, EventType, KB_BACKSPACE};
 use crate::core::geometry::Rect;
 use crate::core::history::HistoryManager;
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
+use crossterm::event::KeyModifiers;
+
+/// Check if `text` contains the characters of `pattern` in order,
+/// ignoring case
+pub fn fuzzy_match(pattern: &str, text: &str) -> bool {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+    pattern
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|wanted| text.any(|ch| ch == wanted))
+}
 
 /// HistoryViewer - Displays history items for a specific history ID
 ///
@@ -27,7 +42,10 @@ use crate::terminal::Terminal;
 pub struct HistoryViewer {
     bounds: Rect,
     history_id: u16,
+    all_items: Vec<String>,
     items: Vec<String>,
+    fuzzy: bool,
+    filter: String,
     list_state: ListViewerState,
     state: StateFlags,
     palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
@@ -43,7 +61,10 @@ impl HistoryViewer {
         Self {
             bounds,
             history_id,
+            all_items: items.clone(),
             items,
+            fuzzy: false,
+            filter: String::new(),
             list_state,
             state: 0,
             palette_chain: None,
@@ -52,8 +73,64 @@ impl HistoryViewer {
 
     /// Refresh the history items from HistoryManager
     pub fn refresh(&mut self) {
-        self.items = HistoryManager::get_list(self.history_id);
+        self.all_items = HistoryManager::get_list(self.history_id);
+        self.apply_filter();
+    }
+
+    /// Let typed characters filter the list instead of searching it
+    /// (default: off)
+    pub fn set_fuzzy(&mut self, fuzzy: bool) {
+        self.fuzzy = fuzzy;
+        if !fuzzy {
+            self.set_filter("");
+        }
+    }
+
+    /// Show only the items that fuzzy-match `filter`
+    pub fn set_filter(&mut self, filter: &str) {
+        self.filter = filter.to_string();
+        self.apply_filter();
+    }
+
+    /// Current filter text
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    fn apply_filter(&mut self) {
+        self.items = self
+            .all_items
+            .iter()
+            .filter(|item| fuzzy_match(&self.filter, item))
+            .cloned()
+            .collect();
         self.list_state.set_range(self.items.len());
+        let visible_rows = usize::from(self.bounds.height_clamped().cast_unsigned());
+        if !self.items.is_empty() {
+            self.list_state.focus_item(0, visible_rows);
+        }
+    }
+
+    /// Edit the filter with a key; returns true if the key was consumed
+    fn handle_filter_key(&mut self, event: &Event) -> bool {
+        if event.what != EventType::Keyboard
+            || event
+                .key_modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return false;
+        }
+        match event.key_code {
+            KB_BACKSPACE if !self.filter.is_empty() => {
+                self.filter.pop();
+            }
+            key @ (0x20..0x7F | 0xA0..0x100) => {
+                self.filter.extend(char::from_u32(u32::from(key)));
+            }
+            _ => return false,
+        }
+        self.apply_filter();
+        true
     }
 
     /// Get the currently selected history item
@@ -124,12 +201,17 @@ impl View for HistoryViewer {
                 // Empty line
                 buf.move_char(0, ' ', color_normal, width);
             }
+            self.draw_type_ahead(&mut buf, item_idx, 0, i);
 
             write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + i as i16, &buf);
         }
     }
 
     fn handle_event(&mut self, event: &mut Event) {
+        if self.fuzzy && self.handle_filter_key(event) {
+            event.clear();
+            return;
+        }
         // Use ListViewer trait's standard event handling
         self.handle_list_event(event);
     }
@@ -226,12 +308,47 @@ mod tests {
         assert_eq!(viewer.item_count(), 0);
         assert_eq!(viewer.get_selected_item(), None);
     }
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert!(fuzzy_match("srt", "search term"));
+        assert!(fuzzy_match("SRC", "src/main.rs"));
+        assert!(fuzzy_match("", "anything"));
+        assert!(!fuzzy_match("tse", "test"));
+    }
+
+    #[test]
+    fn test_history_viewer_fuzzy_filter() {
+        // Fill the viewer directly, other tests clear the global history
+        let mut viewer = HistoryViewer::new(Rect::new(0, 0, 20, 10), 98);
+        viewer.all_items = vec![
+            "src/lib.rs".to_string(),
+            "README.md".to_string(),
+            "src/main.rs".to_string(),
+        ];
+        viewer.set_fuzzy(true);
+        for ch in "srs".chars() {
+            viewer.handle_event(&mut Event::keyboard(ch as u16));
+        }
+        assert_eq!(viewer.filter(), "srs");
+        assert_eq!(viewer.item_count(), 2);
+        assert_eq!(viewer.get_selected_item(), Some("src/lib.rs"));
+
+        viewer.handle_event(&mut Event::keyboard(KB_BACKSPACE));
+        viewer.handle_event(&mut Event::keyboard(KB_BACKSPACE));
+        assert_eq!(viewer.filter(), "s");
+        assert_eq!(viewer.item_count(), 2);
+
+        viewer.set_fuzzy(false);
+        assert_eq!(viewer.item_count(), 3);
+    }
 }
 
 /// Builder for creating history viewers with a fluent API.
 pub struct HistoryViewerBuilder {
     bounds: Option<Rect>,
     history_id: Option<u16>,
+    fuzzy: bool,
 }
 
 impl HistoryViewerBuilder {
@@ -239,6 +356,7 @@ impl HistoryViewerBuilder {
         Self {
             bounds: None,
             history_id: None,
+            fuzzy: false,
         }
     }
 
@@ -254,12 +372,21 @@ impl HistoryViewerBuilder {
         self
     }
 
+    /// Let typed characters filter the list (default: false)
+    #[must_use]
+    pub fn fuzzy(mut self, fuzzy: bool) -> Self {
+        self.fuzzy = fuzzy;
+        self
+    }
+
     pub fn build(self) -> HistoryViewer {
         let bounds = self.bounds.expect("HistoryViewer bounds must be set");
         let history_id = self
             .history_id
             .expect("HistoryViewer history_id must be set");
-        HistoryViewer::new(bounds, history_id)
+        let mut viewer = HistoryViewer::new(bounds, history_id);
+        viewer.set_fuzzy(self.fuzzy);
+        viewer
     }
 
     pub fn build_boxed(self) -> Box<HistoryViewer> {
@@ -56,6 +56,11 @@ impl HistoryWindow {
         Self { window, viewer }
     }
 
+    /// Let typed characters fuzzy-filter the items (default: off)
+    pub fn set_fuzzy(&mut self, fuzzy: bool) {
+        self.viewer.set_fuzzy(fuzzy);
+    }
+
     /// Execute the history window modally
     ///
     /// Returns the selected history item, or None if cancelled.
@@ -155,6 +160,7 @@ pub struct HistoryWindowBuilder {
     pos: Option<Point>,
     history_id: Option<u16>,
     width: i16,
+    fuzzy: bool,
 }
 
 impl HistoryWindowBuilder {
@@ -163,6 +169,7 @@ impl HistoryWindowBuilder {
             pos: None,
             history_id: None,
             width: 30,
+            fuzzy: false,
         }
     }
 
@@ -184,12 +191,21 @@ impl HistoryWindowBuilder {
         self
     }
 
+    /// Let typed characters fuzzy-filter the items (default: false)
+    #[must_use]
+    pub fn fuzzy(mut self, fuzzy: bool) -> Self {
+        self.fuzzy = fuzzy;
+        self
+    }
+
     pub fn build(self) -> HistoryWindow {
         let pos = self.pos.expect("HistoryWindow pos must be set");
         let history_id = self
             .history_id
             .expect("HistoryWindow history_id must be set");
-        HistoryWindow::new(pos, history_id, self.width)
+        let mut window = HistoryWindow::new(pos, history_id, self.width);
+        window.set_fuzzy(self.fuzzy);
+        window
     }
 
     pub fn build_boxed(self) -> Box<HistoryWindow> {
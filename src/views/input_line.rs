@@ -2,14 +2,17 @@
 
 //! InputLine view - single-line text input with editing and history support.
 
-use super::validator::ValidatorRef;
+use super::dialog_data::FieldValue;
+use super::validator::{VO_TRANSFER, ValidatorRef};
 use super::view::{View, write_line_to_terminal};
 use crate::core::clipboard;
+use crate::core::command::{CM_CANCEL, CM_COPY, CM_CUT, CM_PASTE, CM_SELECT_ALL, CommandId};
 use crate::core::draw::DrawBuffer;
 use crate::core::event::{
     Event, EventType, KB_BACKSPACE, KB_DEL, KB_END, KB_ENTER, KB_HOME, KB_LEFT, KB_RIGHT,
 };
-use crate::core::geometry::Rect;
+use crate::core::geometry::{Point, Rect};
+use crate::core::menu_data::{Menu, clipboard_menu};
 use crate::core::palette::{INPUT_ARROWS, INPUT_FOCUSED, INPUT_NORMAL, INPUT_SELECTED};
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
@@ -135,6 +138,13 @@ impl InputLine {
         self.sel_end = 0;
     }
 
+    /// The validator, if it converts the data (`VO_TRANSFER`)
+    fn transfer_validator(&self) -> Option<&ValidatorRef> {
+        self.validator
+            .as_ref()
+            .filter(|validator| validator.borrow().options() & VO_TRANSFER != 0)
+    }
+
     /// Ensure cursor is visible by adjusting first_pos
     fn make_cursor_visible(&mut self) {
         let width = self.bounds.width_clamped() as usize;
@@ -148,6 +158,52 @@ impl InputLine {
             self.first_pos = self.cursor_pos - width + 1;
         }
     }
+
+    /// Run a clipboard command; returns false if it is not one
+    /// Matches Borland: `TInputLine::handleEvent()` (cut/copy/paste keys)
+    fn handle_command(&mut self, command: CommandId) -> bool {
+        match command {
+            CM_SELECT_ALL => self.select_all(),
+            CM_COPY => {
+                if let Some(selection) = self.get_selection() {
+                    clipboard::set_clipboard(&selection);
+                }
+            }
+            CM_CUT => {
+                if let Some(selection) = self.get_selection() {
+                    clipboard::set_clipboard(&selection);
+                    self.delete_selection();
+                    self.make_cursor_visible();
+                }
+            }
+            CM_PASTE => {
+                let clipboard_text = clipboard::get_clipboard();
+                if !clipboard_text.is_empty() {
+                    // Delete selection if any
+                    if self.has_selection() {
+                        self.delete_selection();
+                    }
+
+                    // Insert clipboard text at cursor position
+                    {
+                        let mut text = self.data.borrow_mut();
+                        let remaining_space = self.max_length.saturating_sub(text.len());
+                        let insert_text = if clipboard_text.len() <= remaining_space {
+                            clipboard_text.as_str()
+                        } else {
+                            &clipboard_text[..remaining_space]
+                        };
+
+                        text.insert_str(self.cursor_pos, insert_text);
+                        self.cursor_pos += insert_text.len();
+                    }
+                    self.make_cursor_visible();
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
 }
 
 impl View for InputLine {
@@ -155,10 +211,54 @@ impl View for InputLine {
         self.bounds
     }
 
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(AccessibleInfo::new(Role::InputLine, "").with_value(self.get_text()))
+    }
+
+    fn context_menu(&self, _local: Point) -> Option<Menu> {
+        Some(clipboard_menu(self.has_selection(), true))
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
     }
 
+    /// Matches Borland: `TInputLine::getData()` - the validator converts the
+    /// text when it has `VO_TRANSFER`
+    fn get_data(&self) -> Option<FieldValue> {
+        let text = self.get_text();
+        let value = self
+            .transfer_validator()
+            .and_then(|validator| validator.borrow().transfer_get(&text));
+        Some(value.unwrap_or(FieldValue::Text(text)))
+    }
+
+    /// Matches Borland: `TInputLine::setData()`
+    fn set_data(&mut self, value: &FieldValue) {
+        let text = match value {
+            FieldValue::Text(text) => Some(text.clone()),
+            value => self
+                .transfer_validator()
+                .and_then(|validator| validator.borrow().transfer_set(value)),
+        };
+        if let Some(text) = text {
+            self.set_text(text);
+        }
+    }
+
+    // Matches Borland: TInputLine::valid() - the validator checks the text,
+    // except when the dialog is cancelled
+    fn valid(&mut self, command: CommandId) -> bool {
+        command == CM_CANCEL || self.validate()
+    }
+
+    fn error_message(&self) -> Option<String> {
+        self.validator
+            .as_ref()
+            .map(|validator| validator.borrow().error_message())
+    }
+
     fn draw(&mut self, terminal: &mut Terminal) {
         let width = self.bounds.width_clamped() as usize;
 
@@ -255,6 +355,14 @@ impl View for InputLine {
             return;
         }
 
+        // Clipboard commands, from the context menu or an Edit menu
+        if event.what == EventType::Command {
+            if self.handle_command(event.command) {
+                event.clear();
+            }
+            return;
+        }
+
         if event.what == EventType::Keyboard {
             match event.key_code {
                 KB_BACKSPACE => {
@@ -320,49 +428,19 @@ impl View for InputLine {
                     // Just pass through without clearing
                 }
                 KB_CTRL_A => {
-                    self.select_all();
+                    self.handle_command(CM_SELECT_ALL);
                     event.clear();
                 }
                 KB_CTRL_C => {
-                    // Copy to clipboard
-                    if let Some(selection) = self.get_selection() {
-                        clipboard::set_clipboard(&selection);
-                    }
+                    self.handle_command(CM_COPY);
                     event.clear();
                 }
                 KB_CTRL_X => {
-                    // Cut to clipboard
-                    if let Some(selection) = self.get_selection() {
-                        clipboard::set_clipboard(&selection);
-                        self.delete_selection();
-                        self.make_cursor_visible();
-                    }
+                    self.handle_command(CM_CUT);
                     event.clear();
                 }
                 KB_CTRL_V => {
-                    // Paste from clipboard
-                    let clipboard_text = clipboard::get_clipboard();
-                    if !clipboard_text.is_empty() {
-                        // Delete selection if any
-                        if self.has_selection() {
-                            self.delete_selection();
-                        }
-
-                        // Insert clipboard text at cursor position
-                        {
-                            let mut text = self.data.borrow_mut();
-                            let remaining_space = self.max_length.saturating_sub(text.len());
-                            let insert_text = if clipboard_text.len() <= remaining_space {
-                                clipboard_text.as_str()
-                            } else {
-                                &clipboard_text[..remaining_space]
-                            };
-
-                            text.insert_str(self.cursor_pos, insert_text);
-                            self.cursor_pos += insert_text.len();
-                        }
-                        self.make_cursor_visible();
-                    }
+                    self.handle_command(CM_PASTE);
                     event.clear();
                 }
                 // Regular character input
@@ -60,6 +60,11 @@ impl View for Label {
         self.bounds
     }
 
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(AccessibleInfo::new(Role::Label, &self.text))
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
     }
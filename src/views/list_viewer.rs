@@ -18,11 +18,106 @@
 //
 // Rust composition:
 //   View trait + ListViewer trait → ListBox (embeds ListViewerState)
+//
+// Type-ahead: typing printable characters focuses the next item whose text
+// starts with (or contains) the typed text; the search restarts after a pause.
 
 use super::view::View;
+use crate::core::draw::DrawBuffer;
 use crate::core::event::{
-    Event, EventType, KB_DOWN, KB_END, KB_ENTER, KB_HOME, KB_PGDN, KB_PGUP, KB_UP, MB_LEFT_BUTTON,
+    Event, EventType, KB_BACKSPACE, KB_CTRL_A, KB_DOWN, KB_END, KB_ESC, KB_HOME, KB_INS, KB_PGDN,
+    KB_PGUP, KB_UP, MB_LEFT_BUTTON,
 };
+use crossterm::event::KeyModifiers;
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+/// How type-ahead text is compared with item text (always case-insensitive)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Item text starts with the typed text
+    #[default]
+    Prefix,
+    /// Item text contains the typed text anywhere
+    Substring,
+}
+
+/// Character range of `needle` in `haystack` under `mode`, ignoring case
+pub fn match_range(mode: MatchMode, needle: &str, haystack: &str) -> Option<Range<usize>> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let needle: Vec<char> = needle.chars().map(fold).collect();
+    let haystack: Vec<char> = haystack.chars().map(fold).collect();
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return None;
+    }
+    let last_start = match mode {
+        MatchMode::Prefix => 0,
+        MatchMode::Substring => haystack.len() - needle.len(),
+    };
+    (0..=last_start)
+        .find(|&start| haystack[start..start + needle.len()] == needle[..])
+        .map(|start| start..start + needle.len())
+}
+
+/// Incremental type-to-find buffer of a list viewer
+#[derive(Clone, Debug)]
+pub struct TypeAhead {
+    /// Whether typing searches the list (off by default, so plain-letter
+    /// hotkeys of the dialog keep working)
+    pub enabled: bool,
+
+    /// Prefix or substring matching
+    pub mode: MatchMode,
+
+    /// Pause after which the next key starts a new search
+    pub timeout: Duration,
+
+    text: String,
+    last_key: Option<Instant>,
+}
+
+impl TypeAhead {
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            mode: MatchMode::Prefix,
+            timeout: Duration::from_secs(1),
+            text: String::new(),
+            last_key: None,
+        }
+    }
+
+    /// Text typed so far (empty when no search is in progress)
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Whether a search is in progress and has not timed out
+    pub fn is_active(&self) -> bool {
+        !self.text.is_empty()
+            && self
+                .last_key
+                .is_some_and(|last| last.elapsed() < self.timeout)
+    }
+
+    /// End the current search
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.last_key = None;
+    }
+
+    /// Character range of the current search text in `item`
+    pub fn find_in(&self, item: &str) -> Option<Range<usize>> {
+        match_range(self.mode, &self.text, item)
+    }
+}
+
+impl Default for TypeAhead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /// State management for list viewer components
 ///
@@ -51,6 +146,18 @@ pub struct ListViewerState {
     /// Whether space bar selects items
     /// Matches Borland: TListViewer::handleSpace
     pub handle_space: bool,
+
+    /// Whether several items can be selected at once
+    pub multi_select: bool,
+
+    /// Selected items when `multi_select` is on (otherwise the focused item is the selection)
+    pub selected: BTreeSet<usize>,
+
+    /// Fixed end of a Shift+arrow or Shift+click range
+    pub anchor: usize,
+
+    /// Type-to-find search
+    pub type_ahead: TypeAhead,
 }
 
 impl ListViewerState {
@@ -62,6 +169,10 @@ impl ListViewerState {
             range: 0,
             num_cols: 1,
             handle_space: true,
+            multi_select: false,
+            selected: BTreeSet::new(),
+            anchor: 0,
+            type_ahead: TypeAhead::new(),
         }
     }
 
@@ -73,6 +184,10 @@ impl ListViewerState {
             range,
             num_cols: 1,
             handle_space: true,
+            multi_select: false,
+            selected: BTreeSet::new(),
+            anchor: 0,
+            type_ahead: TypeAhead::new(),
         }
     }
 
@@ -95,6 +210,99 @@ impl ListViewerState {
         if self.top_item >= range && range > 0 {
             self.top_item = range - 1;
         }
+
+        self.selected.retain(|&item| item < range);
+        self.anchor = self.anchor.min(range.saturating_sub(1));
+    }
+
+    /// Turn multiple selection on or off; clears the selection
+    pub fn set_multi_select(&mut self, multi_select: bool) {
+        self.multi_select = multi_select;
+        self.selected.clear();
+        self.anchor = self.focused.unwrap_or(0);
+    }
+
+    /// Whether `item` is selected: a member of the selection when
+    /// `multi_select` is on, the focused item otherwise
+    pub fn is_item_selected(&self, item: usize) -> bool {
+        if self.multi_select {
+            self.selected.contains(&item)
+        } else {
+            self.focused == Some(item)
+        }
+    }
+
+    /// Selected items in ascending order
+    pub fn selected_indices(&self) -> Vec<usize> {
+        if self.multi_select {
+            self.selected.iter().copied().collect()
+        } else {
+            self.focused.into_iter().collect()
+        }
+    }
+
+    /// Add `item` to the selection or remove it, and make it the range anchor
+    pub fn toggle_selected(&mut self, item: usize) {
+        if item >= self.range {
+            return;
+        }
+        if !self.selected.remove(&item) {
+            self.selected.insert(item);
+        }
+        self.anchor = item;
+    }
+
+    /// Select exactly `item`, and make it the range anchor
+    pub fn select_only(&mut self, item: usize) {
+        self.selected.clear();
+        if item < self.range {
+            self.selected.insert(item);
+            self.anchor = item;
+        }
+    }
+
+    /// Select the items between the anchor and `item` (inclusive), replacing the selection
+    pub fn select_range(&mut self, item: usize) {
+        let (from, to) = (self.anchor.min(item), self.anchor.max(item));
+        self.selected = (from..=to.min(self.range.saturating_sub(1))).collect();
+    }
+
+    pub fn select_all(&mut self) {
+        self.selected = (0..self.range).collect();
+    }
+
+    /// Select the unselected items and unselect the selected ones
+    pub fn invert_selection(&mut self) {
+        self.selected = (0..self.range)
+            .filter(|item| !self.selected.contains(item))
+            .collect();
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Shift the focus, the anchor and the selection after an item was
+    /// inserted at `index` (call after [`set_range`](Self::set_range))
+    pub fn item_inserted(&mut self, index: usize) {
+        let shift = |item: usize| if item >= index { item + 1 } else { item };
+        if self.range > 1 {
+            self.focused = self.focused.map(shift);
+        }
+        self.anchor = shift(self.anchor).min(self.range.saturating_sub(1));
+        self.selected = self.selected.iter().map(|&item| shift(item)).collect();
+    }
+
+    /// After the focus moved: extend the range selection if `extend` (and
+    /// multiple selection is on), otherwise move the anchor to the focus
+    fn focus_moved(&mut self, extend: bool) {
+        if let Some(focused) = self.focused {
+            if extend && self.multi_select {
+                self.select_range(focused);
+            } else {
+                self.anchor = focused;
+            }
+        }
     }
 
     /// Focus a specific item
@@ -233,9 +441,33 @@ pub trait ListViewer: View {
     /// Check if an item is selected
     ///
     /// Matches Borland: TListViewer::isSelected()
-    /// Default: focused item is selected
+    /// Default: focused item is selected, or a member of the selection with multiple selection
     fn is_selected(&self, item: usize) -> bool {
-        Some(item) == self.list_state().focused
+        self.list_state().is_item_selected(item)
+    }
+
+    /// Selected items in ascending order (the focused item without multiple selection)
+    fn selected_indices(&self) -> Vec<usize> {
+        self.list_state().selected_indices()
+    }
+
+    /// Allow selecting several items (Space/Ins toggle, Shift+arrows and
+    /// Shift+click select a range, Ctrl+click toggles, Ctrl+A selects all,
+    /// `*` inverts)
+    fn set_multi_select(&mut self, multi_select: bool) {
+        self.list_state_mut().set_multi_select(multi_select);
+    }
+
+    fn select_all(&mut self) {
+        if self.list_state().multi_select {
+            self.list_state_mut().select_all();
+        }
+    }
+
+    fn invert_selection(&mut self) {
+        if self.list_state().multi_select {
+            self.list_state_mut().invert_selection();
+        }
     }
 
     /// Select an item (for multi-select lists)
@@ -282,6 +514,145 @@ pub trait ListViewer: View {
         self.bounds().height_clamped() as usize
     }
 
+    /// Turn the type-ahead search on or off (off by default)
+    fn set_type_ahead(&mut self, enabled: bool) {
+        let type_ahead = &mut self.list_state_mut().type_ahead;
+        type_ahead.enabled = enabled;
+        type_ahead.clear();
+    }
+
+    /// Text the type-ahead search looks at (default: `get_text`)
+    ///
+    /// Views that decorate their rows (tree lines, markers) return the bare name.
+    fn search_text(&self, item: usize) -> String {
+        self.get_text(item, usize::MAX)
+    }
+
+    /// First item matching `text` at or after `start`, wrapping around
+    ///
+    /// Default: linear scan over `search_text`; sorted lists override it with a
+    /// binary search.
+    fn find_item(&self, text: &str, start: usize) -> Option<usize> {
+        let range = self.item_count();
+        let mode = self.list_state().type_ahead.mode;
+        (0..range)
+            .map(|i| (start + i) % range)
+            .find(|&item| match_range(mode, text, &self.search_text(item)).is_some())
+    }
+
+    /// Feed a key to the type-ahead search
+    ///
+    /// Printable characters extend the search text and focus the next match;
+    /// a character that matches nothing is dropped and not consumed, so it can
+    /// still reach a hotkey. Typing the same letter repeatedly cycles through
+    /// the items starting with it. Backspace shortens the text, Esc ends the
+    /// search. Returns true if the key was consumed.
+    fn handle_type_ahead(&mut self, event: &Event) -> bool {
+        let visible_rows = self.visible_rows();
+        let state = self.list_state();
+        if !state.type_ahead.enabled
+            || event
+                .key_modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return false;
+        }
+        let active = state.type_ahead.is_active();
+        let focused = state.focused.unwrap_or(0);
+        let multi_select = state.multi_select;
+
+        let found = match event.key_code {
+            KB_ESC if active => {
+                self.list_state_mut().type_ahead.clear();
+                return true;
+            }
+            KB_BACKSPACE if active => {
+                let mut text = state.type_ahead.text.clone();
+                text.pop();
+                let found = self.find_item(&text, focused);
+                let type_ahead = &mut self.list_state_mut().type_ahead;
+                type_ahead.text = text;
+                type_ahead.last_key = Some(Instant::now());
+                found
+            }
+            key @ (0x20..0x7F | 0xA0..0x100) => {
+                let ch = char::from_u32(u32::from(key)).unwrap_or(' ');
+                // Space and `*` keep their multi-select meaning unless a search is running
+                if !active && (ch == ' ' || (ch == '*' && multi_select)) {
+                    return false;
+                }
+                let typed = if active {
+                    state.type_ahead.text.as_str()
+                } else {
+                    ""
+                };
+                let mut text = format!("{typed}{ch}");
+                // A new search starts below the focused item, a longer one may stay on it
+                let mut found = self.find_item(&text, if active { focused } else { focused + 1 });
+                if found.is_none() && active && text.chars().all(|c| c == ch) {
+                    text = ch.to_string();
+                    found = self.find_item(&text, focused + 1);
+                }
+                let type_ahead = &mut self.list_state_mut().type_ahead;
+                type_ahead.last_key = Some(Instant::now());
+                if found.is_none() {
+                    if !active {
+                        type_ahead.text.clear();
+                    }
+                    return false;
+                }
+                type_ahead.text = text;
+                found
+            }
+            _ => {
+                self.list_state_mut().type_ahead.clear();
+                return false;
+            }
+        };
+        if let Some(item) = found {
+            let state = self.list_state_mut();
+            state.focus_item(item, visible_rows);
+            state.focus_moved(false);
+        }
+        true
+    }
+
+    /// Character range of the type-ahead match in `item`'s search text
+    ///
+    /// Only the focused item is highlighted, and only while a search runs.
+    fn type_ahead_match(&self, item: usize) -> Option<Range<usize>> {
+        let state = self.list_state();
+        if state.focused != Some(item) || !state.type_ahead.is_active() {
+            return None;
+        }
+        state.type_ahead.find_in(&self.search_text(item))
+    }
+
+    /// Overlay the type-ahead search on a drawn row
+    ///
+    /// Reverses the colors of the matched characters when `item` is focused
+    /// (`text_x` is the column where its search text starts), and shows the
+    /// search text as `/text` at the right end of the last visible row.
+    fn draw_type_ahead(&self, buf: &mut DrawBuffer, item: usize, text_x: usize, row: usize) {
+        if let Some(range) = self.type_ahead_match(item) {
+            for x in range {
+                if let Some(cell) = buf.data.get(text_x + x) {
+                    buf.put_attribute(text_x + x, cell.attr.swap());
+                }
+            }
+        }
+        let type_ahead = &self.list_state().type_ahead;
+        if row + 1 == self.visible_rows() && type_ahead.is_active() {
+            let indicator = format!("/{}", type_ahead.text());
+            let len = indicator.chars().count().min(buf.len());
+            if let Some(cell) = buf.data.last() {
+                let attr = cell.attr.swap();
+                let start = buf.len() - len;
+                buf.move_str(start, &indicator, attr);
+            }
+        }
+    }
+
     /// Handle standard list navigation events
     ///
     /// Matches Borland: TListViewer::handleEvent() navigation logic
@@ -291,44 +662,68 @@ pub trait ListViewer: View {
 
         match event.what {
             EventType::Keyboard => {
+                let extend = event.key_modifiers.contains(KeyModifiers::SHIFT);
                 let state = self.list_state_mut();
-                match event.key_code {
+                let moved = match event.key_code {
                     KB_UP => {
                         state.focus_prev(visible_rows);
-                        event.clear();
                         true
                     }
                     KB_DOWN => {
                         state.focus_next(visible_rows);
-                        event.clear();
                         true
                     }
                     KB_PGUP => {
                         state.focus_page_up(visible_rows);
-                        event.clear();
                         true
                     }
                     KB_PGDN => {
                         state.focus_page_down(visible_rows);
-                        event.clear();
                         true
                     }
                     KB_HOME => {
                         state.focus_first(visible_rows);
-                        event.clear();
                         true
                     }
                     KB_END => {
                         state.focus_last(visible_rows);
-                        event.clear();
                         true
                     }
-                    KB_ENTER => {
-                        // Enter on focused item - subclass should handle
-                        false
-                    }
                     _ => false,
+                };
+                if moved {
+                    state.type_ahead.clear();
+                    state.focus_moved(extend);
+                    event.clear();
+                    return true;
+                }
+                if self.handle_type_ahead(event) {
+                    event.clear();
+                    return true;
+                }
+                let state = self.list_state_mut();
+                if !state.multi_select {
+                    // Enter on focused item - subclass should handle
+                    return false;
+                }
+                match event.key_code {
+                    KB_INS => {
+                        if let Some(focused) = state.focused {
+                            state.toggle_selected(focused);
+                            state.focus_next(visible_rows);
+                        }
+                    }
+                    key if key == ' ' as u16 && state.handle_space => {
+                        if let Some(focused) = state.focused {
+                            state.toggle_selected(focused);
+                        }
+                    }
+                    KB_CTRL_A => state.select_all(),
+                    key if key == '*' as u16 => state.invert_selection(),
+                    _ => return false,
                 }
+                event.clear();
+                true
             }
             EventType::MouseDown => {
                 if event.mouse.buttons & MB_LEFT_BUTTON != 0 {
@@ -343,6 +738,17 @@ pub trait ListViewer: View {
 
                         if clicked_item < self.item_count() {
                             self.select_item(clicked_item);
+                            let modifiers = event.key_modifiers;
+                            let state = self.list_state_mut();
+                            if state.multi_select {
+                                if modifiers.contains(KeyModifiers::SHIFT) {
+                                    state.select_range(clicked_item);
+                                } else if modifiers.contains(KeyModifiers::CONTROL) {
+                                    state.toggle_selected(clicked_item);
+                                } else {
+                                    state.select_only(clicked_item);
+                                }
+                            }
                             event.clear();
                             return true;
                         }
@@ -463,6 +869,42 @@ mod tests {
         assert_eq!(state.top_item, 2);
     }
 
+    #[test]
+    fn test_multi_selection() {
+        let mut state = ListViewerState::with_range(6);
+        state.set_multi_select(true);
+        assert!(state.selected_indices().is_empty());
+
+        state.toggle_selected(1);
+        state.select_range(3);
+        assert_eq!(state.selected_indices(), [1, 2, 3]);
+
+        state.toggle_selected(5);
+        state.invert_selection();
+        assert_eq!(state.selected_indices(), [0, 4]);
+
+        state.set_range(3);
+        assert_eq!(state.selected_indices(), [0]);
+        assert!(state.is_item_selected(0));
+        assert!(!state.is_item_selected(1));
+    }
+
+    #[test]
+    fn test_match_range() {
+        assert_eq!(match_range(MatchMode::Prefix, "ba", "Banana"), Some(0..2));
+        assert_eq!(match_range(MatchMode::Prefix, "na", "Banana"), None);
+        assert_eq!(
+            match_range(MatchMode::Substring, "NA", "Banana"),
+            Some(2..4)
+        );
+        assert_eq!(
+            match_range(MatchMode::Substring, "né", "Ananéas"),
+            Some(3..5)
+        );
+        assert_eq!(match_range(MatchMode::Substring, "", "Banana"), None);
+        assert_eq!(match_range(MatchMode::Prefix, "Bananas", "Banana"), None);
+    }
+
     #[test]
     fn test_focus_item_centered() {
         let mut state = ListViewerState::with_range(20);
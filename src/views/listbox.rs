@@ -1,16 +1,75 @@
 // (C) 2025 - Enzo Lombardi
 
 //! ListBox view - scrollable list with single selection support.
+//!
+//! Items either live in the list box (`set_items()`) or come from a
+//! [`ListDataSource`] that is asked only for the rows on screen, so very large
+//! or remote collections can be browsed without loading them up front.
 
-use super::list_viewer::{ListViewer, ListViewerState};
+use super::dialog_data::FieldValue;
+use super::list_viewer::{ListViewer, ListViewerState, MatchMode, match_range};
 use super::view::{View, write_line_to_terminal};
-use crate::core::command::CommandId;
+use crate::core::clipboard;
+use crate::core::command::{CM_COPY, CM_IDLE, CM_SELECT_ALL, CommandId};
 use crate::core::draw::DrawBuffer;
-use crate::core::event::{Event, EventType, KB_ENTER, MB_LEFT_BUTTON};
-use crate::core::geometry::Rect;
-use crate::core::palette::{LISTBOX_FOCUSED, LISTBOX_NORMAL, LISTBOX_SELECTED};
+use crate::core::event::{Event, EventType, KB_CTRL_C, KB_ENTER, MB_LEFT_BUTTON};
+use crate::core::geometry::{Point, Rect};
+use crate::core::menu_data::{Menu, clipboard_menu};
+use crate::core::palette::{LISTBOX_DIVIDER, LISTBOX_FOCUSED, LISTBOX_NORMAL, LISTBOX_SELECTED};
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
+use std::borrow::Cow;
+
+/// Lazily evaluated items of a [`ListBox`]
+///
+/// The list box asks only for the items it draws, so a source can page through a
+/// database table or a remote listing with hundreds of thousands of entries.
+pub trait ListDataSource {
+    /// Number of items
+    fn count(&self) -> usize;
+
+    /// Text of the item at `index` (always `< count()`)
+    fn item_text(&self, index: usize) -> String;
+
+    /// Pick up data that arrived in the background
+    ///
+    /// Polled while the application is idle; return `true` when the count or any
+    /// item changed so the list is redrawn. Default: the data never changes.
+    fn refresh(&mut self) -> bool {
+        false
+    }
+
+    /// First item at or after `start` (wrapping around) whose text matches
+    /// `text`, for the type-ahead search
+    ///
+    /// Default: reads at most [`SOURCE_SEARCH_LIMIT`] items. Sources that
+    /// keep an index, or sorted data, should override this with a full search.
+    fn find(&self, text: &str, mode: MatchMode, start: usize) -> Option<usize> {
+        let count = self.count();
+        (0..count.min(SOURCE_SEARCH_LIMIT))
+            .map(|i| (start + i) % count)
+            .find(|&index| match_range(mode, text, &self.item_text(index)).is_some())
+    }
+}
+
+/// Items the default [`ListDataSource::find`] reads per search, at most
+pub const SOURCE_SEARCH_LIMIT: usize = 1000;
+
+impl ListDataSource for Vec<String> {
+    fn count(&self) -> usize {
+        self.len()
+    }
+
+    fn item_text(&self, index: usize) -> String {
+        self[index].clone()
+    }
+
+    fn find(&self, text: &str, mode: MatchMode, start: usize) -> Option<usize> {
+        (0..self.len())
+            .map(|i| (start + i) % self.len())
+            .find(|&index| match_range(mode, text, &self[index]).is_some())
+    }
+}
 
 /// ListBox - A scrollable list of selectable items
 ///
@@ -19,6 +78,7 @@ use crate::terminal::Terminal;
 pub struct ListBox {
     bounds: Rect,
     items: Vec<String>,
+    source: Option<Box<dyn ListDataSource>>,
     list_state: ListViewerState, // Embedded state from ListViewer
     state: StateFlags,
     on_select_command: CommandId,
@@ -31,6 +91,7 @@ impl ListBox {
         Self {
             bounds,
             items: Vec::new(),
+            source: None,
             list_state: ListViewerState::new(),
             state: 0,
             on_select_command,
@@ -38,39 +99,95 @@ impl ListBox {
         }
     }
 
-    /// Set the items in the list
+    /// Set the items in the list (replaces a data source; clears the marks)
     pub fn set_items(&mut self, items: Vec<String>) {
         self.items = items;
+        self.source = None;
+        self.list_state.clear_selection();
         self.list_state.set_range(self.items.len());
     }
 
-    /// Add an item to the list
+    /// Add an item to the list (ignored while a data source is set)
     pub fn add_item(&mut self, item: String) {
-        self.items.push(item);
-        self.list_state.set_range(self.items.len());
+        if self.source.is_none() {
+            self.items.push(item);
+            self.list_state.set_range(self.items.len());
+        }
     }
 
-    /// Clear all items
+    /// Clear all items (and the data source)
     pub fn clear(&mut self) {
         self.items.clear();
+        self.source = None;
         self.list_state.set_range(0);
     }
 
+    /// Take the items from a data source instead of holding them (clears the marks)
+    pub fn set_data_source(&mut self, source: Box<dyn ListDataSource>) {
+        self.items.clear();
+        self.list_state.clear_selection();
+        self.list_state.set_range(source.count());
+        self.source = Some(source);
+    }
+
+    /// Builder-style variant of [`set_data_source`](Self::set_data_source)
+    #[must_use]
+    pub fn with_data_source(mut self, source: Box<dyn ListDataSource>) -> Self {
+        self.set_data_source(source);
+        self
+    }
+
+    /// Poll the data source for background updates
+    ///
+    /// Called automatically while the application is idle; returns `true` when
+    /// the list changed.
+    pub fn refresh(&mut self) -> bool {
+        let Some(source) = self.source.as_mut() else {
+            return false;
+        };
+        if !source.refresh() {
+            return false;
+        }
+        self.list_state.set_range(source.count());
+        true
+    }
+
+    /// Text of an item, from the items or the data source
+    fn text_at(&self, index: usize) -> Option<Cow<'_, str>> {
+        match &self.source {
+            Some(source) => (index < source.count()).then(|| Cow::Owned(source.item_text(index))),
+            None => self
+                .items
+                .get(index)
+                .map(|item| Cow::Borrowed(item.as_str())),
+        }
+    }
+
     /// Get the currently selected item index
     pub fn get_selection(&self) -> Option<usize> {
         self.list_state.focused
     }
 
     /// Get the currently selected item text
+    ///
+    /// Always `None` with a data source; use [`selected_text`](Self::selected_text).
     pub fn get_selected_item(&self) -> Option<&str> {
         self.list_state
             .focused
             .and_then(|idx| self.items.get(idx).map(|s| s.as_str()))
     }
 
+    /// Get the currently selected item text, also for a data source
+    pub fn selected_text(&self) -> Option<String> {
+        self.list_state
+            .focused
+            .and_then(|idx| self.text_at(idx))
+            .map(Cow::into_owned)
+    }
+
     /// Set the selected item by index
     pub fn set_selection(&mut self, index: usize) {
-        if index < self.items.len() {
+        if index < self.item_count() {
             let visible_rows = self.bounds.height_clamped() as usize;
             self.list_state.focus_item(index, visible_rows);
         }
@@ -78,7 +195,9 @@ impl ListBox {
 
     /// Get the number of items
     pub fn item_count(&self) -> usize {
-        self.items.len()
+        self.source
+            .as_ref()
+            .map_or(self.items.len(), |source| source.count())
     }
 
     // Convenience methods for compatibility with existing code
@@ -119,6 +238,29 @@ impl ListBox {
         let visible_rows = self.bounds.height_clamped() as usize;
         self.list_state.focus_page_down(visible_rows);
     }
+
+    /// Run a clipboard command; returns false if it is not one
+    ///
+    /// Copy puts the selected items on the clipboard, one per line; Select
+    /// All needs multi-selection.
+    fn handle_command(&mut self, command: CommandId) -> bool {
+        match command {
+            CM_COPY => {
+                let lines: Vec<String> = self
+                    .list_state
+                    .selected_indices()
+                    .into_iter()
+                    .filter_map(|index| self.text_at(index).map(Cow::into_owned))
+                    .collect();
+                if !lines.is_empty() {
+                    clipboard::set_clipboard(&lines.join("\n"));
+                }
+            }
+            CM_SELECT_ALL if self.list_state.multi_select => self.list_state.select_all(),
+            _ => return false,
+        }
+        true
+    }
 }
 
 impl View for ListBox {
@@ -126,10 +268,39 @@ impl View for ListBox {
         self.bounds
     }
 
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let info = AccessibleInfo::new(Role::List, "");
+        Some(match self.get_selection().zip(self.selected_text()) {
+            Some((index, text)) => {
+                info.with_value(format!("{}, {} of {}", text, index + 1, self.item_count()))
+            }
+            None => info.with_value("empty"),
+        })
+    }
+
+    fn context_menu(&self, _local: Point) -> Option<Menu> {
+        let mut menu = clipboard_menu(self.item_count() > 0, false);
+        if let Some(item) = menu.item_by_command_mut(CM_SELECT_ALL) {
+            item.set_enabled(self.list_state.multi_select);
+        }
+        Some(menu)
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
     }
 
+    fn get_data(&self) -> Option<FieldValue> {
+        Some(FieldValue::Index(self.get_selection()))
+    }
+
+    fn set_data(&mut self, value: &FieldValue) {
+        if let FieldValue::Index(Some(index)) = value {
+            self.set_selection(*index);
+        }
+    }
+
     fn draw(&mut self, terminal: &mut Terminal) {
         let width = self.bounds.width_clamped() as usize;
         let height = self.bounds.height_clamped() as usize;
@@ -142,22 +313,23 @@ impl View for ListBox {
             self.map_color(LISTBOX_NORMAL) // Normal
         };
         let color_selected = self.map_color(LISTBOX_SELECTED); // Selected
+        let color_cursor = self.map_color(LISTBOX_DIVIDER); // Focused but unselected (multi-select)
 
         // Draw visible items
         for i in 0..height {
             let mut buf = DrawBuffer::new(width);
             let item_idx = self.list_state.top_item + i;
 
-            if item_idx < self.items.len() {
-                let is_selected = Some(item_idx) == self.list_state.focused;
-                let color = if is_selected {
+            if let Some(text) = self.text_at(item_idx) {
+                let color = if self.list_state.is_item_selected(item_idx) {
                     color_selected
+                } else if Some(item_idx) == self.list_state.focused {
+                    color_cursor
                 } else {
                     color_normal
                 };
 
-                let text = &self.items[item_idx];
-                buf.move_str(0, text, color);
+                buf.move_str(0, &text, color);
 
                 // Fill rest of line with spaces
                 let text_len = text.len();
@@ -168,6 +340,7 @@ impl View for ListBox {
                 // Empty line
                 buf.move_char(0, ' ', color_normal, width);
             }
+            self.draw_type_ahead(&mut buf, item_idx, 0, i);
 
             write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + i as i16, &buf);
         }
@@ -191,7 +364,7 @@ impl View for ListBox {
                     let clicked_item = self.list_state.top_item + relative_y;
 
                     // Update the selection to the double-clicked item
-                    if clicked_item < self.items.len() {
+                    if clicked_item < self.item_count() {
                         let visible_rows = self.bounds.height_clamped() as usize;
                         self.list_state.focus_item(clicked_item, visible_rows);
                     }
@@ -203,6 +376,20 @@ impl View for ListBox {
             }
         }
 
+        // Clipboard commands, from the context menu or an Edit menu
+        if event.what == EventType::Command && self.handle_command(event.command) {
+            event.clear();
+            return;
+        }
+
+        // Pick up background updates of the data source
+        if event.what == EventType::Broadcast && event.command == CM_IDLE {
+            if self.refresh() {
+                super::view::request_redraw();
+            }
+            return;
+        }
+
         // First try standard list navigation (from ListViewer trait)
         // This handles single-click, arrow keys, etc.
         if self.handle_list_event(event) {
@@ -215,6 +402,9 @@ impl View for ListBox {
                 if event.key_code == KB_ENTER {
                     // Enter on selected item generates command
                     *event = Event::command(self.on_select_command);
+                } else if event.key_code == KB_CTRL_C {
+                    self.handle_command(CM_COPY);
+                    event.clear();
                 }
             }
             EventType::MouseDown => {
@@ -292,7 +482,18 @@ impl ListViewer for ListBox {
     }
 
     fn get_text(&self, item: usize, _max_len: usize) -> String {
-        self.items.get(item).cloned().unwrap_or_default()
+        self.text_at(item).map(Cow::into_owned).unwrap_or_default()
+    }
+
+    /// A data source searches itself, so a lazy one is not read row by row
+    fn find_item(&self, text: &str, start: usize) -> Option<usize> {
+        let mode = self.list_state.type_ahead.mode;
+        match &self.source {
+            Some(source) => source.find(text, mode, start),
+            None => (0..self.items.len())
+                .map(|i| (start + i) % self.items.len())
+                .find(|&index| match_range(mode, text, &self.items[index]).is_some()),
+        }
     }
 }
 
@@ -341,6 +542,7 @@ impl Default for ListBoxBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::views::view::take_redraw_request;
 
     #[test]
     fn test_listbox_creation() {
@@ -419,6 +621,151 @@ mod tests {
         assert_eq!(listbox.get_selection(), Some(2)); // Should not change
     }
 
+    #[test]
+    fn test_listbox_multi_select_keys() {
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 10), 1000);
+        listbox.set_items(vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+        listbox.set_multi_select(true);
+
+        listbox.handle_event(&mut Event::keyboard(' ' as u16));
+        for _ in 0..2 {
+            let mut shift_down = Event::keyboard(crate::core::event::KB_DOWN);
+            shift_down.key_modifiers = crossterm::event::KeyModifiers::SHIFT;
+            listbox.handle_event(&mut shift_down);
+        }
+        assert_eq!(listbox.selected_indices(), [0, 1, 2]);
+
+        listbox.handle_event(&mut Event::keyboard(crate::core::event::KB_INS));
+        assert_eq!(listbox.selected_indices(), [0, 1]);
+        listbox.handle_event(&mut Event::keyboard('*' as u16));
+        assert_eq!(listbox.selected_indices(), [2]);
+        listbox.handle_event(&mut Event::keyboard(crate::core::event::KB_CTRL_A));
+        assert_eq!(listbox.selected_indices(), [0, 1, 2]);
+
+        // New items drop the marks of the old ones
+        listbox.set_items(vec!["D".to_string(), "E".to_string(), "F".to_string()]);
+        assert!(listbox.selected_indices().is_empty());
+    }
+
+    #[test]
+    fn test_listbox_data_source_reads_visible_rows_only() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        struct Numbers {
+            count: usize,
+            requested: Rc<RefCell<Vec<usize>>>,
+            grown: bool,
+        }
+
+        impl ListDataSource for Numbers {
+            fn count(&self) -> usize {
+                self.count
+            }
+
+            fn item_text(&self, index: usize) -> String {
+                self.requested.borrow_mut().push(index);
+                format!("Row {index}")
+            }
+
+            fn refresh(&mut self) -> bool {
+                let grow = !self.grown;
+                self.grown = true;
+                if grow {
+                    self.count += 1;
+                }
+                grow
+            }
+        }
+
+        let requested = Rc::new(RefCell::new(Vec::new()));
+        let mut listbox =
+            ListBox::new(Rect::new(0, 0, 12, 3), 1000).with_data_source(Box::new(Numbers {
+                count: 500_000,
+                requested: Rc::clone(&requested),
+                grown: false,
+            }));
+        assert_eq!(listbox.item_count(), 500_000);
+
+        listbox.select_last();
+        let lines = crate::test_util::snapshot::Snapshot::of_view(&mut listbox, 12, 3).text_lines();
+        assert_eq!(lines[2].trim_end(), "Row 499999");
+        assert_eq!(*requested.borrow(), [499_997, 499_998, 499_999]);
+        assert_eq!(listbox.selected_text().as_deref(), Some("Row 499999"));
+        assert_eq!(listbox.get_selected_item(), None);
+
+        // The idle broadcast passes on; the grown list asks for a redraw
+        take_redraw_request();
+        let mut idle = Event::broadcast(CM_IDLE);
+        listbox.handle_event(&mut idle);
+        assert_eq!(idle.what, EventType::Broadcast);
+        assert!(take_redraw_request());
+        assert_eq!(listbox.item_count(), 500_001);
+
+        listbox.handle_event(&mut idle);
+        assert_eq!(idle.what, EventType::Broadcast);
+        assert!(!take_redraw_request());
+
+        // A type-ahead search reads a limited number of rows
+        listbox.set_type_ahead(true);
+        requested.borrow_mut().clear();
+        listbox.handle_event(&mut Event::keyboard('x' as u16));
+        assert_eq!(requested.borrow().len(), SOURCE_SEARCH_LIMIT);
+    }
+
+    #[test]
+    fn test_listbox_type_ahead() {
+        use crate::views::list_viewer::MatchMode;
+
+        let mut listbox = ListBox::new(Rect::new(0, 0, 12, 4), 1000);
+        listbox.set_items(
+            ["apple", "banana", "blueberry", "cherry", "bilberry"]
+                .map(String::from)
+                .to_vec(),
+        );
+        // Off by default: letters pass on to the dialog's hotkeys
+        let mut key = Event::keyboard('b' as u16);
+        listbox.handle_event(&mut key);
+        assert_eq!(key.what, EventType::Keyboard);
+        assert_eq!(listbox.get_selected_item(), Some("apple"));
+
+        listbox.set_type_ahead(true);
+        let type_keys = |listbox: &mut ListBox, keys: &str| {
+            for ch in keys.chars() {
+                listbox.handle_event(&mut Event::keyboard(ch as u16));
+            }
+        };
+
+        type_keys(&mut listbox, "bl");
+        assert_eq!(listbox.get_selected_item(), Some("blueberry"));
+        // A letter that matches nothing is left for a hotkey
+        let mut key = Event::keyboard('x' as u16);
+        listbox.handle_event(&mut key);
+        assert_eq!(key.what, EventType::Keyboard);
+        assert_eq!(listbox.get_selected_item(), Some("blueberry"));
+        assert_eq!(listbox.list_state.type_ahead.text(), "bl");
+
+        let lines = crate::test_util::snapshot::Snapshot::of_view(&mut listbox, 12, 4);
+        assert_eq!(lines.text_lines()[3], "cherry   /bl");
+
+        listbox.handle_event(&mut Event::keyboard(crate::core::event::KB_BACKSPACE));
+        assert_eq!(listbox.list_state.type_ahead.text(), "b");
+        listbox.handle_event(&mut Event::keyboard(crate::core::event::KB_ESC));
+        assert!(!listbox.list_state.type_ahead.is_active());
+
+        // Repeating a letter cycles through the items starting with it, wrapping around
+        type_keys(&mut listbox, "bb");
+        assert_eq!(listbox.get_selected_item(), Some("banana"));
+        type_keys(&mut listbox, "b");
+        assert_eq!(listbox.get_selected_item(), Some("blueberry"));
+
+        listbox.list_state.type_ahead.clear();
+        listbox.list_state.type_ahead.mode = MatchMode::Substring;
+        type_keys(&mut listbox, "err");
+        assert_eq!(listbox.get_selected_item(), Some("cherry"));
+        assert_eq!(listbox.type_ahead_match(3), Some(2..5));
+    }
+
     #[test]
     fn test_listbox_clear() {
         let mut listbox = ListBox::new(Rect::new(0, 0, 20, 10), 1000);
@@ -429,4 +776,28 @@ mod tests {
         assert_eq!(listbox.item_count(), 0);
         assert_eq!(listbox.get_selection(), None);
     }
+
+    #[test]
+    fn test_listbox_context_menu() {
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 10), 1000);
+        listbox.set_items(vec!["Alpha".to_string(), "Beta".to_string()]);
+        listbox.set_focus(true);
+
+        let menu = listbox.context_menu(Point::new(0, 1)).unwrap();
+        assert!(menu.item_by_command(CM_COPY).unwrap().is_selectable());
+        assert!(!menu.item_by_command(CM_SELECT_ALL).unwrap().is_selectable());
+
+        // Select All is left alone without multi-selection
+        let mut event = Event::command(CM_SELECT_ALL);
+        listbox.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Command);
+
+        listbox.set_multi_select(true);
+        let menu = listbox.context_menu(Point::new(0, 1)).unwrap();
+        assert!(menu.item_by_command(CM_SELECT_ALL).unwrap().is_selectable());
+        let mut event = Event::command(CM_SELECT_ALL);
+        listbox.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(listbox.selected_indices(), vec![0, 1]);
+    }
 }
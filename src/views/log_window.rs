@@ -26,7 +26,7 @@
 //! ```
 
 use super::terminal_widget::TerminalWidget;
-use super::view::View;
+use super::view::{View, ViewId};
 use super::window::{Window, WindowPaletteType};
 use crate::core::event::Event;
 use crate::core::geometry::Rect;
@@ -218,6 +218,24 @@ impl View for LogWindow {
     fn bounds(&self) -> Rect {
         self.window.bounds()
     }
+
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.window.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.window.subviews()
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        self.window.accessible()
+    }
+    fn window_number(&self) -> u8 {
+        self.window.number()
+    }
+    fn set_window_number(&mut self, number: u8) {
+        self.window.set_number(number);
+    }
     fn set_bounds(&mut self, bounds: Rect) {
         self.window.set_bounds(bounds);
         // Window handles interior repositioning; widget bounds are updated
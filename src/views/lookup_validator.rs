@@ -8,7 +8,9 @@
 // Validates that input matches one of a predefined list of valid strings.
 // Supports both case-sensitive and case-insensitive matching.
 
+use super::dialog_data::FieldValue;
 use super::validator::Validator;
+use crate::core::i18n::tr;
 
 /// LookupValidator - Validates against a list of valid values
 ///
@@ -16,6 +18,7 @@ use super::validator::Validator;
 pub struct LookupValidator {
     valid_values: Vec<String>,
     case_sensitive: bool,
+    options: u16,
 }
 
 impl LookupValidator {
@@ -24,6 +27,7 @@ impl LookupValidator {
         Self {
             valid_values,
             case_sensitive: true,
+            options: 0,
         }
     }
 
@@ -32,6 +36,7 @@ impl LookupValidator {
         Self {
             valid_values,
             case_sensitive: false,
+            options: 0,
         }
     }
 
@@ -40,6 +45,12 @@ impl LookupValidator {
         self.case_sensitive = case_sensitive;
     }
 
+    /// Set the `VO_*` option flags; with `VO_TRANSFER` the data is the
+    /// index of the value in the list
+    pub fn set_options(&mut self, options: u16) {
+        self.options = options;
+    }
+
     /// Get the list of valid values
     pub fn valid_values(&self) -> &[String] {
         &self.valid_values
@@ -100,6 +111,36 @@ impl Validator for LookupValidator {
         // In a full implementation, this would show a message box
         // For now, just a no-op (the InputLine will handle visual feedback)
     }
+
+    /// Matches Borland's `TLookupValidator::Error()` message
+    fn error_message(&self) -> String {
+        tr("Input is not in the list of valid values")
+    }
+
+    fn options(&self) -> u16 {
+        self.options
+    }
+
+    /// Index of the matching value, `Index(None)` for empty input
+    fn transfer_get(&self, input: &str) -> Option<FieldValue> {
+        if input.is_empty() {
+            return Some(FieldValue::Index(None));
+        }
+        self.find_value(input)
+            .map(|index| FieldValue::Index(Some(index)))
+    }
+
+    fn transfer_set(&self, value: &FieldValue) -> Option<String> {
+        match value {
+            FieldValue::Index(index) => Some(
+                index
+                    .and_then(|index| self.valid_values.get(index))
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+            _ => None,
+        }
+    }
 }
 
 /// Builder for creating lookup validators with a fluent API.
@@ -125,6 +166,7 @@ impl Validator for LookupValidator {
 pub struct LookupValidatorBuilder {
     valid_values: Vec<String>,
     case_sensitive: bool,
+    options: u16,
 }
 
 impl LookupValidatorBuilder {
@@ -133,6 +175,7 @@ impl LookupValidatorBuilder {
         Self {
             valid_values: Vec::new(),
             case_sensitive: true,
+            options: 0,
         }
     }
 
@@ -157,6 +200,13 @@ impl LookupValidatorBuilder {
         self
     }
 
+    /// Sets the `VO_*` option flags (default: none).
+    #[must_use]
+    pub fn options(mut self, options: u16) -> Self {
+        self.options = options;
+        self
+    }
+
     /// Builds the LookupValidator.
     ///
     /// # Panics
@@ -170,6 +220,7 @@ impl LookupValidatorBuilder {
         LookupValidator {
             valid_values: self.valid_values,
             case_sensitive: self.case_sensitive,
+            options: self.options,
         }
     }
 }
@@ -543,6 +543,12 @@ impl View for Memo {
         self.bounds
     }
 
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let line = format!("line {} of {}", self.cursor.y + 1, self.lines.len());
+        Some(AccessibleInfo::new(Role::Editor, "").with_value(line))
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
 
@@ -14,19 +14,21 @@
 use super::menu_box::MenuBox;
 use super::menu_viewer::{MenuViewer, MenuViewerState};
 use super::view::{View, write_line_to_terminal};
+use crate::core::command::CommandId;
 use crate::core::command_set;
 use crate::core::draw::DrawBuffer;
 use crate::core::event::{
-    Event, EventType, KB_ALT_A, KB_ALT_B, KB_ALT_C, KB_ALT_D, KB_ALT_E, KB_ALT_F, KB_ALT_G,
-    KB_ALT_H, KB_ALT_I, KB_ALT_J, KB_ALT_K, KB_ALT_L, KB_ALT_M, KB_ALT_N, KB_ALT_O, KB_ALT_P,
-    KB_ALT_Q, KB_ALT_R, KB_ALT_S, KB_ALT_T, KB_ALT_U, KB_ALT_V, KB_ALT_W, KB_ALT_X, KB_ALT_Y,
-    KB_ALT_Z, KB_ENTER, KB_ESC, KB_ESC_ESC, KB_F10, KB_LEFT, KB_RIGHT, KeyCode, MB_LEFT_BUTTON,
+    Event, EventType, KB_ENTER, KB_ESC, KB_ESC_ESC, KB_F10, KB_LEFT, KB_RIGHT, KeyCode,
+    MB_LEFT_BUTTON, alt_code_to_char,
 };
 use crate::core::geometry::{Point, Rect};
 use crate::core::menu_data::{Menu, MenuItem};
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
 
+/// Called with the plain name of a menu (no `~` markers) before it opens
+type MenuOpenHook = Box<dyn FnMut(&str, &mut Menu)>;
+
 // MenuBar palette indices (matches Borland TMenuView)
 const MENU_NORMAL: u8 = 1; // Normal item text
 const MENU_SELECTED: u8 = 2; // Selected item text
@@ -48,6 +50,15 @@ impl SubMenu {
     }
 }
 
+/// Columns taken by check marks in front of the item texts ("✓ ")
+pub(crate) fn mark_width(menu: &Menu) -> usize {
+    if menu.items.iter().any(MenuItem::is_checkable) {
+        2
+    } else {
+        0
+    }
+}
+
 /// Extract the hotkey character from a menu name with ~X~ markers
 ///
 /// Given a string like "~F~ile" or "~W~indow", returns the character between the tildes.
@@ -84,6 +95,7 @@ pub struct MenuBar {
     menu_state: MenuViewerState, // State for dropdown menu items
     state: StateFlags,
     palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+    open_hook: Option<MenuOpenHook>,
 }
 
 impl MenuBar {
@@ -96,6 +108,7 @@ impl MenuBar {
             menu_state: MenuViewerState::new(),
             state: 0,
             palette_chain: None,
+            open_hook: None,
         }
     }
 
@@ -104,9 +117,70 @@ impl MenuBar {
         self.menu_positions.push(0); // Will be updated during draw
     }
 
+    /// The top-level menus, in display order
+    pub fn submenus(&self) -> &[SubMenu] {
+        &self.submenus
+    }
+
+    /// The top-level menu named `name` (`~` markers are ignored), for
+    /// changing its items
+    pub fn menu_mut(&mut self, name: &str) -> Option<&mut Menu> {
+        let name = name.replace('~', "");
+        self.submenus
+            .iter_mut()
+            .find(|submenu| submenu.name.replace('~', "") == name)
+            .map(|submenu| &mut submenu.menu)
+    }
+
+    /// Find the item sending `command` in any menu
+    pub fn item_by_command(&self, command: CommandId) -> Option<&MenuItem> {
+        self.submenus
+            .iter()
+            .find_map(|submenu| submenu.menu.item_by_command(command))
+    }
+
+    /// Find the item sending `command` in any menu, for changing its text,
+    /// enabled state, shortcut or check mark
+    pub fn item_by_command_mut(&mut self, command: CommandId) -> Option<&mut MenuItem> {
+        self.submenus
+            .iter_mut()
+            .find_map(|submenu| submenu.menu.item_by_command_mut(command))
+    }
+
+    /// Run `hook` before a menu or cascading submenu opens, with its name
+    /// and items, e.g. to rebuild a "Recent files" or "Window" list
+    pub fn set_open_hook(&mut self, hook: impl FnMut(&str, &mut Menu) + 'static) {
+        self.open_hook = Some(Box::new(hook));
+    }
+
+    /// Update the check mark bound to the item sending `command` (toggle a
+    /// check, select a radio item), as picking the item does
+    ///
+    /// The menu bar calls this for the items it sends itself. The application
+    /// calls it for a command bound to a key or picked in the command
+    /// palette, so the mark follows whichever way the command was given.
+    pub fn activate(&self, command: CommandId) {
+        if let Some(item) = self.item_by_command(command) {
+            if item.is_selectable() && command_set::command_enabled(command) {
+                item.check().activate();
+            }
+        }
+    }
+
+    /// Close the menus and send the picked `command`
+    fn pick(&mut self, command: CommandId, event: &mut Event) {
+        self.close_menu();
+        self.activate(command);
+        *event = Event::command(command);
+    }
+
     /// Open a specific submenu by index
     fn open_menu(&mut self, menu_idx: usize) {
         if menu_idx < self.submenus.len() {
+            if let Some(hook) = self.open_hook.as_mut() {
+                let submenu = &mut self.submenus[menu_idx];
+                hook(&submenu.name.replace('~', ""), &mut submenu.menu);
+            }
             self.active_menu_idx = Some(menu_idx);
             self.menu_state
                 .set_menu(self.submenus[menu_idx].menu.clone());
@@ -128,35 +202,7 @@ impl MenuBar {
     fn find_menu_by_hotkey(&self, key_code: KeyCode) -> Option<usize> {
         // Map Alt+Letter keycodes to their corresponding character
         // Use lowercase for comparison since menu hotkeys are case-insensitive
-        let hotkey_char = match key_code {
-            KB_ALT_A => 'a',
-            KB_ALT_B => 'b',
-            KB_ALT_C => 'c',
-            KB_ALT_D => 'd',
-            KB_ALT_E => 'e',
-            KB_ALT_F => 'f',
-            KB_ALT_G => 'g',
-            KB_ALT_H => 'h',
-            KB_ALT_I => 'i',
-            KB_ALT_J => 'j',
-            KB_ALT_K => 'k',
-            KB_ALT_L => 'l',
-            KB_ALT_M => 'm',
-            KB_ALT_N => 'n',
-            KB_ALT_O => 'o',
-            KB_ALT_P => 'p',
-            KB_ALT_Q => 'q',
-            KB_ALT_R => 'r',
-            KB_ALT_S => 's',
-            KB_ALT_T => 't',
-            KB_ALT_U => 'u',
-            KB_ALT_V => 'v',
-            KB_ALT_W => 'w',
-            KB_ALT_X => 'x',
-            KB_ALT_Y => 'y',
-            KB_ALT_Z => 'z',
-            _ => return None, // Not an Alt+Letter key
-        };
+        let hotkey_char = alt_code_to_char(key_code)?; // Not an Alt+Letter key otherwise
 
         // Search through all submenus for a matching hotkey
         for (idx, submenu) in self.submenus.iter().enumerate() {
@@ -179,51 +225,62 @@ impl MenuBar {
 
     /// Show a cascading submenu for the currently selected item (internal)
     fn show_cascading_submenu(&mut self, terminal: &mut Terminal) -> Option<u16> {
-        // Get the current selected item
-        let current_item = self.menu_state.get_current_item()?;
-
-        // Check if it's a SubMenu item
-        if let MenuItem::SubMenu { menu, .. } = current_item {
-            // Calculate position for the cascading menu
-            let current_idx = self.menu_state.current?;
-            let menu_idx = self.active_menu_idx?;
-
-            // Position submenu to the right of the dropdown
-            let dropdown_x = self.menu_positions.get(menu_idx).copied().unwrap_or(0);
-            let item_y = self.bounds.a.y + 2 + current_idx as i16; // +1 for bar, +1 for top border
-
-            // Calculate dropdown width (similar to draw_dropdown logic)
-            let parent_menu = &self.submenus[menu_idx].menu;
-            let mut max_text_width = 10;
-            for item in &parent_menu.items {
-                match item {
-                    MenuItem::Regular { text, shortcut, .. } => {
-                        let text_len = text.replace('~', "").len();
-                        max_text_width = max_text_width.max(text_len);
-                        if let Some(s) = shortcut {
-                            max_text_width = max_text_width.max(text_len + s.len() + 2);
-                        }
-                    }
-                    MenuItem::SubMenu { text, .. } => {
-                        let text_len = text.replace('~', "").len();
-                        max_text_width = max_text_width.max(text_len + 3);
+        // Only a SubMenu item opens a cascading menu
+        if !matches!(
+            self.menu_state.get_current_item()?,
+            MenuItem::SubMenu { .. }
+        ) {
+            return None;
+        }
+        let current_idx = self.menu_state.current?;
+        let menu_idx = self.active_menu_idx?;
+
+        // Let the application rebuild the submenu before it opens
+        let parent_menu = &mut self.submenus.get_mut(menu_idx)?.menu;
+        let MenuItem::SubMenu { text, menu, .. } = parent_menu.items.get_mut(current_idx)? else {
+            return None;
+        };
+        if let Some(hook) = self.open_hook.as_mut() {
+            hook(&text.replace('~', ""), menu);
+        }
+        let menu = menu.clone();
+
+        // Position submenu to the right of the dropdown
+        let dropdown_x = self.menu_positions.get(menu_idx).copied().unwrap_or(0);
+        let item_y = self.bounds.a.y + 2 + current_idx as i16; // +1 for bar, +1 for top border
+
+        // Calculate dropdown width (similar to draw_dropdown logic)
+        let parent_menu = &self.submenus[menu_idx].menu;
+        let mut max_text_width = 10;
+        for item in &parent_menu.items {
+            match item {
+                MenuItem::Regular { text, shortcut, .. } => {
+                    let text_len = text.replace('~', "").len();
+                    max_text_width = max_text_width.max(text_len);
+                    if let Some(s) = shortcut {
+                        max_text_width = max_text_width.max(text_len + s.len() + 2);
                     }
-                    MenuItem::Separator => {}
                 }
+                MenuItem::SubMenu { text, .. } => {
+                    let text_len = text.replace('~', "").len();
+                    max_text_width = max_text_width.max(text_len + 3);
+                }
+                MenuItem::Separator => {}
             }
-            let dropdown_width = max_text_width + 4;
-
-            let submenu_x = dropdown_x + dropdown_width as i16 - 1;
-            let position = Point::new(submenu_x, item_y);
+        }
+        let dropdown_width = max_text_width + mark_width(parent_menu) + 4;
 
-            // Create and execute the cascading menu
-            let mut menu_box = MenuBox::new(position, menu.clone());
-            let command = menu_box.execute(terminal);
+        let submenu_x = dropdown_x + dropdown_width as i16 - 1;
+        let position = Point::new(submenu_x, item_y);
 
-            return Some(command);
+        // Create and execute the cascading menu
+        let mut menu_box = MenuBox::new(position, menu);
+        let command = menu_box.execute(terminal);
+        if command != 0 {
+            self.activate(command);
         }
 
-        None
+        Some(command)
     }
 
     /// Draw the dropdown menu
@@ -261,11 +318,12 @@ impl MenuBar {
             }
         }
 
+        let marks = mark_width(menu);
         let dropdown_width = if max_shortcut_width > 0 {
             max_text_width + 2 + max_shortcut_width + 2
         } else {
             max_text_width + 2
-        };
+        } + marks;
         let dropdown_height = menu.items.len() as i16;
 
         // Draw top border
@@ -295,6 +353,7 @@ impl MenuBar {
                     enabled,
                     shortcut,
                     command,
+                    check,
                     ..
                 } => {
                     // Check if command is enabled in BOTH the MenuItem AND the global command_set
@@ -315,8 +374,12 @@ impl MenuBar {
                         item_buf.put_char(j, ' ', attr);
                     }
 
+                    if let Some(mark) = check.mark() {
+                        item_buf.put_char(1, mark, attr);
+                    }
+
                     // Draw text with accelerator
-                    let mut x = 1;
+                    let mut x = 1 + marks;
                     let mut chars = text.chars();
                     while let Some(ch) = chars.next() {
                         if x >= dropdown_width - 1 {
@@ -370,7 +433,7 @@ impl MenuBar {
                     }
 
                     // Draw text
-                    let mut x = 1;
+                    let mut x = 1 + marks;
                     for ch in text.replace('~', "").chars() {
                         if x >= dropdown_width - 2 {
                             break;
@@ -619,8 +682,7 @@ impl View for MenuBar {
                                         });
 
                                     if let Some(cmd) = command {
-                                        self.close_menu();
-                                        *event = Event::command(cmd);
+                                        self.pick(cmd, event);
                                         return;
                                     }
                                     break;
@@ -726,8 +788,7 @@ impl View for MenuBar {
                             });
 
                             if let Some(cmd) = command {
-                                self.close_menu();
-                                *event = Event::command(cmd);
+                                self.pick(cmd, event);
                                 return;
                             }
                             event.clear();
@@ -792,3 +853,74 @@ impl MenuViewer for MenuBar {
         crate::core::geometry::Rect::new(0, 0, 0, 0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::KB_ALT_F;
+    use crate::core::menu_data::MenuBuilder;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn menu_bar(wrap: &Rc<RefCell<bool>>) -> MenuBar {
+        let mut menu_bar = MenuBar::new(Rect::new(0, 0, 80, 1));
+        let options = MenuBuilder::new()
+            .checkbox("~W~ord wrap", 1100, 0, wrap)
+            .build();
+        menu_bar.add_submenu(SubMenu::new("~F~ile", options));
+        menu_bar
+    }
+
+    #[test]
+    fn test_pick_toggles_check() {
+        let wrap = Rc::new(RefCell::new(false));
+        let mut menu_bar = menu_bar(&wrap);
+
+        menu_bar.handle_event(&mut Event::keyboard(KB_ALT_F));
+        let mut event = Event::keyboard(KB_ENTER);
+        menu_bar.handle_event(&mut event);
+
+        assert_eq!(event.what, EventType::Command);
+        assert_eq!(event.command, 1100);
+        assert!(*wrap.borrow());
+    }
+
+    #[test]
+    fn test_activate_toggles_check() {
+        let wrap = Rc::new(RefCell::new(false));
+        let mut menu_bar = menu_bar(&wrap);
+
+        // A command seen on its way to the views leaves the mark alone
+        let mut event = Event::command(1100);
+        menu_bar.handle_event(&mut event);
+        assert!(!*wrap.borrow());
+        assert_eq!(event.what, EventType::Command);
+
+        // A shortcut or the command palette activates the item explicitly
+        menu_bar.activate(1100);
+        assert!(*wrap.borrow());
+
+        menu_bar
+            .item_by_command_mut(1100)
+            .unwrap()
+            .set_enabled(false);
+        menu_bar.activate(1100);
+        assert!(*wrap.borrow());
+    }
+
+    #[test]
+    fn test_open_hook() {
+        let wrap = Rc::new(RefCell::new(false));
+        let mut menu_bar = menu_bar(&wrap);
+        menu_bar.set_open_hook(|name, menu| {
+            assert_eq!(name, "File");
+            menu.clear();
+            menu.add(MenuItem::new("~n~otes.txt", 1200, 0, 0));
+        });
+
+        menu_bar.handle_event(&mut Event::keyboard(KB_ALT_F));
+        assert!(menu_bar.item_by_command(1100).is_none());
+        assert_eq!(menu_bar.menu_mut("~F~ile").unwrap().len(), 1);
+        assert_eq!(mark_width(menu_bar.menu_mut("File").unwrap()), 0);
+    }
+}
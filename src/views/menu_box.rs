@@ -10,12 +10,18 @@
 //
 // Borland inheritance: TView → TMenuView → TMenuBox
 // Rust composition: View + MenuViewer → MenuBox
+//
+// popup_menu() shows a MenuBox as a context menu, matching magiblot's
+// popupMenu() (TMenuPopup placed by autoPlacePopup()).
 
+use super::menu_bar::mark_width;
 use super::menu_viewer::{MenuViewer, MenuViewerState};
 use super::view::{View, write_line_to_terminal};
 use crate::core::command::CommandId;
 use crate::core::draw::DrawBuffer;
-use crate::core::event::{Event, EventType, KB_ENTER, KB_ESC, KB_ESC_ESC, MB_LEFT_BUTTON};
+use crate::core::event::{
+    Event, EventType, KB_ENTER, KB_ESC, KB_ESC_ESC, MB_LEFT_BUTTON, MB_RIGHT_BUTTON,
+};
 use crate::core::geometry::{Point, Rect};
 use crate::core::menu_data::{Menu, MenuItem};
 use crate::core::state::{SF_SHADOW, StateFlags};
@@ -64,6 +70,7 @@ impl MenuBox {
     fn calculate_bounds(position: Point, menu: &Menu) -> Rect {
         let mut width = 10; // Minimum width
         let mut height = 2; // Top and bottom borders
+        let marks = mark_width(menu);
 
         // Calculate maximum width needed
         for item in &menu.items {
@@ -71,11 +78,11 @@ impl MenuBox {
                 MenuItem::Regular { text, shortcut, .. } => {
                     let text_len = text.replace('~', "").len();
                     let shortcut_len = shortcut.as_ref().map(|s| s.len() + 2).unwrap_or(0);
-                    text_len + shortcut_len + 6 // Padding
+                    text_len + shortcut_len + 6 + marks // Padding
                 }
                 MenuItem::SubMenu { text, .. } => {
                     let text_len = text.replace('~', "").len();
-                    text_len + 6 + 3 // Padding + submenu arrow
+                    text_len + 6 + 3 + marks // Padding + submenu arrow
                 }
                 MenuItem::Separator => 4, // Just borders
             };
@@ -91,6 +98,23 @@ impl MenuBox {
         )
     }
 
+    /// Move the box to open at `position` while staying within `limits`:
+    /// left of the right edge, and above `position` if there is no room below
+    /// Matches magiblot: `autoPlacePopup()`
+    fn place_within(&mut self, position: Point, limits: Rect) {
+        let width = self.bounds.width();
+        let height = self.bounds.height();
+        let x = position.x.min(limits.b.x - width).max(limits.a.x);
+        let y = if position.y + height <= limits.b.y {
+            position.y
+        } else if position.y - height >= limits.a.y {
+            position.y - height
+        } else {
+            (limits.b.y - height).max(limits.a.y)
+        };
+        self.bounds = Rect::new(x, y, x + width, y + height);
+    }
+
     /// Get the command from the currently selected item
     pub fn get_selected_command(&self) -> Option<CommandId> {
         self.menu_state
@@ -132,6 +156,16 @@ impl MenuBox {
     }
 }
 
+/// Show `menu` as a popup (context) menu at `position`, kept within `limits`
+/// Matches magiblot: `popupMenu()`
+///
+/// Returns the picked command, or 0 if the menu was cancelled.
+pub fn popup_menu(terminal: &mut Terminal, position: Point, menu: Menu, limits: Rect) -> CommandId {
+    let mut menu_box = MenuBox::new(position, menu);
+    menu_box.place_within(position, limits);
+    menu_box.execute(terminal)
+}
+
 impl View for MenuBox {
     fn bounds(&self) -> Rect {
         self.bounds
@@ -158,6 +192,7 @@ impl View for MenuBox {
         let selected_attr = self.map_color(MENU_SELECTED);
         let disabled_attr = self.map_color(MENU_DISABLED);
         let shortcut_attr = self.map_color(MENU_SHORTCUT);
+        let marks = mark_width(menu);
 
         // Draw top border
         let mut buf = DrawBuffer::new(width);
@@ -191,6 +226,7 @@ impl View for MenuBox {
                     text,
                     enabled,
                     shortcut,
+                    check,
                     ..
                 } => {
                     let color = if is_selected {
@@ -213,8 +249,12 @@ impl View for MenuBox {
                         buf.put_char(i, ' ', color);
                     }
 
+                    if let Some(mark) = check.mark() {
+                        buf.put_char(2, mark, color);
+                    }
+
                     // Draw text with accelerator highlighting
-                    let mut x = 2;
+                    let mut x = 2 + marks;
                     let mut chars = text.chars();
                     while let Some(ch) = chars.next() {
                         if ch == '~' {
@@ -264,7 +304,7 @@ impl View for MenuBox {
                     }
 
                     // Draw text
-                    let mut x = 2;
+                    let mut x = 2 + marks;
                     let mut chars = text.chars();
                     while let Some(ch) = chars.next() {
                         if ch == '~' {
@@ -350,7 +390,8 @@ impl View for MenuBox {
             EventType::MouseDown => {
                 let mouse_pos = event.mouse.pos;
 
-                if event.mouse.buttons & MB_LEFT_BUTTON != 0 {
+                // Either button picks, as in context menus
+                if event.mouse.buttons & (MB_LEFT_BUTTON | MB_RIGHT_BUTTON) != 0 {
                     // Check if clicked outside menu - cancel
                     if !self.bounds.contains(mouse_pos) {
                         *event = Event::command(0); // Cancel
@@ -514,4 +555,23 @@ mod tests {
 
         assert_eq!(menubox.get_selected_command(), Some(101));
     }
+
+    #[test]
+    fn test_popup_stays_on_screen() {
+        let menu = MenuBuilder::new()
+            .item("~C~opy", 100, 0)
+            .item("~P~aste", 101, 0)
+            .build();
+        let limits = Rect::new(0, 0, 80, 25);
+
+        let mut menubox = MenuBox::new(Point::new(10, 5), menu.clone());
+        menubox.place_within(Point::new(10, 5), limits);
+        assert_eq!(menubox.bounds.a, Point::new(10, 5));
+
+        // Near the bottom right corner: shifted left and opened upwards
+        let mut menubox = MenuBox::new(Point::new(78, 23), menu);
+        menubox.place_within(Point::new(78, 23), limits);
+        assert_eq!(menubox.bounds.b.x, 80);
+        assert_eq!(menubox.bounds.b.y, 23);
+    }
 }
@@ -14,7 +14,12 @@
 //! - [`Group`](group::Group) - Container for organizing child views
 //! - [`Window`](window::Window) - Movable, resizable window with frame
 //! - [`Dialog`](dialog::Dialog) - Modal dialog with standard button handling
+//! - [`DialogData`](dialog_data::DialogData) - Filling a dialog's controls from a record and reading them back
+//! - [`FormValidator`](form_validator::FormValidator) - Cross-field, inline and background validation of a dialog
+//! - [`TabView`](tab_view::TabView) - Notebook of pages selected through a tab strip
+//! - [`Splitter`](splitter::Splitter) - Two resizable panes with a draggable divider
 //! - [`Desktop`](desktop::Desktop) - Root container managing all windows
+//! - [`DesktopState`](desktop_state::DesktopState) - Saving and restoring the open windows
 //!
 //! ## Input Widgets
 //! - [`InputLine`](input_line::InputLine) - Single-line text input with validation
@@ -22,21 +27,30 @@
 //! - [`Button`](button::Button) - Clickable button that emits commands
 //! - [`CheckBox`](checkbox::CheckBox) - Binary on/off checkbox
 //! - [`RadioButton`](radiobutton::RadioButton) - Mutually exclusive radio buttons
+//! - [`ComboBox`](combo_box::ComboBox) - Input line with a drop-down list of choices
+//! - [`SpinInput`](spin_input::SpinInput) - Numeric input stepped with arrows and the mouse wheel
+//! - [`DatePicker`](date_picker::DatePicker), [`TimePicker`](date_picker::TimePicker) - Locale-aware date and time fields with a drop-down calendar
 //!
 //! ## Display Widgets
 //! - [`StaticText`](static_text::StaticText) - Non-interactive text label
 //! - [`TextViewer`](text_viewer::TextViewer) - Scrollable read-only text viewer
 //! - [`ListBox`](listbox::ListBox) - Scrollable list of selectable items
+//! - [`DataGrid`](data_grid::DataGrid) - Table with column headers, sorting and resizable columns
 //! - [`Memo`](memo::Memo) - Multi-line read-only text display
+//! - [`ProgressBar`](progress::ProgressBar), [`Gauge`](progress::Gauge), [`Spinner`](progress::Spinner) - Progress and busy indicators
 //!
 //! ## Menus and Status
 //! - [`MenuBar`](menu_bar::MenuBar) - Top menu bar with pull-down menus
 //! - [`StatusLine`](status_line::StatusLine) - Bottom status line with key hints
+//! - [`CommandPalette`](command_palette::CommandPalette) - Fuzzy search over the menu commands
 //!
 //! ## Dialogs and Utilities
 //! - [`FileDialog`](file_dialog::FileDialog) - File selection dialog
 //! - [`msgbox`] - Message boxes and confirmation dialogs
+//! - [`progress_dialog`] - Cancelable progress dialog for background tasks
 //! - [`HelpWindow`](help_window::HelpWindow) - Context-sensitive help system
+//! - [`WindowListDialog`](window_list::WindowListDialog) - Activate or close desktop windows from a list
+//! - [`Resources`](resource::Resources) - Dialogs, menus and status lines loaded from resource files
 //!
 //! # Examples
 //!
@@ -53,6 +67,7 @@
 //! window.add(Box::new(button));
 //! ```
 
+pub mod accessibility;
 pub mod ansi_background;
 pub mod background;
 pub mod button;
@@ -61,8 +76,14 @@ pub mod checkbox;
 pub mod cluster;
 pub mod color_dialog;
 pub mod color_selector;
+pub mod combo_box;
+pub mod command_palette;
+pub mod data_grid;
+pub mod date_picker;
 pub mod desktop;
+pub mod desktop_state;
 pub mod dialog;
+pub mod dialog_data;
 pub mod dir_listbox;
 pub mod edit_window;
 pub mod editor;
@@ -70,6 +91,7 @@ pub mod editor_traits;
 pub mod file_dialog;
 pub mod file_editor;
 pub mod file_list;
+pub mod form_validator;
 pub mod frame;
 pub mod group;
 pub mod help_context;
@@ -97,18 +119,25 @@ pub mod msgbox;
 pub mod outline;
 pub mod paramtext;
 pub mod picture_validator;
+pub mod progress;
+pub mod progress_dialog;
 pub mod radiobutton;
+pub mod resource;
 pub mod scrollbar;
 pub mod scroller;
 pub mod sorted_listbox;
+pub mod spin_input;
+pub mod splitter;
 pub mod static_text;
 pub mod status_line;
 pub mod syntax;
+pub mod tab_view;
 pub mod terminal_widget;
 pub mod text_viewer;
 pub mod validator;
 pub mod view;
 pub mod window;
+pub mod window_list;
 
 #[doc(inline)]
 pub use cluster::{Cluster, ClusterState};
@@ -121,4 +150,8 @@ pub use menu_box::MenuBox;
 #[doc(inline)]
 pub use menu_viewer::{MenuViewer, MenuViewerState};
 #[doc(inline)]
-pub use view::{IdleView, View, ViewId};
+pub use view::{
+    IdleView, View, ViewId, contains_view, find_context_menu, find_view_mut, focus_path,
+    key_contexts, request_redraw, send_context_command, short_type_name, take_redraw_request,
+    views_at,
+};
@@ -2,6 +2,7 @@
 
 //! MsgBox - message box utilities for displaying alerts and confirmations.
 
+use super::accessibility::plain_text;
 use super::button::Button;
 use super::dialog::Dialog;
 use super::input_line::InputLine;
@@ -10,6 +11,7 @@ use super::static_text::StaticText;
 use crate::app::Application;
 use crate::core::command::{CM_CANCEL, CM_NO, CM_OK, CM_YES, CommandId};
 use crate::core::geometry::Rect;
+use crate::core::i18n::{cstrlen, text_width, tr};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -55,7 +57,13 @@ pub fn message_box(app: &mut Application, message: &str, options: u16) -> Comman
         .unwrap_or(20);
     let msg_height = wrapped.lines().count().max(1);
 
-    let width = (msg_width + 6).min(target_w).max(30);
+    // Translated titles and buttons may need more room than the message.
+    let title_width = cstrlen(&message_box_title(options)) + 10;
+    let width = (msg_width + 6)
+        .min(target_w)
+        .max(30)
+        .max(title_width)
+        .max(buttons_width(&message_box_buttons(options)) + 4);
     let max_height = (screen_h as usize).saturating_sub(2).max(7);
     let height = (msg_height + 6).min(max_height).max(7);
 
@@ -152,16 +160,8 @@ pub fn message_box_rect(
     message: &str,
     options: u16,
 ) -> CommandId {
-    // Determine title based on message type
-    let title = match options & 0x03 {
-        MF_WARNING => "\u{26A0} Warning",
-        MF_ERROR => "\u{274C} Error",
-        MF_INFORMATION => "\u{2139}\u{FE0F} Information",
-        MF_CONFIRMATION => "\u{2753} Confirm",
-        _ => "Message",
-    };
-
-    let mut dialog = Dialog::new(bounds, title);
+    let title = message_box_title(options);
+    let mut dialog = Dialog::new(bounds, &title);
 
     // Add static text with message (one row higher). Left-align so
     // wrapped lines hang from a consistent left margin instead of
@@ -170,30 +170,17 @@ pub fn message_box_rect(
     let text_bounds = Rect::new(3, 1, bounds.width() - 2, bounds.height() - 4);
     dialog.add(Box::new(StaticText::new(text_bounds, message)));
 
-    // Determine which buttons to show
-    let button_configs = [
-        (MF_YES_BUTTON, " ~Y~es", CM_YES),
-        (MF_NO_BUTTON, " ~N~o", CM_NO),
-        (MF_OK_BUTTON, " ~O~K", CM_OK),
-        (MF_CANCEL_BUTTON, " ~C~ancel", CM_CANCEL),
-    ];
-
-    let mut buttons = Vec::new();
-    for (flag, label, cmd) in &button_configs {
-        if options & flag != 0 {
-            buttons.push((*label, *cmd));
-        }
-    }
+    let buttons = message_box_buttons(options);
 
     // Calculate button positions (one row higher)
     let button_y = bounds.height() - 4;
-    let total_width: usize = buttons.iter().map(|(label, _)| label.len() + 2).sum();
-    let mut x = (bounds.width_clamped() as usize - total_width) / 2;
+    let total_width = buttons_width(&buttons);
+    let mut x = (bounds.width_clamped() as usize).saturating_sub(total_width) / 2;
 
     // Add buttons
     let is_default = buttons.len() == 1 || (options & MF_OK_BUTTON != 0);
     for (i, (label, cmd)) in buttons.iter().enumerate() {
-        let button_width = label.len() as i16;
+        let button_width = text_width(label) + 2;
         let button_bounds = Rect::new(x as i16, button_y, x as i16 + button_width, button_y + 2);
         let is_this_default = is_default && (i == 0 || *cmd == CM_OK);
         dialog.add(Box::new(Button::new(
@@ -206,9 +193,78 @@ pub fn message_box_rect(
     }
 
     dialog.set_initial_focus();
+    // Screen readers get the message itself, not only the focused button.
+    let kind = title.trim_start_matches(|c: char| !c.is_alphanumeric());
+    app.announce(&format!("{kind}: {}", plain_text(message)));
     dialog.execute(app)
 }
 
+/// Translated message box title for the message type in `options`
+fn message_box_title(options: u16) -> String {
+    match options & 0x03 {
+        MF_WARNING => format!("\u{26A0} {}", tr("Warning")),
+        MF_ERROR => format!("\u{274C} {}", tr("Error")),
+        MF_INFORMATION => format!("\u{2139}\u{FE0F} {}", tr("Information")),
+        MF_CONFIRMATION => format!("\u{2753} {}", tr("Confirm")),
+        _ => tr("Message"),
+    }
+}
+
+/// Translated labels and commands of the buttons selected in `options`
+fn message_box_buttons(options: u16) -> Vec<(String, CommandId)> {
+    [
+        (MF_YES_BUTTON, "~Y~es", CM_YES),
+        (MF_NO_BUTTON, "~N~o", CM_NO),
+        (MF_OK_BUTTON, "~O~K", CM_OK),
+        (MF_CANCEL_BUTTON, "~C~ancel", CM_CANCEL),
+    ]
+    .into_iter()
+    .filter(|(flag, _, _)| options & flag != 0)
+    .map(|(_, label, cmd)| (format!(" {}", tr(label)), cmd))
+    .collect()
+}
+
+/// Columns taken by a row of message box buttons, including the gaps
+fn buttons_width(buttons: &[(String, CommandId)]) -> usize {
+    buttons.iter().map(|(label, _)| cstrlen(label) + 4).sum()
+}
+
+/// Add translated OK and Cancel buttons to an input dialog, OK at `ok_x`
+///
+/// Buttons are 10 columns wide unless the translated text needs more.
+fn add_ok_cancel_buttons(dialog: &mut Dialog, ok_x: i16, y: i16) {
+    let ok = format!(" {}", tr("~O~K"));
+    let cancel = format!(" {}", tr("~C~ancel"));
+    let ok_width = input_button_width(&ok);
+    let cancel_x = ok_x + ok_width + 2;
+    let ok_bounds = Rect::new(ok_x, y, ok_x + ok_width, y + 2);
+    dialog.add(Box::new(Button::new(ok_bounds, &ok, CM_OK, true)));
+    let cancel_bounds = Rect::new(cancel_x, y, cancel_x + input_button_width(&cancel), y + 2);
+    dialog.add(Box::new(Button::new(
+        cancel_bounds,
+        &cancel,
+        CM_CANCEL,
+        false,
+    )));
+}
+
+fn input_button_width(label: &str) -> i16 {
+    (text_width(label) + 3).max(10)
+}
+
+/// Columns needed by `add_ok_cancel_buttons` from `ok_x` to the right frame
+fn ok_cancel_extent(ok_x: i16) -> i16 {
+    ok_x + input_button_width(&format!(" {}", tr("~O~K")))
+        + 2
+        + input_button_width(&format!(" {}", tr("~C~ancel")))
+        + 3
+}
+
+/// Width of a prompt label: its text plus a blank, at least `min` columns
+fn prompt_width(label: &str, min: i16) -> i16 {
+    (text_width(label) + 1).max(min)
+}
+
 /// Display a simple message box with OK button
 ///
 /// Returns CM_OK when dismissed.
@@ -285,7 +341,7 @@ pub fn input_box(
     max_length: usize,
 ) -> Option<String> {
     // Calculate dialog size
-    let label_len = label.len();
+    let label_len = cstrlen(label);
     let width = (label_len + max_length + 12).min(60).max(30);
     let height = 8;
 
@@ -329,21 +385,9 @@ pub fn input_box_rect(
         data.clone(),
     )));
 
-    // Add OK button
+    // Add OK and Cancel buttons
     let button_y = bounds.height() - 4;
-    let ok_x = bounds.width() / 2 - 11;
-    let ok_bounds = Rect::new(ok_x, button_y, ok_x + 10, button_y + 2);
-    dialog.add(Box::new(Button::new(ok_bounds, " ~O~K", CM_OK, true)));
-
-    // Add Cancel button
-    let cancel_x = ok_x + 12;
-    let cancel_bounds = Rect::new(cancel_x, button_y, cancel_x + 10, button_y + 2);
-    dialog.add(Box::new(Button::new(
-        cancel_bounds,
-        " ~C~ancel",
-        CM_CANCEL,
-        false,
-    )));
+    add_ok_cancel_buttons(&mut dialog, (bounds.width() / 2 - 11).max(2), button_y);
 
     dialog.set_initial_focus();
 
@@ -369,8 +413,10 @@ pub fn input_box_rect(
 /// }
 /// ```
 pub fn search_box(app: &mut Application, title: &str) -> Option<String> {
-    // Calculate dialog size
-    let width = 50;
+    let find_label = tr("~F~ind:");
+
+    // Calculate dialog size (wider if translated texts need it)
+    let width = 50.max(ok_cancel_extent(15));
     let height = 9;
 
     // Center on screen
@@ -386,25 +432,15 @@ pub fn search_box(app: &mut Application, title: &str) -> Option<String> {
     let data = Rc::new(RefCell::new(String::new()));
 
     // Add label
-    let label_bounds = Rect::new(2, 2, 20, 3);
-    dialog.add(Box::new(Label::new(label_bounds, "~F~ind:")));
+    let label_bounds = Rect::new(2, 2, 2 + prompt_width(&find_label, 18), 3);
+    dialog.add(Box::new(Label::new(label_bounds, &find_label)));
 
     // Add input line
     let input_bounds = Rect::new(2, 3, width - 4, 4);
     dialog.add(Box::new(InputLine::new(input_bounds, 100, data.clone())));
 
-    // Add OK button
-    let ok_bounds = Rect::new(15, 5, 25, 7);
-    dialog.add(Box::new(Button::new(ok_bounds, " ~O~K", CM_OK, true)));
-
-    // Add Cancel button
-    let cancel_bounds = Rect::new(27, 5, 37, 7);
-    dialog.add(Box::new(Button::new(
-        cancel_bounds,
-        " ~C~ancel",
-        CM_CANCEL,
-        false,
-    )));
+    // Add OK and Cancel buttons
+    add_ok_cancel_buttons(&mut dialog, 15, 5);
 
     dialog.set_initial_focus();
 
@@ -431,8 +467,11 @@ pub fn search_box(app: &mut Application, title: &str) -> Option<String> {
 /// }
 /// ```
 pub fn search_replace_box(app: &mut Application, title: &str) -> Option<(String, String)> {
-    // Calculate dialog size
-    let width = 50;
+    let find_label = tr("~F~ind:");
+    let replace_label = tr("~R~eplace with:");
+
+    // Calculate dialog size (wider if translated texts need it)
+    let width = 50.max(ok_cancel_extent(15));
     let height = 13;
 
     // Center on screen
@@ -449,8 +488,8 @@ pub fn search_replace_box(app: &mut Application, title: &str) -> Option<(String,
     let replace_data = Rc::new(RefCell::new(String::new()));
 
     // Add find label
-    let label1_bounds = Rect::new(2, 2, 20, 3);
-    dialog.add(Box::new(Label::new(label1_bounds, "~F~ind:")));
+    let label1_bounds = Rect::new(2, 2, 2 + prompt_width(&find_label, 18), 3);
+    dialog.add(Box::new(Label::new(label1_bounds, &find_label)));
 
     // Add find input line
     let input1_bounds = Rect::new(2, 3, width - 4, 4);
@@ -461,8 +500,8 @@ pub fn search_replace_box(app: &mut Application, title: &str) -> Option<(String,
     )));
 
     // Add replace label
-    let label2_bounds = Rect::new(2, 5, 20, 6);
-    dialog.add(Box::new(Label::new(label2_bounds, "~R~eplace with:")));
+    let label2_bounds = Rect::new(2, 5, 2 + prompt_width(&replace_label, 18), 6);
+    dialog.add(Box::new(Label::new(label2_bounds, &replace_label)));
 
     // Add replace input line
     let input2_bounds = Rect::new(2, 6, width - 4, 7);
@@ -472,18 +511,8 @@ pub fn search_replace_box(app: &mut Application, title: &str) -> Option<(String,
         replace_data.clone(),
     )));
 
-    // Add OK button
-    let ok_bounds = Rect::new(15, 9, 25, 11);
-    dialog.add(Box::new(Button::new(ok_bounds, " ~O~K", CM_OK, true)));
-
-    // Add Cancel button
-    let cancel_bounds = Rect::new(27, 9, 37, 11);
-    dialog.add(Box::new(Button::new(
-        cancel_bounds,
-        " ~C~ancel",
-        CM_CANCEL,
-        false,
-    )));
+    // Add OK and Cancel buttons
+    add_ok_cancel_buttons(&mut dialog, 15, 9);
 
     dialog.set_initial_focus();
 
@@ -515,8 +544,10 @@ pub fn search_replace_box(app: &mut Application, title: &str) -> Option<(String,
 /// }
 /// ```
 pub fn goto_line_box(app: &mut Application, title: &str) -> Option<usize> {
-    // Calculate dialog size
-    let width = 40;
+    let line_label = format!(" {}", tr("~L~ine number:"));
+
+    // Calculate dialog size (wider if translated texts need it)
+    let width = 40.max(ok_cancel_extent(10));
     let height = 8;
 
     // Center on screen
@@ -532,25 +563,15 @@ pub fn goto_line_box(app: &mut Application, title: &str) -> Option<usize> {
     let data = Rc::new(RefCell::new(String::new()));
 
     // Add label
-    let label_bounds = Rect::new(2, 2, 20, 3);
-    dialog.add(Box::new(Label::new(label_bounds, " ~L~ine number:")));
+    let label_bounds = Rect::new(2, 2, 2 + prompt_width(&line_label, 18), 3);
+    dialog.add(Box::new(Label::new(label_bounds, &line_label)));
 
     // Add input line
     let input_bounds = Rect::new(2, 3, width - 4, 4);
     dialog.add(Box::new(InputLine::new(input_bounds, 10, data.clone())));
 
-    // Add OK button
-    let ok_bounds = Rect::new(10, 5, 20, 7);
-    dialog.add(Box::new(Button::new(ok_bounds, " ~O~K", CM_OK, true)));
-
-    // Add Cancel button
-    let cancel_bounds = Rect::new(22, 5, 32, 7);
-    dialog.add(Box::new(Button::new(
-        cancel_bounds,
-        " ~C~ancel",
-        CM_CANCEL,
-        false,
-    )));
+    // Add OK and Cancel buttons
+    add_ok_cancel_buttons(&mut dialog, 10, 5);
 
     dialog.set_initial_focus();
 
@@ -566,7 +587,7 @@ pub fn goto_line_box(app: &mut Application, title: &str) -> Option<usize> {
 
 #[cfg(test)]
 mod tests {
-    use super::wrap_message;
+    use super::*;
 
     #[test]
     fn wraps_long_lines_at_word_boundaries() {
@@ -603,4 +624,18 @@ mod tests {
         let recombined: String = out.lines().collect();
         assert_eq!(recombined, path);
     }
+
+    #[test]
+    fn buttons_and_titles_follow_locale() {
+        use crate::core::i18n::set_locale;
+
+        set_locale("de");
+        let buttons = message_box_buttons(MF_CONFIRMATION | MF_YES_NO_CANCEL);
+        let labels: Vec<&str> = buttons.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(labels, [" ~J~a", " ~N~ein", " ~A~bbrechen"]);
+        assert!(message_box_title(MF_CONFIRMATION).ends_with("Bestätigen"));
+        // Visible text plus four columns of button frame and gap each
+        assert_eq!(buttons_width(&buttons), 7 + 9 + 14);
+        set_locale("en");
+    }
 }
@@ -9,17 +9,55 @@
 //! - Visual tree structure (├─, └─, │)
 //! - Keyboard navigation
 //! - Custom node data
+//! - Children loaded on demand from a [`TreeDataSource`], with a "loading…"
+//!   placeholder while they arrive in the background
+//! - Tri-state check boxes (Space or a click toggles a node and its subtree),
+//!   per-node icons and colors
+//! - Editing once [`OutlineViewer::set_editing`] is called: F2 renames in place,
+//!   Ins/Ctrl+Ins insert a sibling/child, Del deletes, Alt+Up/Down move a node
+//!   among its siblings, Alt+Left/Right outdent/indent it, and a node can be
+//!   dragged onto another one with the mouse. Every edit is recorded
+//!   ([`OutlineViewer::take_changes`]) and announced with a `CM_OUTLINE_CHANGED`
+//!   broadcast carrying the [`OutlineChangeKind`] in `key_code`.
 
 use super::list_viewer::{ListViewer, ListViewerState};
 use super::view::{View, write_line_to_terminal};
+use crate::core::command::{CM_IDLE, CM_OUTLINE_CHANGED};
 use crate::core::draw::DrawBuffer;
-use crate::core::event::{Event, EventType, KB_ENTER, KB_LEFT, KB_RIGHT};
+use crate::core::event::{
+    Event, EventType, KB_BACKSPACE, KB_DEL, KB_DOWN, KB_END, KB_ENTER, KB_ESC, KB_F2, KB_HOME,
+    KB_INS, KB_LEFT, KB_RIGHT, KB_UP, MB_LEFT_BUTTON,
+};
 use crate::core::geometry::Rect;
-use crate::core::state::StateFlags;
+use crate::core::i18n::tr;
+use crate::core::palette::Attr;
+use crate::core::state::{SF_DRAGGING, StateFlags};
 use crate::terminal::Terminal;
+use crossterm::event::KeyModifiers;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+type NodeRef<T> = Rc<RefCell<Node<T>>>;
+
+/// State of a node's check box
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckState {
+    Unchecked,
+    Checked,
+    /// Some, but not all, checkable children are checked
+    Partial,
+}
+
+impl CheckState {
+    fn marker(self) -> &'static str {
+        match self {
+            Self::Unchecked => "[ ] ",
+            Self::Checked => "[X] ",
+            Self::Partial => "[▪] ",
+        }
+    }
+}
+
 /// A node in the tree
 /// Matches Borland: TNode
 pub struct Node<T> {
@@ -29,6 +67,14 @@ pub struct Node<T> {
     pub children: Vec<Rc<RefCell<Node<T>>>>,
     /// Whether this node is expanded (showing children)
     pub expanded: bool,
+    /// Children not loaded yet; the viewer's data source supplies them on first expansion
+    pub lazy: bool,
+    /// Check box shown before the text (`None`: no check box)
+    pub check: Option<CheckState>,
+    /// Symbol shown before the text
+    pub icon: Option<char>,
+    /// Text color instead of the list color (except on the focused row)
+    pub color: Option<Attr>,
 }
 
 impl<T> Node<T> {
@@ -38,26 +84,58 @@ impl<T> Node<T> {
             data,
             children: Vec::new(),
             expanded: false,
+            lazy: false,
+            check: None,
+            icon: None,
+            color: None,
+        }
+    }
+
+    /// Create a node whose children are loaded when it is first expanded
+    pub fn lazy(data: T) -> Self {
+        Self {
+            lazy: true,
+            ..Self::new(data)
         }
     }
 
     /// Create a new node with children
     pub fn with_children(data: T, children: Vec<Rc<RefCell<Node<T>>>>) -> Self {
         Self {
-            data,
             children,
-            expanded: false,
+            ..Self::new(data)
         }
     }
 
+    /// Show a check box in the given state
+    #[must_use]
+    pub fn with_check(mut self, check: CheckState) -> Self {
+        self.check = Some(check);
+        self
+    }
+
+    /// Show a symbol before the text
+    #[must_use]
+    pub fn with_icon(mut self, icon: char) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Draw the text in its own color
+    #[must_use]
+    pub fn with_color(mut self, color: Attr) -> Self {
+        self.color = Some(color);
+        self
+    }
+
     /// Add a child node
     pub fn add_child(&mut self, child: Rc<RefCell<Node<T>>>) {
         self.children.push(child);
     }
 
-    /// Check if this node has children
+    /// Check if this node has children (or may have, while they are not loaded)
     pub fn has_children(&self) -> bool {
-        !self.children.is_empty()
+        !self.children.is_empty() || self.lazy
     }
 
     /// Toggle expanded state
@@ -66,6 +144,97 @@ impl<T> Node<T> {
     }
 }
 
+/// Children of a node as reported by a [`TreeDataSource`]
+pub enum ChildLoad<T> {
+    /// The children are available now
+    Ready(Vec<T>),
+    /// Loading continues in the background; the viewer shows a placeholder and
+    /// asks again after [`TreeDataSource::refresh`] reports progress
+    Pending,
+}
+
+/// On-demand contents of an [`OutlineViewer`]
+///
+/// Nothing below the roots is requested until the user expands a node, so huge
+/// or remote hierarchies (directory trees, database schemas) stay cheap.
+pub trait TreeDataSource<T> {
+    /// Top-level items
+    fn roots(&mut self) -> Vec<T>;
+
+    /// Whether `data` may have children (decides between `[+]` and a leaf without loading)
+    fn has_children(&self, data: &T) -> bool;
+
+    /// Children of `data`, requested when its node is expanded for the first time
+    fn load_children(&mut self, data: &T) -> ChildLoad<T>;
+
+    /// Pick up work finished in the background
+    ///
+    /// Polled while the application is idle; return `true` when pending
+    /// `load_children()` calls may now be `Ready`. Default: loading is synchronous.
+    fn refresh(&mut self) -> bool {
+        false
+    }
+}
+
+/// What an edit in an [`OutlineViewer`] did
+///
+/// Sent as `key_code` of the `CM_OUTLINE_CHANGED` broadcast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum OutlineChangeKind {
+    Renamed = 1,
+    Inserted = 2,
+    Deleted = 3,
+    Moved = 4,
+    Checked = 5,
+}
+
+/// An edit made in an [`OutlineViewer`], for persisting it
+pub struct OutlineChange<T> {
+    pub kind: OutlineChangeKind,
+    /// The renamed, inserted, deleted, moved or (un)checked node
+    pub node: Rc<RefCell<Node<T>>>,
+}
+
+/// Where [`OutlineViewer::insert_node`] and [`OutlineViewer::move_node`] put a node
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InsertPosition {
+    /// Right after the reference node, as its sibling
+    After,
+    /// As the last child of the reference node
+    Child,
+}
+
+/// Direction for [`OutlineViewer::move_selected`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveDirection {
+    /// Before the previous sibling
+    Up,
+    /// After the next sibling
+    Down,
+    /// After the parent, as its sibling
+    Outdent,
+    /// Into the previous sibling, as its last child
+    Indent,
+}
+
+type RenameFn<T> = Box<dyn Fn(&mut T, &str)>;
+
+/// Callbacks that turn edited text into node data
+struct EditFns<T> {
+    create: Box<dyn Fn(&str) -> T>,
+    rename: RenameFn<T>,
+}
+
+/// In-place rename in progress
+struct InlineRename<T> {
+    /// Node being renamed (its row may change while the tree is refreshed)
+    node: NodeRef<T>,
+    text: String,
+    /// Cursor position in characters
+    cursor: usize,
+}
+
 /// Flattened node for display (with nesting level and display text)
 struct DisplayNode {
     /// Display text
@@ -82,9 +251,20 @@ struct DisplayNode {
     is_last: bool,
     /// Track which parent levels continue (for vertical lines)
     parent_continues: Vec<bool>,
+    check: Option<CheckState>,
+    icon: Option<char>,
+    color: Option<Attr>,
 }
 
 impl DisplayNode {
+    /// Column of the check box, if the node has one
+    fn check_x(&self) -> Option<usize> {
+        self.check?;
+        let branch = if self.level > 0 { 2 } else { 0 };
+        let marker = if self.has_children { 4 } else { 0 };
+        Some(self.level * 2 + branch + marker)
+    }
+
     /// Format with tree characters
     fn display_text(&self) -> String {
         let mut result = String::new();
@@ -116,6 +296,14 @@ impl DisplayNode {
             }
         }
 
+        if let Some(check) = self.check {
+            result.push_str(check.marker());
+        }
+        if let Some(icon) = self.icon {
+            result.push(icon);
+            result.push(' ');
+        }
+
         result.push_str(&self.text);
         result
     }
@@ -136,6 +324,17 @@ pub struct OutlineViewer<T> {
     list_state: ListViewerState,
     /// Function to convert data to display string
     format_fn: Box<dyn Fn(&T) -> String>,
+    /// Lazy loader for children
+    source: Option<Box<dyn TreeDataSource<T>>>,
+    /// Expanded nodes waiting for their children
+    pending: Vec<Rc<RefCell<Node<T>>>>,
+    /// Editing callbacks; the tree is read-only without them
+    editing: Option<EditFns<T>>,
+    rename: Option<InlineRename<T>>,
+    /// Node dragged with the mouse and the row under the mouse
+    drag: Option<(NodeRef<T>, Option<usize>)>,
+    /// Edits not collected with `take_changes()` yet
+    changes: Vec<OutlineChange<T>>,
     palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
 }
 
@@ -154,6 +353,12 @@ impl<T: 'static> OutlineViewer<T> {
             all_nodes: Vec::new(),
             list_state: ListViewerState::new(),
             format_fn: Box::new(format_fn),
+            source: None,
+            pending: Vec::new(),
+            editing: None,
+            rename: None,
+            drag: None,
+            changes: Vec::new(),
             palette_chain: None,
         }
     }
@@ -170,6 +375,82 @@ impl<T: 'static> OutlineViewer<T> {
         self.rebuild_display();
     }
 
+    /// Load the tree from a data source; roots are read now, children on expansion
+    pub fn set_data_source(&mut self, mut source: Box<dyn TreeDataSource<T>>) {
+        let roots = source.roots();
+        self.roots = roots
+            .into_iter()
+            .map(|data| Self::source_node(source.as_ref(), data))
+            .collect();
+        self.source = Some(source);
+        self.pending.clear();
+        self.rebuild_display();
+    }
+
+    /// Builder-style variant of [`set_data_source`](Self::set_data_source)
+    #[must_use]
+    pub fn with_data_source(mut self, source: Box<dyn TreeDataSource<T>>) -> Self {
+        self.set_data_source(source);
+        self
+    }
+
+    /// Poll the data source for children loaded in the background
+    ///
+    /// Called automatically while the application is idle; returns `true` when
+    /// the tree changed.
+    pub fn refresh(&mut self) -> bool {
+        if self.pending.is_empty() || !self.source.as_mut().is_some_and(|source| source.refresh()) {
+            return false;
+        }
+        let pending = std::mem::take(&mut self.pending);
+        let before = pending.len();
+        for node in pending {
+            self.load_children(&node);
+        }
+        let changed = self.pending.len() != before;
+        if changed {
+            self.rebuild_display();
+        }
+        changed
+    }
+
+    fn source_node(source: &dyn TreeDataSource<T>, data: T) -> Rc<RefCell<Node<T>>> {
+        let node = if source.has_children(&data) {
+            Node::lazy(data)
+        } else {
+            Node::new(data)
+        };
+        Rc::new(RefCell::new(node))
+    }
+
+    /// Ask the data source for the children of a lazy node
+    ///
+    /// Nodes whose children are still on their way are kept in `pending`.
+    fn load_children(&mut self, node: &Rc<RefCell<Node<T>>>) {
+        let Some(source) = self.source.as_mut() else {
+            return;
+        };
+        if !node.borrow().lazy {
+            return;
+        }
+        let load = source.load_children(&node.borrow().data);
+        match load {
+            ChildLoad::Ready(children) => {
+                let mut node = node.borrow_mut();
+                node.children = children
+                    .into_iter()
+                    .map(|data| Self::source_node(source.as_ref(), data))
+                    .collect();
+                node.lazy = false;
+            }
+            ChildLoad::Pending => {
+                if !self.pending.iter().any(|p| Rc::ptr_eq(p, node)) {
+                    self.pending.push(Rc::clone(node));
+                }
+            }
+        }
+    }
+
     /// Rebuild the flattened display list
     fn rebuild_display(&mut self) {
         self.display_nodes.clear();
@@ -210,10 +491,28 @@ impl<T: 'static> OutlineViewer<T> {
             expanded,
             is_last,
             parent_continues: parent_continues.clone(),
+            check: node_borrow.check,
+            icon: node_borrow.icon,
+            color: node_borrow.color,
         });
 
-        // If expanded, show children
-        if expanded && has_children {
+        // Children still loading: show a placeholder row that selects the node itself
+        if expanded && node_borrow.lazy {
+            let mut continues = parent_continues.clone();
+            continues.push(!is_last);
+            self.display_nodes.push(DisplayNode {
+                text: tr("loading…"),
+                level: level + 1,
+                node_index,
+                has_children: false,
+                expanded: false,
+                is_last: true,
+                parent_continues: continues,
+                check: None,
+                icon: None,
+                color: None,
+            });
+        } else if expanded && has_children {
             let children_len = node_borrow.children.len();
             drop(node_borrow); // Release borrow before recursing
 
@@ -245,6 +544,9 @@ impl<T: 'static> OutlineViewer<T> {
     /// Expand the currently selected node
     fn expand_selected(&mut self) {
         if let Some(node) = self.selected_node() {
+            if !node.borrow().expanded {
+                self.load_children(&node);
+            }
             let mut node_borrow = node.borrow_mut();
             if node_borrow.has_children() && !node_borrow.expanded {
                 node_borrow.expanded = true;
@@ -269,6 +571,9 @@ impl<T: 'static> OutlineViewer<T> {
     /// Toggle expand/collapse on the currently selected node
     fn toggle_selected(&mut self) {
         if let Some(node) = self.selected_node() {
+            if !node.borrow().expanded {
+                self.load_children(&node);
+            }
             let mut node_borrow = node.borrow_mut();
             if node_borrow.has_children() {
                 node_borrow.toggle();
@@ -277,6 +582,554 @@ impl<T: 'static> OutlineViewer<T> {
             }
         }
     }
+
+    /// Allow editing the tree
+    ///
+    /// `create` makes the data of an inserted node from its initial name and
+    /// `rename` stores a name typed in place into existing data.
+    pub fn set_editing<C, R>(&mut self, create: C, rename: R)
+    where
+        C: Fn(&str) -> T + 'static,
+        R: Fn(&mut T, &str) + 'static,
+    {
+        self.editing = Some(EditFns {
+            create: Box::new(create),
+            rename: Box::new(rename),
+        });
+    }
+
+    /// Builder-style variant of [`set_editing`](Self::set_editing)
+    #[must_use]
+    pub fn with_editing<C, R>(mut self, create: C, rename: R) -> Self
+    where
+        C: Fn(&str) -> T + 'static,
+        R: Fn(&mut T, &str) + 'static,
+    {
+        self.set_editing(create, rename);
+        self
+    }
+
+    /// Edits made since the last call, oldest first
+    pub fn take_changes(&mut self) -> Vec<OutlineChange<T>> {
+        std::mem::take(&mut self.changes)
+    }
+
+    /// Whether an in-place rename is in progress
+    pub fn is_renaming(&self) -> bool {
+        self.rename.is_some()
+    }
+
+    fn record(&mut self, kind: OutlineChangeKind, node: &NodeRef<T>) {
+        self.changes.push(OutlineChange {
+            kind,
+            node: Rc::clone(node),
+        });
+    }
+
+    /// Row showing `node` (not its "loading…" placeholder)
+    fn row_of(&self, node: &NodeRef<T>) -> Option<usize> {
+        self.display_nodes
+            .iter()
+            .position(|display| Rc::ptr_eq(&self.all_nodes[display.node_index], node))
+    }
+
+    fn focus_node(&mut self, node: &NodeRef<T>) {
+        if let Some(row) = self.row_of(node) {
+            let visible_rows = self.visible_rows();
+            self.list_state.focus_item(row, visible_rows);
+        }
+    }
+
+    /// Parent of `node` (`None` for a root) and its index among its siblings
+    fn locate(&self, node: &NodeRef<T>) -> Option<(Option<NodeRef<T>>, usize)> {
+        fn search<T>(
+            list: &[NodeRef<T>],
+            parent: Option<&NodeRef<T>>,
+            node: &NodeRef<T>,
+        ) -> Option<(Option<NodeRef<T>>, usize)> {
+            list.iter().enumerate().find_map(|(i, candidate)| {
+                if Rc::ptr_eq(candidate, node) {
+                    Some((parent.cloned(), i))
+                } else {
+                    search(&candidate.borrow().children, Some(candidate), node)
+                }
+            })
+        }
+        search(&self.roots, None, node)
+    }
+
+    /// Run `f` on the list holding the children of `parent` (the roots for `None`)
+    fn with_siblings<R>(
+        &mut self,
+        parent: Option<&NodeRef<T>>,
+        f: impl FnOnce(&mut Vec<NodeRef<T>>) -> R,
+    ) -> R {
+        match parent {
+            Some(parent) => f(&mut parent.borrow_mut().children),
+            None => f(&mut self.roots),
+        }
+    }
+
+    /// Whether `node` is `ancestor` or lies in its subtree
+    fn is_within(node: &NodeRef<T>, ancestor: &NodeRef<T>) -> bool {
+        Rc::ptr_eq(node, ancestor)
+            || ancestor
+                .borrow()
+                .children
+                .iter()
+                .any(|child| Self::is_within(node, child))
+    }
+
+    /// Make `child` the last child of `parent` and show it
+    ///
+    /// Fails for nodes whose children are still being loaded.
+    fn adopt(&mut self, parent: &NodeRef<T>, child: NodeRef<T>) -> bool {
+        self.load_children(parent);
+        let mut parent = parent.borrow_mut();
+        if parent.lazy {
+            return false;
+        }
+        parent.children.push(child);
+        parent.expanded = true;
+        true
+    }
+
+    /// Insert a node next to or below the selected one (a root if nothing is selected)
+    pub fn insert_node(
+        &mut self,
+        data: T,
+        position: InsertPosition,
+    ) -> Option<Rc<RefCell<Node<T>>>> {
+        let node = Rc::new(RefCell::new(Node::new(data)));
+        match self.selected_node() {
+            None => self.roots.push(Rc::clone(&node)),
+            Some(selected) => match position {
+                InsertPosition::Child => {
+                    if !self.adopt(&selected, Rc::clone(&node)) {
+                        return None;
+                    }
+                }
+                InsertPosition::After => {
+                    let (parent, index) = self.locate(&selected)?;
+                    let new = Rc::clone(&node);
+                    self.with_siblings(parent.as_ref(), |siblings| {
+                        siblings.insert(index + 1, new);
+                    });
+                }
+            },
+        }
+        self.update_checks_above(&node);
+        self.record(OutlineChangeKind::Inserted, &node);
+        self.rebuild_display();
+        self.focus_node(&node);
+        Some(node)
+    }
+
+    /// Remove the selected node with its subtree
+    pub fn delete_selected(&mut self) -> Option<Rc<RefCell<Node<T>>>> {
+        let node = self.selected_node()?;
+        let (parent, index) = self.locate(&node)?;
+        self.with_siblings(parent.as_ref(), |siblings| siblings.remove(index));
+        if let Some(parent) = parent {
+            self.update_checks_from(parent);
+        }
+        self.pending
+            .retain(|pending| !Self::is_within(pending, &node));
+        self.record(OutlineChangeKind::Deleted, &node);
+        self.rebuild_display();
+        Some(node)
+    }
+
+    /// Move the selected node; returns false if it cannot go that way
+    pub fn move_selected(&mut self, direction: MoveDirection) -> bool {
+        let Some(node) = self.selected_node() else {
+            return false;
+        };
+        let Some((parent, index)) = self.locate(&node) else {
+            return false;
+        };
+        let moved = match direction {
+            MoveDirection::Up | MoveDirection::Down => {
+                self.with_siblings(parent.as_ref(), |siblings| {
+                    let other = match direction {
+                        MoveDirection::Up => index.checked_sub(1),
+                        _ => Some(index + 1).filter(|&other| other < siblings.len()),
+                    };
+                    other.map(|other| siblings.swap(index, other)).is_some()
+                })
+            }
+            MoveDirection::Outdent => {
+                match parent
+                    .clone()
+                    .and_then(|p| self.locate(&p).map(|location| (p, location)))
+                {
+                    Some((parent, (grandparent, parent_index))) => {
+                        parent.borrow_mut().children.remove(index);
+                        self.with_siblings(grandparent.as_ref(), |siblings| {
+                            siblings.insert(parent_index + 1, Rc::clone(&node));
+                        });
+                        true
+                    }
+                    None => false,
+                }
+            }
+            MoveDirection::Indent => {
+                let previous = index.checked_sub(1).map(|previous| {
+                    self.with_siblings(parent.as_ref(), |siblings| Rc::clone(&siblings[previous]))
+                });
+                match previous {
+                    Some(previous) if !previous.borrow().lazy => {
+                        self.with_siblings(parent.as_ref(), |siblings| siblings.remove(index));
+                        self.adopt(&previous, Rc::clone(&node))
+                    }
+                    _ => false,
+                }
+            }
+        };
+        if moved {
+            if let Some(parent) = parent {
+                self.update_checks_from(parent);
+            }
+            self.update_checks_above(&node);
+            self.record(OutlineChangeKind::Moved, &node);
+            self.rebuild_display();
+            self.focus_node(&node);
+        }
+        moved
+    }
+
+    /// Move `node` next to or into `target` (what a mouse drop does)
+    ///
+    /// Refused when `target` lies inside `node`'s subtree.
+    pub fn move_node(
+        &mut self,
+        node: &Rc<RefCell<Node<T>>>,
+        target: &Rc<RefCell<Node<T>>>,
+        position: InsertPosition,
+    ) -> bool {
+        if Self::is_within(target, node)
+            || (position == InsertPosition::Child && target.borrow().lazy)
+        {
+            return false;
+        }
+        let Some((parent, index)) = self.locate(node) else {
+            return false;
+        };
+        self.with_siblings(parent.as_ref(), |siblings| siblings.remove(index));
+        let placed = match position {
+            InsertPosition::Child => self.adopt(target, Rc::clone(node)),
+            InsertPosition::After => match self.locate(target) {
+                Some((target_parent, target_index)) => {
+                    self.with_siblings(target_parent.as_ref(), |siblings| {
+                        siblings.insert(target_index + 1, Rc::clone(node));
+                    });
+                    true
+                }
+                None => false,
+            },
+        };
+        if !placed {
+            // Put it back where it was
+            self.with_siblings(parent.as_ref(), |siblings| {
+                siblings.insert(index, Rc::clone(node));
+            });
+            return false;
+        }
+        if let Some(parent) = parent {
+            self.update_checks_from(parent);
+        }
+        self.update_checks_above(node);
+        self.record(OutlineChangeKind::Moved, node);
+        self.rebuild_display();
+        self.focus_node(node);
+        true
+    }
+
+    /// Recompute the check boxes of the ancestors of `node`, nearest first
+    fn update_checks_above(&self, node: &NodeRef<T>) {
+        if let Some((Some(parent), _)) = self.locate(node) {
+            self.update_checks_from(parent);
+        }
+    }
+
+    /// Recompute the check box of `node` from its children, then those of
+    /// its ancestors
+    fn update_checks_from(&self, node: NodeRef<T>) {
+        let mut current = Some(node);
+        while let Some(node) = current {
+            let summary = node
+                .borrow()
+                .children
+                .iter()
+                .filter_map(|child| child.borrow().check)
+                .reduce(|a, b| if a == b { a } else { CheckState::Partial });
+            {
+                let mut node_mut = node.borrow_mut();
+                if node_mut.check.is_some() && summary.is_some() {
+                    node_mut.check = summary;
+                }
+            }
+            current = self.locate(&node).and_then(|(parent, _)| parent);
+        }
+    }
+
+    /// Toggle a node's check box, together with its subtree
+    ///
+    /// Checked ancestors become partially checked when only some of their
+    /// checkable children are checked.
+    pub fn toggle_check(&mut self, node: &Rc<RefCell<Node<T>>>) -> bool {
+        fn set_subtree<T>(node: &NodeRef<T>, state: CheckState) {
+            let mut node = node.borrow_mut();
+            if node.check.is_some() {
+                node.check = Some(state);
+            }
+            for child in &node.children {
+                set_subtree(child, state);
+            }
+        }
+
+        let state = match node.borrow().check {
+            None => return false,
+            Some(CheckState::Checked) => CheckState::Unchecked,
+            Some(_) => CheckState::Checked,
+        };
+        set_subtree(node, state);
+        self.update_checks_above(node);
+
+        self.record(OutlineChangeKind::Checked, node);
+        self.rebuild_display();
+        true
+    }
+
+    /// Start renaming the selected node in place (editable trees only)
+    pub fn start_rename(&mut self) -> bool {
+        let Some(item) = self.list_state.focused else {
+            return false;
+        };
+        if self.editing.is_none() || self.search_text(item).is_empty() {
+            return false;
+        }
+        let node = Rc::clone(&self.all_nodes[self.display_nodes[item].node_index]);
+        let text = (self.format_fn)(&node.borrow().data);
+        let cursor = text.chars().count();
+        self.rename = Some(InlineRename { node, text, cursor });
+        true
+    }
+
+    /// Store the name typed in place
+    fn commit_rename(&mut self) {
+        let (Some(rename), Some(editing)) = (self.rename.take(), self.editing.as_ref()) else {
+            return;
+        };
+        // The node may have gone while a refresh rebuilt the tree
+        if self.locate(&rename.node).is_none() {
+            return;
+        }
+        (editing.rename)(&mut rename.node.borrow_mut().data, &rename.text);
+        self.record(OutlineChangeKind::Renamed, &rename.node);
+        self.rebuild_display();
+    }
+
+    /// Keys while renaming: the rename swallows all of them
+    fn handle_rename_key(&mut self, event: &Event) {
+        let Some(rename) = self.rename.as_mut() else {
+            return;
+        };
+        let byte_at = |text: &str, cursor: usize| {
+            text.char_indices()
+                .nth(cursor)
+                .map_or(text.len(), |(i, _)| i)
+        };
+        let len = rename.text.chars().count();
+        match event.key_code {
+            KB_ENTER => self.commit_rename(),
+            KB_ESC => self.rename = None,
+            KB_LEFT => rename.cursor = rename.cursor.saturating_sub(1),
+            KB_RIGHT => rename.cursor = (rename.cursor + 1).min(len),
+            KB_HOME => rename.cursor = 0,
+            KB_END => rename.cursor = len,
+            KB_BACKSPACE if rename.cursor > 0 => {
+                rename.cursor -= 1;
+                let at = byte_at(&rename.text, rename.cursor);
+                rename.text.remove(at);
+            }
+            KB_DEL if rename.cursor < len => {
+                let at = byte_at(&rename.text, rename.cursor);
+                rename.text.remove(at);
+            }
+            key @ (0x20..0x7F | 0xA0..0x100) => {
+                let at = byte_at(&rename.text, rename.cursor);
+                rename
+                    .text
+                    .insert(at, char::from_u32(u32::from(key)).unwrap_or(' '));
+                rename.cursor += 1;
+            }
+            _ => {}
+        }
+    }
+
+    /// Editing keys: F2, Ins, Ctrl+Ins, Del and Alt+arrows
+    fn handle_edit_key(&mut self, event: &Event) -> bool {
+        let Some(editing) = self.editing.as_ref() else {
+            return false;
+        };
+        let alt = event.key_modifiers.contains(KeyModifiers::ALT);
+        match event.key_code {
+            KB_F2 => self.start_rename(),
+            KB_INS => {
+                let position = if event.key_modifiers.contains(KeyModifiers::CONTROL) {
+                    InsertPosition::Child
+                } else {
+                    InsertPosition::After
+                };
+                let data = (editing.create)(&tr("New item"));
+                self.insert_node(data, position).is_some() && self.start_rename()
+            }
+            KB_DEL => self.delete_selected().is_some(),
+            KB_UP if alt => self.move_selected(MoveDirection::Up),
+            KB_DOWN if alt => self.move_selected(MoveDirection::Down),
+            KB_LEFT if alt => self.move_selected(MoveDirection::Outdent),
+            KB_RIGHT if alt => self.move_selected(MoveDirection::Indent),
+            _ => return false,
+        };
+        true
+    }
+
+    /// Row under the mouse, if it shows a node
+    fn row_at(&self, event: &Event) -> Option<usize> {
+        let row =
+            usize::try_from(event.mouse.pos.y - self.bounds.a.y).ok()? + self.list_state.top_item;
+        (row < self.display_nodes.len()).then_some(row)
+    }
+
+    /// Stop dragging and release the mouse; returns the drag that was running
+    fn end_drag(&mut self) -> Option<(NodeRef<T>, Option<usize>)> {
+        self.state &= !SF_DRAGGING;
+        self.drag.take()
+    }
+
+    /// Check box clicks and drag-and-drop; true if the event was used
+    fn handle_mouse(&mut self, event: &Event) -> bool {
+        match event.what {
+            EventType::MouseDown if event.mouse.buttons & MB_LEFT_BUTTON != 0 => {
+                if !self.bounds.contains(event.mouse.pos) {
+                    return false;
+                }
+                self.end_drag();
+                self.commit_rename();
+                let Some(row) = self.row_at(event) else {
+                    return false;
+                };
+                let node = Rc::clone(&self.all_nodes[self.display_nodes[row].node_index]);
+                let x = usize::try_from(event.mouse.pos.x - self.bounds.a.x).unwrap_or(0);
+                if let Some(check_x) = self.display_nodes[row].check_x() {
+                    if (check_x..check_x + 3).contains(&x) {
+                        let visible_rows = self.visible_rows();
+                        self.list_state.focus_item(row, visible_rows);
+                        self.toggle_check(&node);
+                        return true;
+                    }
+                }
+                if self.editing.is_some() {
+                    // Keep the mouse until the button is released, wherever that is
+                    self.drag = Some((node, None));
+                    self.state |= SF_DRAGGING;
+                }
+                // Selection is left to the list viewer
+                false
+            }
+            EventType::MouseMove if self.drag.is_some() => {
+                let row = self.row_at(event);
+                if let Some(drag) = self.drag.as_mut() {
+                    drag.1 = row;
+                }
+                true
+            }
+            EventType::MouseUp if self.drag.is_some() => {
+                let Some((node, _)) = self.end_drag() else {
+                    return false;
+                };
+                let below_rows = event.mouse.pos.y - self.bounds.a.y
+                    >= i16::try_from(self.display_nodes.len()).unwrap_or(i16::MAX);
+                if let Some(row) = self.row_at(event) {
+                    let target = Rc::clone(&self.all_nodes[self.display_nodes[row].node_index]);
+                    if !Rc::ptr_eq(&target, &node) {
+                        self.move_node(&node, &target, InsertPosition::Child);
+                    }
+                } else if below_rows && self.bounds.contains(event.mouse.pos) {
+                    // Dropped below the last row: becomes the last root
+                    if let Some(last) = self.roots.last().cloned() {
+                        if !Rc::ptr_eq(&last, &node) {
+                            self.move_node(&node, &last, InsertPosition::After);
+                        }
+                    }
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Event handling without the change broadcast
+    fn handle_outline_event(&mut self, event: &mut Event) {
+        // Pick up children loaded in the background
+        if event.what == EventType::Broadcast && event.command == CM_IDLE {
+            if self.refresh() {
+                super::view::request_redraw();
+            }
+            return;
+        }
+
+        if self.handle_mouse(event) {
+            event.clear();
+            return;
+        }
+
+        if event.what == EventType::Keyboard {
+            if self.rename.is_some() {
+                self.handle_rename_key(event);
+                event.clear();
+                return;
+            }
+            if self.handle_edit_key(event) {
+                event.clear();
+                return;
+            }
+        }
+
+        // Handle expand/collapse keys first
+        if event.what == EventType::Keyboard {
+            match event.key_code {
+                KB_RIGHT => {
+                    self.expand_selected();
+                    event.clear();
+                    return;
+                }
+                KB_LEFT => {
+                    self.collapse_selected();
+                    event.clear();
+                    return;
+                }
+                KB_ENTER => {
+                    self.toggle_selected();
+                    event.clear();
+                    return;
+                }
+                key if key == ' ' as u16 && !self.list_state.type_ahead.is_active() => {
+                    if let Some(node) = self.selected_node() {
+                        if self.toggle_check(&node) {
+                            event.clear();
+                            return;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Let list viewer handle standard navigation (arrows, page up/down, etc.)
+        self.handle_list_event(event);
+    }
 }
 
 impl<T: 'static> View for OutlineViewer<T> {
@@ -305,6 +1158,10 @@ impl<T: 'static> View for OutlineViewer<T> {
         } else {
             LISTBOX_SELECTED
         };
+        let rename_row = self
+            .rename
+            .as_ref()
+            .and_then(|rename| self.row_of(&rename.node));
 
         // Draw visible items
         for i in 0..height {
@@ -312,14 +1169,20 @@ impl<T: 'static> View for OutlineViewer<T> {
             let item_idx = self.list_state.top_item + i;
 
             if item_idx < self.display_nodes.len() {
+                let display_node = &self.display_nodes[item_idx];
                 let is_selected = Some(item_idx) == self.list_state.focused;
+                let is_drop_target = self
+                    .drag
+                    .as_ref()
+                    .is_some_and(|(_, row)| *row == Some(item_idx) && !is_selected);
                 let color = if is_selected {
                     color_selected
+                } else if is_drop_target {
+                    color_normal.swap()
                 } else {
                     color_normal
                 };
 
-                let display_node = &self.display_nodes[item_idx];
                 let text = display_node.display_text();
                 buf.move_str(0, &text, color);
 
@@ -328,40 +1191,65 @@ impl<T: 'static> View for OutlineViewer<T> {
                 if text_len < width {
                     buf.move_char(text_len, ' ', color, width - text_len);
                 }
+
+                let text_x = text_len - display_node.text.chars().count();
+                match &self.rename {
+                    // In-place rename: an input field from the text to the right edge
+                    Some(rename) if rename_row == Some(item_idx) => {
+                        let color = crate::core::palette::colors::INPUT_FOCUSED;
+                        buf.move_char(text_x, ' ', color, width.saturating_sub(text_x));
+                        buf.move_str(text_x, &rename.text, color);
+                    }
+                    _ => {
+                        if let Some(node_color) = display_node.color.filter(|_| !is_selected) {
+                            buf.move_str(text_x, &display_node.text, node_color);
+                        }
+                    }
+                }
             } else {
                 // Empty line
                 buf.move_char(0, ' ', color_normal, width);
             }
+            // The node text follows the tree lines and the expansion marker
+            let text_x = self.display_nodes.get(item_idx).map_or(0, |node| {
+                node.display_text().chars().count() - node.text.chars().count()
+            });
+            self.draw_type_ahead(&mut buf, item_idx, text_x, i);
 
             write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + i as i16, &buf);
         }
     }
 
     fn handle_event(&mut self, event: &mut Event) {
-        // Handle expand/collapse keys first
-        if event.what == EventType::Keyboard {
-            match event.key_code {
-                KB_RIGHT => {
-                    self.expand_selected();
-                    event.clear();
-                    return;
-                }
-                KB_LEFT => {
-                    self.collapse_selected();
-                    event.clear();
-                    return;
-                }
-                KB_ENTER => {
-                    self.toggle_selected();
-                    event.clear();
-                    return;
-                }
-                _ => {}
-            }
+        let recorded = self.changes.len();
+        self.handle_outline_event(event);
+
+        // Announce edits so the application can persist them
+        if let Some(change) = self.changes.get(recorded..).and_then(<[_]>::last) {
+            *event = Event::broadcast(CM_OUTLINE_CHANGED);
+            event.key_code = change.kind as u16;
         }
+    }
 
-        // Let list viewer handle standard navigation (arrows, page up/down, etc.)
-        self.handle_list_event(event);
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        let Some(rename) = self.rename.as_ref().filter(|_| self.is_focused()) else {
+            return;
+        };
+        let Some(item) = self.row_of(&rename.node) else {
+            return;
+        };
+        let Some(row) = item.checked_sub(self.list_state.top_item) else {
+            return;
+        };
+        let display = &self.display_nodes[item];
+        let x =
+            display.display_text().chars().count() - display.text.chars().count() + rename.cursor;
+        if let (Ok(x), Ok(row)) = (i16::try_from(x), i16::try_from(row)) {
+            let _ = terminal.show_cursor(
+                (self.bounds.a.x + x).cast_unsigned(),
+                (self.bounds.a.y + row).cast_unsigned(),
+            );
+        }
     }
 
     fn state(&self) -> StateFlags {
@@ -407,4 +1295,243 @@ impl<T: 'static> ListViewer for OutlineViewer<T> {
             String::new()
         }
     }
+
+    fn search_text(&self, item: usize) -> String {
+        // A "loading…" placeholder shares the node of the row above it
+        let is_placeholder = |index: usize| {
+            index > 0
+                && self.display_nodes[index - 1].node_index == self.display_nodes[index].node_index
+        };
+        self.display_nodes
+            .get(item)
+            .filter(|_| !is_placeholder(item))
+            .map(|node| node.text.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::geometry::Point;
+    use crate::test_util::snapshot::Snapshot;
+    use crate::views::view::take_redraw_request;
+
+    /// Two-level tree whose second level arrives after one `refresh()`
+    struct SlowTree {
+        ready: bool,
+    }
+
+    impl TreeDataSource<String> for SlowTree {
+        fn roots(&mut self) -> Vec<String> {
+            vec!["src".to_string(), "README".to_string()]
+        }
+
+        fn has_children(&self, data: &String) -> bool {
+            data == "src"
+        }
+
+        fn load_children(&mut self, _data: &String) -> ChildLoad<String> {
+            if self.ready {
+                ChildLoad::Ready(vec!["lib.rs".to_string(), "main.rs".to_string()])
+            } else {
+                ChildLoad::Pending
+            }
+        }
+
+        fn refresh(&mut self) -> bool {
+            let changed = !self.ready;
+            self.ready = true;
+            changed
+        }
+    }
+
+    fn lines(viewer: &mut OutlineViewer<String>) -> Vec<String> {
+        Snapshot::of_view(viewer, 20, 4)
+            .text_lines()
+            .into_iter()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn lazy_children_show_placeholder_until_loaded() {
+        let mut viewer = OutlineViewer::new(Rect::new(0, 0, 20, 4), |s: &String| s.clone())
+            .with_data_source(Box::new(SlowTree { ready: false }));
+        assert_eq!(lines(&mut viewer), ["[+] src", "README", "", ""]);
+
+        viewer.handle_event(&mut Event::keyboard(KB_RIGHT));
+        assert_eq!(
+            lines(&mut viewer),
+            ["[-] src", "│ └─loading…", "README", ""]
+        );
+
+        take_redraw_request();
+        let mut idle = Event::broadcast(CM_IDLE);
+        viewer.handle_event(&mut idle);
+        assert_eq!(idle.what, EventType::Broadcast);
+        assert!(take_redraw_request());
+        assert_eq!(
+            lines(&mut viewer),
+            ["[-] src", "│ ├─lib.rs", "│ └─main.rs", "README"]
+        );
+    }
+
+    fn editable_tree() -> OutlineViewer<String> {
+        let docs = Node::with_children(
+            "docs".to_string(),
+            vec![Rc::new(RefCell::new(Node::new("guide".to_string())))],
+        );
+        let mut viewer = OutlineViewer::new(Rect::new(0, 0, 20, 4), |s: &String| s.clone())
+            .with_editing(str::to_string, |data: &mut String, name| {
+                *data = name.to_string();
+            });
+        viewer.add_root(Rc::new(RefCell::new(docs)));
+        viewer.add_root(Rc::new(RefCell::new(Node::new("src".to_string()))));
+        viewer
+    }
+
+    fn key(viewer: &mut OutlineViewer<String>, key_code: u16, modifiers: KeyModifiers) -> Event {
+        let mut event = Event::keyboard(key_code);
+        event.key_modifiers = modifiers;
+        viewer.handle_event(&mut event);
+        event
+    }
+
+    #[test]
+    fn insert_and_rename_in_place() {
+        let mut viewer = editable_tree();
+        let event = key(&mut viewer, KB_INS, KeyModifiers::NONE);
+        assert_eq!(event.what, EventType::Broadcast);
+        assert_eq!(event.command, CM_OUTLINE_CHANGED);
+        assert_eq!(event.key_code, OutlineChangeKind::Inserted as u16);
+        assert!(viewer.is_renaming());
+
+        for _ in 0.."New item".len() {
+            key(&mut viewer, KB_BACKSPACE, KeyModifiers::NONE);
+        }
+        for ch in "lib".chars() {
+            key(&mut viewer, ch as u16, KeyModifiers::NONE);
+        }
+        assert_eq!(lines(&mut viewer), ["[+] docs", "lib", "src", ""]);
+        let event = key(&mut viewer, KB_ENTER, KeyModifiers::NONE);
+        assert_eq!(event.key_code, OutlineChangeKind::Renamed as u16);
+
+        let changes = viewer.take_changes();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].node.borrow().data, "lib");
+        assert!(viewer.take_changes().is_empty());
+    }
+
+    #[test]
+    fn alt_arrows_move_nodes() {
+        let mut viewer = editable_tree();
+        key(&mut viewer, KB_DOWN, KeyModifiers::NONE);
+        key(&mut viewer, KB_RIGHT, KeyModifiers::ALT);
+        assert_eq!(lines(&mut viewer), ["[-] docs", "  ├─guide", "  └─src", ""]);
+
+        key(&mut viewer, KB_UP, KeyModifiers::ALT);
+        assert_eq!(lines(&mut viewer), ["[-] docs", "  ├─src", "  └─guide", ""]);
+
+        key(&mut viewer, KB_LEFT, KeyModifiers::ALT);
+        assert_eq!(lines(&mut viewer), ["[-] docs", "│ └─guide", "src", ""]);
+
+        let event = key(&mut viewer, KB_DEL, KeyModifiers::NONE);
+        assert_eq!(event.key_code, OutlineChangeKind::Deleted as u16);
+        assert_eq!(lines(&mut viewer), ["[-] docs", "  └─guide", "", ""]);
+    }
+
+    #[test]
+    fn check_boxes_are_tri_state() {
+        let leaf = |name: &str| {
+            Rc::new(RefCell::new(
+                Node::new(name.to_string()).with_check(CheckState::Unchecked),
+            ))
+        };
+        let mut root = Node::with_children("all".to_string(), vec![leaf("a"), leaf("b")])
+            .with_check(CheckState::Unchecked)
+            .with_icon('*');
+        root.expanded = true;
+        let mut viewer = OutlineViewer::new(Rect::new(0, 0, 20, 4), |s: &String| s.clone());
+        viewer.add_root(Rc::new(RefCell::new(root)));
+
+        key(&mut viewer, KB_DOWN, KeyModifiers::NONE);
+        let event = key(&mut viewer, ' ' as u16, KeyModifiers::NONE);
+        assert_eq!(event.key_code, OutlineChangeKind::Checked as u16);
+        assert_eq!(
+            lines(&mut viewer),
+            ["[-] [▪] * all", "  ├─[X] a", "  └─[ ] b", ""]
+        );
+
+        // Clicking the parent's check box checks the whole subtree
+        let mut click = Event::mouse(
+            EventType::MouseDown,
+            Point::new(5, 0),
+            MB_LEFT_BUTTON,
+            false,
+        );
+        viewer.handle_event(&mut click);
+        assert_eq!(
+            lines(&mut viewer),
+            ["[-] [X] * all", "  ├─[X] a", "  └─[X] b", ""]
+        );
+    }
+
+    #[test]
+    fn deleting_a_child_updates_the_parent_summary() {
+        let leaf = |name: &str| {
+            Rc::new(RefCell::new(
+                Node::new(name.to_string()).with_check(CheckState::Unchecked),
+            ))
+        };
+        let mut root = Node::with_children("all".to_string(), vec![leaf("a"), leaf("b")])
+            .with_check(CheckState::Unchecked);
+        root.expanded = true;
+        let mut viewer = OutlineViewer::new(Rect::new(0, 0, 20, 4), |s: &String| s.clone())
+            .with_editing(str::to_string, |data: &mut String, name| {
+                *data = name.to_string();
+            });
+        viewer.add_root(Rc::new(RefCell::new(root)));
+
+        key(&mut viewer, KB_DOWN, KeyModifiers::NONE);
+        key(&mut viewer, ' ' as u16, KeyModifiers::NONE);
+        key(&mut viewer, KB_DOWN, KeyModifiers::NONE);
+        key(&mut viewer, KB_DEL, KeyModifiers::NONE);
+        assert_eq!(lines(&mut viewer), ["[-] [X] all", "  └─[X] a", "", ""]);
+    }
+
+    #[test]
+    fn drag_and_drop_moves_node_into_target() {
+        let mut viewer = editable_tree();
+        let mouse = |what, y| Event::mouse(what, Point::new(2, y), MB_LEFT_BUTTON, false);
+        viewer.handle_event(&mut mouse(EventType::MouseDown, 1));
+        assert!(viewer.get_state_flag(SF_DRAGGING));
+        viewer.handle_event(&mut mouse(EventType::MouseMove, 0));
+        let mut drop = mouse(EventType::MouseUp, 0);
+        viewer.handle_event(&mut drop);
+        assert_eq!(drop.key_code, OutlineChangeKind::Moved as u16);
+        assert!(!viewer.get_state_flag(SF_DRAGGING));
+        assert_eq!(lines(&mut viewer), ["[-] docs", "  ├─guide", "  └─src", ""]);
+
+        // A node cannot be dropped into its own subtree
+        viewer.handle_event(&mut mouse(EventType::MouseDown, 0));
+        let mut drop = mouse(EventType::MouseUp, 2);
+        viewer.handle_event(&mut drop);
+        assert_eq!(drop.what, EventType::Nothing);
+        assert_eq!(lines(&mut viewer)[0], "[-] docs");
+    }
+
+    #[test]
+    fn synchronous_source_loads_on_first_expansion() {
+        let mut viewer = OutlineViewer::new(Rect::new(0, 0, 20, 4), |s: &String| s.clone())
+            .with_data_source(Box::new(SlowTree { ready: true }));
+        let root = viewer.selected_node().unwrap();
+        assert!(root.borrow().lazy);
+
+        viewer.handle_event(&mut Event::keyboard(KB_ENTER));
+        assert!(!root.borrow().lazy);
+        assert_eq!(root.borrow().children.len(), 2);
+        viewer.handle_event(&mut Event::keyboard(KB_ENTER));
+        assert_eq!(lines(&mut viewer), ["[+] src", "README", "", ""]);
+    }
 }
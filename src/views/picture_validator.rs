@@ -21,6 +21,7 @@
 //
 // Reference: Borland Turbo Vision tvalidat.cc, validate.h
 
+use crate::core::i18n::tr;
 use crate::views::validator::{Validator, ValidatorRef};
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -245,6 +246,11 @@ impl Validator for PictureValidator {
         // For now, just a no-op (the InputLine will handle visual feedback)
     }
 
+    /// Matches Borland's `TPXPictureValidator::Error()` message
+    fn error_message(&self) -> String {
+        format!("{}: {}", tr("Input does not conform to picture"), self.mask)
+    }
+
     fn valid(&self, input: &str) -> bool {
         if self.is_valid(input) {
             true
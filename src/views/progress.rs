@@ -0,0 +1,533 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Progress views - progress bar, gauge and spinner for long-running work.
+//!
+//! [`ProgressBar`] shows how much of a task is done, or a bouncing block when
+//! the amount is unknown. [`Gauge`] shows a value within a range, and
+//! [`Spinner`] an animated busy indicator next to a short text.
+//!
+//! The animated views advance on the `CM_IDLE` broadcast the application sends
+//! while waiting for input, so they keep moving inside modal dialogs; a view
+//! that moved asks for a redraw and leaves the broadcast to its siblings. They also
+//! implement [`IdleView`] for use with `Application::add_overlay_widget()`.
+//! To run a task on a worker thread behind a progress dialog, see
+//! [`run_with_progress`](super::progress_dialog::run_with_progress).
+
+use super::view::{IdleView, View, request_redraw, write_line_to_terminal};
+use crate::core::command::CM_IDLE;
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{Event, EventType};
+use crate::core::geometry::Rect;
+use crate::core::palette::{PROGRESS_FILLED, PROGRESS_TEXT, PROGRESS_TRACK, STATIC_TEXT_NORMAL};
+use crate::terminal::Terminal;
+use std::time::{Duration, Instant};
+
+/// Time between two animation frames
+const FRAME_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Frames of the spinner animation
+const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Columns reserved for the ` 100%` text of a progress bar
+const PERCENT_WIDTH: usize = 5;
+
+/// Fill `width` cells from `start` with a bar that is `fraction` full
+fn fill_bar(buf: &mut DrawBuffer, start: usize, width: usize, fraction: f64, view: &dyn View) {
+    let filled = bar_cells(fraction, width);
+    buf.move_char(start, '█', view.map_color(PROGRESS_FILLED), filled);
+    buf.move_char(
+        start + filled,
+        '░',
+        view.map_color(PROGRESS_TRACK),
+        width - filled,
+    );
+}
+
+/// Number of filled cells out of `width` for `fraction` (0.0 to 1.0)
+fn bar_cells(fraction: f64, width: usize) -> usize {
+    let width_f = f64::from(u32::try_from(width).unwrap_or(u32::MAX));
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "clamped to 0..=width"
+    )]
+    let cells = (fraction.clamp(0.0, 1.0) * width_f).floor() as usize;
+    cells.min(width)
+}
+
+/// Whole percent of `fraction` (0.0 to 1.0)
+fn percent(fraction: f64) -> u8 {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "clamped to 0..=100"
+    )]
+    let pct = (fraction.clamp(0.0, 1.0) * 100.0).floor() as u8;
+    pct
+}
+
+/// Progress bar - determinate (with an optional percentage) or indeterminate
+pub struct ProgressBar {
+    bounds: Rect,
+    /// Fraction done, or `None` while the amount of work is unknown
+    progress: Option<f64>,
+    show_percent: bool,
+    phase: usize,
+    last_frame: Instant,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl ProgressBar {
+    /// Create an empty determinate bar that shows its percentage
+    pub fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            progress: Some(0.0),
+            show_percent: true,
+            phase: 0,
+            last_frame: Instant::now(),
+            palette_chain: None,
+        }
+    }
+
+    /// Create an indeterminate bar (a bouncing block)
+    pub fn indeterminate(bounds: Rect) -> Self {
+        let mut bar = Self::new(bounds);
+        bar.progress = None;
+        bar
+    }
+
+    /// Show or hide the percentage at the right end of the bar
+    #[must_use]
+    pub fn with_percent(mut self, show: bool) -> Self {
+        self.show_percent = show;
+        self
+    }
+
+    /// Fraction done, or `None` if indeterminate
+    pub fn progress(&self) -> Option<f64> {
+        self.progress
+    }
+
+    /// Set the fraction done (clamped to 0.0..=1.0); makes the bar determinate
+    pub fn set_progress(&mut self, fraction: f64) {
+        self.progress = Some(if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        });
+    }
+
+    /// Set the progress from a count of completed steps
+    pub fn set_steps(&mut self, done: u64, total: u64) {
+        self.set_progress(fraction_of(done, total));
+    }
+
+    /// Switch to the indeterminate bouncing block
+    pub fn set_indeterminate(&mut self) {
+        self.progress = None;
+    }
+
+    /// Advance the animation if a frame is due; returns true if the bar changed
+    pub fn tick(&mut self) -> bool {
+        if self.progress.is_some() || self.last_frame.elapsed() < FRAME_INTERVAL {
+            return false;
+        }
+        self.last_frame = Instant::now();
+        self.phase = self.phase.wrapping_add(1);
+        true
+    }
+
+    /// Start column and width of the bouncing block within `width` cells
+    fn block_span(&self, width: usize) -> (usize, usize) {
+        let block = (width / 5).max(3).min(width);
+        let travel = width - block;
+        if travel == 0 {
+            return (0, block);
+        }
+        let step = self.phase % (2 * travel);
+        let start = if step <= travel {
+            step
+        } else {
+            2 * travel - step
+        };
+        (start, block)
+    }
+}
+
+/// Fraction `done / total`, 0.0 for an empty total
+pub(crate) fn fraction_of(done: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    #[allow(clippy::cast_precision_loss, reason = "only matters beyond 2^52 steps")]
+    let fraction = done.min(total) as f64 / total as f64;
+    fraction
+}
+
+impl View for ProgressBar {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+        let mut buf = DrawBuffer::new(width);
+        match self.progress {
+            Some(fraction) if self.show_percent && width > PERCENT_WIDTH + 2 => {
+                let bar_width = width - PERCENT_WIDTH;
+                fill_bar(&mut buf, 0, bar_width, fraction, self);
+                let text = format!(" {:>3}%", percent(fraction));
+                buf.move_str(bar_width, &text, self.map_color(PROGRESS_TEXT));
+            }
+            Some(fraction) => fill_bar(&mut buf, 0, width, fraction, self),
+            None => {
+                buf.move_char(0, '░', self.map_color(PROGRESS_TRACK), width);
+                let (start, block) = self.block_span(width);
+                buf.move_char(start, '█', self.map_color(PROGRESS_FILLED), block);
+            }
+        }
+        for y in self.bounds.a.y..self.bounds.b.y {
+            write_line_to_terminal(terminal, self.bounds.a.x, y, &buf);
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        // Animate while the application is idle
+        if event.what == EventType::Broadcast && event.command == CM_IDLE && self.tick() {
+            request_redraw();
+        }
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let value = match self.progress {
+            Some(fraction) => format!("{}%", percent(fraction)),
+            None => "busy".to_string(),
+        };
+        Some(AccessibleInfo::new(Role::ProgressBar, "").with_value(value))
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_PROGRESS_BAR))
+    }
+}
+
+impl IdleView for ProgressBar {
+    fn idle(&mut self) {
+        self.tick();
+    }
+}
+
+/// Gauge - a bar showing a value within a range, followed by `value/max`
+pub struct Gauge {
+    bounds: Rect,
+    min: i64,
+    max: i64,
+    value: i64,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl Gauge {
+    /// Create a gauge for `min..=max`, starting at `min`
+    pub fn new(bounds: Rect, min: i64, max: i64) -> Self {
+        Self {
+            bounds,
+            min,
+            max: max.max(min),
+            value: min,
+            palette_chain: None,
+        }
+    }
+
+    /// Current value
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Set the value, clamped to the range
+    pub fn set_value(&mut self, value: i64) {
+        self.value = value.clamp(self.min, self.max);
+    }
+
+    /// Change the range; the value is clamped to it
+    pub fn set_range(&mut self, min: i64, max: i64) {
+        self.min = min;
+        self.max = max.max(min);
+        self.set_value(self.value);
+    }
+
+    /// Range as `(min, max)`
+    pub fn range(&self) -> (i64, i64) {
+        (self.min, self.max)
+    }
+
+    /// How full the gauge is, from 0.0 to 1.0
+    pub fn fraction(&self) -> f64 {
+        let span = self.max.abs_diff(self.min);
+        fraction_of(self.value.abs_diff(self.min), span)
+    }
+}
+
+impl View for Gauge {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+        let text = format!(" {}/{}", self.value, self.max);
+        let text_width = text.chars().count().min(width);
+        let mut buf = DrawBuffer::new(width);
+        fill_bar(&mut buf, 0, width - text_width, self.fraction(), self);
+        buf.move_str(width - text_width, &text, self.map_color(PROGRESS_TEXT));
+        for y in self.bounds.a.y..self.bounds.b.y {
+            write_line_to_terminal(terminal, self.bounds.a.x, y, &buf);
+        }
+    }
+
+    fn handle_event(&mut self, _event: &mut Event) {}
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(
+            AccessibleInfo::new(Role::ProgressBar, "")
+                .with_value(format!("{} of {}", self.value, self.max)),
+        )
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_PROGRESS_BAR))
+    }
+}
+
+/// Spinner - an animated busy indicator followed by a text
+pub struct Spinner {
+    bounds: Rect,
+    text: String,
+    frame: usize,
+    spinning: bool,
+    last_frame: Instant,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl Spinner {
+    /// Create a spinning indicator with `text` next to it
+    pub fn new(bounds: Rect, text: &str) -> Self {
+        Self {
+            bounds,
+            text: text.to_string(),
+            frame: 0,
+            spinning: true,
+            last_frame: Instant::now(),
+            palette_chain: None,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    pub fn is_spinning(&self) -> bool {
+        self.spinning
+    }
+
+    /// Start the animation
+    pub fn start(&mut self) {
+        self.spinning = true;
+    }
+
+    /// Stop the animation; the indicator is hidden until started again
+    pub fn stop(&mut self) {
+        self.spinning = false;
+    }
+
+    /// Advance the animation if a frame is due; returns true if the spinner changed
+    pub fn tick(&mut self) -> bool {
+        if !self.spinning || self.last_frame.elapsed() < FRAME_INTERVAL {
+            return false;
+        }
+        self.last_frame = Instant::now();
+        self.frame = (self.frame + 1) % SPINNER_FRAMES.len();
+        true
+    }
+}
+
+impl View for Spinner {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+        let attr = self.map_color(STATIC_TEXT_NORMAL);
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', attr, width);
+        if self.spinning {
+            buf.put_char(0, SPINNER_FRAMES[self.frame], attr);
+        }
+        buf.move_str(2, &self.text, attr);
+        write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        // Animate while the application is idle
+        if event.what == EventType::Broadcast && event.command == CM_IDLE && self.tick() {
+            request_redraw();
+        }
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let info = AccessibleInfo::new(Role::Text, &self.text);
+        Some(if self.spinning {
+            info.with_value("busy")
+        } else {
+            info
+        })
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_STATIC_TEXT))
+    }
+}
+
+impl IdleView for Spinner {
+    fn idle(&mut self) {
+        self.tick();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::snapshot::Snapshot;
+    use crate::views::view::take_redraw_request;
+
+    #[test]
+    fn progress_bar_shows_fill_and_percent() {
+        let mut bar = ProgressBar::new(Rect::new(0, 0, 15, 1));
+        bar.set_steps(1, 4);
+        let snapshot = Snapshot::of_view(&mut bar, 15, 1);
+        assert_eq!(snapshot.text_lines(), vec!["██░░░░░░░░  25%"]);
+
+        bar.set_progress(2.0);
+        let snapshot = Snapshot::of_view(&mut bar, 15, 1);
+        assert_eq!(snapshot.text_lines(), vec!["██████████ 100%"]);
+        assert_eq!(bar.accessible().unwrap().to_string(), "progress bar, 100%");
+    }
+
+    #[test]
+    fn indeterminate_bar_bounces_on_idle() {
+        let mut bar = ProgressBar::indeterminate(Rect::new(0, 0, 10, 1));
+        let snapshot = Snapshot::of_view(&mut bar, 10, 1);
+        assert_eq!(snapshot.text_lines(), vec!["███░░░░░░░"]);
+
+        // Not due yet: no redraw needed
+        take_redraw_request();
+        let mut idle = Event::broadcast(CM_IDLE);
+        bar.handle_event(&mut idle);
+        assert!(!take_redraw_request());
+
+        // The broadcast always passes on to the siblings
+        bar.last_frame -= FRAME_INTERVAL;
+        bar.handle_event(&mut idle);
+        assert_eq!(idle.what, EventType::Broadcast);
+        assert!(take_redraw_request());
+        let snapshot = Snapshot::of_view(&mut bar, 10, 1);
+        assert_eq!(snapshot.text_lines(), vec!["░███░░░░░░"]);
+
+        // The block turns around at the right end
+        bar.phase = 8;
+        assert_eq!(bar.block_span(10), (6, 3));
+    }
+
+    #[test]
+    fn every_bar_in_a_dialog_animates() {
+        let mut dialog = crate::views::dialog::Dialog::new(Rect::new(0, 0, 14, 5), "Busy");
+        for y in [0, 1] {
+            let mut bar = ProgressBar::indeterminate(Rect::new(1, y, 11, y + 1));
+            bar.last_frame -= FRAME_INTERVAL;
+            dialog.add(Box::new(bar));
+        }
+
+        dialog.handle_event(&mut Event::broadcast(CM_IDLE));
+        let lines = Snapshot::of_view(&mut dialog, 14, 5).text_lines();
+        for row in &lines[1..3] {
+            let bar: String = row.chars().skip(2).take(10).collect();
+            assert_eq!(bar, "░███░░░░░░");
+        }
+    }
+
+    #[test]
+    fn gauge_clamps_value_to_range() {
+        let mut gauge = Gauge::new(Rect::new(0, 0, 16, 1), 0, 10);
+        gauge.set_value(15);
+        assert_eq!(gauge.value(), 10);
+        gauge.set_value(5);
+        let snapshot = Snapshot::of_view(&mut gauge, 16, 1);
+        assert_eq!(snapshot.text_lines(), vec!["█████░░░░░░ 5/10"]);
+
+        gauge.set_range(6, 8);
+        assert_eq!(gauge.value(), 6);
+        assert!(gauge.fraction().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn spinner_advances_only_while_spinning() {
+        let mut spinner = Spinner::new(Rect::new(0, 0, 12, 1), "Working");
+        spinner.last_frame -= FRAME_INTERVAL;
+        assert!(spinner.tick());
+        let snapshot = Snapshot::of_view(&mut spinner, 12, 1);
+        assert_eq!(snapshot.text_lines(), vec!["⠙ Working   "]);
+
+        spinner.stop();
+        spinner.last_frame -= FRAME_INTERVAL;
+        assert!(!spinner.tick());
+        let snapshot = Snapshot::of_view(&mut spinner, 12, 1);
+        assert_eq!(snapshot.text_lines(), vec!["  Working   "]);
+    }
+}
@@ -0,0 +1,455 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Progress dialog - run a task on a worker thread behind a cancelable progress dialog.
+//!
+//! [`run_with_progress`] starts the task on its own thread and shows a modal
+//! dialog with the task's message, a [`ProgressBar`], the elapsed and estimated
+//! remaining time and a Cancel button. The dialog runs in
+//! `Application::exec_view()` and polls the task on the `CM_IDLE` broadcast, so
+//! the screen stays responsive while the task runs.
+//!
+//! The task reports through a [`ProgressReporter`] and should check
+//! [`ProgressReporter::is_cancelled`] regularly:
+//!
+//! ```rust,no_run
+//! use turbo_vision::app::Application;
+//! use turbo_vision::views::progress_dialog::run_with_progress;
+//!
+//! # fn main() -> turbo_vision::core::error::Result<()> {
+//! let mut app = Application::new()?;
+//! let sum = run_with_progress(&mut app, "Adding", |progress| {
+//!     let mut sum = 0u64;
+//!     for i in 0..1_000u64 {
+//!         if progress.is_cancelled() {
+//!             break;
+//!         }
+//!         progress.set_message(&format!("Adding {i}"));
+//!         progress.set_steps(i + 1, 1_000);
+//!         sum += i;
+//!     }
+//!     sum
+//! });
+//! // `None` if the user cancelled
+//! # let _ = sum;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::button::Button;
+use super::dialog::Dialog;
+use super::progress::{ProgressBar, fraction_of};
+use super::view::{View, request_redraw, write_line_to_terminal};
+use crate::app::Application;
+use crate::core::command::{CM_CANCEL, CM_IDLE, CM_OK, CommandId};
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{Event, EventType};
+use crate::core::geometry::Rect;
+use crate::core::i18n::{cstrlen, tr};
+use crate::core::layout::{Axis, Extent, stack};
+use crate::core::palette::STATIC_TEXT_NORMAL;
+use crate::core::state::OF_POST_PROCESS;
+use crate::terminal::Terminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Progress reported by the task
+#[derive(Debug, Clone, PartialEq)]
+struct Report {
+    /// Fraction done, `None` if unknown
+    fraction: Option<f64>,
+    message: String,
+}
+
+#[derive(Debug)]
+struct Shared {
+    report: Mutex<Report>,
+    cancelled: AtomicBool,
+    finished: AtomicBool,
+}
+
+/// Handle through which a background task reports progress and learns about
+/// cancellation; cheap to clone and safe to share between threads
+#[derive(Debug, Clone)]
+pub struct ProgressReporter {
+    shared: Arc<Shared>,
+}
+
+impl ProgressReporter {
+    /// Create a reporter with unknown progress and no message
+    pub fn new() -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                report: Mutex::new(Report {
+                    fraction: None,
+                    message: String::new(),
+                }),
+                cancelled: AtomicBool::new(false),
+                finished: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Report)) {
+        let mut report = self
+            .shared
+            .report
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        f(&mut report);
+    }
+
+    fn report(&self) -> Report {
+        self.shared
+            .report
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Set the fraction done, from 0.0 to 1.0
+    pub fn set_fraction(&self, fraction: f64) {
+        self.update(|report| report.fraction = Some(fraction.clamp(0.0, 1.0)));
+    }
+
+    /// Set the progress from a count of completed steps
+    pub fn set_steps(&self, done: u64, total: u64) {
+        self.update(|report| report.fraction = Some(fraction_of(done, total)));
+    }
+
+    /// Report that the amount of remaining work is unknown
+    pub fn set_indeterminate(&self) {
+        self.update(|report| report.fraction = None);
+    }
+
+    /// Set the line of text shown above the bar
+    pub fn set_message(&self, message: &str) {
+        self.update(|report| {
+            if report.message != message {
+                report.message = message.to_string();
+            }
+        });
+    }
+
+    /// Ask the task to stop
+    pub fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// True once the user cancelled; the task should return soon after
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.shared.finished.load(Ordering::Acquire)
+    }
+}
+
+impl Default for ProgressReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Marks the task as finished when the worker returns or panics
+struct FinishGuard(ProgressReporter);
+
+impl Drop for FinishGuard {
+    fn drop(&mut self) {
+        self.0.shared.finished.store(true, Ordering::Release);
+    }
+}
+
+/// `m:ss`, or `h:mm:ss` from one hour on
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+/// Remaining time estimated from the time spent so far
+fn estimate_remaining(elapsed: Duration, fraction: f64) -> Option<Duration> {
+    if fraction <= 0.0 || elapsed < Duration::from_secs(1) {
+        return None;
+    }
+    Duration::try_from_secs_f64(elapsed.as_secs_f64() * (1.0 - fraction) / fraction).ok()
+}
+
+/// Body of the progress dialog: message, bar and timing line
+///
+/// Polls the reporter on `CM_IDLE`, turns Cancel into a cancellation request
+/// while the task runs, and ends its modal state (`CM_OK`, or `CM_CANCEL`
+/// after a cancellation) once the task has returned, which ends the dialog.
+struct TaskMonitor {
+    bounds: Rect,
+    reporter: ProgressReporter,
+    end_state: CommandId,
+    bar: ProgressBar,
+    message: String,
+    timing: String,
+    started: Instant,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl TaskMonitor {
+    fn new(bounds: Rect, reporter: ProgressReporter) -> Self {
+        let mut monitor = Self {
+            bounds,
+            reporter,
+            end_state: 0,
+            bar: ProgressBar::indeterminate(bounds),
+            message: String::new(),
+            timing: String::new(),
+            started: Instant::now(),
+            palette_chain: None,
+        };
+        monitor.set_bounds(bounds);
+        monitor.refresh();
+        monitor
+    }
+
+    /// Pick up the latest report; returns true if anything visible changed
+    fn refresh(&mut self) -> bool {
+        let report = self.reporter.report();
+        let message = if self.reporter.is_cancelled() {
+            tr("Cancelling…")
+        } else {
+            report.message
+        };
+        let elapsed = self.started.elapsed();
+        let remaining = report
+            .fraction
+            .and_then(|fraction| estimate_remaining(elapsed, fraction))
+            .map_or_else(|| "--:--".to_string(), format_duration);
+        let timing = format!(
+            "{} {}   {} {}",
+            tr("Elapsed:"),
+            format_duration(elapsed),
+            tr("Remaining:"),
+            remaining
+        );
+
+        let mut changed = self.bar.tick();
+        if self.bar.progress() != report.fraction {
+            match report.fraction {
+                Some(fraction) => self.bar.set_progress(fraction),
+                None => self.bar.set_indeterminate(),
+            }
+            changed = true;
+        }
+        if message != self.message || timing != self.timing {
+            self.message = message;
+            self.timing = timing;
+            changed = true;
+        }
+        changed
+    }
+
+    fn draw_text(&self, terminal: &mut Terminal, y: i16, text: &str) {
+        let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+        let attr = self.map_color(STATIC_TEXT_NORMAL);
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', attr, width);
+        buf.move_str(0, text, attr);
+        write_line_to_terminal(terminal, self.bounds.a.x, y, &buf);
+    }
+}
+
+impl View for TaskMonitor {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        let rows = stack(
+            bounds,
+            Axis::Vertical,
+            &[Extent::Fixed(1), Extent::Fixed(1), Extent::Fixed(1)],
+        );
+        self.bar.set_bounds(rows[1]);
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        self.draw_text(terminal, self.bounds.a.y, &self.message);
+        self.bar.draw(terminal);
+        self.draw_text(terminal, self.bounds.a.y + 2, &self.timing);
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::Broadcast if event.command == CM_IDLE => {
+                if self.reporter.is_finished() {
+                    // The dialog ends with this view's end state
+                    self.end_state = if self.reporter.is_cancelled() {
+                        CM_CANCEL
+                    } else {
+                        CM_OK
+                    };
+                } else if self.refresh() {
+                    request_redraw();
+                }
+            }
+            EventType::Command if event.command == CM_CANCEL && !self.reporter.is_finished() => {
+                // Keep the dialog open until the task has stopped
+                self.reporter.cancel();
+                self.refresh();
+                event.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn options(&self) -> u16 {
+        OF_POST_PROCESS
+    }
+
+    fn get_end_state(&self) -> CommandId {
+        self.end_state
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        let mut info = self.bar.accessible()?;
+        info.label = super::accessibility::plain_text(&self.message);
+        Some(info)
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        // The bar maps its colors through the dialog, like a sibling would
+        self.bar.set_palette_chain(node.clone());
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_STATIC_TEXT))
+    }
+}
+
+/// Run `task` on a worker thread while a modal progress dialog titled `title`
+/// shows its progress
+///
+/// Returns the task's result, or `None` if the user cancelled (Cancel button,
+/// Esc or quitting the application). After a cancellation the dialog stays
+/// open until the task returns. A panic in the task is resumed on the calling
+/// thread.
+pub fn run_with_progress<T, F>(app: &mut Application, title: &str, task: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&ProgressReporter) -> T + Send + 'static,
+{
+    let reporter = ProgressReporter::new();
+    let worker = {
+        let reporter = reporter.clone();
+        thread::spawn(move || {
+            let _finished = FinishGuard(reporter.clone());
+            task(&reporter)
+        })
+    };
+
+    let (screen_w, screen_h) = app.terminal.size();
+    let width = i16::try_from(cstrlen(title) + 10)
+        .unwrap_or(i16::MAX)
+        .max(50)
+        .min(screen_w);
+    let height = 10;
+    let x = (screen_w - width) / 2;
+    let y = (screen_h - height).max(0) / 2;
+
+    let mut dialog = Dialog::new_modal(Rect::new(x, y, x + width, y + height), title);
+    let cancel = tr("~C~ancel");
+    let button_width = i16::try_from(cstrlen(&cancel) + 4).unwrap_or(12).max(12);
+    let button_x = (width - button_width) / 2;
+    dialog.add(Box::new(Button::new(
+        Rect::new(button_x, 6, button_x + button_width, 8),
+        &cancel,
+        CM_CANCEL,
+        false,
+    )));
+    dialog.add(Box::new(TaskMonitor::new(
+        Rect::new(3, 2, width - 3, 5),
+        reporter.clone(),
+    )));
+    dialog.set_initial_focus();
+
+    // Only the Cancel button, Esc or quitting cancel the task; any other
+    // command that ends the dialog leaves it to finish
+    if app.exec_view(dialog) == CM_CANCEL {
+        reporter.cancel();
+    }
+    match worker.join() {
+        Ok(value) if !reporter.is_cancelled() => Some(value),
+        Ok(_) => None,
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::snapshot::headless_terminal;
+
+    #[test]
+    fn format_duration_and_estimate() {
+        assert_eq!(format_duration(Duration::from_secs(75)), "1:15");
+        assert_eq!(format_duration(Duration::from_secs(3725)), "1:02:05");
+        assert_eq!(
+            estimate_remaining(Duration::from_secs(10), 0.25),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(estimate_remaining(Duration::from_secs(10), 0.0), None);
+    }
+
+    #[test]
+    fn monitor_consumes_cancel_until_task_finishes() {
+        let reporter = ProgressReporter::new();
+        reporter.set_message("Copying");
+        let mut monitor = TaskMonitor::new(Rect::new(0, 0, 40, 3), reporter.clone());
+        assert_eq!(monitor.message, "Copying");
+
+        let mut cancel = Event::command(CM_CANCEL);
+        monitor.handle_event(&mut cancel);
+        assert_eq!(cancel.what, EventType::Nothing);
+        assert!(reporter.is_cancelled());
+        assert_eq!(monitor.message, "Cancelling…");
+
+        drop(FinishGuard(reporter.clone()));
+        let mut idle = Event::broadcast(CM_IDLE);
+        monitor.handle_event(&mut idle);
+        assert_eq!(idle.what, EventType::Broadcast);
+        assert_eq!(monitor.get_end_state(), CM_CANCEL);
+    }
+
+    #[test]
+    fn run_with_progress_returns_task_result() {
+        let mut app = Application::with_terminal(headless_terminal(80, 25));
+        let result = run_with_progress(&mut app, "Counting", |progress| {
+            progress.set_steps(1, 2);
+            progress.set_steps(2, 2);
+            42
+        });
+        assert_eq!(result, Some(42));
+    }
+
+    #[test]
+    fn run_with_progress_cancels_task() {
+        let mut app = Application::with_terminal(headless_terminal(80, 25));
+        app.terminal.put_event(Event::command(CM_CANCEL));
+        let result = run_with_progress(&mut app, "Waiting", |progress| {
+            while !progress.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            7
+        });
+        assert_eq!(result, None);
+    }
+}
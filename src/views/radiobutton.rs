@@ -23,6 +23,7 @@
 //   );
 
 use super::cluster::{Cluster, ClusterState};
+use super::dialog_data::FieldValue;
 use super::view::View;
 use crate::core::event::Event;
 use crate::core::geometry::Rect;
@@ -82,10 +83,30 @@ impl View for RadioButton {
         self.bounds
     }
 
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let value = if self.is_selected() {
+            "selected"
+        } else {
+            "not selected"
+        };
+        Some(AccessibleInfo::new(Role::RadioButton, &self.label).with_value(value))
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
     }
 
+    fn get_data(&self) -> Option<FieldValue> {
+        Some(FieldValue::Bool(self.is_selected()))
+    }
+
+    fn set_data(&mut self, value: &FieldValue) {
+        if let FieldValue::Bool(checked) = value {
+            self.set_selected(*checked);
+        }
+    }
+
     fn handle_event(&mut self, event: &mut Event) {
         // Use Cluster trait's standard event handling
         self.handle_cluster_event(event);
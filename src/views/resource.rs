@@ -0,0 +1,1088 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Resource files - dialogs, menu bars and status lines described in text.
+//!
+//! Instead of laying out every [`Dialog`] with `Rect::new` calls, an
+//! application can describe its dialogs, menus and status lines in a `.tvr`
+//! resource file and build the views at runtime with [`Resources`]. Layouts
+//! can then be tweaked without recompiling.
+//!
+//! A resource file is a list of blocks. Each line holds one statement: a
+//! keyword, positional arguments (words, numbers and `"quoted text"`),
+//! bare flags and `key=value` options. A statement ending in `{` opens a
+//! block, closed by `}` on its own line; `#` starts a comment.
+//!
+//! ```text
+//! # Commands defined by the application
+//! command CM_FIND_NEXT 200
+//!
+//! dialog find "Find" 50 11 help=1000
+//!     label "~T~ext to find:" 3 2 20 3 link=text
+//!     input text 3 3 44 4 max=80 help=1001
+//!     checkbox case "~C~ase sensitive" 3 5 30 6
+//!     radio scope "~G~lobal" 3 6 20 7 group=1 selected
+//!     radio "~S~elected text" 3 7 20 7 group=1
+//!     button ok "~O~K" 24 8 34 10 command=CM_OK default
+//!     button "Cancel" 36 8 46 10 command=CM_CANCEL
+//! end
+//! ```
+//!
+//! The dialog above uses the short form ending in `end` (no braces). Both
+//! forms are accepted everywhere a block is expected.
+//!
+//! # Dialogs
+//!
+//! `dialog NAME "Title" W H` creates a dialog centered when inserted; four
+//! numbers give its bounds instead. Flags: `modal`, `resizable`; option
+//! `help=N` sets the help context of the dialog. Controls follow the pattern
+//! `KIND [NAME] ["text"] X1 Y1 X2 Y2 [flags] [options]`, with coordinates
+//! relative to the dialog:
+//!
+//! | Kind | Text | Flags and options |
+//! |------|------|-------------------|
+//! | `label` | label | `link=NAME` (the control it focuses) |
+//! | `text` | static text, `\n` for new lines | `centered` |
+//! | `input` | - | `max=N`, `value="..."`, `validator="..."` |
+//! | `spin` | - | `min=N`, `max=N`, `step=N`, `decimals=N`, `value="..."` |
+//! | `checkbox` | label | `checked` |
+//! | `radio` | label | `group=N`, `selected` |
+//! | `button` | title | `command=CMD`, `default` |
+//! | `listbox` | - | `items="a\|b\|c"`, `command=CMD` |
+//! | `combo` | - | `items="a\|b\|c"`, `value="..."`, `readonly` |
+//!
+//! Every control takes `help=N`. Validators are `range MIN MAX [DECIMALS]`,
+//! `filter CHARS`, `picture MASK` or `lookup a|b|c`.
+//!
+//! # Menus and status lines
+//!
+//! ```text
+//! menubar main
+//!     submenu "~F~ile"
+//!         item "~O~pen..." CM_OPEN F3 help=10
+//!         separator
+//!         item "E~x~it" CM_QUIT Alt+X
+//!     end
+//! end
+//!
+//! statusline main
+//!     item "~F1~ Help" F1 CM_HELP_INDEX
+//!     range 1000 1999
+//!         item "~F3~ Find next" F3 CM_FIND_NEXT
+//!     end
+//! end
+//! ```
+//!
+//! Commands are standard names (`CM_OPEN`, see
+//! [`command_by_name`](crate::core::command::command_by_name)), names declared
+//! with `command NAME ID` or [`Resources::define_command`], or plain numbers.
+//! Keys use the chord syntax of [`parse_key_chord`]; `-` means no key.
+//!
+//! ```rust
+//! use turbo_vision::views::resource::Resources;
+//!
+//! let resources = Resources::parse(r#"
+//!     dialog login "Log in" 40 8
+//!         label "~U~ser:" 2 2 9 3 link=user
+//!         input user 10 2 36 3 max=32
+//!         button "~O~K" 14 5 24 7 command=CM_OK default
+//!     end
+//! "#).unwrap();
+//! let login = resources.dialog("login").unwrap();
+//! *login.text("user").unwrap().borrow_mut() = "enzo".to_string();
+//! assert!(login.id("user").is_some());
+//! ```
+
+use super::button::Button;
+use super::checkbox::CheckBox;
+use super::combo_box::ComboBox;
+use super::dialog::Dialog;
+use super::help_context::{HC_NO_CONTEXT, HelpContextId};
+use super::input_line::InputLine;
+use super::label::Label;
+use super::listbox::ListBox;
+use super::lookup_validator::LookupValidator;
+use super::menu_bar::{MenuBar, SubMenu};
+use super::picture_validator::PictureValidator;
+use super::radiobutton::RadioButton;
+use super::spin_input::SpinInput;
+use super::static_text::StaticText;
+use super::status_line;
+use super::validator::{FilterValidator, RangeValidator, ValidatorRef};
+use super::view::{View, ViewId};
+use crate::core::command::{CM_OK, CommandId, command_by_name};
+use crate::core::error::{Result, TurboVisionError};
+use crate::core::event::{KeyCode, parse_key_chord};
+use crate::core::geometry::Rect;
+use crate::core::menu_data::{Menu, MenuItem};
+use crate::core::quoted::read_quoted;
+use crate::core::state::OF_CENTERED;
+use crate::core::status_data::{StatusItem, StatusLine, StatusLineBuilder};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+
+/// One positional argument of a statement
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Arg {
+    /// Bare word or number
+    Word(String),
+    /// Quoted text
+    Text(String),
+}
+
+/// `key=value` options of a statement
+pub(super) type Options = Vec<(String, String)>;
+
+/// One line of a resource file, with the block it opens
+#[derive(Debug, Clone)]
+struct Statement {
+    line: usize,
+    keyword: String,
+    args: Vec<Arg>,
+    options: Options,
+    block: Option<Vec<Statement>>,
+}
+
+pub(super) fn error_at(line: usize, message: impl std::fmt::Display) -> TurboVisionError {
+    TurboVisionError::parse(format!("line {line}: {message}"))
+}
+
+impl Statement {
+    fn option(&self, key: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn number_option<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        self.option(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|error| error_at(self.line, format!("`{key}`: {error}")))
+            })
+            .transpose()
+    }
+
+    fn help(&self) -> Result<HelpContextId> {
+        Ok(self.number_option("help")?.unwrap_or(HC_NO_CONTEXT))
+    }
+
+    fn block(&self) -> Result<&[Statement]> {
+        self.block
+            .as_deref()
+            .ok_or_else(|| error_at(self.line, format!("`{}` needs a block", self.keyword)))
+    }
+
+    fn args(&self) -> Args<'_> {
+        Args {
+            statement: self,
+            next: 0,
+        }
+    }
+}
+
+/// Reads the positional arguments of a statement in order
+struct Args<'a> {
+    statement: &'a Statement,
+    next: usize,
+}
+
+impl Args<'_> {
+    fn peek(&self) -> Option<&Arg> {
+        self.statement.args.get(self.next)
+    }
+
+    fn error(&self, message: impl std::fmt::Display) -> TurboVisionError {
+        error_at(self.statement.line, message)
+    }
+
+    /// An optional name: a word that is not a number
+    fn name(&mut self) -> Option<String> {
+        match self.peek() {
+            Some(Arg::Word(word)) if word.parse::<i64>().is_err() => {
+                let word = word.clone();
+                self.next += 1;
+                Some(word)
+            }
+            _ => None,
+        }
+    }
+
+    /// An optional quoted text
+    fn text(&mut self) -> Option<String> {
+        match self.peek() {
+            Some(Arg::Text(text)) => {
+                let text = text.clone();
+                self.next += 1;
+                Some(text)
+            }
+            _ => None,
+        }
+    }
+
+    fn required_text(&mut self) -> Result<String> {
+        self.text()
+            .ok_or_else(|| self.error(format!("`{}` needs a quoted text", self.statement.keyword)))
+    }
+
+    /// An optional key chord, `-` if absent; `flags` are never taken as keys
+    fn key(&mut self, flags: &[&str]) -> String {
+        match self.peek() {
+            Some(Arg::Word(word)) if !flags.contains(&word.as_str()) => {
+                let word = word.clone();
+                self.next += 1;
+                word
+            }
+            _ => "-".to_string(),
+        }
+    }
+
+    fn word(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(Arg::Word(word)) => {
+                let word = word.clone();
+                self.next += 1;
+                Ok(word)
+            }
+            _ => Err(self.error(format!(
+                "`{}` is missing an argument",
+                self.statement.keyword
+            ))),
+        }
+    }
+
+    /// Up to `count` numbers
+    fn numbers(&mut self, count: usize) -> Vec<i16> {
+        let mut numbers = Vec::new();
+        while numbers.len() < count {
+            match self.peek() {
+                Some(Arg::Word(word)) => match word.parse::<i16>() {
+                    Ok(number) => {
+                        numbers.push(number);
+                        self.next += 1;
+                    }
+                    Err(_) => break,
+                },
+                _ => break,
+            }
+        }
+        numbers
+    }
+
+    fn rect(&mut self) -> Result<Rect> {
+        match self.numbers(4)[..] {
+            [x1, y1, x2, y2] => Ok(Rect::new(x1, y1, x2, y2)),
+            _ => Err(self.error(format!(
+                "`{}` needs bounds X1 Y1 X2 Y2",
+                self.statement.keyword
+            ))),
+        }
+    }
+
+    /// The remaining words, which must all be known flags
+    fn flags(&mut self, known: &[&str]) -> Result<Vec<String>> {
+        let mut flags = Vec::new();
+        while let Some(arg) = self.peek() {
+            match arg {
+                Arg::Word(word) if known.contains(&word.as_str()) => flags.push(word.clone()),
+                Arg::Word(word) | Arg::Text(word) => {
+                    return Err(self.error(format!("unexpected `{word}`")));
+                }
+            }
+            self.next += 1;
+        }
+        Ok(flags)
+    }
+}
+
+/// Split a line into arguments and options, dropping a `#` comment
+pub(super) fn tokenize(line: &str, number: usize) -> Result<(Vec<Arg>, Options)> {
+    fn quoted(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, line: usize) -> Result<String> {
+        read_quoted(chars).ok_or_else(|| error_at(line, "unterminated quoted text"))
+    }
+
+    let mut args = Vec::new();
+    let mut options = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+        } else if ch == '#' {
+            break;
+        } else if ch == '"' {
+            chars.next();
+            args.push(Arg::Text(quoted(&mut chars, number)?));
+        } else {
+            let mut word = String::new();
+            while let Some(&ch) = chars.peek() {
+                if ch.is_whitespace() || ch == '"' {
+                    break;
+                }
+                word.push(ch);
+                chars.next();
+            }
+            match word.split_once('=') {
+                Some((key, "")) if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    options.push((key.to_string(), quoted(&mut chars, number)?));
+                }
+                Some((key, value)) => options.push((key.to_string(), value.to_string())),
+                None => args.push(Arg::Word(word)),
+            }
+        }
+    }
+    Ok((args, options))
+}
+
+/// Parse the statements up to the end of the text or of the enclosing block
+fn parse_block(
+    lines: &mut std::iter::Peekable<impl Iterator<Item = (usize, String)>>,
+    opened: Option<usize>,
+) -> Result<Vec<Statement>> {
+    let mut statements = Vec::new();
+    while let Some((line, text)) = lines.next() {
+        let (mut args, options) = tokenize(&text, line)?;
+        let Some(Arg::Word(keyword)) = args.first().cloned() else {
+            if args.is_empty() && options.is_empty() {
+                continue;
+            }
+            return Err(error_at(line, "a statement must start with a keyword"));
+        };
+        args.remove(0);
+        if keyword == "}" || keyword == "end" {
+            if opened.is_none() {
+                return Err(error_at(line, format!("`{keyword}` without an open block")));
+            }
+            return Ok(statements);
+        }
+        let braced = args.last() == Some(&Arg::Word("{".to_string()));
+        if braced {
+            args.pop();
+        }
+        let opens_block = braced
+            || matches!(
+                keyword.as_str(),
+                "dialog" | "menubar" | "submenu" | "statusline" | "range"
+            );
+        let block = if opens_block {
+            Some(parse_block(lines, Some(line))?)
+        } else {
+            None
+        };
+        statements.push(Statement {
+            line,
+            keyword,
+            args,
+            options,
+            block,
+        });
+    }
+    match opened {
+        Some(line) => Err(error_at(line, "block is never closed")),
+        None => Ok(statements),
+    }
+}
+
+/// A dialog built from a resource, with its named controls
+pub struct LoadedDialog {
+    dialog: Dialog,
+    ids: HashMap<String, ViewId>,
+    help_contexts: HashMap<ViewId, HelpContextId>,
+    help_context: HelpContextId,
+    texts: HashMap<String, Rc<RefCell<String>>>,
+    selections: HashMap<String, Rc<RefCell<Option<usize>>>>,
+}
+
+impl LoadedDialog {
+    /// `ViewId` of the control named `name`
+    pub fn id(&self, name: &str) -> Option<ViewId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Names of all named controls
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.ids.keys().map(String::as_str)
+    }
+
+    /// Help context of a control, or of the dialog if the control has none
+    pub fn help_context(&self, view_id: ViewId) -> HelpContextId {
+        self.help_contexts
+            .get(&view_id)
+            .copied()
+            .unwrap_or(self.help_context)
+    }
+
+    /// Text bound to the `input`, `spin` or `combo` control named `name`
+    pub fn text(&self, name: &str) -> Option<Rc<RefCell<String>>> {
+        self.texts.get(name).map(Rc::clone)
+    }
+
+    /// Chosen item of the `combo` control named `name`
+    pub fn selection(&self, name: &str) -> Option<Rc<RefCell<Option<usize>>>> {
+        self.selections.get(name).map(Rc::clone)
+    }
+
+    pub fn dialog(&self) -> &Dialog {
+        &self.dialog
+    }
+
+    pub fn dialog_mut(&mut self) -> &mut Dialog {
+        &mut self.dialog
+    }
+
+    pub fn into_dialog(self) -> Dialog {
+        self.dialog
+    }
+}
+
+/// Dialogs, menu bars and status lines read from a resource file
+pub struct Resources {
+    commands: HashMap<String, CommandId>,
+    dialogs: HashMap<String, Statement>,
+    menu_bars: HashMap<String, Statement>,
+    status_lines: HashMap<String, Statement>,
+}
+
+impl Resources {
+    /// Parse resource text; controls are checked when they are built
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.to_string()))
+            .peekable();
+        let mut resources = Self {
+            commands: HashMap::new(),
+            dialogs: HashMap::new(),
+            menu_bars: HashMap::new(),
+            status_lines: HashMap::new(),
+        };
+        for statement in parse_block(&mut lines, None)? {
+            let mut args = statement.args();
+            let name = args.name().ok_or_else(|| {
+                error_at(
+                    statement.line,
+                    format!("`{}` needs a name", statement.keyword),
+                )
+            })?;
+            let table = match statement.keyword.as_str() {
+                "command" => {
+                    let id = args.word()?.parse::<CommandId>().map_err(|error| {
+                        error_at(statement.line, format!("command ID: {error}"))
+                    })?;
+                    resources.commands.insert(name, id);
+                    continue;
+                }
+                "dialog" => &mut resources.dialogs,
+                "menubar" => &mut resources.menu_bars,
+                "statusline" => &mut resources.status_lines,
+                other => {
+                    return Err(error_at(
+                        statement.line,
+                        format!("unknown resource `{other}`"),
+                    ));
+                }
+            };
+            if table.insert(name.clone(), statement.clone()).is_some() {
+                return Err(error_at(
+                    statement.line,
+                    format!("`{name}` is defined twice"),
+                ));
+            }
+        }
+        Ok(resources)
+    }
+
+    /// Read and parse a resource file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| TurboVisionError::file_operation(path, error))?;
+        Self::parse(&text)
+    }
+
+    /// Give an application command a name usable in the resources
+    pub fn define_command(&mut self, name: &str, command: CommandId) {
+        self.commands.insert(name.to_string(), command);
+    }
+
+    pub fn dialog_names(&self) -> impl Iterator<Item = &str> {
+        self.dialogs.keys().map(String::as_str)
+    }
+
+    fn command(&self, word: &str, line: usize) -> Result<CommandId> {
+        word.parse::<CommandId>()
+            .ok()
+            .or_else(|| self.commands.get(word).copied())
+            .or_else(|| command_by_name(word))
+            .ok_or_else(|| error_at(line, format!("unknown command `{word}`")))
+    }
+
+    fn key(word: &str, line: usize) -> Result<KeyCode> {
+        if word == "-" {
+            return Ok(0);
+        }
+        parse_key_chord(word)
+            .map(|event| event.key_code)
+            .ok_or_else(|| error_at(line, format!("unknown key `{word}`")))
+    }
+
+    fn validator(spec: &str, line: usize) -> Result<ValidatorRef> {
+        let (kind, rest) = spec.trim().split_once(' ').unwrap_or((spec.trim(), ""));
+        let rest = rest.trim();
+        let validator: ValidatorRef = match kind {
+            "range" => {
+                let numbers: Vec<i64> = rest
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<std::result::Result<_, _>>()
+                    .map_err(|error| error_at(line, format!("range validator: {error}")))?;
+                match numbers[..] {
+                    [min, max] => Rc::new(RefCell::new(RangeValidator::new(min, max))),
+                    [min, max, decimals] => Rc::new(RefCell::new(RangeValidator::with_decimals(
+                        min,
+                        max,
+                        u32::try_from(decimals).unwrap_or(0),
+                    ))),
+                    _ => return Err(error_at(line, "range validator needs MIN MAX [DECIMALS]")),
+                }
+            }
+            "filter" => Rc::new(RefCell::new(FilterValidator::new(rest))),
+            "picture" => Rc::new(RefCell::new(PictureValidator::new(rest))),
+            "lookup" => Rc::new(RefCell::new(LookupValidator::new(
+                rest.split('|').map(str::to_string).collect(),
+            ))),
+            other => return Err(error_at(line, format!("unknown validator `{other}`"))),
+        };
+        Ok(validator)
+    }
+
+    /// Build the dialog named `name`, with fresh data for its controls
+    pub fn dialog(&self, name: &str) -> Result<LoadedDialog> {
+        let definition = self
+            .dialogs
+            .get(name)
+            .ok_or_else(|| TurboVisionError::invalid_input(format!("no dialog named `{name}`")))?;
+        let mut args = definition.args();
+        args.name();
+        let title = args.text().unwrap_or_default();
+        let (bounds, centered) = match args.numbers(4)[..] {
+            [width, height] => (Rect::new(0, 0, width, height), true),
+            [x1, y1, x2, y2] => (Rect::new(x1, y1, x2, y2), false),
+            _ => return Err(args.error("`dialog` needs a size W H or bounds X1 Y1 X2 Y2")),
+        };
+        let flags = args.flags(&["modal", "resizable"])?;
+
+        let mut dialog = if flags.iter().any(|flag| flag == "modal") {
+            *Dialog::new_modal(bounds, &title)
+        } else {
+            Dialog::new(bounds, &title)
+        };
+        if centered {
+            dialog.set_options(dialog.options() | OF_CENTERED);
+        }
+        dialog.set_resizable(flags.iter().any(|flag| flag == "resizable"));
+
+        let mut loaded = LoadedDialog {
+            dialog,
+            ids: HashMap::new(),
+            help_contexts: HashMap::new(),
+            help_context: definition.help()?,
+            texts: HashMap::new(),
+            selections: HashMap::new(),
+        };
+        // Labels linked to a control further down wait until it is added
+        let mut pending_labels: Vec<PendingLabel> = Vec::new();
+        for control in definition.block()? {
+            let (name, view) = self.control(control, &mut loaded)?;
+            let help = control.help()?;
+            let view = match (view, control.option("link")) {
+                (Built::Label(mut label), Some(target)) => {
+                    match loaded.id(target) {
+                        Some(id) => {
+                            label.set_link(id);
+                            loaded.add(name, label, help);
+                        }
+                        None => pending_labels.push(PendingLabel {
+                            target: target.to_string(),
+                            name,
+                            label,
+                            help,
+                            line: control.line,
+                        }),
+                    }
+                    continue;
+                }
+                (view, _) => view,
+            };
+            let id = loaded.add(name.clone(), view.into_view(), help);
+            let (ready, waiting): (Vec<_>, Vec<_>) = pending_labels
+                .into_iter()
+                .partition(|pending| Some(&pending.target) == name.as_ref());
+            pending_labels = waiting;
+            for mut pending in ready {
+                pending.label.set_link(id);
+                loaded.add(pending.name, pending.label, pending.help);
+            }
+        }
+        if let Some(pending) = pending_labels.first() {
+            return Err(error_at(
+                pending.line,
+                format!("label links to unknown control `{}`", pending.target),
+            ));
+        }
+        loaded.dialog.set_initial_focus();
+        Ok(loaded)
+    }
+
+    /// Build one control of a dialog
+    #[allow(clippy::too_many_lines, reason = "one arm per control kind")]
+    fn control(
+        &self,
+        control: &Statement,
+        loaded: &mut LoadedDialog,
+    ) -> Result<(Option<String>, Built)> {
+        let mut args = control.args();
+        let name = args.name();
+        let line = control.line;
+        let built = match control.keyword.as_str() {
+            "label" => {
+                let text = args.required_text()?;
+                let bounds = args.rect()?;
+                args.flags(&[])?;
+                Built::Label(Box::new(Label::new(bounds, &text)))
+            }
+            "text" => {
+                let text = args.required_text()?;
+                let bounds = args.rect()?;
+                let flags = args.flags(&["centered"])?;
+                Built::View(if flags.is_empty() {
+                    Box::new(StaticText::new(bounds, &text))
+                } else {
+                    Box::new(StaticText::new_centered(bounds, &text))
+                })
+            }
+            "input" => {
+                let bounds = args.rect()?;
+                args.flags(&[])?;
+                let data = Rc::new(RefCell::new(
+                    control.option("value").unwrap_or("").to_string(),
+                ));
+                let max_length = control.number_option("max")?.unwrap_or(255);
+                let mut input = InputLine::new(bounds, max_length, Rc::clone(&data));
+                if let Some(spec) = control.option("validator") {
+                    input.set_validator(Self::validator(spec, line)?);
+                }
+                if let Some(name) = &name {
+                    loaded.texts.insert(name.clone(), data);
+                }
+                Built::View(Box::new(input))
+            }
+            "spin" => {
+                let bounds = args.rect()?;
+                args.flags(&[])?;
+                let data = Rc::new(RefCell::new(
+                    control.option("value").unwrap_or("").to_string(),
+                ));
+                let mut spin = SpinInput::with_decimals(
+                    bounds,
+                    control.number_option("min")?.unwrap_or(0),
+                    control.number_option("max")?.unwrap_or(100),
+                    control.number_option("decimals")?.unwrap_or(0),
+                    Rc::clone(&data),
+                );
+                spin.set_step(control.number_option("step")?.unwrap_or(1));
+                if let Some(spec) = control.option("validator") {
+                    spin.set_validator(Self::validator(spec, line)?);
+                }
+                if let Some(name) = &name {
+                    loaded.texts.insert(name.clone(), data);
+                }
+                Built::View(Box::new(spin))
+            }
+            "checkbox" => {
+                let text = args.required_text()?;
+                let bounds = args.rect()?;
+                let flags = args.flags(&["checked"])?;
+                let mut checkbox = CheckBox::new(bounds, &text);
+                checkbox.set_checked(!flags.is_empty());
+                Built::View(Box::new(checkbox))
+            }
+            "radio" => {
+                let text = args.required_text()?;
+                let bounds = args.rect()?;
+                let flags = args.flags(&["selected"])?;
+                let mut radio =
+                    RadioButton::new(bounds, &text, control.number_option("group")?.unwrap_or(0));
+                radio.set_selected(!flags.is_empty());
+                Built::View(Box::new(radio))
+            }
+            "button" => {
+                let text = args.required_text()?;
+                let bounds = args.rect()?;
+                let flags = args.flags(&["default"])?;
+                let command = match control.option("command") {
+                    Some(word) => self.command(word, line)?,
+                    None => CM_OK,
+                };
+                Built::View(Box::new(Button::new(
+                    bounds,
+                    &text,
+                    command,
+                    !flags.is_empty(),
+                )))
+            }
+            "listbox" => {
+                let bounds = args.rect()?;
+                args.flags(&[])?;
+                let command = match control.option("command") {
+                    Some(word) => self.command(word, line)?,
+                    None => CM_OK,
+                };
+                let mut listbox = ListBox::new(bounds, command);
+                listbox.set_items(split_items(control.option("items")));
+                Built::View(Box::new(listbox))
+            }
+            "combo" => {
+                let bounds = args.rect()?;
+                let flags = args.flags(&["readonly"])?;
+                let data = Rc::new(RefCell::new(
+                    control.option("value").unwrap_or("").to_string(),
+                ));
+                let items = split_items(control.option("items"));
+                let selection = Rc::new(RefCell::new(
+                    items.iter().position(|item| *item == *data.borrow()),
+                ));
+                let mut combo = ComboBox::with_data(
+                    bounds,
+                    items,
+                    Rc::clone(&selection),
+                    Rc::clone(&data),
+                    255,
+                );
+                combo.set_editable(flags.is_empty());
+                if let Some(name) = &name {
+                    loaded.texts.insert(name.clone(), data);
+                    loaded.selections.insert(name.clone(), selection);
+                }
+                Built::View(Box::new(combo))
+            }
+            other => return Err(error_at(line, format!("unknown control `{other}`"))),
+        };
+        Ok((name, built))
+    }
+
+    /// Build the menus of the menu bar named `name`
+    pub fn submenus(&self, name: &str) -> Result<Vec<SubMenu>> {
+        let definition = self.menu_bars.get(name).ok_or_else(|| {
+            TurboVisionError::invalid_input(format!("no menu bar named `{name}`"))
+        })?;
+        definition
+            .block()?
+            .iter()
+            .map(|statement| {
+                if statement.keyword != "submenu" {
+                    return Err(error_at(
+                        statement.line,
+                        "a menu bar holds `submenu` blocks",
+                    ));
+                }
+                let title = statement.args().required_text()?;
+                Ok(SubMenu::new(&title, self.menu(statement.block()?)?))
+            })
+            .collect()
+    }
+
+    /// Build a menu bar with the menus named `name`
+    pub fn menu_bar(&self, name: &str, bounds: Rect) -> Result<MenuBar> {
+        let mut menu_bar = MenuBar::new(bounds);
+        for submenu in self.submenus(name)? {
+            menu_bar.add_submenu(submenu);
+        }
+        Ok(menu_bar)
+    }
+
+    fn menu(&self, statements: &[Statement]) -> Result<Menu> {
+        let mut items = Vec::new();
+        for statement in statements {
+            let mut args = statement.args();
+            let help = statement.help()?;
+            let item = match statement.keyword.as_str() {
+                "separator" => MenuItem::separator(),
+                "item" => {
+                    let text = args.required_text()?;
+                    let command = self.command(&args.word()?, statement.line)?;
+                    let key = args.key(&["disabled"]);
+                    let flags = args.flags(&["disabled"])?;
+                    let key_code = Self::key(&key, statement.line)?;
+                    let mut item = match statement.option("shortcut") {
+                        Some(shortcut) => {
+                            MenuItem::with_shortcut(&text, command, key_code, shortcut, help)
+                        }
+                        None if key_code != 0 => {
+                            MenuItem::with_shortcut(&text, command, key_code, &key, help)
+                        }
+                        None => MenuItem::new(&text, command, key_code, help),
+                    };
+                    if let (false, MenuItem::Regular { enabled, .. }) =
+                        (flags.is_empty(), &mut item)
+                    {
+                        *enabled = false;
+                    }
+                    item
+                }
+                "submenu" => {
+                    let text = args.required_text()?;
+                    let key = args.key(&[]);
+                    let key_code = Self::key(&key, statement.line)?;
+                    MenuItem::submenu(&text, key_code, self.menu(statement.block()?)?, help)
+                }
+                other => {
+                    return Err(error_at(
+                        statement.line,
+                        format!("unknown menu entry `{other}`"),
+                    ));
+                }
+            };
+            items.push(item);
+        }
+        Ok(Menu::from_items(items))
+    }
+
+    /// Build the status line data named `name`
+    pub fn status_line(&self, name: &str) -> Result<StatusLine> {
+        let definition = self.status_lines.get(name).ok_or_else(|| {
+            TurboVisionError::invalid_input(format!("no status line named `{name}`"))
+        })?;
+        let mut builder = StatusLineBuilder::new();
+        let mut defaults = Vec::new();
+        for statement in definition.block()? {
+            match statement.keyword.as_str() {
+                "item" => defaults.push(self.status_item(statement)?),
+                "range" => {
+                    let mut args = statement.args();
+                    let [min, max] = args.numbers(2)[..] else {
+                        return Err(args.error("`range` needs MIN MAX"));
+                    };
+                    let items = statement
+                        .block()?
+                        .iter()
+                        .map(|item| self.status_item(item))
+                        .collect::<Result<Vec<_>>>()?;
+                    builder = builder.add_def(min.cast_unsigned(), max.cast_unsigned(), items);
+                }
+                other => {
+                    return Err(error_at(
+                        statement.line,
+                        format!("unknown status entry `{other}`"),
+                    ));
+                }
+            }
+        }
+        if !defaults.is_empty() {
+            builder = builder.add_default_def(defaults);
+        }
+        Ok(builder.build())
+    }
+
+    /// Build a status line view showing the items for `HC_NO_CONTEXT`
+    pub fn status_line_view(&self, name: &str, bounds: Rect) -> Result<status_line::StatusLine> {
+        let data = self.status_line(name)?;
+        let items = data
+            .get_def_for(HC_NO_CONTEXT)
+            .map(|def| {
+                def.items
+                    .iter()
+                    .map(|item| {
+                        status_line::StatusItem::new(&item.text, item.key_code, item.command)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(status_line::StatusLine::new(bounds, items))
+    }
+
+    fn status_item(&self, statement: &Statement) -> Result<StatusItem> {
+        if statement.keyword != "item" {
+            return Err(error_at(
+                statement.line,
+                "a status range holds `item` lines",
+            ));
+        }
+        let mut args = statement.args();
+        let text = args.required_text()?;
+        let key = Self::key(&args.word()?, statement.line)?;
+        let command = self.command(&args.word()?, statement.line)?;
+        args.flags(&[])?;
+        Ok(StatusItem::new(&text, key, command))
+    }
+}
+
+/// A built control: labels are kept apart until their link is known
+enum Built {
+    Label(Box<Label>),
+    View(Box<dyn View>),
+}
+
+impl Built {
+    fn into_view(self) -> Box<dyn View> {
+        match self {
+            Built::Label(label) => label,
+            Built::View(view) => view,
+        }
+    }
+}
+
+/// A label whose linked control has not been added yet
+struct PendingLabel {
+    target: String,
+    name: Option<String>,
+    label: Box<Label>,
+    help: HelpContextId,
+    line: usize,
+}
+
+impl LoadedDialog {
+    fn add(&mut self, name: Option<String>, view: Box<dyn View>, help: HelpContextId) -> ViewId {
+        let id = self.dialog.add(view);
+        if let Some(name) = name {
+            self.ids.insert(name, id);
+        }
+        if help != HC_NO_CONTEXT {
+            self.help_contexts.insert(id, help);
+        }
+        id
+    }
+}
+
+fn split_items(items: Option<&str>) -> Vec<String> {
+    items
+        .filter(|items| !items.is_empty())
+        .map(|items| items.split('|').map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::{CM_CANCEL, CM_OPEN, CM_QUIT};
+    use crate::core::event::{KB_ALT_X, KB_F3};
+
+    const SAMPLE: &str = r#"
+# Sample resources
+command CM_FIND_NEXT 200
+
+dialog find "Find" 50 11 help=1000
+    label "~T~ext to find:" 3 2 20 3 link=text
+    input text 3 3 44 4 max=80 value="needle" help=1001
+    spin count 3 5 12 6 min=1 max=9 value="3"
+    checkbox case "~C~ase sensitive" 3 6 30 7 checked
+    combo where 3 7 30 8 items="Files|Buffers" value="Buffers" readonly
+    button ok "~O~K" 24 8 34 10 command=CM_FIND_NEXT default
+    button "Cancel" 36 8 46 10 command=CM_CANCEL
+end
+
+menubar main {
+    submenu "~F~ile" {
+        item "~O~pen..." CM_OPEN F3 help=10
+        separator
+        submenu "~R~ecent" {
+            item "none" 7 disabled
+        }
+        item "E~x~it" CM_QUIT Alt+X
+    }
+}
+
+statusline main
+    item "~Alt+X~ Exit" Alt+X CM_QUIT
+    range 1000 1999
+        item "~F3~ Find next" F3 CM_FIND_NEXT
+    end
+end
+"#;
+
+    #[test]
+    fn dialog_is_built_with_named_controls() {
+        let resources = Resources::parse(SAMPLE).unwrap();
+        let find = resources.dialog("find").unwrap();
+        assert_eq!(find.dialog().bounds(), Rect::new(0, 0, 50, 11));
+        assert_ne!(find.dialog().options() & OF_CENTERED, 0);
+        // The label, six controls
+        assert_eq!(find.dialog().child_count(), 7);
+        assert_eq!(*find.text("text").unwrap().borrow(), "needle");
+        assert_eq!(*find.text("count").unwrap().borrow(), "3");
+        assert_eq!(*find.selection("where").unwrap().borrow(), Some(1));
+
+        let text = find.id("text").unwrap();
+        assert_eq!(find.help_context(text), 1001);
+        assert_eq!(find.help_context(find.id("case").unwrap()), 1000);
+        assert!(find.id("ok").is_some());
+
+        // Each build gets fresh data
+        let again = resources.dialog("find").unwrap();
+        assert!(!Rc::ptr_eq(
+            &find.text("text").unwrap(),
+            &again.text("text").unwrap()
+        ));
+    }
+
+    #[test]
+    fn menus_and_status_lines_resolve_commands_and_keys() {
+        let mut resources = Resources::parse(SAMPLE).unwrap();
+        let menus = resources.submenus("main").unwrap();
+        assert_eq!(menus.len(), 1);
+        let items = &menus[0].menu.items;
+        assert!(matches!(
+            &items[0],
+            MenuItem::Regular { command, key_code, help_ctx, .. }
+                if *command == CM_OPEN && *key_code == KB_F3 && *help_ctx == 10
+        ));
+        assert!(matches!(&items[2], MenuItem::SubMenu { .. }));
+        assert!(matches!(
+            &items[3],
+            MenuItem::Regular { command, key_code, .. } if *command == CM_QUIT && *key_code == KB_ALT_X
+        ));
+
+        let status = resources.status_line("main").unwrap();
+        assert_eq!(status.get_def_for(1500).unwrap().items[0].command, 200);
+        assert_eq!(status.get_def_for(0).unwrap().items[0].command, CM_QUIT);
+
+        resources.define_command("CM_FIND_NEXT", CM_CANCEL);
+        let status = resources.status_line("main").unwrap();
+        assert_eq!(
+            status.get_def_for(1500).unwrap().items[0].command,
+            CM_CANCEL
+        );
+    }
+
+    #[test]
+    fn errors_report_the_line() {
+        let error = Resources::parse("dialog d \"D\" 10 5\n    button \"x\" 1 1 5\nend\n")
+            .unwrap()
+            .dialog("d")
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("line 2"));
+
+        let error = Resources::parse("menubar m {\n    submenu \"x\" {\n}\n")
+            .err()
+            .unwrap();
+        assert!(error.is_parse());
+        assert!(error.to_string().contains("line 1"));
+
+        let error =
+            Resources::parse("dialog d \"D\" 10 5\n    label \"~A~\" 1 1 5 2 link=nowhere\nend\n")
+                .unwrap()
+                .dialog("d")
+                .err()
+                .unwrap();
+        assert!(error.to_string().contains("nowhere"));
+    }
+}
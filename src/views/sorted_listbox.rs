@@ -22,7 +22,7 @@
 //       sorted.set_selection(idx);
 //   }
 
-use super::list_viewer::{ListViewer, ListViewerState};
+use super::list_viewer::{ListViewer, ListViewerState, MatchMode, match_range};
 use super::view::View;
 use crate::core::command::CommandId;
 use crate::core::event::Event;
@@ -63,22 +63,27 @@ impl SortedListBox {
         if self.case_sensitive != case_sensitive {
             self.case_sensitive = case_sensitive;
             self.sort_items();
+            self.list_state.clear_selection();
         }
     }
 
-    /// Set the items in the list (will be automatically sorted)
+    /// Set the items in the list (will be automatically sorted; clears the marks)
     pub fn set_items(&mut self, items: Vec<String>) {
         self.items = items;
         self.sort_items();
+        self.list_state.clear_selection();
         self.list_state.set_range(self.items.len());
     }
 
     /// Add an item to the list (maintains sorted order)
+    ///
+    /// Marked items stay marked, and so does the focused item.
     pub fn add_item(&mut self, item: String) {
         // Use binary search to find insertion point
         let insertion_point = self.find_insertion_point(&item);
         self.items.insert(insertion_point, item);
         self.list_state.set_range(self.items.len());
+        self.list_state.item_inserted(insertion_point);
     }
 
     /// Clear all items
@@ -146,7 +151,7 @@ impl SortedListBox {
     /// Helper for case-sensitive prefix search
     fn find_prefix_case_sensitive(&self, prefix: &str) -> Option<usize> {
         let compare_fn = |item: &String| -> std::cmp::Ordering {
-            let item_prefix = &item[..prefix.len().min(item.len())];
+            let item_prefix = &item[..item.floor_char_boundary(prefix.len())];
             item_prefix.cmp(prefix)
         };
 
@@ -179,7 +184,7 @@ impl SortedListBox {
         let prefix_lower = prefix.to_lowercase();
 
         let compare_fn = |item: &String| -> std::cmp::Ordering {
-            let item_prefix = &item[..prefix_lower.len().min(item.len())];
+            let item_prefix = &item[..item.floor_char_boundary(prefix_lower.len())];
             item_prefix.to_lowercase().as_str().cmp(&prefix_lower)
         };
 
@@ -274,6 +279,8 @@ impl View for SortedListBox {
         } else {
             crate::core::palette::colors::LISTBOX_SELECTED
         };
+        // Focused but unselected item in a multi-select list, as in ListBox
+        let color_cursor = self.map_color(crate::core::palette::LISTBOX_DIVIDER);
 
         // Draw visible items
         for i in 0..height {
@@ -281,9 +288,10 @@ impl View for SortedListBox {
             let item_idx = self.list_state.top_item + i;
 
             if item_idx < self.items.len() {
-                let is_selected = Some(item_idx) == self.list_state.focused;
-                let color = if is_selected {
+                let color = if self.list_state.is_item_selected(item_idx) {
                     color_selected
+                } else if Some(item_idx) == self.list_state.focused {
+                    color_cursor
                 } else {
                     color_normal
                 };
@@ -300,17 +308,15 @@ impl View for SortedListBox {
                 // Empty line
                 buf.move_char(0, ' ', color_normal, width);
             }
+            self.draw_type_ahead(&mut buf, item_idx, 0, i);
 
             write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + i as i16, &buf);
         }
     }
 
     fn handle_event(&mut self, event: &mut Event) {
-        // Use ListViewer trait's standard event handling
+        // Use ListViewer trait's standard event handling (includes type-ahead search)
         self.handle_list_event(event);
-
-        // TODO: Add incremental search on keyboard input
-        // When user types letters, find and focus on matching items
     }
 
     fn can_focus(&self) -> bool {
@@ -360,6 +366,27 @@ impl ListViewer for SortedListBox {
     fn get_text(&self, item: usize, _max_len: usize) -> String {
         self.items.get(item).cloned().unwrap_or_default()
     }
+
+    /// Prefix matches form one block of the sorted items, found by binary search
+    fn find_item(&self, text: &str, start: usize) -> Option<usize> {
+        if self.list_state.type_ahead.mode != MatchMode::Prefix || self.case_sensitive {
+            return (0..self.items.len())
+                .map(|i| (start + i) % self.items.len())
+                .find(|&i| {
+                    match_range(self.list_state.type_ahead.mode, text, &self.items[i]).is_some()
+                });
+        }
+        let first = self.find_prefix(text)?;
+        let in_block = self
+            .items
+            .get(start)
+            .is_some_and(|item| match_range(MatchMode::Prefix, text, item).is_some());
+        Some(if start > first && in_block {
+            start
+        } else {
+            first
+        })
+    }
 }
 
 /// Builder for creating sorted listboxes with a fluent API.
@@ -515,6 +542,27 @@ mod tests {
         assert_eq!(listbox.get_selection(), Some(0));
     }
 
+    #[test]
+    fn test_sorted_listbox_type_ahead_uses_prefix_block() {
+        let mut listbox = SortedListBox::new(Rect::new(0, 0, 20, 10), 1000);
+        listbox.set_items(
+            ["Cherry", "apple", "Apricot", "Banana", "éclair"]
+                .map(String::from)
+                .to_vec(),
+        );
+
+        assert_eq!(listbox.find_item("ap", 0), Some(0));
+        assert_eq!(listbox.find_item("ap", 1), Some(1));
+        assert_eq!(listbox.find_item("ap", 3), Some(0));
+        assert_eq!(listbox.find_item("é", 0), Some(4));
+
+        listbox.set_type_ahead(true);
+        for key in ['a', 'p', 'r'] {
+            listbox.handle_event(&mut Event::keyboard(key as u16));
+        }
+        assert_eq!(listbox.get_selected_item(), Some("Apricot"));
+    }
+
     #[test]
     fn test_sorted_listbox_case_sensitive() {
         let mut listbox = SortedListBox::new(Rect::new(0, 0, 20, 10), 1000);
@@ -552,4 +600,21 @@ mod tests {
         assert_eq!(listbox.items[1], "Banana");
         assert_eq!(listbox.items[2], "ZEBRA");
     }
+
+    #[test]
+    fn test_sorted_listbox_marks_follow_inserted_items() {
+        let mut listbox = SortedListBox::new(Rect::new(0, 0, 20, 10), 1000);
+        listbox.set_items(vec!["b".to_string(), "d".to_string()]);
+        listbox.set_multi_select(true);
+        listbox.list_state_mut().toggle_selected(1); // "d"
+        listbox.set_selection(1);
+
+        listbox.add_item("a".to_string());
+        listbox.add_item("e".to_string());
+        assert_eq!(listbox.selected_indices(), [2]);
+        assert_eq!(listbox.get_selected_item(), Some("d"));
+
+        listbox.set_items(vec!["x".to_string()]);
+        assert!(listbox.selected_indices().is_empty());
+    }
 }
@@ -0,0 +1,526 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Spin input view - numeric input line with up/down arrows.
+//!
+//! A [`SpinInput`] is an [`InputLine`] for a number between a minimum and a
+//! maximum, followed by `▲▼` arrows. Up and Down (or the mouse wheel, or a
+//! click on an arrow) add or subtract the step; Page Up and Page Down move
+//! ten steps.
+//! The value is clamped to the range.
+//!
+//! Decimal inputs use fixed-point values: with two decimals, the range, the
+//! step and [`value`](SpinInput::value) are in hundredths, so a range of
+//! `0..=1000` shows as `0.00` to `10.00`.
+//!
+//! Typing is filtered and the final text checked by a [`RangeValidator`]
+//! (or the [`ValidatorRef`] given to [`set_validator`](SpinInput::set_validator)),
+//! so the dialog refuses to close with an out-of-range value.
+//!
+//! ```rust
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::spin_input::SpinInputBuilder;
+//!
+//! let data = Rc::new(RefCell::new("0.50".to_string()));
+//! let mut spin = SpinInputBuilder::new()
+//!     .bounds(Rect::new(2, 2, 12, 3))
+//!     .range(0, 500)
+//!     .step(25)
+//!     .decimals(2)
+//!     .data(Rc::clone(&data))
+//!     .build();
+//! spin.spin(1);
+//! assert_eq!(*data.borrow(), "0.75");
+//! ```
+
+use super::dialog_data::FieldValue;
+use super::input_line::InputLine;
+use super::validator::{RangeValidator, ValidatorRef, format_fixed, parse_fixed};
+use super::view::{View, write_line_to_terminal};
+use crate::core::command::{CM_CANCEL, CommandId};
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{Event, EventType, KB_DOWN, KB_PGDN, KB_PGUP, KB_UP, MB_LEFT_BUTTON};
+use crate::core::geometry::Rect;
+use crate::core::state::StateFlags;
+use crate::terminal::Terminal;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Columns taken by the `▲▼` arrows
+const ARROWS_WIDTH: i16 = 2;
+
+/// Steps taken by Page Up and Page Down
+const PAGE_STEPS: i64 = 10;
+
+// Palette indices (maps to CP_HISTORY, like the history button)
+const SPIN_BUTTON: u8 = 1;
+const SPIN_ARROW: u8 = 2;
+
+/// Spin input - a numeric input line stepped with arrows
+pub struct SpinInput {
+    bounds: Rect,
+    input: InputLine,
+    validator: ValidatorRef,
+    min: i64,
+    max: i64,
+    step: i64,
+    decimals: u32,
+    state: StateFlags,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl SpinInput {
+    /// Create an integer spin input for `min..=max`, bound to `data`
+    pub fn new(bounds: Rect, min: i64, max: i64, data: Rc<RefCell<String>>) -> Self {
+        Self::with_decimals(bounds, min, max, 0, data)
+    }
+
+    /// Create a spin input with `decimals` fractional digits; `min` and `max`
+    /// are fixed-point values in units of 10^-`decimals`
+    pub fn with_decimals(
+        bounds: Rect,
+        min: i64,
+        max: i64,
+        decimals: u32,
+        data: Rc<RefCell<String>>,
+    ) -> Self {
+        let (min, max) = (min.min(max), min.max(max));
+        let validator: ValidatorRef = Rc::new(RefCell::new(RangeValidator::with_decimals(
+            min, max, decimals,
+        )));
+        let max_length = format_fixed(min, decimals)
+            .len()
+            .max(format_fixed(max, decimals).len());
+        let input = InputLine::with_validator(
+            Self::input_bounds(bounds),
+            max_length,
+            data,
+            Rc::clone(&validator),
+        );
+        Self {
+            bounds,
+            input,
+            validator,
+            min,
+            max,
+            step: 1,
+            decimals,
+            state: 0,
+            palette_chain: None,
+        }
+    }
+
+    fn input_bounds(bounds: Rect) -> Rect {
+        Rect::new(
+            bounds.a.x,
+            bounds.a.y,
+            (bounds.b.x - ARROWS_WIDTH).max(bounds.a.x),
+            bounds.a.y + 1,
+        )
+    }
+
+    fn arrows_bounds(&self) -> Rect {
+        Rect::new(
+            self.input.bounds().b.x,
+            self.bounds.a.y,
+            self.bounds.b.x,
+            self.bounds.a.y + 1,
+        )
+    }
+
+    /// Amount added per step, in the same units as the range (default: 1)
+    pub fn set_step(&mut self, step: i64) {
+        self.step = step.max(1);
+    }
+
+    pub fn step(&self) -> i64 {
+        self.step
+    }
+
+    pub fn range(&self) -> (i64, i64) {
+        (self.min, self.max)
+    }
+
+    pub fn decimals(&self) -> u32 {
+        self.decimals
+    }
+
+    /// Replace the validator checking the text (the range still clamps stepping)
+    pub fn set_validator(&mut self, validator: ValidatorRef) {
+        self.input.set_validator(Rc::clone(&validator));
+        self.validator = validator;
+    }
+
+    /// Current value in fixed-point units, `None` if the text is not a number
+    pub fn value(&self) -> Option<i64> {
+        parse_fixed(&self.input.get_text(), self.decimals)
+    }
+
+    /// Current value as a float, `None` if the text is not a number
+    #[allow(clippy::cast_precision_loss, reason = "spin values are far below 2^52")]
+    pub fn value_f64(&self) -> Option<f64> {
+        let scale = 10_f64.powi(i32::try_from(self.decimals).unwrap_or(0));
+        self.value().map(|value| value as f64 / scale)
+    }
+
+    /// Show `value` (clamped to the range)
+    pub fn set_value(&mut self, value: i64) {
+        let value = value.clamp(self.min, self.max);
+        self.input.set_text(format_fixed(value, self.decimals));
+    }
+
+    /// Current text
+    pub fn text(&self) -> String {
+        self.input.get_text()
+    }
+
+    /// Add `steps` steps (negative to go down), clamped to the range
+    ///
+    /// Text that is not a number restarts from the value nearest to zero.
+    pub fn spin(&mut self, steps: i64) {
+        let current = self
+            .value()
+            .unwrap_or_else(|| 0_i64.clamp(self.min, self.max));
+        self.set_value(current.saturating_add(self.step.saturating_mul(steps)));
+    }
+
+    fn handle_mouse(&mut self, event: &mut Event) {
+        let pos = event.mouse.pos;
+        match event.what {
+            EventType::MouseWheelUp | EventType::MouseWheelDown if self.bounds.contains(pos) => {
+                self.spin(if event.what == EventType::MouseWheelUp {
+                    1
+                } else {
+                    -1
+                });
+                event.clear();
+            }
+            EventType::MouseDown | EventType::MouseAuto
+                if event.mouse.buttons & MB_LEFT_BUTTON != 0
+                    && self.arrows_bounds().contains(pos) =>
+            {
+                // The left arrow counts up, the right one down
+                self.spin(if pos.x == self.arrows_bounds().a.x {
+                    1
+                } else {
+                    -1
+                });
+                event.clear();
+            }
+            EventType::MouseDown => self.input.handle_event(event),
+            _ => {}
+        }
+    }
+}
+
+impl View for SpinInput {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        self.input.set_bounds(Self::input_bounds(bounds));
+    }
+
+    /// The value in fixed-point units, `None` if the text is not a number
+    fn get_data(&self) -> Option<FieldValue> {
+        self.value().map(FieldValue::Int)
+    }
+
+    fn set_data(&mut self, value: &FieldValue) {
+        if let FieldValue::Int(value) = value {
+            self.set_value(*value);
+        }
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        self.input.draw(terminal);
+
+        let arrows = self.arrows_bounds();
+        let width = usize::from(arrows.width_clamped().cast_unsigned());
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', self.map_color(SPIN_BUTTON), width);
+        buf.put_char(0, '▲', self.map_color(SPIN_ARROW));
+        if width > 1 {
+            buf.put_char(1, '▼', self.map_color(SPIN_ARROW));
+        }
+        write_line_to_terminal(terminal, arrows.a.x, arrows.a.y, &buf);
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::Keyboard if self.is_focused() => {
+                let steps = match event.key_code {
+                    KB_UP => 1,
+                    KB_DOWN => -1,
+                    KB_PGUP => PAGE_STEPS,
+                    KB_PGDN => -PAGE_STEPS,
+                    _ => 0,
+                };
+                if steps == 0 {
+                    self.input.handle_event(event);
+                } else {
+                    self.spin(steps);
+                    event.clear();
+                }
+            }
+            EventType::Keyboard => {}
+            EventType::MouseDown
+            | EventType::MouseAuto
+            | EventType::MouseWheelUp
+            | EventType::MouseWheelDown => self.handle_mouse(event),
+            _ => self.input.handle_event(event),
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.set_state_flag(crate::core::state::SF_FOCUSED, focused);
+        self.input.set_focus(focused);
+        if focused {
+            self.input.select_all();
+        } else if let Some(value) = self.value() {
+            // Show the value in its canonical form (`5` -> `5.00`)
+            if value >= self.min && value <= self.max {
+                self.input.set_text(format_fixed(value, self.decimals));
+            }
+        }
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        self.input.update_cursor(terminal);
+    }
+
+    // Matches Borland: TInputLine::valid() - the validator checks the text,
+    // except when the dialog is cancelled
+    fn valid(&mut self, command: CommandId) -> bool {
+        command == CM_CANCEL || self.validator.borrow().valid(&self.input.get_text())
+    }
+
+    fn error_message(&self) -> Option<String> {
+        Some(self.validator.borrow().error_message())
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(AccessibleInfo::new(Role::SpinButton, "").with_value(self.text()))
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        // The input line maps its colors through the owner
+        self.input.set_palette_chain(node.clone());
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_HISTORY))
+    }
+}
+
+/// Builder for creating spin inputs with a fluent API.
+///
+/// # Examples
+///
+/// ```ignore
+/// use turbo_vision::views::spin_input::SpinInputBuilder;
+/// use turbo_vision::core::geometry::Rect;
+/// use std::rc::Rc;
+/// use std::cell::RefCell;
+///
+/// let copies = Rc::new(RefCell::new("1".to_string()));
+/// let spin = SpinInputBuilder::new()
+///     .bounds(Rect::new(10, 5, 18, 6))
+///     .range(1, 99)
+///     .data(copies.clone())
+///     .build();
+/// ```
+pub struct SpinInputBuilder {
+    bounds: Option<Rect>,
+    min: i64,
+    max: i64,
+    step: i64,
+    decimals: u32,
+    data: Option<Rc<RefCell<String>>>,
+    validator: Option<ValidatorRef>,
+}
+
+impl SpinInputBuilder {
+    /// Creates a new `SpinInputBuilder` with default values.
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            min: 0,
+            max: 100,
+            step: 1,
+            decimals: 0,
+            data: None,
+            validator: None,
+        }
+    }
+
+    /// Sets the spin input bounds, one row high (required).
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the range (default: 0 to 100), in units of 10^-decimals.
+    #[must_use]
+    pub fn range(mut self, min: i64, max: i64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Sets the amount added per step (default: 1).
+    #[must_use]
+    pub fn step(mut self, step: i64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the number of fractional digits (default: 0).
+    #[must_use]
+    pub fn decimals(mut self, decimals: u32) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Binds the text (optional).
+    #[must_use]
+    pub fn data(mut self, data: Rc<RefCell<String>>) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Replaces the default `RangeValidator` (optional).
+    #[must_use]
+    pub fn validator(mut self, validator: ValidatorRef) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Builds the `SpinInput`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if bounds are not set.
+    pub fn build(self) -> SpinInput {
+        let bounds = self.bounds.expect("SpinInput bounds must be set");
+        let mut spin = SpinInput::with_decimals(
+            bounds,
+            self.min,
+            self.max,
+            self.decimals,
+            self.data.unwrap_or_default(),
+        );
+        spin.set_step(self.step);
+        if let Some(validator) = self.validator {
+            spin.set_validator(validator);
+        }
+        spin
+    }
+
+    /// Builds the `SpinInput` as a Box.
+    pub fn build_boxed(self) -> Box<SpinInput> {
+        Box::new(self.build())
+    }
+}
+
+impl Default for SpinInputBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::CM_OK;
+    use crate::core::geometry::Point;
+
+    fn spin(decimals: u32, min: i64, max: i64, text: &str) -> (SpinInput, Rc<RefCell<String>>) {
+        let data = Rc::new(RefCell::new(text.to_string()));
+        let mut spin = SpinInputBuilder::new()
+            .bounds(Rect::new(0, 0, 10, 1))
+            .range(min, max)
+            .decimals(decimals)
+            .data(Rc::clone(&data))
+            .build();
+        spin.set_focus(true);
+        (spin, data)
+    }
+
+    #[test]
+    fn arrows_and_wheel_step_within_range() {
+        let (mut spin, data) = spin(0, 1, 12, "11");
+        spin.handle_event(&mut Event::keyboard(KB_UP));
+        assert_eq!(*data.borrow(), "12");
+        spin.handle_event(&mut Event::keyboard(KB_UP));
+        assert_eq!(*data.borrow(), "12");
+        spin.handle_event(&mut Event::keyboard(KB_PGDN));
+        assert_eq!(*data.borrow(), "2");
+
+        let mut wheel = Event::mouse(EventType::MouseWheelDown, Point::new(3, 0), 0, false);
+        spin.handle_event(&mut wheel);
+        assert_eq!(*data.borrow(), "1");
+
+        // A click on the up arrow
+        let mut click = Event::mouse(
+            EventType::MouseDown,
+            Point::new(8, 0),
+            MB_LEFT_BUTTON,
+            false,
+        );
+        spin.handle_event(&mut click);
+        assert_eq!(spin.value(), Some(2));
+    }
+
+    #[test]
+    fn decimal_values_are_fixed_point() {
+        let (mut spin, data) = spin(2, -100, 100, "");
+        spin.handle_event(&mut Event::keyboard(KB_DOWN));
+        assert_eq!(*data.borrow(), "-0.01");
+
+        spin.set_value(5);
+        spin.set_step(50);
+        spin.spin(1);
+        assert_eq!(*data.borrow(), "0.55");
+        assert_eq!(spin.value_f64(), Some(0.55));
+    }
+
+    #[test]
+    fn out_of_range_text_is_invalid() {
+        let (mut spin, data) = spin(0, 1, 12, "");
+        spin.input.select_all();
+        for ch in "42".bytes() {
+            spin.handle_event(&mut Event::keyboard(u16::from(ch)));
+        }
+        assert_eq!(*data.borrow(), "42");
+        assert!(!spin.valid(CM_OK));
+        assert!(spin.valid(CM_CANCEL));
+
+        // Letters never get in
+        spin.handle_event(&mut Event::keyboard(u16::from(b'q')));
+        assert_eq!(*data.borrow(), "42");
+    }
+}
@@ -0,0 +1,423 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Splitter view - two panes separated by a mouse-draggable divider.
+//!
+//! A horizontal splitter puts its panes side by side with a vertical divider
+//! line between them; a vertical splitter stacks them with a horizontal line:
+//!
+//! ```text
+//! horizontal        vertical
+//! left │ right      top
+//!      │            ──────────
+//!      │            bottom
+//! ```
+//!
+//! Dragging the divider resizes both panes; when the splitter itself is
+//! resized, the divider keeps its relative position. Each pane is any view -
+//! typically an editor, a list or a [`Group`](super::group::Group) - so
+//! splitters nest to build IDE-style layouts:
+//!
+//! ```rust,no_run
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::editor::EditorWindow;
+//! use turbo_vision::views::splitter::Splitter;
+//! use turbo_vision::views::terminal_widget::TerminalWidget;
+//!
+//! let bounds = Rect::new(0, 0, 78, 20);
+//! let editor = EditorWindow::new(bounds);
+//! let output = TerminalWidget::new(bounds);
+//! let layout = Splitter::vertical(bounds, Box::new(editor), Box::new(output)).with_position(14);
+//! ```
+
+use super::view::{View, ViewId, write_line_to_terminal};
+use crate::core::command::{CM_RELEASED_FOCUS, CommandId};
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{Event, EventType, KB_SHIFT_TAB, KB_TAB};
+use crate::core::geometry::Rect;
+use crate::core::layout::{Axis, Extent, stack};
+use crate::core::palette::{FRAME_ACTIVE_BORDER, FRAME_ICON};
+use crate::core::state::{SF_DRAGGING, SF_FOCUSED, SF_RESIZING, StateFlags};
+use crate::terminal::Terminal;
+
+/// Splitter - two panes sharing an area, divided by a draggable line
+pub struct Splitter {
+    bounds: Rect,
+    axis: Axis,
+    panes: [Box<dyn View>; 2],
+    ids: [ViewId; 2],
+    position: i16, // Size of the first pane along the axis
+    min_size: i16,
+    focused: usize,
+    state: StateFlags,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl Splitter {
+    /// Create a splitter with `first` and `second` side by side, split in half
+    pub fn horizontal(bounds: Rect, first: Box<dyn View>, second: Box<dyn View>) -> Self {
+        Self::new(bounds, Axis::Horizontal, first, second)
+    }
+
+    /// Create a splitter with `first` above `second`, split in half
+    pub fn vertical(bounds: Rect, first: Box<dyn View>, second: Box<dyn View>) -> Self {
+        Self::new(bounds, Axis::Vertical, first, second)
+    }
+
+    /// Create a splitter; the panes' bounds are replaced by the splitter's layout
+    pub fn new(bounds: Rect, axis: Axis, first: Box<dyn View>, second: Box<dyn View>) -> Self {
+        let mut splitter = Self {
+            bounds,
+            axis,
+            panes: [first, second],
+            ids: [ViewId::new(), ViewId::new()],
+            position: 0,
+            min_size: 1,
+            focused: 0,
+            state: 0,
+            palette_chain: None,
+        };
+        splitter.focused = usize::from(!splitter.panes[0].can_focus());
+        splitter.set_position((splitter.span() - 1) / 2);
+        splitter
+    }
+
+    /// Set the initial size of the first pane
+    #[must_use]
+    pub fn with_position(mut self, position: i16) -> Self {
+        self.set_position(position);
+        self
+    }
+
+    /// Set the smallest size either pane can be dragged to (default 1)
+    #[must_use]
+    pub fn with_min_size(mut self, min_size: i16) -> Self {
+        self.min_size = min_size.max(0);
+        self.set_position(self.position);
+        self
+    }
+
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// Size of the first pane along the axis (columns or rows)
+    pub fn position(&self) -> i16 {
+        self.position
+    }
+
+    /// Move the divider, keeping both panes at least the minimum size
+    pub fn set_position(&mut self, position: i16) {
+        let max = self.span() - 1 - self.min_size;
+        self.position = position.min(max).max(self.min_size.min(max)).max(0);
+        self.layout();
+    }
+
+    /// Get a pane (0 = left/top, 1 = right/bottom)
+    pub fn pane(&self, index: usize) -> &dyn View {
+        &*self.panes[index]
+    }
+
+    pub fn pane_mut(&mut self, index: usize) -> &mut dyn View {
+        &mut *self.panes[index]
+    }
+
+    /// Index of the pane holding the focus
+    pub fn focused_pane(&self) -> usize {
+        self.focused
+    }
+
+    /// Move the focus to a pane, if it can take it
+    pub fn focus_pane(&mut self, index: usize) {
+        if index > 1 || !self.panes[index].can_focus() {
+            return;
+        }
+        let focused = self.is_focused();
+        self.panes[self.focused].set_focus(false);
+        self.focused = index;
+        self.panes[index].set_focus(focused);
+    }
+
+    /// Length of the splitter along its axis
+    fn span(&self) -> i16 {
+        match self.axis {
+            Axis::Horizontal => self.bounds.width_clamped(),
+            Axis::Vertical => self.bounds.height_clamped(),
+        }
+    }
+
+    /// Place both panes and the divider between them
+    fn layout(&mut self) {
+        let slots = stack(
+            self.bounds,
+            self.axis,
+            &[
+                Extent::Fixed(self.position),
+                Extent::Fixed(1),
+                Extent::Fill(1),
+            ],
+        );
+        self.panes[0].set_bounds(slots[0]);
+        self.panes[1].set_bounds(slots[2]);
+    }
+
+    /// The divider's row or column, relative to the splitter
+    fn divider_at(&self, x: i16, y: i16) -> bool {
+        match self.axis {
+            Axis::Horizontal => x == self.position,
+            Axis::Vertical => y == self.position,
+        }
+    }
+
+    fn handle_mouse(&mut self, event: &mut Event) {
+        let (x, y) = self.make_local(event.mouse.pos.x, event.mouse.pos.y);
+        if self.get_state_flag(SF_DRAGGING) {
+            match event.what {
+                EventType::MouseMove => {
+                    self.set_position(match self.axis {
+                        Axis::Horizontal => x,
+                        Axis::Vertical => y,
+                    });
+                }
+                EventType::MouseUp => self.set_state_flag(SF_DRAGGING, false),
+                _ => {}
+            }
+            event.clear();
+            return;
+        }
+
+        if event.what == EventType::MouseDown && self.divider_at(x, y) {
+            self.set_state_flag(SF_DRAGGING, true);
+            event.clear();
+            return;
+        }
+
+        // A pane that is dragging or resizing keeps the mouse, like in Group
+        let busy = self.panes[self.focused].state() & (SF_DRAGGING | SF_RESIZING) != 0;
+        let target = if busy {
+            Some(self.focused)
+        } else {
+            self.panes
+                .iter()
+                .position(|pane| pane.bounds().contains(event.mouse.pos))
+        };
+        if let Some(index) = target {
+            if event.what == EventType::MouseDown {
+                self.focus_pane(index);
+            }
+            self.panes[index].handle_event(event);
+        }
+    }
+}
+
+impl View for Splitter {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Resize the splitter; the divider keeps its relative position
+    fn set_bounds(&mut self, bounds: Rect) {
+        let old_span = self.span();
+        self.bounds = bounds;
+        let new_span = self.span();
+        let position = if old_span > 0 && old_span != new_span {
+            let scaled = (i32::from(self.position) * i32::from(new_span) + i32::from(old_span) / 2)
+                / i32::from(old_span);
+            i16::try_from(scaled).unwrap_or(self.position)
+        } else {
+            self.position
+        };
+        self.set_position(position);
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        for pane in &mut self.panes {
+            // Panes take their colors from the owner, as if inserted directly into it
+            pane.set_palette_chain(self.palette_chain.clone());
+            pane.draw(terminal);
+        }
+
+        let color = if self.get_state_flag(SF_DRAGGING) {
+            self.map_color(FRAME_ICON)
+        } else {
+            self.map_color(FRAME_ACTIVE_BORDER)
+        };
+        match self.axis {
+            Axis::Horizontal => {
+                let mut buf = DrawBuffer::new(1);
+                buf.move_char(0, '│', color, 1);
+                for y in self.bounds.a.y..self.bounds.b.y {
+                    write_line_to_terminal(terminal, self.bounds.a.x + self.position, y, &buf);
+                }
+            }
+            Axis::Vertical => {
+                let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+                let mut buf = DrawBuffer::new(width);
+                buf.move_char(0, '─', color, width);
+                write_line_to_terminal(
+                    terminal,
+                    self.bounds.a.x,
+                    self.bounds.a.y + self.position,
+                    &buf,
+                );
+            }
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::MouseDown | EventType::MouseMove | EventType::MouseUp => {
+                self.handle_mouse(event);
+            }
+            EventType::Broadcast => {
+                for pane in &mut self.panes {
+                    if event.what == EventType::Nothing {
+                        break;
+                    }
+                    pane.handle_event(event);
+                }
+            }
+            EventType::Keyboard => {
+                self.panes[self.focused].handle_event(event);
+                // Tab keys the pane did not use move between the panes
+                let other = 1 - self.focused;
+                let towards_other = match event.key_code {
+                    KB_TAB => other == 1,
+                    KB_SHIFT_TAB => other == 0,
+                    _ => false,
+                };
+                if event.what == EventType::Keyboard
+                    && towards_other
+                    && self.panes[other].can_focus()
+                {
+                    self.focus_pane(other);
+                    event.clear();
+                }
+            }
+            _ => self.panes[self.focused].handle_event(event),
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        self.panes.iter().any(|pane| pane.can_focus())
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.set_state_flag(SF_FOCUSED, focused);
+        self.panes[self.focused].set_focus(focused);
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        self.panes[self.focused].update_cursor(terminal);
+    }
+
+    fn valid(&mut self, command: CommandId) -> bool {
+        if command == CM_RELEASED_FOCUS {
+            return self.panes[self.focused].valid(command);
+        }
+        self.panes.iter_mut().all(|pane| pane.valid(command))
+    }
+
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        Some((self.ids[self.focused], &*self.panes[self.focused]))
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.ids
+            .iter()
+            .copied()
+            .zip(self.panes.iter().map(|pane| &**pane))
+            .collect()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        // Transparent like TGroup: the divider uses the owner's frame colors
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::MB_LEFT_BUTTON;
+    use crate::core::geometry::Point;
+    use crate::test_util::snapshot::Snapshot;
+    use crate::views::static_text::StaticText;
+
+    fn text(s: &str) -> Box<dyn View> {
+        Box::new(StaticText::new(Rect::new(0, 0, 1, 1), s))
+    }
+
+    fn mouse(what: EventType, x: i16, y: i16) -> Event {
+        Event::mouse(what, Point::new(x, y), MB_LEFT_BUTTON, false)
+    }
+
+    #[test]
+    fn lays_out_panes_around_divider() {
+        let mut splitter =
+            Splitter::horizontal(Rect::new(0, 0, 11, 2), text("Left"), text("Right"));
+        assert_eq!(splitter.position(), 5);
+        assert_eq!(splitter.pane(0).bounds(), Rect::new(0, 0, 5, 2));
+        assert_eq!(splitter.pane(1).bounds(), Rect::new(6, 0, 11, 2));
+
+        let snapshot = Snapshot::of_view(&mut splitter, 11, 2);
+        assert_eq!(snapshot.text_lines(), vec!["Left │Right", "     │     "]);
+    }
+
+    #[test]
+    fn dragging_divider_resizes_panes() {
+        let mut splitter = Splitter::vertical(Rect::new(0, 0, 10, 10), text("Top"), text("Bottom"));
+        assert_eq!(splitter.position(), 4);
+
+        let mut event = mouse(EventType::MouseDown, 3, 4);
+        splitter.handle_event(&mut event);
+        assert!(splitter.get_state_flag(SF_DRAGGING));
+
+        // Dragging past the end stops at the minimum pane size
+        let mut event = mouse(EventType::MouseMove, 3, 20);
+        splitter.handle_event(&mut event);
+        assert_eq!(splitter.position(), 8);
+        let mut event = mouse(EventType::MouseMove, 3, 2);
+        splitter.handle_event(&mut event);
+        let mut event = mouse(EventType::MouseUp, 3, 2);
+        splitter.handle_event(&mut event);
+
+        assert!(!splitter.get_state_flag(SF_DRAGGING));
+        assert_eq!(splitter.position(), 2);
+        assert_eq!(splitter.pane(0).bounds(), Rect::new(0, 0, 10, 2));
+        assert_eq!(splitter.pane(1).bounds(), Rect::new(0, 3, 10, 10));
+    }
+
+    #[test]
+    fn resizing_keeps_divider_proportional() {
+        let mut splitter =
+            Splitter::horizontal(Rect::new(0, 0, 20, 5), text("A"), text("B")).with_position(5);
+        splitter.set_bounds(Rect::new(10, 2, 50, 7));
+        assert_eq!(splitter.position(), 10);
+        assert_eq!(splitter.pane(0).bounds(), Rect::new(10, 2, 20, 7));
+        assert_eq!(splitter.pane(1).bounds(), Rect::new(21, 2, 50, 7));
+    }
+}
@@ -8,6 +8,7 @@ use crate::core::command_set;
 use crate::core::draw::DrawBuffer;
 use crate::core::event::{Event, EventType, KeyCode, MB_LEFT_BUTTON};
 use crate::core::geometry::Rect;
+use crate::core::i18n::tr;
 use crate::core::palette::{
     STATUSLINE_DISABLED, STATUSLINE_NORMAL, STATUSLINE_SELECTED, STATUSLINE_SELECTED_SHORTCUT,
     STATUSLINE_SHORTCUT,
@@ -80,7 +81,10 @@ impl StatusLine {
 
         let mut x = 0; // Start at position 0 (Borland starts at i=0)
         for (idx, item) in self.items.iter().enumerate() {
-            if x + item.text.len() + 4 < width {
+            // Texts are looked up in the message catalog at draw time, so a
+            // locale change shows up on the next redraw.
+            let text = tr(&item.text);
+            if x + text.len() + 4 < width {
                 // Need space for: space + text + space + separator
                 // Hit area starts at the leading space (matches Borland tstatusl.cc:204)
                 let start_x = x as i16;
@@ -111,7 +115,7 @@ impl StatusLine {
                 x += 1;
 
                 // Parse ~X~ for highlighting - everything between tildes is highlighted
-                let mut chars = item.text.chars();
+                let mut chars = text.chars();
                 while let Some(ch) = chars.next() {
                     if ch == '~' {
                         // Read all characters until closing ~ in highlight color
@@ -146,7 +150,7 @@ impl StatusLine {
         // as fit and ellipsises the tail on narrow terminals. No
         // leading separator — the hint starts where the items end so
         // the whole status row reads like one continuous line.
-        if let Some(ref hint) = self.hint_text {
+        if let Some(hint) = self.hint_text.as_deref().map(tr) {
             if x + 1 < width {
                 let avail = width - x;
                 let chars: Vec<char> = hint.chars().collect();
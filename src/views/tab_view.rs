@@ -0,0 +1,727 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Tab view - notebook container showing one of several pages behind a tab strip.
+//!
+//! Each page is a [`Group`]; only the current page is drawn and receives
+//! events, and every page remembers its focused control while hidden. The tab
+//! strip takes the top two rows:
+//!
+//! ```text
+//! │ General │ Advanced │
+//! ┘         └─────────────
+//! ```
+//!
+//! Pages are switched with Ctrl+Tab / Ctrl+Shift+Tab, Ctrl+PgDn / Ctrl+PgUp,
+//! the Alt+letter hotkey marked with `~x~` in a title, or a click on a tab.
+//! Tab and Shift+Tab move through the controls of the current page and leave
+//! the tab view after its last (or before its first) control.
+//!
+//! `valid()` checks every page, not only the visible one, so a dialog refuses
+//! OK while any page holds invalid data; the first invalid page is brought
+//! to the front.
+
+use super::group::Group;
+use super::view::{View, ViewId, contains_view, write_line_to_terminal};
+use crate::core::command::{CM_RELEASED_FOCUS, CommandId};
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{
+    Event, EventType, KB_PGDN, KB_PGUP, KB_SHIFT_TAB, KB_TAB, KeyCode, char_to_alt_code,
+};
+use crate::core::geometry::Rect;
+use crate::core::palette::{TAB_VIEW_FRAME, TAB_VIEW_NORMAL, TAB_VIEW_SELECTED, TAB_VIEW_SHORTCUT};
+use crate::core::state::{OF_POST_PROCESS, SF_FOCUSED, StateFlags};
+use crate::terminal::Terminal;
+use crossterm::event::KeyModifiers;
+use unicode_width::UnicodeWidthStr;
+
+/// Rows taken by the tab strip above the pages
+const STRIP_HEIGHT: i16 = 2;
+
+struct Page {
+    id: ViewId,
+    title: String,
+    group: Group,
+}
+
+impl Page {
+    /// Columns taken by this page's tab, including its left separator
+    fn tab_width(&self) -> usize {
+        self.title.replace('~', "").width() + 3
+    }
+
+    /// Alt+letter key code of the `~x~` hotkey in the title
+    fn hotkey(&self) -> Option<KeyCode> {
+        let (_, rest) = self.title.split_once('~')?;
+        char_to_alt_code(rest.chars().next()?.to_ascii_lowercase())
+    }
+}
+
+/// Tab view - a stack of [`Group`] pages selected through a tab strip
+pub struct TabView {
+    bounds: Rect,
+    pages: Vec<Page>,
+    current: usize,
+    state: StateFlags,
+    options: u16,
+    palette_chain: Option<crate::core::palette_chain::PaletteChainNode>,
+}
+
+impl TabView {
+    pub fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            pages: Vec::new(),
+            current: 0,
+            state: 0,
+            options: OF_POST_PROCESS, // Tab hotkeys work while another control is focused
+            palette_chain: None,
+        }
+    }
+
+    /// Area below the tab strip where pages are shown
+    fn page_area(&self) -> Rect {
+        let top = (self.bounds.a.y + STRIP_HEIGHT).min(self.bounds.b.y);
+        Rect::new(self.bounds.a.x, top, self.bounds.b.x, self.bounds.b.y)
+    }
+
+    /// Create an empty page group covering the whole page area,
+    /// ready to be filled and passed to [`add_page()`](Self::add_page)
+    pub fn new_page(&self) -> Group {
+        let area = self.page_area();
+        Group::new(Rect::new(0, 0, area.width(), area.height()))
+    }
+
+    /// Add a page and return its index
+    ///
+    /// The page's bounds are relative to the area below the tab strip, like
+    /// a child's bounds in [`Group::add()`]. `title` may mark a hotkey with `~x~`.
+    pub fn add_page(&mut self, title: &str, mut page: Group) -> usize {
+        let area = self.page_area();
+        let b = page.bounds();
+        page.set_bounds(Rect::new(
+            area.a.x + b.a.x,
+            area.a.y + b.a.y,
+            area.a.x + b.b.x,
+            area.a.y + b.b.y,
+        ));
+        self.pages.push(Page {
+            id: ViewId::new(),
+            title: title.to_string(),
+            group: page,
+        });
+        if self.pages.len() == 1 && self.is_focused() {
+            self.focus_page();
+        }
+        self.pages.len() - 1
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    pub fn page(&self, index: usize) -> &Group {
+        &self.pages[index].group
+    }
+
+    pub fn page_mut(&mut self, index: usize) -> &mut Group {
+        &mut self.pages[index].group
+    }
+
+    pub fn page_title(&self, index: usize) -> &str {
+        &self.pages[index].title
+    }
+
+    /// Index of the page currently shown
+    pub fn current_page(&self) -> usize {
+        self.current
+    }
+
+    /// Show another page; its previously focused control gets the focus back
+    pub fn set_current_page(&mut self, index: usize) {
+        if index >= self.pages.len() || index == self.current {
+            return;
+        }
+        self.pages[self.current].group.clear_all_focus();
+        self.current = index;
+        if self.is_focused() {
+            self.focus_page();
+        }
+    }
+
+    /// Show the next page, wrapping around after the last one
+    pub fn next_page(&mut self) {
+        if !self.pages.is_empty() {
+            self.set_current_page((self.current + 1) % self.pages.len());
+        }
+    }
+
+    /// Show the previous page, wrapping around before the first one
+    pub fn prev_page(&mut self) {
+        if !self.pages.is_empty() {
+            self.set_current_page((self.current + self.pages.len() - 1) % self.pages.len());
+        }
+    }
+
+    /// Restore the focus inside the current page, or focus its first control
+    fn focus_page(&mut self) {
+        let Some(page) = self.pages.get_mut(self.current) else {
+            return;
+        };
+        let remembered = page
+            .group
+            .current()
+            .filter(|(_, child)| child.can_focus())
+            .map(|(id, _)| id);
+        if !remembered.is_some_and(|id| page.group.focus_by_view_id(id)) {
+            page.group.set_initial_focus();
+        }
+    }
+
+    /// Visible tabs as (page index, start column, column of the closing separator),
+    /// scrolled so the current tab fits
+    fn tab_spans(&self) -> Vec<(usize, usize, usize)> {
+        let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+        let fits = |first: usize| {
+            let used: usize = self.pages[first..=self.current]
+                .iter()
+                .map(Page::tab_width)
+                .sum();
+            used < width
+        };
+        let first = (0..self.current)
+            .find(|&first| fits(first))
+            .unwrap_or(self.current);
+
+        let mut spans = Vec::new();
+        let mut x = 0;
+        for (index, page) in self.pages.iter().enumerate().skip(first) {
+            if x >= width {
+                break;
+            }
+            let end = x + page.tab_width();
+            spans.push((index, x, end));
+            x = end;
+        }
+        spans
+    }
+
+    fn tab_at(&self, x: i16) -> Option<usize> {
+        let x = usize::try_from(x).ok()?;
+        self.tab_spans()
+            .into_iter()
+            .find(|&(_, start, end)| x >= start && x < end)
+            .map(|(index, _, _)| index)
+    }
+
+    fn page_by_hotkey(&self, key_code: KeyCode) -> Option<usize> {
+        self.pages
+            .iter()
+            .position(|page| page.hotkey() == Some(key_code))
+    }
+
+    /// Page switching keys: Ctrl+Tab, Ctrl+Shift+Tab, Ctrl+PgDn and Ctrl+PgUp
+    fn handle_switch_key(&mut self, event: &mut Event) -> bool {
+        if !event.key_modifiers.contains(KeyModifiers::CONTROL) {
+            return false;
+        }
+        match event.key_code {
+            KB_TAB | KB_PGDN => self.next_page(),
+            KB_SHIFT_TAB | KB_PGUP => self.prev_page(),
+            _ => return false,
+        }
+        event.clear();
+        true
+    }
+
+    fn handle_key(&mut self, event: &mut Event) {
+        if self.is_focused() && !self.pages.is_empty() {
+            if self.handle_switch_key(event) {
+                return;
+            }
+            // Tab past the last control (or Shift+Tab before the first) is left
+            // to the owner, which moves the focus out of the tab view
+            let leaving = match event.key_code {
+                KB_TAB => self.pages[self.current].group.focus_wraps(true),
+                KB_SHIFT_TAB => self.pages[self.current].group.focus_wraps(false),
+                _ => false,
+            };
+            if leaving {
+                return;
+            }
+            self.pages[self.current].group.handle_event(event);
+        }
+        if event.what == EventType::Keyboard {
+            if let Some(index) = self.page_by_hotkey(event.key_code) {
+                self.set_current_page(index);
+                event.clear();
+            }
+        }
+    }
+
+    fn handle_mouse(&mut self, event: &mut Event) {
+        let (x, y) = self.make_local(event.mouse.pos.x, event.mouse.pos.y);
+        if event.what == EventType::MouseDown && y < STRIP_HEIGHT {
+            if let Some(index) = self.tab_at(x).filter(|_| y == 0) {
+                self.set_current_page(index);
+            }
+            event.clear();
+            return;
+        }
+        if let Some(page) = self.pages.get_mut(self.current) {
+            page.group.handle_event(event);
+        }
+    }
+}
+
+impl View for TabView {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        let area = self.page_area();
+        for page in &mut self.pages {
+            page.group.set_bounds(area);
+        }
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let width = usize::from(self.bounds.width_clamped().cast_unsigned());
+        let frame = self.map_color(TAB_VIEW_FRAME);
+        let normal = self.map_color(TAB_VIEW_NORMAL);
+        let selected = self.map_color(TAB_VIEW_SELECTED);
+        let shortcut = self.map_color(TAB_VIEW_SHORTCUT);
+
+        let mut strip = DrawBuffer::new(width);
+        let mut line = DrawBuffer::new(width);
+        strip.move_char(0, ' ', frame, width);
+        line.move_char(0, '─', frame, width);
+        for (index, start, end) in self.tab_spans() {
+            let attr = if index == self.current {
+                selected
+            } else {
+                normal
+            };
+            strip.move_char(start, '│', frame, 1);
+            strip.move_char(start + 1, ' ', attr, end - start - 1);
+            strip.move_str_with_shortcut(start + 2, &self.pages[index].title, attr, shortcut);
+            strip.move_char(end, '│', frame, 1);
+            if index == self.current {
+                // The current tab opens into its page
+                line.move_char(start, '┘', frame, 1);
+                line.move_char(start + 1, ' ', frame, end - start - 1);
+                line.move_char(end, '└', frame, 1);
+            }
+        }
+
+        let mut blank = DrawBuffer::new(width);
+        blank.move_char(0, ' ', frame, width);
+        for (row, y) in (self.bounds.a.y..self.bounds.b.y).enumerate() {
+            let buf = match row {
+                0 => &strip,
+                1 => &line,
+                _ => &blank,
+            };
+            write_line_to_terminal(terminal, self.bounds.a.x, y, buf);
+        }
+
+        if let Some(page) = self.pages.get_mut(self.current) {
+            // Pages take their colors from the owner, as if inserted directly into it
+            page.group.set_palette_chain(self.palette_chain.clone());
+            page.group.draw(terminal);
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::Keyboard => self.handle_key(event),
+            EventType::MouseDown | EventType::MouseMove | EventType::MouseUp => {
+                self.handle_mouse(event);
+            }
+            EventType::Command => {
+                if let Some(page) = self.pages.get_mut(self.current) {
+                    page.group.handle_event(event);
+                }
+            }
+            EventType::Broadcast => {
+                for page in &mut self.pages {
+                    if event.what == EventType::Nothing {
+                        break;
+                    }
+                    page.group.handle_event(event);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        !self.pages.is_empty()
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.set_state_flag(SF_FOCUSED, focused);
+        if focused {
+            self.focus_page();
+        } else if let Some(page) = self.pages.get_mut(self.current) {
+            page.group.clear_all_focus();
+        }
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn options(&self) -> u16 {
+        self.options
+    }
+
+    fn set_options(&mut self, options: u16) {
+        self.options = options;
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        if self.is_focused() {
+            if let Some(page) = self.pages.get(self.current) {
+                page.group.update_cursor(terminal);
+            }
+        }
+    }
+
+    /// Validate every page; an invalid hidden page is brought to the front
+    fn valid(&mut self, command: CommandId) -> bool {
+        if command == CM_RELEASED_FOCUS {
+            return self
+                .pages
+                .get_mut(self.current)
+                .is_none_or(|page| page.group.valid(command));
+        }
+        match self
+            .pages
+            .iter_mut()
+            .position(|page| !page.group.valid(command))
+        {
+            Some(index) => {
+                self.set_current_page(index);
+                false
+            }
+            None => true,
+        }
+    }
+
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.pages
+            .get(self.current)
+            .map(|page| (page.id, &page.group as &dyn View))
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.pages
+            .iter()
+            .map(|page| (page.id, &page.group as &dyn View))
+            .collect()
+    }
+
+    fn subviews_mut(&mut self) -> Vec<(ViewId, &mut dyn View)> {
+        self.pages
+            .iter_mut()
+            .map(|page| (page.id, &mut page.group as &mut dyn View))
+            .collect()
+    }
+
+    fn focus_subview(&mut self, id: ViewId) -> bool {
+        let Some(index) = self
+            .pages
+            .iter()
+            .position(|page| contains_view(&page.group, id))
+        else {
+            return false;
+        };
+        self.set_current_page(index);
+        self.pages[index].group.focus_subview(id)
+    }
+
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        let page = self.pages.get(self.current)?;
+        Some(
+            AccessibleInfo::new(Role::TabList, &page.title).with_value(format!(
+                "{} of {}",
+                self.current + 1,
+                self.pages.len()
+            )),
+        )
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn set_palette_chain(&mut self, node: Option<crate::core::palette_chain::PaletteChainNode>) {
+        self.palette_chain = node;
+    }
+
+    fn get_palette_chain(&self) -> Option<&crate::core::palette_chain::PaletteChainNode> {
+        self.palette_chain.as_ref()
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_TAB_VIEW))
+    }
+}
+
+/// Builder for creating tab views with a fluent API.
+pub struct TabViewBuilder {
+    bounds: Option<Rect>,
+    pages: Vec<(String, Group)>,
+}
+
+impl TabViewBuilder {
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            pages: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Add a page; see [`TabView::add_page()`]
+    #[must_use]
+    pub fn page(mut self, title: impl Into<String>, page: Group) -> Self {
+        self.pages.push((title.into(), page));
+        self
+    }
+
+    pub fn build(self) -> TabView {
+        let bounds = self.bounds.expect("TabView bounds must be set");
+        let mut tab_view = TabView::new(bounds);
+        for (title, page) in self.pages {
+            tab_view.add_page(&title, page);
+        }
+        tab_view
+    }
+
+    pub fn build_boxed(self) -> Box<TabView> {
+        Box::new(self.build())
+    }
+}
+
+impl Default for TabViewBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::CM_OK;
+    use crate::core::event::{KB_ALT_A, MB_LEFT_BUTTON};
+    use crate::core::geometry::Point;
+    use crate::test_util::snapshot::Snapshot;
+    use crate::views::static_text::StaticText;
+
+    /// Focusable control whose validity is set by the test
+    struct Field {
+        bounds: Rect,
+        state: StateFlags,
+        ok: bool,
+    }
+
+    impl Field {
+        fn boxed(x: i16, ok: bool) -> Box<Self> {
+            Box::new(Self {
+                bounds: Rect::new(x, 0, x + 4, 1),
+                state: 0,
+                ok,
+            })
+        }
+    }
+
+    impl View for Field {
+        fn bounds(&self) -> Rect {
+            self.bounds
+        }
+
+        fn set_bounds(&mut self, bounds: Rect) {
+            self.bounds = bounds;
+        }
+
+        fn draw(&mut self, _terminal: &mut Terminal) {}
+
+        fn handle_event(&mut self, _event: &mut Event) {}
+
+        fn can_focus(&self) -> bool {
+            true
+        }
+
+        fn state(&self) -> StateFlags {
+            self.state
+        }
+
+        fn set_state(&mut self, state: StateFlags) {
+            self.state = state;
+        }
+
+        fn valid(&mut self, _command: CommandId) -> bool {
+            self.ok
+        }
+
+        fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+            None
+        }
+    }
+
+    /// Two pages with two fields each; returns the tab view and the second field's id per page
+    fn two_pages(second_page_ok: bool) -> (TabView, ViewId, ViewId) {
+        let mut tabs = TabView::new(Rect::new(0, 0, 24, 5));
+        let mut general = tabs.new_page();
+        general.add(Field::boxed(0, true));
+        let general_last = general.add(Field::boxed(6, true));
+        let mut advanced = tabs.new_page();
+        advanced.add(Field::boxed(0, true));
+        let advanced_last = advanced.add(Field::boxed(6, second_page_ok));
+        tabs.add_page("~G~eneral", general);
+        tabs.add_page("~A~dvanced", advanced);
+        tabs.set_focus(true);
+        (tabs, general_last, advanced_last)
+    }
+
+    fn focused_id(tabs: &TabView, page: usize) -> Option<ViewId> {
+        tabs.page(page)
+            .current()
+            .filter(|(_, child)| child.is_focused())
+            .map(|(id, _)| id)
+    }
+
+    fn ctrl(key_code: KeyCode) -> Event {
+        Event {
+            key_modifiers: KeyModifiers::CONTROL,
+            ..Event::keyboard(key_code)
+        }
+    }
+
+    #[test]
+    fn draws_tab_strip_and_current_page() {
+        let mut tabs = TabViewBuilder::new()
+            .bounds(Rect::new(0, 0, 24, 4))
+            .page("~O~ne", Group::new(Rect::new(0, 0, 24, 2)))
+            .page("~T~wo", Group::new(Rect::new(0, 0, 24, 2)))
+            .build();
+        tabs.page_mut(1)
+            .add(Box::new(StaticText::new(Rect::new(1, 0, 10, 1), "Second")));
+
+        let snapshot = Snapshot::of_view(&mut tabs, 24, 4);
+        assert_eq!(
+            snapshot.text_lines(),
+            vec![
+                "│ One │ Two │           ",
+                "┘     └─────────────────",
+                "                        ",
+                "                        ",
+            ]
+        );
+
+        tabs.next_page();
+        let snapshot = Snapshot::of_view(&mut tabs, 24, 4);
+        assert_eq!(
+            snapshot.text_lines(),
+            vec![
+                "│ One │ Two │           ",
+                "──────┘     └───────────",
+                " Second                 ",
+                "                        ",
+            ]
+        );
+    }
+
+    #[test]
+    fn switching_pages_keeps_focus_per_page() {
+        let (mut tabs, general_last, advanced_last) = two_pages(true);
+
+        let mut event = Event::keyboard(KB_TAB);
+        tabs.handle_event(&mut event);
+        assert_eq!(focused_id(&tabs, 0), Some(general_last));
+
+        let mut event = ctrl(KB_PGDN);
+        tabs.handle_event(&mut event);
+        assert_eq!(tabs.current_page(), 1);
+        assert_eq!(focused_id(&tabs, 0), None);
+        let mut event = Event::keyboard(KB_TAB);
+        tabs.handle_event(&mut event);
+        assert_eq!(focused_id(&tabs, 1), Some(advanced_last));
+
+        let mut event = ctrl(KB_TAB);
+        tabs.handle_event(&mut event);
+        assert_eq!(tabs.current_page(), 0);
+        assert_eq!(focused_id(&tabs, 0), Some(general_last));
+
+        let mut event = Event::keyboard(KB_ALT_A);
+        tabs.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(focused_id(&tabs, 1), Some(advanced_last));
+    }
+
+    #[test]
+    fn tab_leaves_after_last_control() {
+        let (mut tabs, _, _) = two_pages(true);
+        let mut event = Event::keyboard(KB_TAB);
+        tabs.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+
+        let mut event = Event::keyboard(KB_TAB);
+        tabs.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Keyboard);
+        assert_eq!(event.key_code, KB_TAB);
+    }
+
+    #[test]
+    fn clicking_a_tab_selects_its_page() {
+        let (mut tabs, _, _) = two_pages(true);
+        // "│ General │ Advanced │": the second tab spans columns 10..21
+        let mut event = Event::mouse(
+            EventType::MouseDown,
+            Point::new(14, 0),
+            MB_LEFT_BUTTON,
+            false,
+        );
+        tabs.handle_event(&mut event);
+        assert_eq!(tabs.current_page(), 1);
+        assert_eq!(event.what, EventType::Nothing);
+
+        let mut event = Event::mouse(
+            EventType::MouseDown,
+            Point::new(3, 0),
+            MB_LEFT_BUTTON,
+            false,
+        );
+        tabs.handle_event(&mut event);
+        assert_eq!(tabs.current_page(), 0);
+    }
+
+    #[test]
+    fn valid_checks_hidden_pages() {
+        let (mut tabs, _, _) = two_pages(false);
+        assert!(!tabs.valid(CM_OK));
+        assert_eq!(tabs.current_page(), 1);
+
+        let (mut tabs, _, _) = two_pages(true);
+        assert!(tabs.valid(CM_OK));
+        assert_eq!(tabs.current_page(), 0);
+    }
+}
@@ -18,6 +18,7 @@
 //! - Optional ANSI color code support
 //! - Read-only (unlike EditorWindow)
 
+use super::desktop_state::ViewState;
 use super::scrollbar::ScrollBar;
 use super::view::{View, write_line_to_terminal};
 use crate::core::draw::DrawBuffer;
@@ -426,6 +427,47 @@ impl View for TerminalWidget {
         self
     }
 
+    /// Saves the scrollback; colored lines keep their attribute as a hex prefix
+    fn save_state(&self) -> Option<ViewState> {
+        let mut state = ViewState::new("terminal", self.bounds);
+        for line in &self.lines {
+            match line.attr {
+                Some(attr) => state.push("colored", format!("{:02X} {}", attr.to_u8(), line.text)),
+                None => state.push("line", line.text.clone()),
+            }
+        }
+        state.set("top_line", self.top_line.to_string());
+        state.set("auto_scroll", self.auto_scroll.to_string());
+        Some(state)
+    }
+
+    fn restore_state(&mut self, state: &ViewState) {
+        self.lines.clear();
+        for (key, value) in state.values() {
+            match key {
+                "line" => self.lines.push(OutputLine::new(value.to_string())),
+                "colored" => {
+                    let (attr, text) = value.split_once(' ').unwrap_or((value, ""));
+                    let line = match u8::from_str_radix(attr, 16) {
+                        Ok(attr) => OutputLine::with_attr(text.to_string(), Attr::from_u8(attr)),
+                        Err(_) => OutputLine::new(text.to_string()),
+                    };
+                    self.lines.push(line);
+                }
+                _ => {}
+            }
+        }
+        self.trim_buffer();
+        self.auto_scroll = state.get_parsed("auto_scroll").unwrap_or(true);
+        if self.auto_scroll {
+            self.scroll_to_bottom();
+        } else {
+            let last = self.lines.len().saturating_sub(1);
+            self.top_line = state.get_parsed("top_line").unwrap_or(0).min(last);
+        }
+        self.update_scrollbar();
+    }
+
     fn get_palette(&self) -> Option<crate::core::palette::Palette> {
         use crate::core::palette::{Palette, palettes};
         Some(Palette::from_slice(palettes::CP_SCROLLER))
@@ -1,6 +1,8 @@
 // (C) 2025 - Enzo Lombardi
 
 //! Validator - input validation system for InputLine controls.
+use super::dialog_data::FieldValue;
+use crate::core::i18n::tr;
 use std::cell::RefCell;
 /// Validator module - Input validation for InputLine
 /// Matches Borland's TValidator architecture from validate.h and tvalidat.cc
@@ -51,11 +53,31 @@ pub trait Validator {
     /// Matches Borland's TValidator::Error() - pure virtual in base class
     fn error(&self);
 
+    /// Text explaining why the input is not valid, shown inline by a form
+    /// Matches Borland: the message `Error()` puts in its message box
+    fn error_message(&self) -> String {
+        tr("Invalid value")
+    }
+
     /// Get validator options
     fn options(&self) -> u16 {
         0
     }
 
+    /// Convert the input line's text to a typed value
+    /// Matches Borland's `TValidator::Transfer()` with `vtGetData`
+    /// Only used when `options()` has `VO_TRANSFER`; `None` keeps the text
+    fn transfer_get(&self, _input: &str) -> Option<FieldValue> {
+        None
+    }
+
+    /// Convert a typed value to the text shown in the input line
+    /// Matches Borland's `TValidator::Transfer()` with `vtSetData`
+    /// Only used when `options()` has `VO_TRANSFER`; `None` if not convertible
+    fn transfer_set(&self, _value: &FieldValue) -> Option<String> {
+        None
+    }
+
     /// Validate and show error if invalid
     /// Matches Borland's TValidator::Valid() (tvalidat.cc:43-48)
     fn valid(&self, input: &str) -> bool {
@@ -116,6 +138,10 @@ impl Validator for FilterValidator {
         // Matches Borland's TFilterValidator::Error() (tfilterv.cc:59-62)
     }
 
+    fn error_message(&self) -> String {
+        tr("Invalid character in input")
+    }
+
     fn options(&self) -> u16 {
         self.options
     }
@@ -131,10 +157,18 @@ impl Validator for FilterValidator {
 /// assert!(validator.is_valid("50"));
 /// assert!(!validator.is_valid("150"));
 /// assert!(!validator.is_valid("abc"));
+///
+/// // Two decimal places: bounds are in hundredths
+/// let validator = RangeValidator::with_decimals(0, 1000, 2); // 0.00 to 10.00
+/// assert!(validator.is_valid("9.75"));
+/// assert!(!validator.is_valid("10.5"));
+/// assert!(!validator.is_valid("1.234"));
 /// ```
 pub struct RangeValidator {
     min: i64,
     max: i64,
+    /// Digits after the decimal point (0 for plain integers)
+    decimals: u32,
     valid_chars: String,
     options: u16,
 }
@@ -159,6 +193,7 @@ impl RangeValidator {
         Self {
             min,
             max,
+            decimals: 0,
             valid_chars,
             options: 0,
         }
@@ -170,6 +205,31 @@ impl RangeValidator {
         validator
     }
 
+    /// Set the `VO_*` option flags
+    pub fn set_options(&mut self, options: u16) {
+        self.options = options;
+    }
+
+    /// Create a `RangeValidator` for decimal numbers with up to `decimals`
+    /// fractional digits; `min` and `max` are fixed-point values in units of
+    /// 10^-`decimals` (Rust-specific)
+    pub fn with_decimals(min: i64, max: i64, decimals: u32) -> Self {
+        let mut validator = Self::new(min, max);
+        validator.decimals = decimals;
+        if decimals > 0 {
+            validator.valid_chars = if min < 0 {
+                "-+0123456789.".to_string()
+            } else {
+                "+0123456789.".to_string()
+            };
+        }
+        validator
+    }
+
+    pub fn decimals(&self) -> u32 {
+        self.decimals
+    }
+
     /// Parse input string to i64, supporting hex (0x) and octal (0) prefixes
     /// Matches Borland's get_val() and get_uval() functions (trangeva.cc:59-69)
     fn parse_value(&self, input: &str) -> Result<i64, std::num::ParseIntError> {
@@ -202,6 +262,11 @@ impl Validator for RangeValidator {
             return false;
         }
 
+        if self.decimals > 0 {
+            return parse_fixed(input, self.decimals)
+                .is_some_and(|value| value >= self.min && value <= self.max);
+        }
+
         // Try to parse the value
         match self.parse_value(input) {
             Ok(value) => {
@@ -217,6 +282,16 @@ impl Validator for RangeValidator {
         // Smart validation: only allow hex letters (a-f, A-F) if user has typed "0x" prefix
         // This prevents confusing UX where users can type letters for decimal ranges like 1-12
 
+        if self.decimals > 0 {
+            // One decimal point, no more fractional digits than allowed
+            let places = usize::try_from(self.decimals).unwrap_or(usize::MAX);
+            return input.chars().all(|ch| self.valid_chars.contains(ch))
+                && input.matches('.').count() <= 1
+                && input
+                    .split_once('.')
+                    .is_none_or(|(_, fraction)| fraction.len() <= places);
+        }
+
         let is_hex_input = input.starts_with("0x")
             || input.starts_with("0X")
             || input.starts_with("+0x")
@@ -255,9 +330,71 @@ impl Validator for RangeValidator {
         // The message would be: "Value not in the range {min} to {max}"
     }
 
+    /// Matches Borland's `TRangeValidator::Error()` message (trangeva.cc:48-57)
+    fn error_message(&self) -> String {
+        format!(
+            "{} {} - {}",
+            tr("Value not in the range"),
+            format_fixed(self.min, self.decimals),
+            format_fixed(self.max, self.decimals)
+        )
+    }
+
     fn options(&self) -> u16 {
         self.options
     }
+
+    /// Matches Borland's `TRangeValidator::Transfer()` (trangeva.cc:93-115)
+    fn transfer_get(&self, input: &str) -> Option<FieldValue> {
+        let value = if self.decimals > 0 {
+            parse_fixed(input, self.decimals)
+        } else {
+            self.parse_value(input).ok()
+        };
+        value.map(FieldValue::Int)
+    }
+
+    fn transfer_set(&self, value: &FieldValue) -> Option<String> {
+        match value {
+            FieldValue::Int(value) => Some(format_fixed(*value, self.decimals)),
+            _ => None,
+        }
+    }
+}
+
+/// Parse `12.5` into fixed-point units of 10^-`decimals` (1250 for two
+/// decimals); `None` for malformed input or too many fractional digits
+pub(crate) fn parse_fixed(input: &str, decimals: u32) -> Option<i64> {
+    let trimmed = input.trim();
+    let (negative, digits) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let places = usize::try_from(decimals).unwrap_or(usize::MAX);
+    if (whole.is_empty() && fraction.is_empty())
+        || fraction.len() > places
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|ch| ch.is_ascii_digit())
+    {
+        return None;
+    }
+    let value = format!("{whole}{fraction:0<places$}").parse::<i64>().ok()?;
+    Some(if negative { -value } else { value })
+}
+
+/// Format fixed-point `value` with `decimals` fractional digits (1250 -> `12.50`)
+pub(crate) fn format_fixed(value: i64, decimals: u32) -> String {
+    if decimals == 0 {
+        return value.to_string();
+    }
+    let scale = 10_u64.pow(decimals);
+    let magnitude = value.unsigned_abs();
+    let sign = if value < 0 { "-" } else { "" };
+    let places = usize::try_from(decimals).unwrap_or(0);
+    format!("{sign}{}.{:0places$}", magnitude / scale, magnitude % scale)
 }
 
 /// Type alias for shared validator references
@@ -302,11 +439,18 @@ impl Default for FilterValidatorBuilder {
 pub struct RangeValidatorBuilder {
     min: i64,
     max: i64,
+    decimals: u32,
+    options: u16,
 }
 
 impl RangeValidatorBuilder {
     pub fn new() -> Self {
-        Self { min: 0, max: 100 }
+        Self {
+            min: 0,
+            max: 100,
+            decimals: 0,
+            options: 0,
+        }
     }
 
     #[must_use]
@@ -328,8 +472,24 @@ impl RangeValidatorBuilder {
         self
     }
 
+    /// Fractional digits; the range is then in units of 10^-`decimals`
+    #[must_use]
+    pub fn decimals(mut self, decimals: u32) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// `VO_*` option flags, such as `VO_TRANSFER`
+    #[must_use]
+    pub fn options(mut self, options: u16) -> Self {
+        self.options = options;
+        self
+    }
+
     pub fn build(self) -> RangeValidator {
-        RangeValidator::new(self.min, self.max)
+        let mut validator = RangeValidator::with_decimals(self.min, self.max, self.decimals);
+        validator.options = self.options;
+        validator
     }
 
     pub fn build_ref(self) -> ValidatorRef {
@@ -471,4 +631,24 @@ mod tests {
         assert!(!validator.is_valid_input("+a", false));
         assert!(!validator.is_valid_input("-f", false));
     }
+
+    #[test]
+    fn test_range_validator_decimals() {
+        let validator = RangeValidator::with_decimals(-150, 1000, 2);
+        assert!(validator.is_valid("10"));
+        assert!(validator.is_valid("-1.5"));
+        assert!(validator.is_valid(".25"));
+        assert!(!validator.is_valid("-1.51"));
+        assert!(!validator.is_valid("0.125"));
+        assert!(!validator.is_valid("1.2.3"));
+        assert!(!validator.is_valid("0x10"));
+
+        assert!(validator.is_valid_input("3.", false));
+        assert!(!validator.is_valid_input("3.141", false));
+        assert!(!validator.is_valid_input("3..", false));
+
+        assert_eq!(format_fixed(1250, 2), "12.50");
+        assert_eq!(format_fixed(-5, 2), "-0.05");
+        assert_eq!(parse_fixed("-0.05", 2), Some(-5));
+    }
 }
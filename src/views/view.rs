@@ -5,7 +5,8 @@
 use crate::core::command::CommandId;
 use crate::core::draw::DrawBuffer;
 use crate::core::event::Event;
-use crate::core::geometry::Rect;
+use crate::core::geometry::{Point, Rect};
+use crate::core::menu_data::Menu;
 use crate::core::state::{SF_FOCUSED, SF_SHADOW, SHADOW_ATTR, StateFlags, shadow_size};
 use crate::terminal::Terminal;
 use std::io;
@@ -80,6 +81,17 @@ pub trait View {
         self.get_state_flag(SF_FOCUSED)
     }
 
+    /// Get the grow mode flags (`GF_GROW_LO_X`, `GF_GROW_HI_Y`, etc.) a group uses
+    /// when it is resized. Groups copy this when the view is added; change it
+    /// afterwards with `Group::set_grow_mode()`.
+    /// Matches Borland: TView::growMode
+    ///
+    /// Default follows the owner's size (`GF_GROW_HI_X | GF_GROW_HI_Y`), which is
+    /// how groups resized every child before grow modes existed.
+    fn grow_mode(&self) -> crate::core::state::GrowMode {
+        crate::core::state::GF_GROW_HI_X | crate::core::state::GF_GROW_HI_Y
+    }
+
     /// Get view option flags (OF_SELECTABLE, OF_PRE_PROCESS, OF_POST_PROCESS, etc.)
     fn options(&self) -> u16 {
         0
@@ -334,6 +346,93 @@ pub trait View {
         None // Default: not a label or no link
     }
 
+    /// Get the currently focused child of a container view, with its `ViewId`
+    /// Matches Borland: TGroup::current
+    ///
+    /// Containers (Group, Window, Dialog, Desktop and the views wrapping them)
+    /// return their focused child so callers can walk the focus chain down to
+    /// the focused leaf control. Default implementation returns None (no children).
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        None
+    }
+
+    /// Get the direct subviews of a container view, in Z-order, with their `ViewId`s
+    /// Matches Borland: iterating TGroup's subview list (TGroup::forEach)
+    ///
+    /// Default implementation returns an empty list (no children).
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        Vec::new()
+    }
+
+    /// Mutable counterpart of [`subviews`](View::subviews)
+    ///
+    /// Implemented by the containers a dialog's controls can live in
+    /// (`Group`, `Window`, `Dialog`, `TabView`) so a data record reaches
+    /// nested controls.
+    fn subviews_mut(&mut self) -> Vec<(ViewId, &mut dyn View)> {
+        Vec::new()
+    }
+
+    /// Focus the view `id` somewhere below this one, making each container
+    /// on the way current (a tab view shows the page holding it)
+    /// Matches Borland: `TView::select()` on a nested subview
+    ///
+    /// Returns false if `id` is not below this view. Default implementation
+    /// returns false (no children).
+    fn focus_subview(&mut self, _id: ViewId) -> bool {
+        false
+    }
+
+    /// Describe this view for assistive technology (role, label and value)
+    /// See [`accessibility`](super::accessibility). Default implementation
+    /// returns None (purely decorative view, e.g. frame or background).
+    fn accessible(&self) -> Option<super::accessibility::AccessibleInfo> {
+        None
+    }
+
+    /// Key binding context this view adds while it is on the focus chain
+    /// See [`keymap`](crate::core::keymap). Default implementation returns
+    /// None (the view only sees the contexts of its owners).
+    fn key_context(&self) -> Option<crate::core::keymap::KeyContext> {
+        None
+    }
+
+    /// Context menu for a right-click at `local` (relative to the view's
+    /// top-left corner), or for the Menu key with `local` at (0, 0)
+    /// Matches magiblot: `popupMenu()` opened from a view's `handleEvent()`
+    ///
+    /// The picked command is sent straight to this view, whether or not it has
+    /// the focus; if the view leaves it unhandled, it goes on to the owners
+    /// like any other command. Default implementation returns None (no
+    /// context menu; the owner's is used instead).
+    fn context_menu(&self, _local: Point) -> Option<Menu> {
+        None
+    }
+
+    /// Number of this window, for Alt+1..9 and the window list
+    /// Matches Borland: `TWindow::number` - default implementation returns
+    /// `WN_NO_NUMBER` (0: not a numbered window)
+    fn window_number(&self) -> u8 {
+        super::window::WN_NO_NUMBER
+    }
+
+    /// Give this window a number, see [`Desktop::add`](super::desktop::Desktop::add)
+    fn set_window_number(&mut self, _number: u8) {
+        // Default: only windows are numbered
+    }
+
+    /// Whether the view holds changes that are not saved yet (e.g. an edit
+    /// window), marked in the window list. Default implementation returns false
+    fn is_modified(&self) -> bool {
+        false
+    }
+
+    /// Get the concrete type name of this view (e.g. `turbo_vision::views::button::Button`)
+    /// Used for diagnostics and automation, where views have no other name
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
     /// Initialize internal owner pointers after view is added to parent and won't move
     /// This is called by parent's add() method after the view is in its final position
     /// Views that contain other views by value should override this to set up owner chains
@@ -349,6 +448,38 @@ pub trait View {
         // Default: no action needed (only windows need this)
     }
 
+    /// Value of this control for a dialog's data record
+    /// Matches Borland: `TView::getData()` - see [`dialog_data`](super::dialog_data)
+    /// Default implementation returns None (the view carries no data)
+    fn get_data(&self) -> Option<super::dialog_data::FieldValue> {
+        None
+    }
+
+    /// Show a value from a dialog's data record
+    /// Matches Borland: `TView::setData()` - values of the wrong kind are ignored
+    fn set_data(&mut self, _value: &super::dialog_data::FieldValue) {
+        // Default: the view carries no data
+    }
+
+    /// Why `valid()` failed, for showing next to the control in a form
+    /// Default implementation returns None (no message)
+    fn error_message(&self) -> Option<String> {
+        None
+    }
+
+    /// Describe this view's state for saving the desktop
+    /// Matches Borland: `TStreamable::write()` - see [`desktop_state`](super::desktop_state)
+    /// Default implementation returns None (the view is not saved)
+    fn save_state(&self) -> Option<super::desktop_state::ViewState> {
+        None
+    }
+
+    /// Apply a state recorded by `save_state()`, except the bounds
+    /// Matches Borland: `TStreamable::read()` - the owner sets the bounds first
+    fn restore_state(&mut self, _state: &super::desktop_state::ViewState) {
+        // Default: nothing to restore
+    }
+
     /// Set the QCell-based palette chain node for this view.
     /// Called by parent (Group/Window) during draw to establish the safe owner chain.
     fn set_palette_chain(&mut self, _node: Option<crate::core::palette_chain::PaletteChainNode>) {
@@ -475,6 +606,143 @@ pub trait IdleView: View {
     fn idle(&mut self);
 }
 
+thread_local! {
+    static REDRAW_REQUESTED: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Ask for the screen to be redrawn after the current idle pass
+///
+/// Views that change on the `CM_IDLE` broadcast (animations, data loaded in
+/// the background) call this instead of clearing the broadcast, so every view
+/// gets its idle time.
+pub fn request_redraw() {
+    REDRAW_REQUESTED.with(|requested| requested.set(true));
+}
+
+/// Whether a redraw was requested since the last call; clears the request
+pub fn take_redraw_request() -> bool {
+    REDRAW_REQUESTED.with(|requested| requested.replace(false))
+}
+
+/// Follow the focus chain down from `root` via [`View::current`]
+///
+/// Returns the focused child of `root`, its focused child, and so on, each with
+/// its `ViewId`; the last entry is the focused leaf. Empty if `root` has no children.
+pub fn focus_path(root: &dyn View) -> Vec<(ViewId, &dyn View)> {
+    let mut path = Vec::new();
+    let mut next = root.current();
+    while let Some((id, view)) = next {
+        path.push((id, view));
+        next = view.current();
+    }
+    path
+}
+
+/// True if the view `id` is somewhere below `root`, following [`View::subviews`]
+pub fn contains_view(root: &dyn View, id: ViewId) -> bool {
+    root.subviews()
+        .into_iter()
+        .any(|(child_id, child)| child_id == id || contains_view(child, id))
+}
+
+/// The view `id` somewhere below `root`, following [`View::subviews_mut`]
+pub fn find_view_mut(root: &mut dyn View, id: ViewId) -> Option<&mut dyn View> {
+    for (child_id, child) in root.subviews_mut() {
+        if child_id == id {
+            return Some(child);
+        }
+        if let Some(found) = find_view_mut(child, id) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+/// Key binding contexts active under `root`, innermost first
+///
+/// The [`key_context`](View::key_context)s of `root` and of the focus chain
+/// below it, ending with [`KeyContext::Global`](crate::core::keymap::KeyContext::Global).
+pub fn key_contexts(root: &dyn View) -> Vec<crate::core::keymap::KeyContext> {
+    use crate::core::keymap::KeyContext;
+
+    let mut contexts = vec![KeyContext::Global];
+    let chain = std::iter::once(root).chain(focus_path(root).into_iter().map(|(_, view)| view));
+    for context in chain.filter_map(View::key_context) {
+        if !contexts.contains(&context) {
+            contexts.insert(0, context);
+        }
+    }
+    contexts
+}
+
+/// Views under the screen position `pos`, from the topmost child of `root`
+/// down to the innermost view, each with its `ViewId`
+///
+/// Follows [`View::subviews`], taking the topmost child containing `pos` at
+/// each level. Empty if no child of `root` contains `pos`.
+pub fn views_at(root: &dyn View, pos: Point) -> Vec<(ViewId, &dyn View)> {
+    let mut path = Vec::new();
+    let mut next = root
+        .subviews()
+        .into_iter()
+        .rev()
+        .find(|(_, view)| view.bounds().contains(pos));
+    while let Some((id, view)) = next {
+        path.push((id, view));
+        next = view
+            .subviews()
+            .into_iter()
+            .rev()
+            .find(|(_, child)| child.bounds().contains(pos));
+    }
+    path
+}
+
+/// The context menu to open under `root`, with the screen position to open it
+/// at and the `ViewId` of the view it belongs to (None for `root` itself)
+///
+/// For a right-click at `pos`, the menu of the innermost view under the mouse
+/// that has one ([`View::context_menu`]); without a position (Menu key), that
+/// of the innermost view on the focus chain, opened at its top-left corner.
+pub fn find_context_menu(
+    root: &dyn View,
+    pos: Option<Point>,
+) -> Option<(Menu, Point, Option<ViewId>)> {
+    let path = match pos {
+        Some(pos) => views_at(root, pos),
+        None => focus_path(root),
+    };
+    std::iter::once((None, root))
+        .chain(path.into_iter().map(|(id, view)| (Some(id), view)))
+        .rev()
+        .find_map(|(id, view)| {
+            let origin = view.bounds().a;
+            let at = pos.unwrap_or(origin);
+            view.context_menu(Point::new(at.x - origin.x, at.y - origin.y))
+                .map(|menu| (menu, at, id))
+        })
+}
+
+/// Send the command picked in a context menu to the view `id` below `root`
+/// that asked for the menu (see [`find_context_menu`])
+///
+/// Returns the event, cleared if the view handled it. Nothing is sent if
+/// `id` is None or no longer below `root`.
+pub fn send_context_command(root: &mut dyn View, id: Option<ViewId>, command: CommandId) -> Event {
+    let mut event = Event::command(command);
+    if let Some(view) = id.and_then(|id| find_view_mut(root, id)) {
+        view.handle_event(&mut event);
+    }
+    event
+}
+
+/// Last path segment of a [`View::type_name`], without generic arguments
+/// (e.g. `Button` for `turbo_vision::views::button::Button`)
+pub fn short_type_name(type_name: &str) -> &str {
+    let base = type_name.split('<').next().unwrap_or(type_name);
+    base.rsplit("::").next().unwrap_or(base)
+}
+
 /// Helper to draw a line to the terminal
 pub fn write_line_to_terminal(terminal: &mut Terminal, x: i16, y: i16, buf: &DrawBuffer) {
     if y < 0 || y >= terminal.size().1 {
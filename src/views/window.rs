@@ -2,6 +2,7 @@
 
 //! Window view - draggable, resizable window with frame and shadow.
 
+use super::desktop_state::ViewState;
 use super::frame::Frame;
 use super::group::Group;
 use super::view::{View, ViewId};
@@ -11,6 +12,10 @@ use crate::core::geometry::{Point, Rect};
 use crate::core::state::{SF_DRAGGING, SF_MODAL, SF_RESIZING, SF_SHADOW, StateFlags, shadow_size};
 use crate::terminal::Terminal;
 
+/// Number of a window that has none
+/// Matches Borland: `wnNoNumber`
+pub const WN_NO_NUMBER: u8 = 0;
+
 pub struct Window {
     bounds: Rect,
     frame: Frame,
@@ -48,6 +53,8 @@ pub struct Window {
     /// bubbles up uncleared and the owner is responsible for both the
     /// validation and the eventual `set_state(SF_CLOSED)`.
     auto_close: bool,
+    /// Kind under which the window is saved with the desktop (None: not saved)
+    state_kind: Option<String>,
 }
 
 #[derive(Clone, Copy)]
@@ -143,6 +150,7 @@ impl Window {
             custom_palette: None,
             explicit_drag_limits: None,
             auto_close: true,
+            state_kind: None,
         };
 
         window
@@ -213,6 +221,25 @@ impl Window {
         self.frame.set_title(title);
     }
 
+    /// Get the window title
+    pub fn get_title(&self) -> &str {
+        self.frame.get_title()
+    }
+
+    /// Set the number shown in the frame; Alt+1..9 selects windows 1 to 9
+    /// Matches Borland: `TWindow::number` (`WN_NO_NUMBER` for none)
+    ///
+    /// [`Desktop::add`](super::desktop::Desktop::add) numbers windows that
+    /// have none, so this is only needed to pick a specific number.
+    pub fn set_number(&mut self, number: u8) {
+        self.frame.set_number(number);
+    }
+
+    /// Get the number shown in the frame
+    pub fn number(&self) -> u8 {
+        self.frame.number()
+    }
+
     /// Set whether the window is resizable.
     /// Resizable windows show single-line bottom corners and a resize handle.
     pub fn set_resizable(&mut self, resizable: bool) {
@@ -236,6 +263,51 @@ impl Window {
         self.auto_close = auto_close;
     }
 
+    /// Save this window with the desktop under `kind`
+    ///
+    /// Windows without a kind are left out of
+    /// [`Desktop::save_state`](super::desktop::Desktop::save_state). The
+    /// title, the size before a zoom and the state of the children (e.g. a
+    /// `TerminalWidget`) are saved; register a factory for `kind` in
+    /// [`WindowFactories`](super::desktop_state::WindowFactories) that builds
+    /// the same children to restore it.
+    pub fn set_state_kind(&mut self, kind: &str) {
+        self.state_kind = Some(kind.to_string());
+    }
+
+    /// Record the title, zoom and children state under `kind`
+    pub(crate) fn save_window_state(&self, kind: &str) -> ViewState {
+        let mut state = ViewState::new(kind, self.bounds);
+        state.set("title", self.get_title());
+        let zoomed = self
+            .explicit_drag_limits
+            .is_some_and(|limits| limits.size() == self.bounds.size());
+        if zoomed && self.zoom_rect != self.bounds {
+            state.set_rect("restore", self.zoom_rect);
+        }
+        for index in 0..self.interior.len() {
+            if let Some(child) = self.interior.child_at(index).save_state() {
+                state.add_child(index, child);
+            }
+        }
+        state
+    }
+
+    /// Apply what `save_window_state()` recorded
+    pub(crate) fn restore_window_state(&mut self, state: &ViewState) {
+        if let Some(title) = state.get("title") {
+            self.set_title(title);
+        }
+        if let Some(restore) = state.get_rect("restore") {
+            self.zoom_rect = restore;
+        }
+        for (index, child) in state.children() {
+            if *index < self.interior.len() {
+                self.interior.child_at_mut(*index).restore_state(child);
+            }
+        }
+    }
+
     /// Set minimum window size (matches Borland: minWinSize)
     /// Prevents window from being resized smaller than these dimensions
     pub fn set_min_size(&mut self, min_size: Point) {
@@ -356,12 +428,24 @@ impl Window {
         self.interior.child_by_id_mut(view_id)
     }
 
+    /// Focus the child with the given `ViewId`
+    /// Returns false if it is not found or cannot take the focus
+    pub fn focus_by_view_id(&mut self, view_id: ViewId) -> bool {
+        self.interior.focus_by_view_id(view_id)
+    }
+
     /// Remove a child by its ViewId
     /// Returns true if a child was found and removed, false otherwise
     pub fn remove_by_id(&mut self, view_id: ViewId) -> bool {
         self.interior.remove_by_id(view_id)
     }
 
+    /// Change how a child follows the window when it is resized (`GF_*` flags)
+    /// Returns false if the `ViewId` is not found
+    pub fn set_grow_mode(&mut self, view_id: ViewId, mode: crate::core::state::GrowMode) -> bool {
+        self.interior.set_grow_mode(view_id, mode)
+    }
+
     /// Get the union rect of current and previous bounds (for redrawing)
     /// Matches Borland: TView::locate() calculates union rect
     /// Returns None if window hasn't moved yet
@@ -427,6 +511,35 @@ impl View for Window {
         self.bounds
     }
 
+    fn accessible(&self) -> Option<crate::views::accessibility::AccessibleInfo> {
+        use crate::views::accessibility::{AccessibleInfo, Role};
+        Some(AccessibleInfo::new(Role::Window, self.get_title()))
+    }
+
+    fn window_number(&self) -> u8 {
+        self.number()
+    }
+
+    fn set_window_number(&mut self, number: u8) {
+        self.set_number(number);
+    }
+
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.interior.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.interior.subviews()
+    }
+
+    fn subviews_mut(&mut self) -> Vec<(ViewId, &mut dyn View)> {
+        self.interior.subviews_mut()
+    }
+
+    fn focus_subview(&mut self, id: ViewId) -> bool {
+        self.interior.focus_subview(id)
+    }
+
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
         self.frame.set_bounds(bounds);
@@ -770,6 +883,15 @@ impl View for Window {
         self.constrain_to_limits();
     }
 
+    fn save_state(&self) -> Option<ViewState> {
+        let kind = self.state_kind.as_deref()?;
+        Some(self.save_window_state(kind))
+    }
+
+    fn restore_state(&mut self, state: &ViewState) {
+        self.restore_window_state(state);
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -933,4 +1055,16 @@ mod tests {
             .build();
         assert_eq!(window.bounds(), Rect::new(5, 5, 40, 20));
     }
+
+    #[test]
+    fn test_title_stops_before_window_number() {
+        use crate::test_util::snapshot::Snapshot;
+
+        let mut window = Window::new(Rect::new(0, 0, 20, 3), "A rather long title");
+        let top = |window: &mut Window| Snapshot::of_view(window, 20, 3).text_lines()[0].clone();
+        assert_eq!(top(&mut window), "╔═[■]═ A rather lo ╗");
+
+        window.set_number(3);
+        assert_eq!(top(&mut window), "╔═[■]═ A rather ═3═╗");
+    }
 }
@@ -0,0 +1,359 @@
+// (C) 2026 - Enzo Lombardi
+
+//! Window list - activate or close desktop windows from a list.
+//!
+//! Every window on the desktop is listed, topmost first, with its number, a
+//! `*` when it holds unsaved changes and its title. Enter, a double click or
+//! the Switch To button brings the focused window to the front. Space,
+//! Ctrl+click and Shift+click mark several windows for the Close button.
+//!
+//! [`Application::show_window_list`](crate::app::Application::show_window_list)
+//! opens it on `CM_WINDOW_LIST` (Alt+0) and carries out the picked action.
+
+use super::ViewId;
+use super::accessibility::AccessibleInfo;
+use super::button::Button;
+use super::desktop::Desktop;
+use super::dialog::Dialog;
+use super::label::Label;
+use super::list_viewer::ListViewer;
+use super::listbox::ListBox;
+use super::view::View;
+use super::window::WN_NO_NUMBER;
+use crate::core::command::{CM_CANCEL, CM_OK, CommandId};
+use crate::core::event::Event;
+use crate::core::geometry::Rect;
+use crate::core::i18n::{text_width, tr};
+use crate::core::state::StateFlags;
+use crate::terminal::Terminal;
+
+// Ends the dialog to close the marked windows
+const CM_CLOSE_WINDOWS: CommandId = 210;
+
+/// One desktop window offered by the list
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WindowListEntry {
+    pub view_id: ViewId,
+    /// Window number (`WN_NO_NUMBER` for none)
+    pub number: u8,
+    pub title: String,
+    /// True if the window holds unsaved changes
+    pub modified: bool,
+}
+
+impl WindowListEntry {
+    /// Text shown in the list, such as `" 1 *notes.txt"`
+    pub fn text(&self) -> String {
+        let number = match self.number {
+            WN_NO_NUMBER => String::new(),
+            number => number.to_string(),
+        };
+        let mark = if self.modified { '*' } else { ' ' };
+        format!("{number:>2} {mark}{}", self.title)
+    }
+}
+
+/// Collect the windows of a desktop, topmost first
+pub fn window_entries(desktop: &Desktop) -> Vec<WindowListEntry> {
+    desktop
+        .subviews()
+        .into_iter()
+        .skip(1) // The background
+        .rev()
+        .map(|(view_id, window)| WindowListEntry {
+            view_id,
+            number: window.window_number(),
+            title: window
+                .accessible()
+                .map(|info| info.label)
+                .unwrap_or_default(),
+            modified: window.is_modified(),
+        })
+        .collect()
+}
+
+/// What the user picked in a [`WindowListDialog`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WindowListAction {
+    /// Bring this window to the front
+    Activate(ViewId),
+    /// Close these windows
+    Close(Vec<ViewId>),
+}
+
+/// Window list dialog
+pub struct WindowListDialog {
+    dialog: Dialog,
+    entries: Vec<WindowListEntry>,
+    list_id: ViewId,
+}
+
+impl WindowListDialog {
+    /// Create a dialog listing `entries`, the first one focused
+    pub fn new(bounds: Rect, title: &str, entries: Vec<WindowListEntry>) -> Self {
+        let switch_text = tr("~S~witch to");
+        let close_text = tr("~C~lose");
+        let cancel_text = tr("Cancel");
+        let button_width = [&switch_text, &close_text, &cancel_text]
+            .iter()
+            .map(|text| text_width(text) + 4)
+            .max()
+            .unwrap_or(12)
+            .max(12);
+
+        let mut dialog = Dialog::new(bounds, title);
+        let width = bounds.width();
+        let height = bounds.height();
+        let button_x = width - button_width - 2;
+
+        let mut list = ListBox::new(Rect::new(2, 2, button_x - 2, height - 2), CM_OK);
+        list.set_items(entries.iter().map(WindowListEntry::text).collect());
+        list.set_multi_select(true);
+        let list_id = dialog.add(Box::new(list));
+
+        let label_text = tr("~W~indows");
+        let mut label = Label::new(Rect::new(2, 1, 4 + text_width(&label_text), 2), &label_text);
+        label.set_link(list_id);
+        dialog.add(Box::new(label));
+
+        let buttons = [
+            (switch_text, CM_OK, true),
+            (close_text, CM_CLOSE_WINDOWS, false),
+            (cancel_text, CM_CANCEL, false),
+        ];
+        for (row, (text, command, is_default)) in (0..).zip(buttons) {
+            let y = 2 + row * 3;
+            let button_bounds = Rect::new(button_x, y, button_x + button_width, y + 2);
+            dialog.add(Box::new(Button::new(
+                button_bounds,
+                &text,
+                command,
+                is_default,
+            )));
+        }
+        dialog.set_initial_focus();
+
+        Self {
+            dialog,
+            entries,
+            list_id,
+        }
+    }
+
+    /// Execute the dialog modally
+    ///
+    /// Returns the picked action, or None if the dialog was cancelled
+    pub fn execute(&mut self, app: &mut crate::app::Application) -> Option<WindowListAction> {
+        let command = self.dialog.execute(app);
+        self.action(command)
+    }
+
+    /// The action of the button that ended the dialog: Switch To activates
+    /// the focused window, Close closes the marked windows (or the focused one)
+    fn action(&self, command: CommandId) -> Option<WindowListAction> {
+        let list = self
+            .dialog
+            .child_by_id(self.list_id)?
+            .as_any()
+            .downcast_ref::<ListBox>()?;
+        let view_id = |index: usize| self.entries.get(index).map(|entry| entry.view_id);
+        match command {
+            CM_OK => list
+                .get_selection()
+                .and_then(view_id)
+                .map(WindowListAction::Activate),
+            CM_CLOSE_WINDOWS => {
+                let mut indices = list.selected_indices();
+                if indices.is_empty() {
+                    indices.extend(list.get_selection());
+                }
+                let view_ids: Vec<ViewId> = indices.into_iter().filter_map(view_id).collect();
+                (!view_ids.is_empty()).then_some(WindowListAction::Close(view_ids))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl View for WindowListDialog {
+    fn bounds(&self) -> Rect {
+        self.dialog.bounds()
+    }
+
+    fn current(&self) -> Option<(ViewId, &dyn View)> {
+        self.dialog.current()
+    }
+
+    fn subviews(&self) -> Vec<(ViewId, &dyn View)> {
+        self.dialog.subviews()
+    }
+
+    fn accessible(&self) -> Option<AccessibleInfo> {
+        self.dialog.accessible()
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.dialog.set_bounds(bounds);
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        self.dialog.draw(terminal);
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        self.dialog.handle_event(event);
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn state(&self) -> StateFlags {
+        self.dialog.state()
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.dialog.set_state(state);
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        self.dialog.get_palette()
+    }
+}
+
+/// Builder for creating window list dialogs with a fluent API.
+///
+/// # Examples
+///
+/// ```ignore
+/// use turbo_vision::views::window_list::{WindowListDialogBuilder, window_entries};
+/// use turbo_vision::core::geometry::Rect;
+///
+/// let dialog = WindowListDialogBuilder::new()
+///     .bounds(Rect::new(10, 3, 70, 18))
+///     .entries(window_entries(&app.desktop))
+///     .build();
+/// ```
+pub struct WindowListDialogBuilder {
+    bounds: Option<Rect>,
+    title: String,
+    entries: Vec<WindowListEntry>,
+}
+
+impl WindowListDialogBuilder {
+    /// Creates a new `WindowListDialogBuilder` with default values.
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            title: tr("Windows"),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Sets the dialog bounds (required).
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the dialog title (default: "Windows").
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Adds the given entries.
+    #[must_use]
+    pub fn entries(mut self, entries: Vec<WindowListEntry>) -> Self {
+        self.entries.extend(entries);
+        self
+    }
+
+    /// Builds the `WindowListDialog`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if bounds have not been set.
+    pub fn build(self) -> WindowListDialog {
+        let bounds = self.bounds.expect("WindowListDialog bounds must be set");
+        WindowListDialog::new(bounds, &self.title, self.entries)
+    }
+}
+
+impl Default for WindowListDialogBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::window::Window;
+
+    fn desktop_with_windows() -> (Desktop, Vec<ViewId>) {
+        let mut desktop = Desktop::new(Rect::new(0, 1, 80, 24));
+        let ids = ["One", "Two", "Three"]
+            .iter()
+            .map(|title| desktop.add(Box::new(Window::new(Rect::new(5, 5, 40, 15), title))))
+            .collect();
+        (desktop, ids)
+    }
+
+    fn list_mut(dialog: &mut WindowListDialog) -> &mut ListBox {
+        dialog
+            .dialog
+            .child_by_id_mut(dialog.list_id)
+            .and_then(|view| view.as_any_mut().downcast_mut::<ListBox>())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_window_entries() {
+        let (desktop, ids) = desktop_with_windows();
+        let entries = window_entries(&desktop);
+        let texts: Vec<String> = entries.iter().map(WindowListEntry::text).collect();
+        assert_eq!(texts, [" 3  Three", " 2  Two", " 1  One"]);
+        assert_eq!(entries[0].view_id, ids[2]);
+
+        let entry = WindowListEntry {
+            view_id: ids[0],
+            number: WN_NO_NUMBER,
+            title: "notes.txt".to_string(),
+            modified: true,
+        };
+        assert_eq!(entry.text(), "   *notes.txt");
+    }
+
+    #[test]
+    fn test_window_list_actions() {
+        let (desktop, ids) = desktop_with_windows();
+        let mut dialog = WindowListDialog::new(
+            Rect::new(10, 3, 60, 15),
+            "Windows",
+            window_entries(&desktop),
+        );
+
+        list_mut(&mut dialog).set_selection(1);
+        assert_eq!(
+            dialog.action(CM_OK),
+            Some(WindowListAction::Activate(ids[1]))
+        );
+        // Without marks Close takes the focused window
+        assert_eq!(
+            dialog.action(CM_CLOSE_WINDOWS),
+            Some(WindowListAction::Close(vec![ids[1]]))
+        );
+
+        let list = list_mut(&mut dialog);
+        list.list_state_mut().toggle_selected(0);
+        list.list_state_mut().toggle_selected(2);
+        assert_eq!(
+            dialog.action(CM_CLOSE_WINDOWS),
+            Some(WindowListAction::Close(vec![ids[2], ids[0]]))
+        );
+        assert_eq!(dialog.action(CM_CANCEL), None);
+    }
+}